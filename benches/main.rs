@@ -12,7 +12,7 @@ use _pydantic_core::{validate_core_schema, SchemaValidator};
 fn build_schema_validator_with_globals(py: Python, code: &str, globals: Option<&Bound<'_, PyDict>>) -> SchemaValidator {
     let mut schema = py.eval_bound(code, globals, None).unwrap().extract().unwrap();
     schema = validate_core_schema(&schema, None).unwrap().extract().unwrap();
-    SchemaValidator::py_new(py, &schema, None).unwrap()
+    SchemaValidator::py_new(py, &schema, None, None).unwrap()
 }
 
 fn build_schema_validator(py: Python, code: &str) -> SchemaValidator {
@@ -29,7 +29,20 @@ fn ints_json(bench: &mut Bencher) {
         let validator = build_schema_validator(py, "{'type': 'int'}");
 
         let result = validator
-            .validate_json(py, &json(py, "123"), None, None, None, false.into())
+            .validate_json(
+                py,
+                &json(py, "123"),
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                "float",
+                "document",
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -37,7 +50,20 @@ fn ints_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, "123"), None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &json(py, "123"),
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -51,7 +77,20 @@ fn ints_python(bench: &mut Bencher) {
 
         let input = 123_i64.into_py(py).into_bound(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -60,7 +99,20 @@ fn ints_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -79,7 +131,20 @@ fn list_int_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -104,7 +169,20 @@ fn list_int_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -138,7 +216,20 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, &json(py, &code), None, None, None, false.into()) {
+        match validator.validate_json(
+            py,
+            &json(py, &code),
+            None,
+            None,
+            None,
+            false.into(),
+            None,
+            None,
+            false,
+            false,
+            "float",
+            "document",
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value_bound(py);
@@ -149,12 +240,25 @@ fn list_error_json(bench: &mut Bencher) {
             }
         };
 
-        bench.iter(
-            || match validator.validate_json(py, &json(py, &code), None, None, None, false.into()) {
+        bench.iter(|| {
+            match validator.validate_json(
+                py,
+                &json(py, &code),
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                "float",
+                "document",
+            ) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
-            },
-        )
+            }
+        })
     })
 }
 
@@ -170,7 +274,20 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval_bound(&code, None, None).unwrap().extract().unwrap();
 
-    match validator.validate_python(py, &input, None, None, None, None, false.into()) {
+    match validator.validate_python(
+        py,
+        &input,
+        None,
+        None,
+        None,
+        None,
+        false.into(),
+        false,
+        false,
+        false,
+        None,
+        false,
+    ) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value_bound(py);
@@ -190,7 +307,20 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, false.into());
+            let result = validator.validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            );
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -226,7 +356,20 @@ fn list_any_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -245,7 +388,20 @@ fn list_any_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -279,7 +435,20 @@ fn dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -305,7 +474,20 @@ fn dict_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -334,7 +516,20 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval_bound(&code, None, None).unwrap().to_object(py).into_bound(py);
 
-        match validator.validate_python(py, &input, None, None, None, None, false.into()) {
+        match validator.validate_python(
+            py,
+            &input,
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            false,
+            false,
+            false,
+            None,
+            false,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value_bound(py);
@@ -347,7 +542,20 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, false.into());
+            let result = validator.validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            );
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -385,7 +593,20 @@ fn typed_dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -420,7 +641,20 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -461,7 +695,20 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval_bound(code, None, None).unwrap().to_object(py);
         let input = black_box(input.bind(py));
 
-        match validator.validate_python(py, &input, None, None, None, None, false.into()) {
+        match validator.validate_python(
+            py,
+            &input,
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            false,
+            false,
+            false,
+            None,
+            false,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value_bound(py);
@@ -473,7 +720,20 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, false.into());
+            let result = validator.validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            );
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -492,7 +752,7 @@ fn complete_model(bench: &mut Bencher) {
         let complete_schema = py.import_bound("complete_schema").unwrap();
         let mut schema = complete_schema.call_method0("schema").unwrap();
         schema = validate_core_schema(&schema, None).unwrap().extract().unwrap();
-        let validator = SchemaValidator::py_new(py, &schema, None).unwrap();
+        let validator = SchemaValidator::py_new(py, &schema, None, None).unwrap();
 
         let input = complete_schema.call_method0("input_data_lax").unwrap();
         let input = black_box(input);
@@ -500,7 +760,20 @@ fn complete_model(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             );
         })
@@ -516,19 +789,45 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let complete_schema = py.import_bound("nested_schema").unwrap();
         let mut schema = complete_schema.call_method0("schema_using_defs").unwrap();
         schema = validate_core_schema(&schema, None).unwrap().extract().unwrap();
-        let validator = SchemaValidator::py_new(py, &schema, None).unwrap();
+        let validator = SchemaValidator::py_new(py, &schema, None, None).unwrap();
 
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             );
         })
@@ -544,19 +843,45 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let complete_schema = py.import_bound("nested_schema").unwrap();
         let mut schema = complete_schema.call_method0("inlined_schema").unwrap();
         schema = validate_core_schema(&schema, None).unwrap().extract().unwrap();
-        let validator = SchemaValidator::py_new(py, &schema, None).unwrap();
+        let validator = SchemaValidator::py_new(py, &schema, None, None).unwrap();
 
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             );
         })
@@ -571,7 +896,20 @@ fn literal_ints_few_python(bench: &mut Bencher) {
         let input = 4_i64.into_py(py);
         let input = input.bind(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
@@ -580,7 +918,20 @@ fn literal_ints_few_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -596,7 +947,20 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -605,7 +969,20 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -624,7 +1001,20 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -633,7 +1023,20 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -668,7 +1071,20 @@ class Foo(Enum):
         let input = py.eval_bound("Foo.v4", Some(&globals), None).unwrap();
         let input = input.to_object(py).into_bound(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         assert!(input.eq(result).unwrap());
 
@@ -676,7 +1092,20 @@ class Foo(Enum):
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -690,7 +1119,20 @@ fn literal_ints_many_python(bench: &mut Bencher) {
 
         let input = 99_i64.into_py(py).into_bound(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -699,7 +1141,20 @@ fn literal_ints_many_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -715,7 +1170,20 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -724,7 +1192,20 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -743,7 +1224,20 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         let input = input.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into())
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -752,7 +1246,20 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into())
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        false,
+                        false,
+                        false,
+                        None,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -767,7 +1274,20 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         let input_json = py.eval_bound("'99'", None, None).unwrap();
         let input_json = input_json.to_object(py).into_bound(py);
         let result = validator
-            .validate_json(py, &input_json, None, None, None, false.into())
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                "float",
+                "document",
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -776,7 +1296,20 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -797,7 +1330,20 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input_json = input_json.to_object(py).into_bound(py);
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, false.into())
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                "float",
+                "document",
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -806,7 +1352,20 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, false.into())
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        "float",
+                        "document",
+                    )
                     .unwrap(),
             )
         })
@@ -843,7 +1402,20 @@ class Foo(Enum):
             let input = input.to_object(py).into_bound(py);
             let input_str: String = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
@@ -852,7 +1424,20 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into())
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            false,
+                            false,
+                            false,
+                            None,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
@@ -864,7 +1449,20 @@ class Foo(Enum):
             let input = input.to_object(py).into_bound(py);
             let input_int: i64 = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
@@ -873,7 +1471,20 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into())
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            false,
+                            false,
+                            false,
+                            None,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
@@ -884,7 +1495,20 @@ class Foo(Enum):
             let input = py.eval_bound("None", None, None).unwrap();
             let input = input.to_object(py).into_bound(py);
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -892,7 +1516,20 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into())
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            false,
+                            false,
+                            false,
+                            None,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
@@ -903,7 +1540,20 @@ class Foo(Enum):
             let input = py.eval_bound("Foo.v4", Some(&globals), None).unwrap();
             let input = input.to_object(py).into_bound(py);
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into())
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    false,
+                    false,
+                    false,
+                    None,
+                    false,
+                )
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -911,7 +1561,20 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into())
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            false,
+                            false,
+                            false,
+                            None,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
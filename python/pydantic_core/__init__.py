@@ -5,6 +5,8 @@ from typing import Any as _Any
 
 from ._pydantic_core import (
     ArgsKwargs,
+    ErrorDetail,
+    InvalidValue,
     MultiHostUrl,
     PydanticCustomError,
     PydanticKnownError,
@@ -14,15 +16,21 @@ from ._pydantic_core import (
     PydanticUndefined,
     PydanticUndefinedType,
     PydanticUseDefault,
+    RawJson,
     SchemaError,
     SchemaSerializer,
     SchemaValidator,
+    Secret,
     Some,
     TzInfo,
     Url,
     ValidationError,
     __version__,
+    format_loc,
     from_json,
+    is_known_error_type,
+    register_error_messages,
+    set_include_url,
     to_json,
     to_jsonable_python,
     validate_core_schema,
@@ -47,6 +55,8 @@ __all__ = [
     'SchemaValidator',
     'SchemaSerializer',
     'Some',
+    'Secret',
+    'RawJson',
     'Url',
     'MultiHostUrl',
     'ArgsKwargs',
@@ -54,6 +64,8 @@ __all__ = [
     'PydanticUndefinedType',
     'SchemaError',
     'ErrorDetails',
+    'ErrorDetail',
+    'InvalidValue',
     'InitErrorDetails',
     'ValidationError',
     'PydanticCustomError',
@@ -67,6 +79,10 @@ __all__ = [
     'from_json',
     'to_jsonable_python',
     'validate_core_schema',
+    'set_include_url',
+    'is_known_error_type',
+    'register_error_messages',
+    'format_loc',
 ]
 
 
@@ -78,8 +94,16 @@ class ErrorDetails(_TypedDict):
 
     `type` is unique for each error message, and can hence be used as an identifier to build custom error messages.
     """
-    loc: tuple[int | str, ...]
-    """Tuple of strings and ints identifying where in the schema the error occurred."""
+    code: _NotRequired[str]
+    """
+    A short stable code for the error, only included when `include_code=True`; this defaults to `type`
+    but can be set explicitly for custom errors.
+    """
+    loc: tuple[int | str | tuple[_Any, ...], ...]
+    """
+    Tuple of strings and ints identifying where in the schema the error occurred, compound keys
+    (e.g. tuple dict keys) are included as nested tuples.
+    """
     msg: str
     """A human readable error message."""
     input: _Any
@@ -89,12 +113,17 @@ class ErrorDetails(_TypedDict):
     Values which are required to render the error message, and could hence be useful in rendering custom error messages.
     Also useful for passing custom error data forward.
     """
+    source: _NotRequired[dict[str, _Any]]
+    """
+    Only included when `include_source=True` for errors which come from a nested `ValidationError`,
+    the `title` of that error and the `loc_prefix` it was found at.
+    """
 
 
 class InitErrorDetails(_TypedDict):
     type: str | PydanticCustomError
     """The type of error that occurred, this should a "slug" identifier that changes rarely or never."""
-    loc: _NotRequired[tuple[int | str, ...]]
+    loc: _NotRequired[tuple[int | str | tuple[_Any, ...], ...]]
     """Tuple of strings and ints identifying where in the schema the error occurred."""
     input: _Any
     """The input data at this `loc` that caused the error."""
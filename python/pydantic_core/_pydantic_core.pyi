@@ -1,8 +1,8 @@
 import datetime
-from collections.abc import Mapping
+from collections.abc import Iterable, Iterator, Mapping
 from typing import Any, Callable, Generic, Literal, TypeVar, final
 
-from _typeshed import SupportsAllComparisons
+from _typeshed import SupportsAllComparisons, SupportsWrite
 from typing_extensions import LiteralString, Self, TypeAlias
 
 from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
@@ -20,6 +20,8 @@ __all__ = [
     'MultiHostUrl',
     'SchemaError',
     'ValidationError',
+    'InvalidValue',
+    'ErrorDetail',
     'PydanticCustomError',
     'PydanticKnownError',
     'PydanticOmit',
@@ -29,10 +31,15 @@ __all__ = [
     'PydanticUndefined',
     'PydanticUndefinedType',
     'Some',
+    'Secret',
     'to_json',
     'from_json',
     'to_jsonable_python',
     'list_all_errors',
+    'is_known_error_type',
+    'set_include_url',
+    'register_error_messages',
+    'format_loc',
     'TzInfo',
     'validate_core_schema',
 ]
@@ -64,6 +71,51 @@ class Some(Generic[_T]):
     @classmethod
     def __class_getitem__(cls, item: Any, /) -> type[Self]: ...
 
+@final
+class Secret(Generic[_T]):
+    """
+    Wraps a sensitive value, e.g. a password, so it isn't shown by `repr()` or `str()`, or when serialized
+    unless `dump_secret_values=True` is passed, see [`secret_schema`][pydantic_core.core_schema.secret_schema].
+
+    Secrets compare and hash by their wrapped value, and `len()` returns the length of the wrapped value.
+    """
+
+    def __init__(self, value: _T) -> None: ...
+    def get_secret_value(self) -> _T:
+        """
+        Returns the value wrapped by `Secret`.
+        """
+    def __len__(self) -> int:
+        """
+        Returns the length of the wrapped value, e.g. for `str` and `bytes` secrets.
+        """
+    @classmethod
+    def __class_getitem__(cls, item: Any, /) -> type[Self]: ...
+
+@final
+class RawJson(Generic[_T]):
+    """
+    The output of a [`json_schema`][pydantic_core.core_schema.json_schema] with `keep_raw=True`, holds the
+    validated value along with the exact JSON text it was parsed from.
+
+    Serializing to JSON emits the original text unchanged, so signed payloads round-trip byte for byte, unless the
+    text isn't strict JSON, e.g. it has `NaN` or `Infinity` literals, in which case the value is serialized instead.
+    """
+
+    def __init__(self, value: _T, raw: str) -> None: ...
+    @property
+    def value(self) -> _T:
+        """
+        Returns the validated value.
+        """
+    @property
+    def raw(self) -> str:
+        """
+        Returns the JSON text the value was parsed from.
+        """
+    @classmethod
+    def __class_getitem__(cls, item: Any, /) -> type[Self]: ...
+
 @final
 class SchemaValidator:
     """
@@ -74,15 +126,23 @@ class SchemaValidator:
     # note: pyo3 currently supports __new__, but not __init__, though we include __init__ stubs
     # and docstrings here (and in the following classes) for documentation purposes
 
-    def __init__(self, schema: CoreSchema, config: CoreConfig | None = None) -> None:
+    def __init__(
+        self, schema: CoreSchema, config: CoreConfig | None = None, default_context: dict[str, Any] | None = None
+    ) -> None:
         """Initializes the `SchemaValidator`.
 
         Arguments:
             schema: The `CoreSchema` to use for validation.
             config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure validation.
+            default_context: Optionally a dict of context used by every validation call, the `context` passed to
+                a call is shallow-merged on top of it, with keys from the call taking precedence. Validators
+                see a fresh copy of the merged dict on each call, a per-call context which isn't a dict replaces
+                the defaults entirely.
         """
 
-    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self: ...
+    def __new__(
+        cls, schema: CoreSchema, config: CoreConfig | None = None, default_context: dict[str, Any] | None = None
+    ) -> Self: ...
     @property
     def title(self) -> str:
         """
@@ -97,6 +157,11 @@ class SchemaValidator:
         context: Any | None = None,
         self_instance: Any | None = None,
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
+        partial: bool = False,
+        mark_invalid: bool = False,
+        return_fields_set: bool = False,
+        on_validator_event: Callable[[Literal['enter', 'exit'], str, tuple[int | str, ...]], None] | None = None,
+        return_validator_stats: bool = False,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -114,13 +179,31 @@ class SchemaValidator:
             allow_partial: Whether to allow partial validation; if `True` errors in the last element of sequences
                 and mappings are ignored.
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
+            partial: Whether to validate typed dicts as if all their keys were optional; if `True` missing keys
+                are left out of the result, without raising `missing` errors or filling in defaults.
+            mark_invalid: Whether to keep going when model or typed dict fields fail validation, if `True` each
+                invalid field is set to an [`InvalidValue`][pydantic_core.InvalidValue] holding its input and errors
+                rather than raising, e.g. to save partially complete form data, see `inline_errors` on
+                [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json]. Unions only use a choice with
+                invalid fields if no choice is fully valid. This is separate from `allow_partial`, which drops
+                errors from the incomplete end of the input rather than marking them, the two can be combined.
+            return_fields_set: Whether to return `(value, fields_set)` where `fields_set` is the set of field names
+                provided by the input rather than defaulted, e.g. for building diffs. This only applies when the
+                schema is a model or typed dict, for other schemas the validated object is returned as usual.
+            on_validator_event: Called with `('enter', name, location)` as validation enters each validator and
+                `('exit', name, location)` as it leaves, whether or not validation succeeded, `name` being the
+                validator name used in error locations and titles and `location` the validator's location in the
+                input like the `loc` of errors, e.g. for tracing where validation time is spent.
+            return_validator_stats: Whether to return a `(value, stats)` tuple, `stats` being a dict of how many times
+                each validator ran, keyed by the same names as `on_validator_event`, in the order they first ran.
+                Validators that were tried but failed, e.g. union choices, are counted too.
 
         Raises:
             ValidationError: If validation fails.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated object.
+            The validated object, or a tuple of it and its fields set if `return_fields_set` applies.
         """
     def isinstance_python(
         self,
@@ -148,6 +231,12 @@ class SchemaValidator:
         context: Any | None = None,
         self_instance: Any | None = None,
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
+        max_input_size: int | None = None,
+        on_progress: Callable[[int], None] | None = None,
+        partial: bool = False,
+        mark_invalid: bool = False,
+        json_float_mode: Literal['float', 'decimal'] = 'float',
+        json_mode: Literal['document', 'concatenated'] = 'document',
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -168,6 +257,25 @@ class SchemaValidator:
             allow_partial: Whether to allow partial validation; if `True` incomplete JSON will be parsed successfully
                 and errors in the last element of sequences and mappings are ignored.
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
+            max_input_size: The maximum size of the JSON input in bytes, larger inputs are rejected with a
+                `json_too_large` error before being parsed.
+            on_progress: A function called periodically while the JSON is parsed with the number of bytes
+                consumed so far, and once more with the total length when parsing completes,
+                e.g. to report progress for large payloads. Cannot be used with `allow_partial`.
+            partial: Whether to validate typed dicts as if all their keys were optional; if `True` missing keys
+                are left out of the result, without raising `missing` errors or filling in defaults.
+            mark_invalid: Whether to keep going when model or typed dict fields fail validation, if `True` each
+                invalid field is set to an [`InvalidValue`][pydantic_core.InvalidValue] holding its input and errors
+                rather than raising, e.g. to save partially complete form data, see `inline_errors` on
+                [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json]. Unions only use a choice with
+                invalid fields if no choice is fully valid. This is separate from `allow_partial`, which drops
+                errors from the incomplete end of the input rather than marking them, the two can be combined.
+            json_float_mode: How numbers with a fractional part or exponent are read, with `'decimal'` they're
+                validated as `Decimal`s from the number as written instead of being read as floats first,
+                so no precision is lost. Cannot be used with `allow_partial`.
+            json_mode: With `'concatenated'` the input may hold several JSON documents back to back, e.g. JSON lines,
+                each is validated against the schema and a list of the results is returned, errors are located by
+                the index of their document. Cannot be used with `allow_partial`, `on_progress` or `self_instance`.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -183,6 +291,7 @@ class SchemaValidator:
         strict: bool | None = None,
         context: Any | None = None,
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
+        partial: bool = False,
     ) -> Any:
         """
         Validate a string against the schema and return the validated Python object.
@@ -199,6 +308,8 @@ class SchemaValidator:
             allow_partial: Whether to allow partial validation; if `True` errors in the last element of sequences
                 and mappings are ignored.
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
+            partial: Whether to validate typed dicts as if all their keys were optional; if `True` missing keys
+                are left out of the result, without raising `missing` errors or filling in defaults.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -238,6 +349,101 @@ class SchemaValidator:
         Returns:
             Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
         """
+    def validate_update(
+        self,
+        base: Any,
+        patch: dict[str, Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> Any:
+        """
+        Apply a partial update to a model, dataclass or typed dict, e.g. for `PATCH` requests.
+
+        Each field in `patch` is validated as [`validate_assignment()`][pydantic_core.SchemaValidator.validate_assignment]
+        would validate it, and set on a copy of `base` made with `copy.copy()`; fields not in `patch` are left as
+        they are and `base` itself is not modified.
+
+        Arguments:
+            base: The existing model instance, dataclass instance or dict to update.
+            patch: A dict mapping field names to their new, unvalidated, values.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If any of the fields in `patch` fail validation, with errors for all of them.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The updated copy of `base`.
+        """
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        return_errors: bool = False,
+    ) -> list[Any]:
+        """
+        Eagerly validate each item of an iterable against the schema, in a single call.
+
+        This is faster than calling [`validate_python`][pydantic_core.SchemaValidator.validate_python] for each item
+        since validation setup is shared between the items.
+
+        Arguments:
+            inputs: The Python iterable of items to validate.
+            strict: Whether to validate the items in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            return_errors: Whether to return the `ValidationError` of each invalid item in its place in the result,
+                instead of raising on the first invalid item.
+
+        Raises:
+            ValidationError: If an item is invalid and `return_errors` is `False`, error locations
+                are prefixed with the index of the item.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            A list of the validated items, in order.
+        """
+    def iter_validate(
+        self,
+        iterable: Iterable[Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> Iterator[tuple[int, Any | ValidationError]]:
+        """
+        Lazily validate each item of an iterable against the schema, one item at a time.
+
+        Invalid items don't stop iteration, their `ValidationError` is yielded in place of the validated value.
+
+        Arguments:
+            iterable: The Python iterable of items to validate, it's consumed as the result is iterated.
+            strict: Whether to validate the items in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            Exception: Errors raised while iterating `iterable`, or internal errors, are raised from the iterator.
+
+        Returns:
+            An iterator of `(index, result)` tuples, where `result` is the validated item or a `ValidationError`.
+        """
     def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
         """
         Get the default value for the schema, including running default value validation.
@@ -292,6 +498,11 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        collect_warnings: bool = False,
+        dump_secret_values: bool = False,
+        exclude_computed: bool = False,
+        max_depth: int | None = None,
+        inline_errors: bool = False,
     ) -> Any:
         """
         Serialize/marshal a Python object to a Python object including transforming and filtering data.
@@ -315,12 +526,24 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            collect_warnings: Whether to return warnings rather than emitting them, if `True` a tuple of
+                `(value, warnings)` is returned where each warning is a dict with `loc` and `msg` keys,
+                `loc` being the tuple of field names leading to the value.
+                With `warnings='error'` an error is still raised.
+            dump_secret_values: Whether to serialize the values wrapped by [`Secret`][pydantic_core.Secret]s,
+                by default secrets are replaced by `'**********'` in JSON mode and left as `Secret`s in python mode.
+            exclude_computed: Whether to skip computed fields, their getters aren't called.
+            max_depth: How many levels of a recursive schema to serialize, deeper values are replaced by `None`
+                with a warning rather than recursing further, if `None` there's no limit beyond the recursion guard.
+            inline_errors: Whether to serialize fields set to an [`InvalidValue`][pydantic_core.InvalidValue] by
+                `mark_invalid` validation as `{'__error__': {'input': ..., 'errors': [...]}}`, so one response can
+                carry both the data and its errors.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
         Returns:
-            The serialized Python object.
+            The serialized Python object, or a `(value, warnings)` tuple if `collect_warnings` is `True`.
         """
     def to_json(
         self,
@@ -338,6 +561,12 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        collect_warnings: bool = False,
+        file: SupportsWrite[bytes] | None = None,
+        dump_secret_values: bool = False,
+        exclude_computed: bool = False,
+        max_depth: int | None = None,
+        inline_errors: bool = False,
     ) -> bytes:
         """
         Serialize a Python object to JSON including transforming and filtering data.
@@ -360,12 +589,108 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            collect_warnings: Whether to return warnings rather than emitting them, if `True` a tuple of
+                `(json_bytes, warnings)` is returned where each warning is a dict with `loc` and `msg` keys,
+                `loc` being the tuple of field names leading to the value.
+                With `warnings='error'` an error is still raised.
+            file: A binary file-like object to write the JSON to, if provided the output is written in chunks by
+                calling `file.write()` instead of being returned, which avoids holding all the JSON in memory.
+            dump_secret_values: Whether to serialize the values wrapped by [`Secret`][pydantic_core.Secret]s,
+                by default secrets are replaced by `'**********'`.
+            exclude_computed: Whether to skip computed fields, their getters aren't called.
+            max_depth: How many levels of a recursive schema to serialize, deeper values are replaced by `None`
+                with a warning rather than recursing further, if `None` there's no limit beyond the recursion guard.
+            inline_errors: Whether to serialize fields set to an [`InvalidValue`][pydantic_core.InvalidValue] by
+                `mark_invalid` validation as `{'__error__': {'input': ..., 'errors': [...]}}`, so one response can
+                carry both the data and its errors.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
         Returns:
-           JSON bytes.
+           JSON bytes, or a `(json_bytes, warnings)` tuple if `collect_warnings` is `True`.
+           If `file` is provided `None` takes the place of the JSON bytes.
+        """
+    def serializer_context(
+        self,
+        *,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        dump_secret_values: bool = False,
+        exclude_computed: bool = False,
+        max_depth: int | None = None,
+        inline_errors: bool = False,
+    ) -> SerializerContext:
+        """
+        Create a reusable context for serializing many values with the same options.
+
+        Calling its methods is the same as calling [`to_python`][pydantic_core.SchemaSerializer.to_python] or
+        [`to_json`][pydantic_core.SchemaSerializer.to_json] with these options, except the options are only
+        parsed once and the state used to collect warnings and guard against recursion is reused between calls.
+
+        Arguments:
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            dump_secret_values: Whether to serialize the values wrapped by [`Secret`][pydantic_core.Secret]s.
+            exclude_computed: Whether to skip computed fields, their getters aren't called.
+            max_depth: How many levels of a recursive schema to serialize, deeper values are replaced by `None`
+                with a warning rather than recursing further, if `None` there's no limit beyond the recursion guard.
+            inline_errors: Whether to serialize fields set to an [`InvalidValue`][pydantic_core.InvalidValue] by
+                `mark_invalid` validation as `{'__error__': {'input': ..., 'errors': [...]}}`, so one response can
+                carry both the data and its errors.
+
+        Returns:
+            A `SerializerContext` with `to_python` and `to_json` methods using these options.
+        """
+
+@final
+class SerializerContext:
+    """
+    Serialization options fixed by
+    [`SchemaSerializer.serializer_context`][pydantic_core.SchemaSerializer.serializer_context].
+    """
+
+    def to_python(
+        self,
+        value: Any,
+        *,
+        mode: str | None = None,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+    ) -> Any:
+        """
+        Serialize a Python object to a Python object using the context's options,
+        see [`SchemaSerializer.to_python`][pydantic_core.SchemaSerializer.to_python].
+        """
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to JSON using the context's options,
+        see [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json].
         """
 
 def to_json(
@@ -377,13 +702,14 @@ def to_json(
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    timedelta_mode: Literal['iso8601', 'float', 'int_seconds'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    file: SupportsWrite[bytes] | None = None,
 ) -> bytes:
     """
     Serialize a Python object to JSON including transforming and filtering data.
@@ -398,7 +724,7 @@ def to_json(
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
-        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'float'` or `'int_seconds'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
@@ -408,12 +734,14 @@ def to_json(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        file: A binary file-like object to write the JSON to, if provided the output is written in chunks by
+            calling `file.write()` instead of being returned.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
     Returns:
-       JSON bytes.
+       JSON bytes, or `None` if `file` is provided.
     """
 
 def from_json(
@@ -453,7 +781,7 @@ def to_jsonable_python(
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    timedelta_mode: Literal['iso8601', 'float', 'int_seconds'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
     serialize_unknown: bool = False,
@@ -474,7 +802,7 @@ def to_jsonable_python(
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
-        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'float'` or `'int_seconds'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
@@ -630,8 +958,19 @@ class ValidationError(ValueError):
             The number of errors in the validation error.
         """
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
-    ) -> list[ErrorDetails]:
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        locale: str | None = None,
+        include_code: bool = False,
+        as_objects: bool = False,
+        loc_as_pointer: bool = False,
+        url_types: set[str] | frozenset[str] | None = None,
+        exclude_url_types: set[str] | frozenset[str] | None = None,
+        include_source: bool = False,
+    ) -> list[ErrorDetails] | list[ErrorDetail]:
         """
         Details about each error in the validation error.
 
@@ -639,9 +978,73 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            locale: The locale to render messages in, using templates registered with
+                [`register_error_messages`][pydantic_core.register_error_messages]; messages without a template
+                for the locale use the default.
+            include_code: Whether to include the `code` of each error, this defaults to the error type
+                unless a custom code is set on a [`PydanticCustomError`][pydantic_core.PydanticCustomError].
+            as_objects: Whether to return [`ErrorDetail`][pydantic_core.ErrorDetail] objects instead of dicts,
+                `code` is always available as an attribute; `include_code`, `loc_as_pointer` and `include_source`
+                can't be used with it.
+            loc_as_pointer: Whether to give the `loc` of each error as an
+                [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901) JSON Pointer string, e.g. `'/items/0/name'`,
+                instead of a tuple.
+            url_types: If set, only errors of these types include a URL, when `include_url` is set.
+            exclude_url_types: Error types which don't include a URL, when `include_url` is set.
+                URLs are never included for [`PydanticCustomError`][pydantic_core.PydanticCustomError]s.
+            include_source: Whether to add a `source` to errors which come from a nested `ValidationError`, e.g. one
+                raised by a validator function, a dict with the `title` of that error and the `loc_prefix` it was
+                found at.
 
         Returns:
-            A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
+            A list of [`ErrorDetails`][pydantic_core.ErrorDetails] (or [`ErrorDetail`][pydantic_core.ErrorDetail]
+                if `as_objects` is set) for each error in the validation error.
+        """
+    def first_error(
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        prioritize_shallow: bool = False,
+        locale: str | None = None,
+    ) -> ErrorDetails | None:
+        """
+        Details about a single error, e.g. for a CLI which only shows the most relevant error.
+
+        This is the same as `errors()[0]` but avoids creating details for every error.
+
+        Args:
+            include_url: Whether to include a URL to documentation on the error.
+            include_context: Whether to include the context of the error.
+            include_input: Whether to include the input value of the error.
+            prioritize_shallow: Whether to return the error with the shortest location instead of the first error,
+                of errors with equally short locations the first is returned.
+            locale: The locale to render the message in, see [`errors()`][pydantic_core.ValidationError.errors].
+
+        Returns:
+            The [`ErrorDetails`][pydantic_core.ErrorDetails] of the error, or `None` if there are no errors.
+        """
+    def to_dict(
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        loc_prefix: tuple[int | str, ...] | list[int | str] | None = None,
+        locale: str | None = None,
+    ) -> dict[str, Any]:
+        """
+        The validation error as a single dict, useful for embedding errors in a larger response.
+
+        Args:
+            include_url: Whether to include a URL to documentation on the error each error.
+            include_context: Whether to include the context of each error.
+            loc_prefix: Location items to prepend to the `loc` of each error.
+            locale: The locale to render messages in, see [`errors()`][pydantic_core.ValidationError.errors].
+
+        Returns:
+            A dict with the `title`, `error_count` and `errors` of the validation error, where `errors`
+                matches [`errors()`][pydantic_core.ValidationError.errors].
         """
     def json(
         self,
@@ -650,6 +1053,13 @@ class ValidationError(ValueError):
         include_url: bool = True,
         include_context: bool = True,
         include_input: bool = True,
+        locale: str | None = None,
+        include_code: bool = False,
+        max_input_repr_len: int | None = None,
+        loc_as_pointer: bool = False,
+        url_types: set[str] | frozenset[str] | None = None,
+        exclude_url_types: set[str] | frozenset[str] | None = None,
+        include_source: bool = False,
     ) -> str:
         """
         Same as [`errors()`][pydantic_core.ValidationError.errors] but returns a JSON string.
@@ -659,11 +1069,73 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            locale: The locale to render messages in, see [`errors()`][pydantic_core.ValidationError.errors].
+            include_code: Whether to include the `code` of each error.
+            max_input_repr_len: If set, input values longer than this many bytes are replaced by a truncated
+                string with `...` in the middle, for anything other than strings the length of its JSON
+                representation is used. By default input values are not truncated.
+            loc_as_pointer: Whether to give the `loc` of each error as a JSON Pointer string,
+                see [`errors()`][pydantic_core.ValidationError.errors].
+            url_types: If set, only errors of these types include a URL, when `include_url` is set.
+            exclude_url_types: Error types which don't include a URL, when `include_url` is set.
+            include_source: Whether to add a `source` to errors which come from a nested `ValidationError`,
+                see [`errors()`][pydantic_core.ValidationError.errors].
 
         Returns:
             a JSON string.
         """
 
+    def categorize(
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        locale: str | None = None,
+    ) -> dict[Literal['missing', 'type', 'value', 'custom'], list[ErrorDetails]]:
+        """
+        Group the errors by a coarse category of their error type, e.g. to triage errors.
+
+        `missing` covers missing fields and arguments, `type` covers inputs of the wrong type or which can't be parsed,
+        `custom` covers [`PydanticCustomError`][pydantic_core.PydanticCustomError]s and `value` everything else.
+
+        Args:
+            include_url: Whether to include a URL to documentation on the error each error.
+            include_context: Whether to include the context of each error.
+            include_input: Whether to include the input value of each error.
+            locale: The locale to render messages in, see [`errors()`][pydantic_core.ValidationError.errors].
+
+        Returns:
+            A dict of all categories, each mapping to a list of [`ErrorDetails`][pydantic_core.ErrorDetails].
+        """
+
+    def as_exception_group(self) -> ExceptionGroup[ValueError]:
+        """
+        Convert the validation error to an `ExceptionGroup`, e.g. for use with `except*`.
+
+        Each error becomes a `ValueError` with the error message, and `loc` and `type` attributes,
+        errors sharing a location prefix are nested in a group named after that prefix.
+
+        Raises:
+            RuntimeError: On Python versions before 3.11.
+        """
+
+    def display(
+        self, *, loc_format: Literal['dotted', 'json_pointer', 'bracketed'] = 'dotted', locale: str | None = None
+    ) -> str:
+        """
+        The same as `str(error)`, with a choice of how error locations and messages are rendered.
+
+        Arguments:
+            loc_format: How to render locations, `'dotted'` gives `items.0.name`,
+                `'json_pointer'` gives an [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901) JSON Pointer
+                like `/items/0/name` and `'bracketed'` gives `items[0][name]`.
+            locale: The locale to render messages in, see [`errors()`][pydantic_core.ValidationError.errors].
+
+        Returns:
+            The formatted validation error.
+        """
+
     def __repr__(self) -> str:
         """
         A string representation of the validation error.
@@ -676,6 +1148,65 @@ class ValidationError(ValueError):
         before the first validation error is created.
         """
 
+@final
+class InvalidValue:
+    """
+    Stands in for the value of a model or typed dict field which failed validation with `mark_invalid=True`.
+
+    With `inline_errors=True` serializers emit it as `{'__error__': {'input': ..., 'errors': [...]}}`.
+    """
+
+    @property
+    def input(self) -> Any:
+        """The input value of the field."""
+    def errors(self, *, include_context: bool = True) -> list[ErrorDetails]:
+        """
+        The field's errors as returned by [`ValidationError.errors()`][pydantic_core.ValidationError.errors], without
+        URLs or inputs, their locations are relative to the field.
+
+        Arguments:
+            include_context: Whether to include the context of each error.
+
+        Returns:
+            A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the field.
+        """
+
+@final
+class ErrorDetail:
+    """
+    Object version of [`ErrorDetails`][pydantic_core.ErrorDetails], returned by
+    [`ValidationError.errors(as_objects=True)`][pydantic_core.ValidationError.errors].
+
+    Attributes are computed when they're accessed; `input`, `ctx` and `url` are `None` if they were excluded
+    when calling `errors()`.
+    """
+
+    def __new__(
+        cls,
+        details: ErrorDetails | InitErrorDetails,
+        input_type: Literal['python', 'json'] = 'python',
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        locale: str | None = None,
+    ) -> Self: ...
+    @property
+    def type(self) -> str: ...
+    @property
+    def code(self) -> str: ...
+    @property
+    def loc(self) -> tuple[int | str | tuple[Any, ...], ...]: ...
+    @property
+    def msg(self) -> str: ...
+    @property
+    def input(self) -> Any: ...
+    @property
+    def ctx(self) -> dict[str, Any] | None: ...
+    @property
+    def url(self) -> str | None: ...
+    def field_path(self) -> str:
+        """The location formatted as a path, see [`format_loc`][pydantic_core.format_loc]."""
+
 class PydanticCustomError(ValueError):
     """A custom exception providing flexible error handling for Pydantic validators.
 
@@ -693,7 +1224,11 @@ class PydanticCustomError(ValueError):
     """
 
     def __init__(
-        self, error_type: LiteralString, message_template: LiteralString, context: dict[str, Any] | None = None
+        self,
+        error_type: LiteralString,
+        message_template: LiteralString,
+        context: dict[str, Any] | None = None,
+        code: str | None = None,
     ) -> None:
         """Initializes the `PydanticCustomError`.
 
@@ -701,10 +1236,16 @@ class PydanticCustomError(ValueError):
             error_type: The error type.
             message_template: The message template.
             context: The data to inject into the message template.
+            code: A short stable code for the error, reported instead of `error_type` when errors are
+                rendered with `include_code=True`.
         """
 
     def __new__(
-        cls, error_type: LiteralString, message_template: LiteralString, context: dict[str, Any] | None = None
+        cls,
+        error_type: LiteralString,
+        message_template: LiteralString,
+        context: dict[str, Any] | None = None,
+        code: str | None = None,
     ) -> Self: ...
     @property
     def context(self) -> dict[str, Any] | None:
@@ -718,6 +1259,10 @@ class PydanticCustomError(ValueError):
     def message_template(self) -> str:
         """The message template associated with the error. This is a string that can be formatted with context variables in `{curly_braces}`."""
 
+    @property
+    def code(self) -> str | None:
+        """The custom code associated with the error, if any."""
+
     def message(self) -> str:
         """The formatted message associated with the error. This presents as the message template with context variables appropriately injected."""
 
@@ -973,6 +1518,58 @@ def list_all_errors() -> list[ErrorTypeInfo]:
     Returns:
         A list of `ErrorTypeInfo` typed dicts.
     """
+
+def is_known_error_type(error_type: str) -> bool:
+    """
+    Check whether a string is the type of a built-in error, e.g. before persisting or matching on error types.
+
+    The full set of built-in error types is available as constants in `pydantic_core.error_types`.
+
+    Arguments:
+        error_type: The error type string to check.
+
+    Returns:
+        `True` if `error_type` is a built-in error type, `False` otherwise, including for custom error types.
+    """
+
+def register_error_messages(locale: str, messages: dict[ErrorType, str]) -> None:
+    """
+    Register message templates for a locale, used by `ValidationError.errors(locale=...)`,
+    `ValidationError.json(locale=...)` and the other methods taking a `locale`.
+
+    Templates use the same placeholders as the default templates, e.g. `'Eingabe sollte kleiner als {lt} sein'`
+    for `less_than`. Registering again for the same locale adds to or replaces existing templates.
+
+    Arguments:
+        locale: The locale, e.g. `'de'`.
+        messages: A mapping of error type to message template.
+
+    Raises:
+        KeyError: If any of the error types are unknown.
+    """
+
+def set_include_url(value: bool) -> None:
+    """
+    Set whether URLs to error documentation are included when displaying a `ValidationError`,
+    overriding the `PYDANTIC_ERRORS_INCLUDE_URL` environment variable.
+
+    Arguments:
+        value: Whether to include URLs.
+    """
+
+def format_loc(loc: tuple[int | str | tuple[Any, ...], ...] | list[int | str | tuple[Any, ...]]) -> str:
+    """
+    Format an error location as a dotted/bracketed path, e.g. `('items', 0, 'name')` becomes `'items[0].name'`,
+    for use as a form field key.
+
+    Strings containing `.`, `[` or `]` are quoted in brackets, e.g. `'a["b.c"]'`.
+
+    Arguments:
+        loc: The location, as in the `loc` of [`ErrorDetails`][pydantic_core.ErrorDetails].
+
+    Returns:
+        The formatted path.
+    """
 @final
 class TzInfo(datetime.tzinfo):
     """An `pydantic-core` implementation of the abstract [`datetime.tzinfo`] class."""
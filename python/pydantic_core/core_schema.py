@@ -10,6 +10,7 @@ import warnings
 from collections.abc import Mapping
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal
+from fractions import Fraction
 from typing import TYPE_CHECKING, Any, Callable, Dict, Hashable, List, Pattern, Set, Tuple, Type, Union
 
 from typing_extensions import deprecated
@@ -67,9 +68,12 @@ class CoreConfig(TypedDict, total=False):
         str_to_upper: Whether to convert string fields to uppercase.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
+            'int_seconds' emits the total seconds truncated towards zero, warning if a fractional part is dropped.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
+        ser_json_complex: The serialization option for `complex` values, either a string like '1+2j'
+            or a `{'real': ..., 'imag': ...}` dict. Default is 'string'.
         val_json_bytes: The validation option for `bytes` values, complementing ser_json_bytes. Default is 'utf8'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
@@ -77,7 +81,8 @@ class CoreConfig(TypedDict, total=False):
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
         cache_strings: Whether to cache strings. Default is `True`, `True` or `'all'` is required to cache strings
-            during general validation since validators don't know if they're in a key or a value.
+            during general validation since validators don't know if they're in a key or a value. Extra keys of
+            typed dicts and models are cached with `'keys'` too.
     """
 
     title: str
@@ -105,9 +110,10 @@ class CoreConfig(TypedDict, total=False):
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
     # the config options are used to customise serialization to JSON
-    ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
+    ser_json_timedelta: Literal['iso8601', 'float', 'int_seconds']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
+    ser_json_complex: Literal['string', 'dict']  # default: 'string'
     val_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
@@ -544,6 +550,8 @@ def none_schema(
 class BoolSchema(TypedDict, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    allow_bool_from_int: bool  # default: True
+    numeric_strict: bool  # default: False
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -551,6 +559,8 @@ class BoolSchema(TypedDict, total=False):
 
 def bool_schema(
     strict: bool | None = None,
+    allow_bool_from_int: bool | None = None,
+    numeric_strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -568,11 +578,23 @@ def bool_schema(
 
     Args:
         strict: Whether the value should be a bool or a value that can be converted to a bool
+        allow_bool_from_int: Whether to accept ints (e.g. `1`) when not in strict mode, defaults to `True`
+        numeric_strict: Whether numbers and numeric strings are only accepted when they're exactly `0` or `1`,
+            other ints, any float and strings like `'2'` or `'3.14'` raise `bool_parsing` with the number
+            as `value` in the context, defaults to `False`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='bool', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='bool',
+        strict=strict,
+        allow_bool_from_int=allow_bool_from_int,
+        numeric_strict=numeric_strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class IntSchema(TypedDict, total=False):
@@ -583,6 +605,9 @@ class IntSchema(TypedDict, total=False):
     lt: int
     gt: int
     strict: bool
+    json_number_strict: bool  # default: False
+    allow_int_from_bool: bool  # default: True
+    strict_number_protocol: bool  # default: False
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -596,6 +621,9 @@ def int_schema(
     lt: int | None = None,
     gt: int | None = None,
     strict: bool | None = None,
+    json_number_strict: bool | None = None,
+    allow_int_from_bool: bool | None = None,
+    strict_number_protocol: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -618,6 +646,10 @@ def int_schema(
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a int or a value that can be converted to a int
+        json_number_strict: Whether to reject JSON float literals (e.g. `1.0`) even when not in strict mode
+        allow_int_from_bool: Whether to accept bools (e.g. `True`) when not in strict mode, defaults to `True`
+        strict_number_protocol: Whether objects implementing `__index__`, like NumPy integer scalars, are accepted
+            in strict mode too, they're always accepted when not in strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -630,15 +662,25 @@ def int_schema(
         lt=lt,
         gt=gt,
         strict=strict,
+        json_number_strict=json_number_strict,
+        allow_int_from_bool=allow_int_from_bool,
+        strict_number_protocol=strict_number_protocol,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+class LocaleFormat(TypedDict):
+    group: str
+    decimal: str
+
+
 class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
+    finite: bool  # whether 'NaN', '+inf', '-inf' are forbidden whatever `allow_inf_nan` is. default: False
+    locale_format: LocaleFormat
     multiple_of: float
     le: float
     ge: float
@@ -653,6 +695,8 @@ class FloatSchema(TypedDict, total=False):
 def float_schema(
     *,
     allow_inf_nan: bool | None = None,
+    finite: bool | None = None,
+    locale_format: LocaleFormat | None = None,
     multiple_of: float | None = None,
     le: float | None = None,
     ge: float | None = None,
@@ -676,6 +720,10 @@ def float_schema(
 
     Args:
         allow_inf_nan: Whether to allow inf and nan values
+        finite: Whether the value must be finite, if `True` inf and nan values are rejected with a
+            `finite_number` error, this takes precedence over `allow_inf_nan` and the `allow_inf_nan` config
+        locale_format: The grouping and decimal characters used to parse strings in lax mode,
+            e.g. `{'group': '.', 'decimal': ','}` to parse `'1.234,56'`
         multiple_of: The value must be a multiple of this number
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
@@ -689,6 +737,8 @@ def float_schema(
     return _dict_not_none(
         type='float',
         allow_inf_nan=allow_inf_nan,
+        finite=finite,
+        locale_format=locale_format,
         multiple_of=multiple_of,
         le=le,
         ge=ge,
@@ -704,6 +754,7 @@ def float_schema(
 class DecimalSchema(TypedDict, total=False):
     type: Required[Literal['decimal']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: False
+    locale_format: LocaleFormat
     multiple_of: Decimal
     le: Decimal
     ge: Decimal
@@ -711,6 +762,7 @@ class DecimalSchema(TypedDict, total=False):
     gt: Decimal
     max_digits: int
     decimal_places: int
+    ser_decimal_places: int
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -720,6 +772,7 @@ class DecimalSchema(TypedDict, total=False):
 def decimal_schema(
     *,
     allow_inf_nan: bool = None,
+    locale_format: LocaleFormat | None = None,
     multiple_of: Decimal | None = None,
     le: Decimal | None = None,
     ge: Decimal | None = None,
@@ -727,6 +780,7 @@ def decimal_schema(
     gt: Decimal | None = None,
     max_digits: int | None = None,
     decimal_places: int | None = None,
+    ser_decimal_places: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -746,6 +800,8 @@ def decimal_schema(
 
     Args:
         allow_inf_nan: Whether to allow inf and nan values
+        locale_format: The grouping and decimal characters used to parse strings in lax mode,
+            e.g. `{'group': '.', 'decimal': ','}` to parse `'1.234,56'`
         multiple_of: The value must be a multiple of this number
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
@@ -753,6 +809,8 @@ def decimal_schema(
         gt: The value must be strictly greater than this number
         max_digits: The maximum number of decimal digits allowed
         decimal_places: The maximum number of decimal places allowed
+        ser_decimal_places: The number of decimal places decimals are quantized to when serializing, e.g. `2` to
+            always dump `Decimal('1')` as `'1.00'` in JSON
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -766,8 +824,10 @@ def decimal_schema(
         le=le,
         max_digits=max_digits,
         decimal_places=decimal_places,
+        ser_decimal_places=ser_decimal_places,
         multiple_of=multiple_of,
         allow_inf_nan=allow_inf_nan,
+        locale_format=locale_format,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -777,6 +837,7 @@ def decimal_schema(
 
 class ComplexSchema(TypedDict, total=False):
     type: Required[Literal['complex']]
+    max_abs: float
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -785,6 +846,7 @@ class ComplexSchema(TypedDict, total=False):
 
 def complex_schema(
     *,
+    max_abs: float | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -803,6 +865,7 @@ def complex_schema(
     ```
 
     Args:
+        max_abs: The absolute value (magnitude) of the value must be less than or equal to this number
         strict: Whether the value should be a complex object instance or a value that can be converted to a complex object
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -810,6 +873,69 @@ def complex_schema(
     """
     return _dict_not_none(
         type='complex',
+        max_abs=max_abs,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class FractionSchema(TypedDict, total=False):
+    type: Required[Literal['fraction']]
+    allow_float: bool  # whether floats are converted exactly to a fraction, default: False
+    le: Fraction
+    ge: Fraction
+    lt: Fraction
+    gt: Fraction
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def fraction_schema(
+    *,
+    allow_float: bool | None = None,
+    le: Fraction | None = None,
+    ge: Fraction | None = None,
+    lt: Fraction | None = None,
+    gt: Fraction | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> FractionSchema:
+    """
+    Returns a schema that matches a fraction value, e.g.:
+
+    ```py
+    from fractions import Fraction
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.fraction_schema(le=Fraction(1, 1))
+    v = SchemaValidator(schema)
+    assert v.validate_python('3/4') == Fraction(3, 4)
+    ```
+
+    Args:
+        allow_float: Whether to accept floats, converting them exactly to a fraction
+        le: The value must be less than or equal to this number
+        ge: The value must be greater than or equal to this number
+        lt: The value must be strictly less than this number
+        gt: The value must be strictly greater than this number
+        strict: Whether the value should be a Fraction instance or a value that can be converted to a Fraction
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='fraction',
+        allow_float=allow_float,
+        gt=gt,
+        ge=ge,
+        lt=lt,
+        le=le,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -823,11 +949,20 @@ class StringSchema(TypedDict, total=False):
     max_length: int
     min_length: int
     strip_whitespace: bool
+    collapse_whitespace: bool  # default: False
+    collapse_unicode_whitespace: bool  # default: True
     to_lower: bool
     to_upper: bool
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    forbid_control_chars: bool  # default: False
+    allow_control_whitespace: bool  # default: False
+    gt: str
+    ge: str
+    lt: str
+    le: str
     strict: bool
     coerce_numbers_to_str: bool
+    coerce_enum: bool  # default: False
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -839,11 +974,20 @@ def str_schema(
     max_length: int | None = None,
     min_length: int | None = None,
     strip_whitespace: bool | None = None,
+    collapse_whitespace: bool | None = None,
+    collapse_unicode_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
+    forbid_control_chars: bool | None = None,
+    allow_control_whitespace: bool | None = None,
+    gt: str | None = None,
+    ge: str | None = None,
+    lt: str | None = None,
+    le: str | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
+    coerce_enum: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -864,6 +1008,10 @@ def str_schema(
         max_length: The value must be at most this length
         min_length: The value must be at least this length
         strip_whitespace: Whether to strip whitespace from the value
+        collapse_whitespace: Whether to replace each run of whitespace with a single space, applied after
+            `strip_whitespace` and before the length and pattern checks
+        collapse_unicode_whitespace: Whether `collapse_whitespace` also treats Unicode whitespace such as `\u00a0`
+            as whitespace rather than only ASCII whitespace, defaults to `True`
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
         regex_engine: The regex engine to use for pattern validation. Default is 'rust-regex'.
@@ -872,8 +1020,17 @@ def str_schema(
               resistant, but does not support all regex features.
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
+        forbid_control_chars: Whether to reject strings containing C0/C1 control characters
+        allow_control_whitespace: Whether to allow `\\t`, `\\n` and `\\r` when `forbid_control_chars` is set
+        gt: The value must be strictly greater than this string, compared after any transformations
+        ge: The value must be greater than or equal to this string, compared after any transformations
+        lt: The value must be strictly less than this string, compared after any transformations
+        le: The value must be less than or equal to this string, compared after any transformations.
+            Like comparing `str`s in Python, `gt`, `ge`, `lt` and `le` compare by code point, not by locale
         strict: Whether the value should be a string or a value that can be converted to a string
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
+        coerce_enum: Whether to replace enum members with their value before validating, also in `strict` mode,
+            members whose value isn't a `str` are rejected with a `string_type` error
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -884,11 +1041,20 @@ def str_schema(
         max_length=max_length,
         min_length=min_length,
         strip_whitespace=strip_whitespace,
+        collapse_whitespace=collapse_whitespace,
+        collapse_unicode_whitespace=collapse_unicode_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
+        forbid_control_chars=forbid_control_chars,
+        allow_control_whitespace=allow_control_whitespace,
+        gt=gt,
+        ge=ge,
+        lt=lt,
+        le=le,
         strict=strict,
         coerce_numbers_to_str=coerce_numbers_to_str,
+        coerce_enum=coerce_enum,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -899,6 +1065,7 @@ class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
+    length_target: Literal['decoded', 'source']
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -909,6 +1076,7 @@ def bytes_schema(
     *,
     max_length: int | None = None,
     min_length: int | None = None,
+    length_target: Literal['decoded', 'source'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -928,6 +1096,9 @@ def bytes_schema(
     Args:
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        length_target: Whether `min_length` and `max_length` apply to the decoded bytes (`'decoded'`, the default)
+            or to the source string (`'source'`) for strings decoded according to `val_json_bytes`,
+            when `val_json_bytes` is `'utf8'` the decoded bytes are always used
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -937,6 +1108,62 @@ def bytes_schema(
         type='bytes',
         max_length=max_length,
         min_length=min_length,
+        length_target=length_target,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class Base64Schema(TypedDict, total=False):
+    type: Required[Literal['base64']]
+    url_safe: bool  # default: False
+    allow_missing_padding: bool  # default: False
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def base64_schema(
+    *,
+    url_safe: bool | None = None,
+    allow_missing_padding: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> Base64Schema:
+    """
+    Returns a schema that matches a base64 encoded string, the decoded bytes are the validated value
+    and are encoded to base64 again when serialized, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.base64_schema(url_safe=True)
+    v = SchemaValidator(schema)
+    assert v.validate_python('aGVsbG8_') == b'hello?'
+
+    s = SchemaSerializer(schema)
+    assert s.to_json(b'hello?') == b'"aGVsbG8_"'
+    ```
+
+    Invalid base64 raises a `base64_decode` error.
+
+    Args:
+        url_safe: Whether to use the URL-safe alphabet (`-` and `_`) rather than the standard one (`+` and `/`)
+        allow_missing_padding: Whether to accept input without `=` padding, serialized values are always padded
+        strict: Whether only `str` input is accepted, rather than also `bytes`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='base64',
+        url_safe=url_safe,
+        allow_missing_padding=allow_missing_padding,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1479,6 +1706,13 @@ class ListSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    skip_empty: bool
+    skip_none: bool
+    coerce_scalar: bool
+    coerce_scalar_str: bool
+    one_based_index: bool
+    output_type: Literal['list', 'deque']  # default: 'list'
+    maxlen: int
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -1491,6 +1725,13 @@ def list_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    skip_empty: bool | None = None,
+    skip_none: bool | None = None,
+    coerce_scalar: bool | None = None,
+    coerce_scalar_str: bool | None = None,
+    one_based_index: bool | None = None,
+    output_type: Literal['list', 'deque'] | None = None,
+    maxlen: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -1512,6 +1753,17 @@ def list_schema(
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
         fail_fast: Stop validation on the first error
+        skip_empty: Drop empty strings and collections from the input before validation,
+            skipped items don't count towards `min_length` and `max_length`
+        skip_none: Drop `None` items from the input before validation,
+            skipped items don't count towards `min_length` and `max_length`
+        coerce_scalar: Validate a single value that isn't a collection as the only item, e.g. `1` as `[1]`
+        coerce_scalar_str: Also wrap `str`, `bytes` and `bytearray` values when `coerce_scalar` is set
+        one_based_index: Report the index of items in error locations counting from 1 rather than 0,
+            e.g. for messages shown to end users
+        output_type: The type of the validated value, `'deque'` creates a `collections.deque` of the items,
+            which is serialized like a list
+        maxlen: The `maxlen` of the deque when `output_type='deque'`, older items are dropped from a longer list
         strict: The value must be a list with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1523,6 +1775,13 @@ def list_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        skip_empty=skip_empty,
+        skip_none=skip_none,
+        coerce_scalar=coerce_scalar,
+        coerce_scalar_str=coerce_scalar_str,
+        one_based_index=one_based_index,
+        output_type=output_type,
+        maxlen=maxlen,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1631,6 +1890,11 @@ class TupleSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    allow_from: List[Literal['list', 'tuple', 'set', 'generator']]
+    coerce_scalar: bool
+    coerce_scalar_str: bool
+    one_based_index: bool
+    cls: Type[Tuple[Any, ...]]
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -1644,6 +1908,11 @@ def tuple_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    allow_from: list[Literal['list', 'tuple', 'set', 'generator']] | None = None,
+    coerce_scalar: bool | None = None,
+    coerce_scalar_str: bool | None = None,
+    one_based_index: bool | None = None,
+    cls: Type[Tuple[Any, ...]] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -1669,6 +1938,14 @@ def tuple_schema(
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
         fail_fast: Stop validation on the first error
+        allow_from: The kinds of python input to accept in lax mode, defaults to any non-string, non-mapping iterable;
+            `'set'` covers frozensets and `'generator'` covers generators and other iterators, other iterables
+            such as `dict.keys()` are only accepted by the default
+        coerce_scalar: Validate a single value that isn't a collection as the only item, e.g. `1` as `(1,)`
+        coerce_scalar_str: Also wrap `str`, `bytes` and `bytearray` values when `coerce_scalar` is set
+        one_based_index: Report the index of items in error locations counting from 1 rather than 0
+        cls: A named tuple class to create from the validated items, rather than a plain tuple, it must have a field
+            for each of `items_schema` and can't be used with `variadic_item_index`
         strict: The value must be a tuple with exactly this many items
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1681,6 +1958,11 @@ def tuple_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        allow_from=allow_from,
+        coerce_scalar=coerce_scalar,
+        coerce_scalar_str=coerce_scalar_str,
+        one_based_index=one_based_index,
+        cls=cls,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1694,6 +1976,10 @@ class SetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    skip_empty: bool
+    skip_none: bool
+    coerce_scalar: bool
+    coerce_scalar_str: bool
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -1706,6 +1992,10 @@ def set_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    skip_empty: bool | None = None,
+    skip_none: bool | None = None,
+    coerce_scalar: bool | None = None,
+    coerce_scalar_str: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -1729,6 +2019,12 @@ def set_schema(
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
         fail_fast: Stop validation on the first error
+        skip_empty: Drop empty strings and collections from the input before validation,
+            skipped items don't count towards `min_length` and `max_length`
+        skip_none: Drop `None` items from the input before validation,
+            skipped items don't count towards `min_length` and `max_length`
+        coerce_scalar: Validate a single value that isn't a collection as the only item, e.g. `1` as `{1}`
+        coerce_scalar_str: Also wrap `str`, `bytes` and `bytearray` values when `coerce_scalar` is set
         strict: The value must be a set with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1740,6 +2036,10 @@ def set_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        skip_empty=skip_empty,
+        skip_none=skip_none,
+        coerce_scalar=coerce_scalar,
+        coerce_scalar_str=coerce_scalar_str,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1753,6 +2053,10 @@ class FrozenSetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    skip_empty: bool
+    skip_none: bool
+    coerce_scalar: bool
+    coerce_scalar_str: bool
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -1765,6 +2069,10 @@ def frozenset_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    skip_empty: bool | None = None,
+    skip_none: bool | None = None,
+    coerce_scalar: bool | None = None,
+    coerce_scalar_str: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -1788,6 +2096,12 @@ def frozenset_schema(
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
         fail_fast: Stop validation on the first error
+        skip_empty: Drop empty strings and collections from the input before validation,
+            skipped items don't count towards `min_length` and `max_length`
+        skip_none: Drop `None` items from the input before validation,
+            skipped items don't count towards `min_length` and `max_length`
+        coerce_scalar: Validate a single value that isn't a collection as the only item, e.g. `1` as `frozenset({1})`
+        coerce_scalar_str: Also wrap `str`, `bytes` and `bytearray` values when `coerce_scalar` is set
         strict: The value must be a frozenset with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1799,6 +2113,10 @@ def frozenset_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        skip_empty=skip_empty,
+        skip_none=skip_none,
+        coerce_scalar=coerce_scalar,
+        coerce_scalar_str=coerce_scalar_str,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1870,10 +2188,24 @@ class IncExDictSerSchema(TypedDict, total=False):
     type: Required[Literal['include-exclude-dict']]
     include: IncExDict
     exclude: IncExDict
+    exclude_key_pattern: str
 
 
-def filter_dict_schema(*, include: IncExDict | None = None, exclude: IncExDict | None = None) -> IncExDictSerSchema:
-    return _dict_not_none(type='include-exclude-dict', include=include, exclude=exclude)
+def filter_dict_schema(
+    *, include: IncExDict | None = None, exclude: IncExDict | None = None, exclude_key_pattern: str | None = None
+) -> IncExDictSerSchema:
+    """
+    Returns a schema for filtering the keys of a dict when serializing.
+
+    Args:
+        include: Keys to include
+        exclude: Keys to exclude
+        exclude_key_pattern: A regular expression, keys whose serialized form it matches (searched, not anchored)
+            are dropped unless explicitly included via `include` or the `include` argument of the call
+    """
+    return _dict_not_none(
+        type='include-exclude-dict', include=include, exclude=exclude, exclude_key_pattern=exclude_key_pattern
+    )
 
 
 IncExDictOrElseSerSchema = Union[IncExDictSerSchema, SerSchema]
@@ -1885,6 +2217,9 @@ class DictSchema(TypedDict, total=False):
     values_schema: CoreSchema  # default: AnySchema
     min_length: int
     max_length: int
+    required_keys: List[str]
+    output_type: Literal['dict', 'ordered_dict', 'counter']  # default: 'dict'
+    on_key_collision: Literal['error', 'last_wins', 'first_wins']  # default: 'error'
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -1897,6 +2232,9 @@ def dict_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    required_keys: list[str] | None = None,
+    output_type: Literal['dict', 'ordered_dict', 'counter'] | None = None,
+    on_key_collision: Literal['error', 'last_wins', 'first_wins'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -1920,6 +2258,12 @@ def dict_schema(
         values_schema: The value must be a dict with values that match this schema
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
+        required_keys: Keys which must be present in the dict, a `missing` error is raised for each absent key
+        output_type: The type of the validated value, `'ordered_dict'` and `'counter'` create a
+            `collections.OrderedDict` or `collections.Counter` from the validated keys and values
+        on_key_collision: What to do when distinct input keys are the same after validation, e.g. `'1'` and `'01'`
+            with an int `keys_schema`; `'error'` raises a `dict_key_collision` error, `'last_wins'` and
+            `'first_wins'` keep the value of the last or first of the colliding keys
         strict: Whether the keys and values should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1931,6 +2275,9 @@ def dict_schema(
         values_schema=values_schema,
         min_length=min_length,
         max_length=max_length,
+        required_keys=required_keys,
+        output_type=output_type,
+        on_key_collision=on_key_collision,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -2379,6 +2726,7 @@ class WithDefaultSchema(TypedDict, total=False):
     default_factory_takes_data: bool
     on_error: Literal['raise', 'omit', 'default']  # default: 'raise'
     validate_default: bool  # default: False
+    copy_on_default: Literal['none', 'shallow', 'deep']
     strict: bool
     ref: str
     metadata: Dict[str, Any]
@@ -2393,6 +2741,7 @@ def with_default_schema(
     default_factory_takes_data: bool | None = None,
     on_error: Literal['raise', 'omit', 'default'] | None = None,
     validate_default: bool | None = None,
+    copy_on_default: Literal['none', 'shallow', 'deep'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
@@ -2419,6 +2768,8 @@ def with_default_schema(
         default_factory_takes_data: Whether the default factory takes a validated data argument
         on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default'
         validate_default: Whether the default value should be validated
+        copy_on_default: How the default value is copied each time it's used, one of 'none', 'shallow', 'deep',
+            by default unhashable values like lists and dicts are deep copied and other values aren't copied
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2431,6 +2782,7 @@ def with_default_schema(
         default_factory_takes_data=default_factory_takes_data,
         on_error=on_error,
         validate_default=validate_default,
+        copy_on_default=copy_on_default,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -2559,6 +2911,7 @@ class TaggedUnionSchema(TypedDict, total=False):
     custom_error_context: Dict[str, Union[str, int, float]]
     strict: bool
     from_attributes: bool  # default: True
+    max_tags_in_error: int
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -2573,6 +2926,7 @@ def tagged_union_schema(
     custom_error_context: dict[str, int | str | float] | None = None,
     strict: bool | None = None,
     from_attributes: bool | None = None,
+    max_tags_in_error: int | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -2628,6 +2982,8 @@ def tagged_union_schema(
         custom_error_context: The custom error context to use if the validation fails
         strict: Whether the underlying schemas should be validated with strict mode
         from_attributes: Whether to use the attributes of the object to retrieve the discriminator value
+        max_tags_in_error: The maximum number of expected tags to list in a `union_tag_invalid` error,
+            any further tags are summarised as "and N more"
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2641,6 +2997,7 @@ def tagged_union_schema(
         custom_error_context=custom_error_context,
         strict=strict,
         from_attributes=from_attributes,
+        max_tags_in_error=max_tags_in_error,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2694,6 +3051,8 @@ class LaxOrStrictSchema(TypedDict, total=False):
     lax_schema: Required[CoreSchema]
     strict_schema: Required[CoreSchema]
     strict: bool
+    strict_context_key: str
+    strict_context_name: str
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -2704,6 +3063,8 @@ def lax_or_strict_schema(
     strict_schema: CoreSchema,
     *,
     strict: bool | None = None,
+    strict_context_key: str | None = None,
+    strict_context_name: str | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -2738,6 +3099,11 @@ def lax_or_strict_schema(
         lax_schema: The lax schema to use
         strict_schema: The strict schema to use
         strict: Whether the strict schema should be used
+        strict_context_key: A key of the validation context, if the context value for this key contains
+            `strict_context_name` (e.g. `context={'strict_fields': ['age']}`) the strict schema is used,
+            otherwise `strict` decides as usual; this allows strictness per request for specific fields
+        strict_context_name: The name to look for in the context value of `strict_context_key`, must be set
+            together with `strict_context_key`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2747,6 +3113,8 @@ def lax_or_strict_schema(
         lax_schema=lax_schema,
         strict_schema=strict_schema,
         strict=strict,
+        strict_context_key=strict_context_key,
+        strict_context_name=strict_context_name,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2817,6 +3185,8 @@ class TypedDictField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_if: Callable[[Any], bool]
+    examples: List[Any]
     metadata: Dict[str, Any]
 
 
@@ -2827,6 +3197,8 @@ def typed_dict_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_if: Callable[[Any], bool] | None = None,
+    examples: List[Any] | None = None,
     metadata: Dict[str, Any] | None = None,
 ) -> TypedDictField:
     """
@@ -2844,6 +3216,10 @@ def typed_dict_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_if: A callable called with the field value, the field is excluded when serializing
+            if it returns a truthy value
+        examples: Valid example values, not used for validation but added to the context of errors raised by the
+            field's schema itself, as `ctx['examples']`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2853,6 +3229,8 @@ def typed_dict_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_if=serialization_exclude_if,
+        examples=examples,
         metadata=metadata,
     )
 
@@ -2943,6 +3321,8 @@ class ModelField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_if: Callable[[Any], bool]
+    examples: List[Any]
     frozen: bool
     metadata: Dict[str, Any]
 
@@ -2953,6 +3333,8 @@ def model_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_if: Callable[[Any], bool] | None = None,
+    examples: List[Any] | None = None,
     frozen: bool | None = None,
     metadata: Dict[str, Any] | None = None,
 ) -> ModelField:
@@ -2970,6 +3352,10 @@ def model_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_if: A callable called with the field value, the field is excluded when serializing
+            if it returns a truthy value
+        examples: Valid example values, not used for validation but added to the context of errors raised by the
+            field's schema itself, as `ctx['examples']`
         frozen: Whether the field is frozen
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
@@ -2979,6 +3365,8 @@ def model_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_if=serialization_exclude_if,
+        examples=examples,
         frozen=frozen,
         metadata=metadata,
     )
@@ -2991,6 +3379,8 @@ class ModelFieldsSchema(TypedDict, total=False):
     computed_fields: List[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extra_audit: bool  # default: False
+    exclude_key_pattern: str
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
@@ -3007,6 +3397,8 @@ def model_fields_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extra_audit: bool | None = None,
+    exclude_key_pattern: str | None = None,
     extra_behavior: ExtraBehavior | None = None,
     populate_by_name: bool | None = None,
     from_attributes: bool | None = None,
@@ -3034,6 +3426,13 @@ def model_fields_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extra_audit: Whether keys dropped with `extra_behavior='ignore'` are still recorded, they're appended to a
+            list at `context['ignored_extras']` as `(model_name, key)` tuples when the validation context is a dict,
+            the list is created if missing, without raising any errors. Keys are only recorded once validation
+            succeeds and only for the union choice which is used, into the context passed to the validation call
+            rather than `default_context`. Only applies to mapping input
+        exclude_key_pattern: A regular expression, fields and extras whose output key (after alias resolution) it
+            matches are dropped when serializing, unless explicitly included via the `include` argument
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
@@ -3048,6 +3447,8 @@ def model_fields_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extra_audit=extra_audit,
+        exclude_key_pattern=exclude_key_pattern,
         extra_behavior=extra_behavior,
         populate_by_name=populate_by_name,
         from_attributes=from_attributes,
@@ -3066,6 +3467,8 @@ class ModelSchema(TypedDict, total=False):
     root_model: bool
     post_init: str
     revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
+    revalidate_after_root: bool
+    private_fields: Dict[str, CoreSchema]
     strict: bool
     frozen: bool
     extra_behavior: ExtraBehavior
@@ -3084,6 +3487,8 @@ def model_schema(
     root_model: bool | None = None,
     post_init: str | None = None,
     revalidate_instances: Literal['always', 'never', 'subclass-instances'] | None = None,
+    revalidate_after_root: bool | None = None,
+    private_fields: Dict[str, CoreSchema] | None = None,
     strict: bool | None = None,
     frozen: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
@@ -3133,6 +3538,13 @@ def model_schema(
         post_init: The call after init to use for the model
         revalidate_instances: whether instances of models and dataclasses (including subclass instances)
             should re-validate defaults to config.revalidate_instances, else 'never'
+        revalidate_after_root: Whether to validate the output of `function-after` and `function-wrap` root validators
+            again with the schema they wrap, at most once, so invariants hold after they transform the data
+        private_fields: Schemas of private attributes, keyed by their names, each must be a
+            [`with_default_schema`][pydantic_core.core_schema.with_default_schema]. After the fields are validated,
+            the defaults (validated with `validate_default=True`) are stored in `__pydantic_private__`,
+            so they're not in `__pydantic_fields_set__` or serialized. Default factories which take data get the
+            validated fields and errors are located by the private name
         strict: Whether the model is strict
         frozen: Whether the model is frozen
         extra_behavior: The extra behavior to use for the model, used in serialization
@@ -3150,6 +3562,8 @@ def model_schema(
         root_model=root_model,
         post_init=post_init,
         revalidate_instances=revalidate_instances,
+        revalidate_after_root=revalidate_after_root,
+        private_fields=private_fields,
         strict=strict,
         frozen=frozen,
         extra_behavior=extra_behavior,
@@ -3171,6 +3585,8 @@ class DataclassField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_if: Callable[[Any], bool]
+    examples: List[Any]
     metadata: Dict[str, Any]
 
 
@@ -3184,6 +3600,8 @@ def dataclass_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_if: Callable[[Any], bool] | None = None,
+    examples: List[Any] | None = None,
     metadata: Dict[str, Any] | None = None,
     frozen: bool | None = None,
 ) -> DataclassField:
@@ -3210,6 +3628,10 @@ def dataclass_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_if: A callable called with the field value, the field is excluded when serializing
+            if it returns a truthy value
+        examples: Valid example values, not used for validation but added to the context of errors raised by the
+            field's schema itself, as `ctx['examples']`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
     """
@@ -3223,6 +3645,8 @@ def dataclass_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_if=serialization_exclude_if,
+        examples=examples,
         metadata=metadata,
         frozen=frozen,
     )
@@ -3305,7 +3729,7 @@ class DataclassSchema(TypedDict, total=False):
     post_init: bool  # default: False
     revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
     strict: bool  # default: False
-    frozen: bool  # default False
+    frozen: bool  # default: the `frozen` parameter of `cls`
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -3349,7 +3773,7 @@ def dataclass_schema(
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
-        frozen: Whether the dataclass is frozen
+        frozen: Whether the dataclass is frozen, defaults to the `frozen` parameter of the `cls` dataclass
         slots: Whether `slots=True` on the dataclass, means each field is assigned independently, rather than
             simply setting `__dict__`, default false
     """
@@ -3599,9 +4023,175 @@ def custom_error_schema(
     )
 
 
+class NumberConstraint(TypedDict, total=False):
+    kind: Required[Literal['gt', 'ge', 'lt', 'le', 'multiple_of']]
+    value: Required[Union[int, float, Decimal]]
+
+
+class ConstraintsSchema(TypedDict, total=False):
+    type: Required[Literal['constraints']]
+    schema: Required[CoreSchema]
+    constraints: Required[List[NumberConstraint]]
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def constraints_schema(
+    schema: CoreSchema,
+    constraints: list[NumberConstraint],
+    *,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> ConstraintsSchema:
+    """
+    Returns a schema that applies a list of constraints to the output of another schema, in order,
+    stopping at the first constraint which fails, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.constraints_schema(
+        schema=core_schema.int_schema(),
+        constraints=[{'kind': 'gt', 'value': 0}, {'kind': 'multiple_of', 'value': 2}],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('4') == 4
+    ```
+
+    Args:
+        schema: The schema whose output the constraints are applied to
+        constraints: The constraints to apply, checked in order
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='constraints',
+        schema=schema,
+        constraints=constraints,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class SelfValidatingSchema(TypedDict, total=False):
+    type: Required[Literal['self-validating']]
+    schema: Required[CoreSchema]
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def self_validating_schema(
+    schema: CoreSchema,
+    *,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> SelfValidatingSchema:
+    """
+    Returns a schema that lets inputs validate themselves: if the input's type defines a
+    `__pydantic_validate__(self, info)` method, it's called with a `ValidationInfo` and its return value
+    is the validated value, any other input is validated with `schema`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Celsius:
+        def __init__(self, degrees):
+            self.degrees = degrees
+
+        def __pydantic_validate__(self, info):
+            if self.degrees < -273.15:
+                raise ValueError('below absolute zero')
+            return self.degrees
+
+    schema = core_schema.self_validating_schema(core_schema.float_schema())
+    v = SchemaValidator(schema)
+    assert v.validate_python(Celsius(21.5)) == 21.5
+    assert v.validate_python('21.5') == 21.5
+    ```
+
+    Errors raised by `__pydantic_validate__` are handled as for validator functions, so `ValueError`,
+    `AssertionError`, `PydanticCustomError` and `PydanticKnownError` become validation errors
+    and any other exception is raised as is.
+
+    Args:
+        schema: The schema used to validate inputs which don't define `__pydantic_validate__`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='self-validating',
+        schema=schema,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class SecretSchema(TypedDict, total=False):
+    type: Required[Literal['secret']]
+    schema: Required[CoreSchema]
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def secret_schema(
+    schema: CoreSchema,
+    *,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> SecretSchema:
+    """
+    Returns a schema for sensitive values: inputs are validated with `schema` and the result is wrapped in
+    a [`Secret`][pydantic_core.Secret] whose `repr()` never shows the value, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.secret_schema(core_schema.str_schema())
+    v = SchemaValidator(schema)
+    secret = v.validate_python('hunter2')
+    assert repr(secret) == "Secret('**********')"
+    assert secret.get_secret_value() == 'hunter2'
+
+    s = SchemaSerializer(schema)
+    assert s.to_json(secret) == b'"**********"'
+    assert s.to_json(secret, dump_secret_values=True) == b'"hunter2"'
+    ```
+
+    When serializing, secrets are replaced by `'**********'` in JSON mode and left as `Secret`s in python mode,
+    unless `dump_secret_values=True` is passed, then the wrapped value is serialized using `schema`.
+
+    Constraints like `min_length` are set on `schema` and checked against the wrapped value, the inputs of any
+    validation errors are wrapped in a `Secret` too so the value isn't shown in the error message.
+
+    Args:
+        schema: The schema used to validate the wrapped value, `Secret` inputs are unwrapped and revalidated
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='secret',
+        schema=schema,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 class JsonSchema(TypedDict, total=False):
     type: Required[Literal['json']]
     schema: CoreSchema
+    keep_raw: bool  # default: False
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -3610,6 +4200,7 @@ class JsonSchema(TypedDict, total=False):
 def json_schema(
     schema: CoreSchema | None = None,
     *,
+    keep_raw: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -3646,11 +4237,16 @@ def json_schema(
 
     Args:
         schema: The schema to use for the JSON schema
+        keep_raw: Whether to return a [`RawJson`][pydantic_core.RawJson] holding both the validated value and the
+            original JSON text, which is emitted again unchanged when serializing to JSON, cannot be used with
+            `allow_partial`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='json', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='json', schema=schema, keep_raw=keep_raw, ref=ref, metadata=metadata, serialization=serialization
+    )
 
 
 class UrlSchema(TypedDict, total=False):
@@ -3906,6 +4502,10 @@ if not MYPY:
         ArgumentsSchema,
         CallSchema,
         CustomErrorSchema,
+        ConstraintsSchema,
+        SelfValidatingSchema,
+        SecretSchema,
+        Base64Schema,
         JsonSchema,
         UrlSchema,
         MultiHostUrlSchema,
@@ -3913,6 +4513,7 @@ if not MYPY:
         DefinitionReferenceSchema,
         UuidSchema,
         ComplexSchema,
+        FractionSchema,
     ]
 elif False:
     CoreSchema: TypeAlias = Mapping[str, Any]
@@ -3963,6 +4564,10 @@ CoreSchemaType = Literal[
     'arguments',
     'call',
     'custom-error',
+    'constraints',
+    'self-validating',
+    'secret',
+    'base64',
     'json',
     'url',
     'multi-host-url',
@@ -3970,6 +4575,7 @@ CoreSchemaType = Literal[
     'definition-ref',
     'uuid',
     'complex',
+    'fraction',
 ]
 
 CoreSchemaFieldType = Literal['model-field', 'dataclass-field', 'typed-dict-field', 'computed-field']
@@ -3982,6 +4588,7 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'needs_python_object',
+    'json_too_large',
     'recursion_loop',
     'missing',
     'frozen_field',
@@ -4010,12 +4617,15 @@ ErrorType = Literal[
     'string_too_short',
     'string_too_long',
     'string_pattern_mismatch',
+    'string_control_char',
     'enum',
     'dict_type',
     'mapping_type',
+    'dict_key_collision',
     'list_type',
     'tuple_type',
     'set_type',
+    'set_item_not_hashable',
     'bool_type',
     'bool_parsing',
     'int_type',
@@ -4078,6 +4688,10 @@ ErrorType = Literal[
     'decimal_whole_digits',
     'complex_type',
     'complex_str_parsing',
+    'complex_max_abs',
+    'fraction_type',
+    'fraction_parsing',
+    'base64_decode',
 ]
 
 
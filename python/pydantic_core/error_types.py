@@ -0,0 +1,126 @@
+"""
+The type strings of all built-in errors, as constants.
+
+Error type strings are part of the public API: they're included in `ValidationError.errors()` and are often
+persisted or matched on, so once released a type string is never renamed or removed, new error types are only added.
+Use [`is_known_error_type`][pydantic_core.is_known_error_type] to check whether a string is one of these.
+"""
+
+from __future__ import annotations as _annotations
+
+from typing import Final as _Final
+from typing import get_args as _get_args
+
+from .core_schema import ErrorType
+
+NO_SUCH_ATTRIBUTE: _Final = 'no_such_attribute'
+JSON_INVALID: _Final = 'json_invalid'
+JSON_TYPE: _Final = 'json_type'
+NEEDS_PYTHON_OBJECT: _Final = 'needs_python_object'
+JSON_TOO_LARGE: _Final = 'json_too_large'
+RECURSION_LOOP: _Final = 'recursion_loop'
+MISSING: _Final = 'missing'
+FROZEN_FIELD: _Final = 'frozen_field'
+FROZEN_INSTANCE: _Final = 'frozen_instance'
+EXTRA_FORBIDDEN: _Final = 'extra_forbidden'
+INVALID_KEY: _Final = 'invalid_key'
+GET_ATTRIBUTE_ERROR: _Final = 'get_attribute_error'
+MODEL_TYPE: _Final = 'model_type'
+MODEL_ATTRIBUTES_TYPE: _Final = 'model_attributes_type'
+DATACLASS_TYPE: _Final = 'dataclass_type'
+DATACLASS_EXACT_TYPE: _Final = 'dataclass_exact_type'
+NONE_REQUIRED: _Final = 'none_required'
+GREATER_THAN: _Final = 'greater_than'
+GREATER_THAN_EQUAL: _Final = 'greater_than_equal'
+LESS_THAN: _Final = 'less_than'
+LESS_THAN_EQUAL: _Final = 'less_than_equal'
+MULTIPLE_OF: _Final = 'multiple_of'
+FINITE_NUMBER: _Final = 'finite_number'
+TOO_SHORT: _Final = 'too_short'
+TOO_LONG: _Final = 'too_long'
+ITERABLE_TYPE: _Final = 'iterable_type'
+ITERATION_ERROR: _Final = 'iteration_error'
+STRING_TYPE: _Final = 'string_type'
+STRING_SUB_TYPE: _Final = 'string_sub_type'
+STRING_UNICODE: _Final = 'string_unicode'
+STRING_TOO_SHORT: _Final = 'string_too_short'
+STRING_TOO_LONG: _Final = 'string_too_long'
+STRING_PATTERN_MISMATCH: _Final = 'string_pattern_mismatch'
+STRING_CONTROL_CHAR: _Final = 'string_control_char'
+ENUM: _Final = 'enum'
+DICT_TYPE: _Final = 'dict_type'
+MAPPING_TYPE: _Final = 'mapping_type'
+DICT_KEY_COLLISION: _Final = 'dict_key_collision'
+LIST_TYPE: _Final = 'list_type'
+TUPLE_TYPE: _Final = 'tuple_type'
+SET_TYPE: _Final = 'set_type'
+SET_ITEM_NOT_HASHABLE: _Final = 'set_item_not_hashable'
+BOOL_TYPE: _Final = 'bool_type'
+BOOL_PARSING: _Final = 'bool_parsing'
+INT_TYPE: _Final = 'int_type'
+INT_PARSING: _Final = 'int_parsing'
+INT_PARSING_SIZE: _Final = 'int_parsing_size'
+INT_FROM_FLOAT: _Final = 'int_from_float'
+FLOAT_TYPE: _Final = 'float_type'
+FLOAT_PARSING: _Final = 'float_parsing'
+BYTES_TYPE: _Final = 'bytes_type'
+BYTES_TOO_SHORT: _Final = 'bytes_too_short'
+BYTES_TOO_LONG: _Final = 'bytes_too_long'
+BYTES_INVALID_ENCODING: _Final = 'bytes_invalid_encoding'
+VALUE_ERROR: _Final = 'value_error'
+ASSERTION_ERROR: _Final = 'assertion_error'
+LITERAL_ERROR: _Final = 'literal_error'
+DATE_TYPE: _Final = 'date_type'
+DATE_PARSING: _Final = 'date_parsing'
+DATE_FROM_DATETIME_PARSING: _Final = 'date_from_datetime_parsing'
+DATE_FROM_DATETIME_INEXACT: _Final = 'date_from_datetime_inexact'
+DATE_PAST: _Final = 'date_past'
+DATE_FUTURE: _Final = 'date_future'
+TIME_TYPE: _Final = 'time_type'
+TIME_PARSING: _Final = 'time_parsing'
+DATETIME_TYPE: _Final = 'datetime_type'
+DATETIME_PARSING: _Final = 'datetime_parsing'
+DATETIME_OBJECT_INVALID: _Final = 'datetime_object_invalid'
+DATETIME_FROM_DATE_PARSING: _Final = 'datetime_from_date_parsing'
+DATETIME_PAST: _Final = 'datetime_past'
+DATETIME_FUTURE: _Final = 'datetime_future'
+TIMEZONE_NAIVE: _Final = 'timezone_naive'
+TIMEZONE_AWARE: _Final = 'timezone_aware'
+TIMEZONE_OFFSET: _Final = 'timezone_offset'
+TIME_DELTA_TYPE: _Final = 'time_delta_type'
+TIME_DELTA_PARSING: _Final = 'time_delta_parsing'
+FROZEN_SET_TYPE: _Final = 'frozen_set_type'
+IS_INSTANCE_OF: _Final = 'is_instance_of'
+IS_SUBCLASS_OF: _Final = 'is_subclass_of'
+CALLABLE_TYPE: _Final = 'callable_type'
+UNION_TAG_INVALID: _Final = 'union_tag_invalid'
+UNION_TAG_NOT_FOUND: _Final = 'union_tag_not_found'
+ARGUMENTS_TYPE: _Final = 'arguments_type'
+MISSING_ARGUMENT: _Final = 'missing_argument'
+UNEXPECTED_KEYWORD_ARGUMENT: _Final = 'unexpected_keyword_argument'
+MISSING_KEYWORD_ONLY_ARGUMENT: _Final = 'missing_keyword_only_argument'
+UNEXPECTED_POSITIONAL_ARGUMENT: _Final = 'unexpected_positional_argument'
+MISSING_POSITIONAL_ONLY_ARGUMENT: _Final = 'missing_positional_only_argument'
+MULTIPLE_ARGUMENT_VALUES: _Final = 'multiple_argument_values'
+URL_TYPE: _Final = 'url_type'
+URL_PARSING: _Final = 'url_parsing'
+URL_SYNTAX_VIOLATION: _Final = 'url_syntax_violation'
+URL_TOO_LONG: _Final = 'url_too_long'
+URL_SCHEME: _Final = 'url_scheme'
+UUID_TYPE: _Final = 'uuid_type'
+UUID_PARSING: _Final = 'uuid_parsing'
+UUID_VERSION: _Final = 'uuid_version'
+DECIMAL_TYPE: _Final = 'decimal_type'
+DECIMAL_PARSING: _Final = 'decimal_parsing'
+DECIMAL_MAX_DIGITS: _Final = 'decimal_max_digits'
+DECIMAL_MAX_PLACES: _Final = 'decimal_max_places'
+DECIMAL_WHOLE_DIGITS: _Final = 'decimal_whole_digits'
+COMPLEX_TYPE: _Final = 'complex_type'
+COMPLEX_STR_PARSING: _Final = 'complex_str_parsing'
+COMPLEX_MAX_ABS: _Final = 'complex_max_abs'
+FRACTION_TYPE: _Final = 'fraction_type'
+FRACTION_PARSING: _Final = 'fraction_parsing'
+BASE64_DECODE: _Final = 'base64_decode'
+
+# all built-in error type strings, the same as the members of `core_schema.ErrorType`
+KNOWN_ERROR_TYPES: _Final[frozenset[ErrorType]] = frozenset(_get_args(ErrorType))
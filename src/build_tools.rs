@@ -6,7 +6,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
-use crate::errors::ValError;
+use crate::errors::{LocFormat, ValError};
 use crate::input::InputType;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
@@ -125,21 +125,27 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty_bound(py).unbind()),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true),
+            SchemaErrorEnum::ValidationError(error) => {
+                error.errors(py, false, false, true, None, false, false, false, None, None, false)
+            }
         }
     }
 
     fn __str__(&self, py: Python) -> String {
         match &self.0 {
             SchemaErrorEnum::Message(message) => message.clone(),
-            SchemaErrorEnum::ValidationError(error) => error.display(py, Some("Invalid Schema:"), false),
+            SchemaErrorEnum::ValidationError(error) => {
+                error.display(py, Some("Invalid Schema:"), false, LocFormat::Dotted, None)
+            }
         }
     }
 
     fn __repr__(&self, py: Python) -> String {
         match &self.0 {
             SchemaErrorEnum::Message(message) => format!("SchemaError({message:?})"),
-            SchemaErrorEnum::ValidationError(error) => error.display(py, Some("Invalid Schema:"), false),
+            SchemaErrorEnum::ValidationError(error) => {
+                error.display(py, Some("Invalid Schema:"), false, LocFormat::Dotted, None)
+            }
         }
     }
 }
@@ -0,0 +1,88 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::input::InputType;
+use crate::py_gc::PyGcTraverse;
+use crate::tools::safe_repr;
+
+use super::validation_exception::PyLineError;
+use super::ValLineError;
+
+/// Stands in for the value of a field which failed validation with `mark_invalid=True`, holding the input and
+/// its errors, serializers emit it as `{"__error__": {...}}` with `inline_errors=True`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct InvalidValue {
+    input: PyObject,
+    line_errors: Vec<PyLineError>,
+    input_type: InputType,
+}
+
+impl InvalidValue {
+    pub fn new(py: Python, input: PyObject, line_errors: Vec<ValLineError>, input_type: InputType) -> Self {
+        Self {
+            input,
+            line_errors: line_errors.into_iter().map(|e| e.into_py(py)).collect(),
+            input_type,
+        }
+    }
+
+    /// The content of the `__error__` object, `{'input': ..., 'errors': [...]}`
+    pub fn error_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item(intern!(py, "input"), &self.input)?;
+        dict.set_item(intern!(py, "errors"), self.errors(py, true)?)?;
+        Ok(dict)
+    }
+}
+
+#[pymethods]
+impl InvalidValue {
+    #[getter]
+    pub fn input(&self, py: Python) -> PyObject {
+        self.input.clone_ref(py)
+    }
+
+    /// Errors as returned by `ValidationError.errors()` without URLs or inputs, locations are relative to the field
+    #[pyo3(signature = (*, include_context = true))]
+    pub fn errors<'py>(&self, py: Python<'py>, include_context: bool) -> PyResult<Bound<'py, PyList>> {
+        let errors = self
+            .line_errors
+            .iter()
+            .map(|e| {
+                e.as_dict(
+                    py,
+                    None,
+                    include_context,
+                    self.input_type,
+                    false,
+                    None,
+                    false,
+                    false,
+                    false,
+                )
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, errors))
+    }
+
+    pub fn __repr__(&self, py: Python) -> String {
+        format!(
+            "InvalidValue(input={}, error_count={})",
+            safe_repr(self.input.bind(py)),
+            self.line_errors.len()
+        )
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.input)?;
+        self.line_errors.py_gc_traverse(&visit)
+    }
+
+    fn __clear__(&mut self) {
+        Python::with_gil(|py| self.input = py.None());
+        self.line_errors.clear();
+    }
+}
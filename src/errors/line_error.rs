@@ -1,5 +1,6 @@
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::types::PyList;
 use pyo3::DowncastError;
 use pyo3::DowncastIntoError;
 
@@ -74,6 +75,46 @@ impl ValError {
         Self::LineErrors(vec![ValLineError::new_custom_input(error_type, input_value)])
     }
 
+    /// add one to the outermost location item of each line error if it's an index, used by collections
+    /// reporting 1-based indices
+    pub fn with_one_based_index(self) -> Self {
+        match self {
+            Self::LineErrors(mut line_errors) => {
+                for line_error in &mut line_errors {
+                    // last because location is stored reversed
+                    if let Location::List(loc_items) = &mut line_error.location {
+                        if let Some(LocItem::I(index)) = loc_items.last_mut() {
+                            *index += 1;
+                        }
+                    }
+                }
+                Self::LineErrors(line_errors)
+            }
+            other => other,
+        }
+    }
+
+    /// add `examples` to the context of line errors without a location, i.e. those raised by the validator
+    /// the examples are for rather than for one of its items
+    pub fn with_examples(self, py: Python, examples: Option<&Py<PyList>>) -> Self {
+        match (self, examples) {
+            (Self::LineErrors(mut line_errors), Some(examples)) => {
+                for line_error in &mut line_errors {
+                    if matches!(line_error.location, Location::Empty) {
+                        if let Err(err) = line_error
+                            .error_type
+                            .set_context_item(py, "examples", examples.bind(py))
+                        {
+                            return Self::InternalErr(err);
+                        }
+                    }
+                }
+                Self::LineErrors(line_errors)
+            }
+            (other, _) => other,
+        }
+    }
+
     /// helper function to call with_outer on line items if applicable
     pub fn with_outer_location(self, into_loc_item: impl Into<LocItem>) -> Self {
         let loc_item = into_loc_item.into();
@@ -98,14 +139,29 @@ pub struct ValLineError {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue,
+    // set when the error was flattened from a nested `ValidationError`, e.g. one raised by a function validator
+    pub source: Option<ErrorSource>,
 }
 
+/// The nested `ValidationError` a line error was flattened from, reported by `errors(include_source=True)`
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ErrorSource {
+    pub title: PyObject,
+    // the number of location items the error had within the nested `ValidationError`,
+    // any items added since are the `loc_prefix` the nested error was embedded at
+    pub depth: usize,
+}
+
+impl_py_gc_traverse!(ErrorSource { title });
+
 impl ValLineError {
     pub fn new(error_type: ErrorType, input: impl ToErrorValue) -> ValLineError {
         Self {
             error_type,
             input_value: input.to_error_value(),
             location: Location::default(),
+            source: None,
         }
     }
 
@@ -114,6 +170,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::new_some(loc.into()),
+            source: None,
         }
     }
 
@@ -122,6 +179,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location,
+            source: None,
         }
     }
 
@@ -130,6 +188,7 @@ impl ValLineError {
             error_type,
             input_value,
             location: Location::default(),
+            source: None,
         }
     }
 
@@ -1,7 +1,8 @@
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::sync::GILOnceCell;
 use std::borrow::Cow;
 use std::fmt;
+use std::fmt::Write;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyList, PyTuple};
@@ -23,6 +24,8 @@ pub enum LocItem {
     ///   * dict with int keys `Dict[int, ...]` (python only)
     ///   * with integer keys in tagged unions
     I(i64),
+    /// compound key, e.g. a tuple used as a dict key `Dict[Tuple[str, int], ...]` (python only)
+    C(Vec<LocItem>),
 }
 
 impl fmt::Display for LocItem {
@@ -31,6 +34,10 @@ impl fmt::Display for LocItem {
             Self::S(s) if s.contains('.') => write!(f, "`{s}`"),
             Self::S(s) => write!(f, "{s}"),
             Self::I(i) => write!(f, "{i}"),
+            Self::C(items) => {
+                let items_str = items.iter().map(ToString::to_string).collect::<Vec<_>>();
+                write!(f, "({})", items_str.join(", "))
+            }
         }
     }
 }
@@ -85,11 +92,39 @@ impl From<PathItem> for LocItem {
     }
 }
 
+impl LocItem {
+    /// write this item as a reference token of an RFC 6901 JSON Pointer, including the leading `/`
+    fn write_pointer_segment(&self, path: &mut impl Write) -> fmt::Result {
+        let segment = match self {
+            Self::S(s) => Cow::Borrowed(s.as_str()),
+            other => Cow::Owned(other.to_string()),
+        };
+        write!(path, "/{}", segment.replace('~', "~0").replace('/', "~1"))
+    }
+
+    /// write this item as a segment of a field path, see [`Location::field_path`]
+    fn write_path_segment(&self, path: &mut String) -> fmt::Result {
+        match self {
+            Self::S(s) if s.contains(['.', '[', ']']) => write!(path, "[{s:?}]"),
+            Self::S(s) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(s);
+                Ok(())
+            }
+            Self::I(i) => write!(path, "[{i}]"),
+            Self::C(_) => write!(path, "[{self}]"),
+        }
+    }
+}
+
 impl ToPyObject for LocItem {
     fn to_object(&self, py: Python<'_>) -> PyObject {
         match self {
             Self::S(val) => val.to_object(py),
             Self::I(val) => val.to_object(py),
+            Self::C(items) => PyTuple::new_bound(py, items).to_object(py),
         }
     }
 }
@@ -102,6 +137,13 @@ impl Serialize for LocItem {
         match self {
             Self::S(s) => serializer.serialize_str(s.as_str()),
             Self::I(loc) => serializer.serialize_i64(*loc),
+            Self::C(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
         }
     }
 }
@@ -152,15 +194,36 @@ impl From<&LookupPath> for Location {
     }
 }
 
+/// How a location is rendered in the display of a `ValidationError`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LocFormat {
+    /// `items.0.name`, strings containing `.` are quoted with backticks
+    #[default]
+    Dotted,
+    /// `/items/0/name`, RFC 6901 JSON Pointer, `~` and `/` are escaped
+    JsonPointer,
+    /// `items[0][name]`
+    Bracketed,
+}
+
+impl TryFrom<&str> for LocFormat {
+    type Error = PyErr;
+
+    fn try_from(loc_format: &str) -> PyResult<Self> {
+        match loc_format {
+            "dotted" => Ok(Self::Dotted),
+            "json_pointer" => Ok(Self::JsonPointer),
+            "bracketed" => Ok(Self::Bracketed),
+            s => Err(PyValueError::new_err(format!(
+                "Invalid loc_format: {s}, should be 'dotted', 'json_pointer' or 'bracketed'"
+            ))),
+        }
+    }
+}
+
 impl fmt::Display for Location {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::List(loc) => {
-                let loc_str = loc.iter().rev().map(ToString::to_string).collect::<Vec<_>>();
-                writeln!(f, "{}", loc_str.join("."))
-            }
-            Self::Empty => Ok(()),
-        }
+        self.write_formatted(f, LocFormat::Dotted)
     }
 }
 
@@ -172,6 +235,66 @@ impl Location {
         Self::List(loc)
     }
 
+    /// format the location as a dotted/bracketed path, e.g. `items[0].name`, usable as a form field key
+    pub fn field_path(&self) -> String {
+        let mut path = String::new();
+        for item in self.iter() {
+            // writing to a `String` can't fail
+            let _ = item.write_path_segment(&mut path);
+        }
+        path
+    }
+
+    /// write the location followed by a newline in the given format, or nothing if the location is empty
+    pub fn write_formatted(&self, f: &mut impl Write, loc_format: LocFormat) -> fmt::Result {
+        if matches!(self, Self::Empty) {
+            return Ok(());
+        }
+        for (index, item) in self.iter().enumerate() {
+            match loc_format {
+                LocFormat::Dotted => {
+                    if index > 0 {
+                        f.write_char('.')?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                LocFormat::JsonPointer => item.write_pointer_segment(f)?,
+                LocFormat::Bracketed => match item {
+                    LocItem::S(s) if index == 0 => f.write_str(s)?,
+                    LocItem::S(s) => write!(f, "[{s}]")?,
+                    other => write!(f, "[{other}]")?,
+                },
+            }
+        }
+        f.write_char('\n')
+    }
+
+    /// format the location as an RFC 6901 JSON Pointer, e.g. `/items/0/name`, the root is `""`
+    pub fn json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for item in self.iter() {
+            // writing to a `String` can't fail
+            let _ = item.write_pointer_segment(&mut pointer);
+        }
+        pointer
+    }
+
+    /// iterate over location items in order, i.e. outermost first
+    pub fn iter(&self) -> impl Iterator<Item = &LocItem> {
+        match self {
+            Self::List(loc) => loc.iter().rev(),
+            Self::Empty => [].iter().rev(),
+        }
+    }
+
+    /// the outermost `len` items of the location, `len` must not exceed the number of items
+    pub fn outer(&self, len: usize) -> Self {
+        match self {
+            Self::List(loc) if len > 0 => Self::List(loc[loc.len() - len..].to_vec()),
+            _ => Self::Empty,
+        }
+    }
+
     pub fn with_outer(&mut self, loc_item: LocItem) {
         match self {
             Self::List(ref mut loc) => loc.push(loc_item),
@@ -229,3 +352,8 @@ impl TryFrom<Option<&Bound<'_, PyAny>>> for Location {
         }
     }
 }
+
+#[pyfunction]
+pub fn format_loc(loc: &Bound<'_, PyAny>) -> PyResult<String> {
+    Ok(Location::try_from(Some(loc))?.field_path())
+}
@@ -1,15 +1,19 @@
 use pyo3::prelude::*;
 
+mod invalid_value;
 mod line_error;
 mod location;
 mod types;
 mod validation_exception;
 mod value_exception;
 
+pub use self::invalid_value::InvalidValue;
 pub use self::line_error::{InputValue, ToErrorValue, ValError, ValLineError, ValResult};
-pub use self::location::LocItem;
-pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number};
-pub use self::validation_exception::ValidationError;
+pub use self::location::{format_loc, LocFormat, LocItem};
+pub use self::types::{
+    is_known_error_type, list_all_errors, plural_s, register_error_messages, ErrorType, ErrorTypeDefaults, Number,
+};
+pub use self::validation_exception::{set_include_url, ErrorDetail, ValidationError};
 pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault};
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
@@ -1,22 +1,28 @@
 use std::any::type_name;
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
 
 use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::sync::GILOnceCell;
+use pyo3::sync::{GILOnceCell, GILProtected};
 use pyo3::types::{PyDict, PyList};
+use pyo3::{PyTraverseError, PyVisit};
 
-use ahash::AHashMap;
+use ahash::{AHashMap, RandomState};
 use num_bigint::BigInt;
 use strum::{Display, EnumMessage, IntoEnumIterator};
 use strum_macros::EnumIter;
 
 use crate::input::{InputType, Int};
-use crate::tools::{extract_i64, py_err, py_error_type};
+use crate::py_gc::PyGcTraverse;
+use crate::tools::{extract_i64, py_err, py_error_type, truncate_safe_repr};
 
 use super::PydanticCustomError;
 
+const EXTRA_FORBIDDEN_VALUE_MAX_LEN: usize = 100;
+
 #[pyfunction]
 pub fn list_all_errors(py: Python) -> PyResult<Bound<'_, PyList>> {
     let mut errors: Vec<Bound<'_, PyDict>> = Vec::with_capacity(100);
@@ -28,12 +34,15 @@ pub fn list_all_errors(py: Python) -> PyResult<Bound<'_, PyList>> {
             d.set_item("message_template_python", message_template_python)?;
             d.set_item(
                 "example_message_python",
-                error_type.render_message(py, InputType::Python)?,
+                error_type.render_message(py, InputType::Python, None)?,
             )?;
             let message_template_json = error_type.message_template_json();
             if message_template_python != message_template_json {
                 d.set_item("message_template_json", message_template_json)?;
-                d.set_item("example_message_json", error_type.render_message(py, InputType::Json)?)?;
+                d.set_item(
+                    "example_message_json",
+                    error_type.render_message(py, InputType::Json, None)?,
+                )?;
             }
             d.set_item("example_context", error_type.py_dict(py)?)?;
             errors.push(d);
@@ -42,6 +51,52 @@ pub fn list_all_errors(py: Python) -> PyResult<Bound<'_, PyList>> {
     Ok(PyList::new_bound(py, errors))
 }
 
+/// Whether `error_type` is the type string of a built-in error, custom error types are never known
+#[pyfunction]
+pub fn is_known_error_type(py: Python, error_type: &str) -> bool {
+    ErrorType::valid_type(py, error_type)
+}
+
+/// Message templates keyed by locale then by error type
+type LocaleMessages = AHashMap<String, AHashMap<String, String>>;
+
+static LOCALE_MESSAGES: GILProtected<RefCell<Option<LocaleMessages>>> = GILProtected::new(RefCell::new(None));
+
+/// Register message templates for `locale`, used in place of the default (English) templates when rendering
+/// messages for that locale; templates use the same `{placeholder}` names as the defaults
+#[pyfunction]
+pub fn register_error_messages(
+    py: Python,
+    locale: String,
+    messages: HashMap<String, String, RandomState>,
+) -> PyResult<()> {
+    let lookup = ERROR_TYPE_LOOKUP.get_or_init(py, ErrorType::build_lookup);
+    if let Some(error_type) = messages
+        .keys()
+        .find(|error_type| !lookup.contains_key(error_type.as_str()))
+    {
+        return py_err!(PyKeyError; "Invalid error type: '{}'", error_type);
+    }
+    LOCALE_MESSAGES
+        .get(py)
+        .borrow_mut()
+        .get_or_insert_with(AHashMap::new)
+        .entry(locale)
+        .or_default()
+        .extend(messages);
+    Ok(())
+}
+
+fn localized_template(py: Python, locale: &str, error_type: &str) -> Option<String> {
+    LOCALE_MESSAGES
+        .get(py)
+        .borrow()
+        .as_ref()?
+        .get(locale)?
+        .get(error_type)
+        .cloned()
+}
+
 fn field_from_context<'py, T: FromPyObject<'py>>(
     context: Option<&Bound<'py, PyDict>>,
     field_name: &str,
@@ -136,6 +191,22 @@ macro_rules! error_types {
                     )+
                 }
             }
+
+            fn context(&self) -> Option<&Py<PyDict>> {
+                match self {
+                    $(
+                        Self::$item { context, .. } => context.as_ref(),
+                    )+
+                }
+            }
+
+            fn context_mut(&mut self) -> &mut Option<Py<PyDict>> {
+                match self {
+                    $(
+                        Self::$item { context, .. } => context,
+                    )+
+                }
+            }
         }
 
         pub struct ErrorTypeDefaults {}
@@ -168,6 +239,9 @@ error_types! {
     },
     JsonType {},
     NeedsPythonObject { method_name: {ctx_type: String, ctx_fn: field_from_context} },
+    JsonTooLarge {
+        max_input_size: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // recursion error
     RecursionLoop {},
@@ -248,6 +322,10 @@ error_types! {
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringControlChar {
+        code_point: {ctx_type: String, ctx_fn: field_from_context},
+        position: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // enum errors
     Enum {
@@ -259,6 +337,9 @@ error_types! {
     MappingType {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    DictKeyCollision {
+        keys: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // list errors
     ListType {},
@@ -268,6 +349,9 @@ error_types! {
     // ---------------------
     // set errors
     SetType {},
+    SetItemNotHashable {
+        item_type: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // bool errors
     BoolType {},
@@ -308,6 +392,7 @@ error_types! {
         // context is a common field in all enums
         error_type: {ctx_type: String, ctx_fn: field_from_context},
         message_template: {ctx_type: String, ctx_fn: field_from_context},
+        code: {ctx_type: Option<String>, ctx_fn: field_from_context},
     },
     // ---------------------
     // literals
@@ -430,6 +515,16 @@ error_types! {
     // Complex errors
     ComplexType {},
     ComplexStrParsing {},
+    ComplexMaxAbs {
+        max_abs: {ctx_type: Number, ctx_fn: field_from_context},
+    },
+    // Fraction errors
+    FractionType {},
+    FractionParsing {},
+    // Base64 errors
+    Base64Decode {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
 }
 
 macro_rules! render {
@@ -454,7 +549,7 @@ macro_rules! to_string_render {
     };
 }
 
-fn plural_s<T: From<u8> + PartialEq>(value: T) -> &'static str {
+pub fn plural_s<T: From<u8> + PartialEq>(value: T) -> &'static str {
     if value == 1.into() {
         ""
     } else {
@@ -462,13 +557,41 @@ fn plural_s<T: From<u8> + PartialEq>(value: T) -> &'static str {
     }
 }
 
+/// The `unit` from the context of bytes length errors, `bytes` by default, made singular for a length of 1
+fn bytes_length_unit(py: Python, context: Option<&Py<PyDict>>, length: usize) -> PyResult<String> {
+    let unit = match context {
+        Some(context) => context.bind(py).get_item("unit")?,
+        None => None,
+    };
+    let unit: String = match unit {
+        Some(unit) => unit.extract()?,
+        None => "bytes".to_owned(),
+    };
+    match (length, unit.strip_suffix('s')) {
+        (1, Some(singular)) => Ok(singular.to_owned()),
+        _ => Ok(unit),
+    }
+}
+
+pub const ERROR_CATEGORIES: [&str; 4] = ["missing", "type", "value", "custom"];
+
 static ERROR_TYPE_LOOKUP: GILOnceCell<AHashMap<String, ErrorType>> = GILOnceCell::new();
 
+impl PyGcTraverse for ErrorType {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Self::ValueError { error, .. } | Self::AssertionError { error, .. } = self {
+            error.py_gc_traverse(visit)?;
+        }
+        self.context().map_or(Ok(()), |context| visit.call(context))
+    }
+}
+
 impl ErrorType {
     pub fn new_custom_error(py: Python, custom_error: PydanticCustomError) -> Self {
         Self::CustomError {
             error_type: custom_error.error_type().to_owned(),
             message_template: custom_error.message_template().to_owned(),
+            code: custom_error.code().map(ToOwned::to_owned),
             context: custom_error.context(py),
         }
     }
@@ -479,6 +602,7 @@ impl ErrorType {
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
             Self::NeedsPythonObject {..} => "Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead",
+            Self::JsonTooLarge {..} => "JSON input should be at most {max_input_size} byte{expected_plural}",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
@@ -507,12 +631,15 @@ impl ErrorType {
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringControlChar {..} => "String should not contain control characters, found {code_point} at position {position}",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
+            Self::DictKeyCollision {..} => "Dictionary keys should be unique after validation, got colliding keys {keys}",
             Self::ListType {..} => "Input should be a valid list",
             Self::TupleType {..} => "Input should be a valid tuple",
             Self::SetType {..} => "Input should be a valid set",
+            Self::SetItemNotHashable {..} => "Set items should be hashable, got an unhashable `{item_type}`",
             Self::BoolType {..} => "Input should be a valid boolean",
             Self::BoolParsing {..} => "Input should be a valid boolean, unable to interpret input",
             Self::IntType {..} => "Input should be a valid integer",
@@ -522,8 +649,8 @@ impl ErrorType {
             Self::FloatType {..} => "Input should be a valid number",
             Self::FloatParsing {..} => "Input should be a valid number, unable to parse string as a number",
             Self::BytesType {..} => "Input should be a valid bytes",
-            Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
-            Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
+            Self::BytesTooShort {..} => "Data should have at least {min_length} {unit}",
+            Self::BytesTooLong {..} => "Data should have at most {max_length} {unit}",
             Self::BytesInvalidEncoding { .. } => "Data should be valid {encoding}: {encoding_error}",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
@@ -576,6 +703,10 @@ impl ErrorType {
             Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
             Self::ComplexType {..} => "Input should be a valid python complex object, a number, or a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
             Self::ComplexStrParsing {..} => "Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
+            Self::ComplexMaxAbs {..} => "Complex input should have an absolute value less than or equal to {max_abs}",
+            Self::FractionType {..} => "Fraction input should be an integer, string or Fraction object",
+            Self::FractionParsing {..} => "Input should be a valid fraction",
+            Self::Base64Decode {..} => "Input should be valid base64, {error}",
         }
     }
 
@@ -620,15 +751,88 @@ impl ErrorType {
         }
     }
 
-    pub fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
-        let tmpl = match input_type {
-            InputType::Python => self.message_template_python(),
-            _ => self.message_template_json(),
+    /// Short stable code for the error, custom errors may set this explicitly, otherwise it's the type string
+    pub fn code(&self) -> String {
+        match self {
+            Self::CustomError { code: Some(code), .. } => code.clone(),
+            _ => self.type_string(),
+        }
+    }
+
+    /// Coarse category of the error used by `ValidationError.categorize()`, one of [`ERROR_CATEGORIES`]
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::Missing { .. }
+            | Self::MissingArgument { .. }
+            | Self::MissingKeywordOnlyArgument { .. }
+            | Self::MissingPositionalOnlyArgument { .. } => "missing",
+            Self::JsonInvalid { .. }
+            | Self::JsonType { .. }
+            | Self::NeedsPythonObject { .. }
+            | Self::ModelType { .. }
+            | Self::ModelAttributesType { .. }
+            | Self::DataclassType { .. }
+            | Self::DataclassExactType { .. }
+            | Self::NoneRequired { .. }
+            | Self::IterableType { .. }
+            | Self::StringType { .. }
+            | Self::StringSubType { .. }
+            | Self::DictType { .. }
+            | Self::MappingType { .. }
+            | Self::ListType { .. }
+            | Self::TupleType { .. }
+            | Self::SetType { .. }
+            | Self::BoolType { .. }
+            | Self::BoolParsing { .. }
+            | Self::IntType { .. }
+            | Self::IntParsing { .. }
+            | Self::IntFromFloat { .. }
+            | Self::FloatType { .. }
+            | Self::FloatParsing { .. }
+            | Self::BytesType { .. }
+            | Self::DateType { .. }
+            | Self::DateParsing { .. }
+            | Self::TimeType { .. }
+            | Self::TimeParsing { .. }
+            | Self::DatetimeType { .. }
+            | Self::DatetimeParsing { .. }
+            | Self::TimeDeltaType { .. }
+            | Self::TimeDeltaParsing { .. }
+            | Self::FrozenSetType { .. }
+            | Self::IsInstanceOf { .. }
+            | Self::IsSubclassOf { .. }
+            | Self::CallableType { .. }
+            | Self::ArgumentsType { .. }
+            | Self::UrlType { .. }
+            | Self::UrlParsing { .. }
+            | Self::UuidType { .. }
+            | Self::UuidParsing { .. }
+            | Self::DecimalType { .. }
+            | Self::DecimalParsing { .. }
+            | Self::ComplexType { .. }
+            | Self::ComplexStrParsing { .. }
+            | Self::FractionType { .. }
+            | Self::FractionParsing { .. } => "type",
+            Self::CustomError { .. } => "custom",
+            _ => "value",
+        }
+    }
+
+    pub fn render_message(&self, py: Python, input_type: InputType, locale: Option<&str>) -> PyResult<String> {
+        let localized = locale.and_then(|locale| localized_template(py, locale, &self.to_string()));
+        let tmpl = match (&localized, input_type) {
+            (Some(localized), _) => localized.as_str(),
+            (None, InputType::Python) => self.message_template_python(),
+            (None, _) => self.message_template_json(),
         };
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
             Self::NeedsPythonObject { method_name, .. } => render!(tmpl, method_name),
+            Self::JsonTooLarge { max_input_size, .. } => {
+                let expected_plural = plural_s(*max_input_size);
+                to_string_render!(tmpl, max_input_size, expected_plural)
+            }
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassType { class_name, .. } => render!(tmpl, class_name),
@@ -658,6 +862,7 @@ impl ErrorType {
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
             Self::IterationError { error, .. } => render!(tmpl, error),
+            Self::SetItemNotHashable { item_type, .. } => render!(tmpl, item_type),
             Self::StringTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
                 to_string_render!(tmpl, min_length, expected_plural)
@@ -667,15 +872,21 @@ impl ErrorType {
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringControlChar {
+                code_point, position, ..
+            } => to_string_render!(tmpl, code_point, position),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::MappingType { error, .. } => render!(tmpl, error),
-            Self::BytesTooShort { min_length, .. } => {
+            Self::DictKeyCollision { keys, .. } => render!(tmpl, keys),
+            Self::BytesTooShort { min_length, context } => {
                 let expected_plural = plural_s(*min_length);
-                to_string_render!(tmpl, min_length, expected_plural)
+                let unit = bytes_length_unit(py, context.as_ref(), *min_length)?;
+                to_string_render!(tmpl, min_length, unit, expected_plural)
             }
-            Self::BytesTooLong { max_length, .. } => {
+            Self::BytesTooLong { max_length, context } => {
                 let expected_plural = plural_s(*max_length);
-                to_string_render!(tmpl, max_length, expected_plural)
+                let unit = bytes_length_unit(py, context.as_ref(), *max_length)?;
+                to_string_render!(tmpl, max_length, unit, expected_plural)
             }
             Self::BytesInvalidEncoding {
                 encoding,
@@ -727,6 +938,7 @@ impl ErrorType {
             }
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
             Self::UuidParsing { error, .. } => render!(tmpl, error),
+            Self::Base64Decode { error, .. } => render!(tmpl, error),
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
             Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
@@ -740,20 +952,44 @@ impl ErrorType {
                 let expected_plural = plural_s(*whole_digits);
                 to_string_render!(tmpl, whole_digits, expected_plural)
             }
+            Self::ComplexMaxAbs { max_abs, .. } => to_string_render!(tmpl, max_abs),
             _ => Ok(tmpl.to_string()),
         }
     }
 
+    /// `extra_forbidden` with the repr of the rejected value, truncated to `EXTRA_FORBIDDEN_VALUE_MAX_LEN`,
+    /// in the context so it's visible when errors are reported without the input
+    pub fn extra_forbidden(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let context = PyDict::new_bound(value.py());
+        context.set_item("value", truncate_safe_repr(value, Some(EXTRA_FORBIDDEN_VALUE_MAX_LEN)))?;
+        Ok(Self::ExtraForbidden {
+            context: Some(context.unbind()),
+        })
+    }
+
+    /// set an extra item in the context, copying rather than changing any context that was already set
+    pub fn set_context_item(&mut self, py: Python, key: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let context = self.context_mut();
+        let dict = match context {
+            Some(existing) => existing.bind(py).copy()?,
+            None => PyDict::new_bound(py),
+        };
+        dict.set_item(key, value)?;
+        *context = Some(dict.unbind());
+        Ok(())
+    }
+
     pub fn py_dict(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
         let dict = PyDict::new_bound(py);
         let custom_ctx_used = self.py_dict_update_ctx(py, &dict)?;
 
         if let Self::CustomError { .. } = self {
             if custom_ctx_used {
-                // Custom error type and message are handled separately by the caller.
+                // Custom error type, message and code are handled separately by the caller.
                 // They are added to the root of the ErrorDetails.
                 dict.del_item("error_type")?;
                 dict.del_item("message_template")?;
+                dict.del_item("code")?;
                 Ok(Some(dict.into()))
             } else {
                 Ok(None)
@@ -1,13 +1,15 @@
+use std::collections::HashSet;
 use std::fmt;
 use std::fmt::{Display, Write};
 use std::str::from_utf8;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
+use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::ffi;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyList, PyString, PyType};
+use pyo3::types::{IntoPyDict, PyBytes, PyDict, PyList, PyModule, PyString, PyTuple, PyType};
 use serde::ser::{Error, SerializeMap, SerializeSeq};
 use serde::{Serialize, Serializer};
 
@@ -17,12 +19,13 @@ use crate::build_tools::py_schema_error_type;
 use crate::errors::LocItem;
 use crate::get_pydantic_version;
 use crate::input::InputType;
+use crate::serializers::ser::RawJsonStr;
 use crate::serializers::{DuckTypingSerMode, Extra, SerMode, SerializationState};
 use crate::tools::{safe_repr, write_truncated_to_limited_bytes, SchemaDict};
 
-use super::line_error::ValLineError;
-use super::location::Location;
-use super::types::ErrorType;
+use super::line_error::{ErrorSource, ValLineError};
+use super::location::{LocFormat, Location};
+use super::types::{ErrorType, ERROR_CATEGORIES};
 use super::value_exception::PydanticCustomError;
 use super::{InputValue, ValError};
 
@@ -84,9 +87,24 @@ impl ValidationError {
         }
     }
 
-    pub fn display(&self, py: Python, prefix_override: Option<&'static str>, hide_input: bool) -> String {
+    pub fn display(
+        &self,
+        py: Python,
+        prefix_override: Option<&'static str>,
+        hide_input: bool,
+        loc_format: LocFormat,
+        locale: Option<&str>,
+    ) -> String {
         let url_prefix = get_url_prefix(py, include_url_env(py));
-        let line_errors = pretty_py_line_errors(py, self.input_type, self.line_errors.iter(), url_prefix, hide_input);
+        let line_errors = pretty_py_line_errors(
+            py,
+            self.input_type,
+            self.line_errors.iter(),
+            url_prefix,
+            hide_input,
+            loc_format,
+            locale,
+        );
         if let Some(prefix) = prefix_override {
             format!("{prefix}\n{line_errors}")
         } else {
@@ -189,17 +207,73 @@ impl ValidationError {
         }
         None
     }
+
+    /// Errors which share a location prefix with other errors are nested in an `ExceptionGroup` named after
+    /// that prefix, everything else becomes a `ValueError` leaf.
+    fn exception_group_children<'py>(
+        &self,
+        py: Python<'py>,
+        group_cls: &Bound<'py, PyAny>,
+        errors: &[&LocatedLineError<'_>],
+        depth: usize,
+    ) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        let mut groups: Vec<(Option<&LocItem>, Vec<&LocatedLineError<'_>>)> = Vec::new();
+        for error in errors {
+            let key = error.0.get(depth).copied();
+            match groups.iter_mut().find(|(k, _)| key.is_some() && *k == key) {
+                Some((_, group)) => group.push(error),
+                None => groups.push((key, vec![error])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(key, group)| match (key, group.as_slice()) {
+                (Some(_), [first, _, ..]) => {
+                    let prefix = first.0[..=depth].iter().map(ToString::to_string).collect::<Vec<_>>();
+                    let children = self.exception_group_children(py, group_cls, &group, depth + 1)?;
+                    group_cls.call1((prefix.join("."), children))
+                }
+                (_, [error]) => self.exception_group_leaf(py, error.1),
+                _ => unreachable!("groups are never empty, and only errors with a key are grouped"),
+            })
+            .collect()
+    }
+
+    fn exception_group_leaf<'py>(&self, py: Python<'py>, line_error: &PyLineError) -> PyResult<Bound<'py, PyAny>> {
+        let message = line_error.error_type.render_message(py, self.input_type, None)?;
+        let leaf = py.get_type_bound::<PyValueError>().call1((message,))?;
+        leaf.setattr(intern!(py, "loc"), line_error.location.to_object(py))?;
+        leaf.setattr(intern!(py, "type"), line_error.error_type.type_string())?;
+        Ok(leaf)
+    }
+
+    /// The line errors as a list of `PyLineError::pickle_state` dicts
+    fn pickle_line_errors<'py>(&self, py: Python<'py>, repr_unpicklable: bool) -> PyResult<Bound<'py, PyList>> {
+        let states = self
+            .line_errors
+            .iter()
+            .map(|line_error| line_error.pickle_state(py, repr_unpicklable))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, states))
+    }
 }
 
-static URL_ENV_VAR: GILOnceCell<bool> = GILOnceCell::new();
+type LocatedLineError<'a> = (Vec<&'a LocItem>, &'a PyLineError);
+
+// an atomic so the value read from the environment can be overridden at runtime via `set_include_url`
+static URL_ENV_VAR: GILOnceCell<AtomicBool> = GILOnceCell::new();
 
 fn include_url_env(py: Python) -> bool {
-    *URL_ENV_VAR.get_or_init(py, || {
+    include_url_cell(py).load(Ordering::Relaxed)
+}
+
+fn include_url_cell(py: Python<'_>) -> &'static AtomicBool {
+    URL_ENV_VAR.get_or_init(py, || {
         // Check the legacy env var first.
         // Using `var_os` here instead of `var` because we don't care about
         // the value (or whether we're able to decode it as UTF-8), just
         // whether it exists (and if it does, whether it's non-empty).
-        match std::env::var_os("PYDANTIC_ERRORS_OMIT_URL") {
+        let include_url = match std::env::var_os("PYDANTIC_ERRORS_OMIT_URL") {
             Some(val) => {
                 // We don't care whether warning succeeded or not, hence the assignment
                 let _ = PyErr::warn_bound(
@@ -216,10 +290,18 @@ fn include_url_env(py: Python) -> bool {
                 Ok(val) => val == "1" || val.to_lowercase() == "true",
                 Err(_) => true,
             },
-        }
+        };
+        AtomicBool::new(include_url)
     })
 }
 
+/// Set whether error URLs are included when displaying validation errors, overriding the
+/// `PYDANTIC_ERRORS_INCLUDE_URL` environment variable
+#[pyfunction]
+pub fn set_include_url(py: Python, value: bool) {
+    include_url_cell(py).store(value, Ordering::Relaxed);
+}
+
 static URL_PREFIX: GILOnceCell<String> = GILOnceCell::new();
 
 fn get_formated_url(py: Python) -> &'static str {
@@ -239,10 +321,52 @@ fn get_url_prefix(py: Python, include_url: bool) -> Option<&str> {
     }
 }
 
+/// The error types to include documentation URLs for, from the `url_types` (allow) and `exclude_url_types`
+/// (deny) arguments of `errors()` and `json()`
+#[derive(Default)]
+struct UrlTypes {
+    include: Option<HashSet<String>>,
+    exclude: Option<HashSet<String>>,
+}
+
+impl UrlTypes {
+    fn allows(&self, error_type: &ErrorType) -> bool {
+        let type_string = error_type.type_string();
+        self.include
+            .as_ref()
+            .map_or(true, |include| include.contains(&type_string))
+            && self
+                .exclude
+                .as_ref()
+                .map_or(true, |exclude| !exclude.contains(&type_string))
+    }
+}
+
 // used to convert a validation error back to ValError for wrap functions
 impl ValidationError {
-    pub(crate) fn into_val_error(self) -> ValError {
-        self.line_errors.into_iter().map(Into::into).collect::<Vec<_>>().into()
+    /// Build the line errors in one pass from a borrowed `ValidationError`, rather than extracting (and so
+    /// cloning) the whole error first then converting each line error
+    pub(crate) fn to_val_error(&self, py: Python) -> ValError {
+        self.line_errors
+            .iter()
+            .map(|line_error| ValLineError {
+                error_type: line_error.error_type.clone(),
+                location: line_error.location.clone(),
+                input_value: InputValue::Python(line_error.input_value.clone_ref(py)),
+                // errors already flattened from a deeper `ValidationError` keep that as their source
+                source: Some(match &line_error.source {
+                    Some(source) => ErrorSource {
+                        title: source.title.clone_ref(py),
+                        depth: source.depth,
+                    },
+                    None => ErrorSource {
+                        title: self.title.clone_ref(py),
+                        depth: line_error.location.iter().count(),
+                    },
+                }),
+            })
+            .collect::<Vec<_>>()
+            .into()
     }
 }
 
@@ -288,14 +412,51 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true, locale = None, include_code = false, as_objects = false, loc_as_pointer = false, url_types = None, exclude_url_types = None, include_source = false))]
+    #[allow(clippy::too_many_arguments)]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        locale: Option<&str>,
+        include_code: bool,
+        as_objects: bool,
+        loc_as_pointer: bool,
+        url_types: Option<HashSet<String>>,
+        exclude_url_types: Option<HashSet<String>>,
+        include_source: bool,
     ) -> PyResult<Py<PyList>> {
+        let url_types = UrlTypes {
+            include: url_types,
+            exclude: exclude_url_types,
+        };
+        if as_objects {
+            if include_code || loc_as_pointer || include_source {
+                return Err(PyTypeError::new_err(
+                    "`include_code`, `loc_as_pointer` and `include_source` can't be used with `as_objects=True`",
+                ));
+            }
+            let details = self
+                .line_errors
+                .iter()
+                .map(|line_error| {
+                    Py::new(
+                        py,
+                        ErrorDetail {
+                            line_error: line_error.clone(),
+                            input_type: self.input_type,
+                            include_url: include_url && url_types.allows(&line_error.error_type),
+                            include_context,
+                            include_input,
+                            locale: locale.map(ToOwned::to_owned),
+                        },
+                    )
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(PyList::new_bound(py, details).unbind());
+        }
         let url_prefix = get_url_prefix(py, include_url);
         let mut iteration_error = None;
         let list = PyList::new_bound(
@@ -307,11 +468,21 @@ impl ValidationError {
                 if iteration_error.is_some() {
                     return py.None();
                 }
-                e.as_dict(py, url_prefix, include_context, self.input_type, include_input)
-                    .unwrap_or_else(|err| {
-                        iteration_error = Some(err);
-                        py.None()
-                    })
+                e.as_dict(
+                    py,
+                    url_prefix.filter(|_| url_types.allows(&e.error_type)),
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    locale,
+                    include_code,
+                    loc_as_pointer,
+                    include_source,
+                )
+                .unwrap_or_else(|err| {
+                    iteration_error = Some(err);
+                    py.None()
+                })
             }),
         );
         if let Some(err) = iteration_error {
@@ -321,7 +492,84 @@ impl ValidationError {
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
+    /// The first error as returned by `errors()`, or the one with the shortest location if `prioritize_shallow`,
+    /// without building dicts for the other errors
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true, prioritize_shallow = false, locale = None))]
+    pub fn first_error(
+        &self,
+        py: Python,
+        include_url: bool,
+        include_context: bool,
+        include_input: bool,
+        prioritize_shallow: bool,
+        locale: Option<&str>,
+    ) -> PyResult<Option<PyObject>> {
+        let line_error = if prioritize_shallow {
+            // `min_by_key` returns the first of equally shallow errors
+            self.line_errors.iter().min_by_key(|e| e.location.iter().count())
+        } else {
+            self.line_errors.first()
+        };
+        line_error
+            .map(|e| {
+                e.as_dict(
+                    py,
+                    get_url_prefix(py, include_url),
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    locale,
+                    false,
+                    false,
+                    false,
+                )
+            })
+            .transpose()
+    }
+
+    #[pyo3(signature = (*, include_url = true, include_context = true, loc_prefix = None, locale = None))]
+    pub fn to_dict<'py>(
+        &self,
+        py: Python<'py>,
+        include_url: bool,
+        include_context: bool,
+        loc_prefix: Option<&Bound<'py, PyAny>>,
+        locale: Option<&str>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let errors = self
+            .errors(
+                py,
+                include_url,
+                include_context,
+                true,
+                locale,
+                false,
+                false,
+                false,
+                None,
+                None,
+                false,
+            )?
+            .into_bound(py);
+        if let Some(loc_prefix) = loc_prefix {
+            let loc_prefix = Location::try_from(Some(loc_prefix))?.to_object(py);
+            let loc_key = intern!(py, "loc");
+            for error in errors.iter() {
+                let error = error.downcast::<PyDict>()?;
+                if let Some(loc) = error.get_item(loc_key)? {
+                    error.set_item(loc_key, loc_prefix.bind(py).add(loc)?)?;
+                }
+            }
+        }
+        let dict = PyDict::new_bound(py);
+        dict.set_item("title", self.title.bind(py))?;
+        dict.set_item("error_count", self.error_count())?;
+        dict.set_item("errors", errors)?;
+        Ok(dict)
+    }
+
+    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true, locale = None, include_code = false, max_input_repr_len = None, loc_as_pointer = false, url_types = None, exclude_url_types = None, include_source = false))]
+    #[allow(clippy::too_many_arguments)]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
@@ -329,7 +577,17 @@ impl ValidationError {
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        locale: Option<&str>,
+        include_code: bool,
+        max_input_repr_len: Option<usize>,
+        loc_as_pointer: bool,
+        url_types: Option<HashSet<String>>,
+        exclude_url_types: Option<HashSet<String>>,
+        include_source: bool,
     ) -> PyResult<Bound<'py, PyString>> {
+        if max_input_repr_len == Some(0) {
+            return Err(PyValueError::new_err("max_input_repr_len must be greater than 0"));
+        }
         let state = SerializationState::new("iso8601", "utf8", "constants")?;
         let extra = state.extra(
             py,
@@ -346,10 +604,19 @@ impl ValidationError {
             py,
             line_errors: &self.line_errors,
             url_prefix: get_url_prefix(py, include_url),
+            url_types: &UrlTypes {
+                include: url_types,
+                exclude: exclude_url_types,
+            },
             include_context,
             include_input,
             extra: &extra,
             input_type: &self.input_type,
+            locale,
+            include_code,
+            max_input_repr_len,
+            loc_as_pointer,
+            include_source,
         };
 
         let writer: Vec<u8> = Vec::with_capacity(self.line_errors.len() * 200);
@@ -371,26 +638,269 @@ impl ValidationError {
         Ok(PyString::new_bound(py, s))
     }
 
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true, locale = None))]
+    pub fn categorize<'py>(
+        &self,
+        py: Python<'py>,
+        include_url: bool,
+        include_context: bool,
+        include_input: bool,
+        locale: Option<&str>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let url_prefix = get_url_prefix(py, include_url);
+        let categories = ERROR_CATEGORIES.map(|category| (category, PyList::empty_bound(py)));
+        for line_error in &self.line_errors {
+            let error = line_error.as_dict(
+                py,
+                url_prefix,
+                include_context,
+                self.input_type,
+                include_input,
+                locale,
+                false,
+                false,
+                false,
+            )?;
+            let category = line_error.error_type.category();
+            if let Some((_, errors)) = categories.iter().find(|(c, _)| *c == category) {
+                errors.append(error)?;
+            }
+        }
+        Ok(categories.into_py_dict_bound(py))
+    }
+
+    pub fn as_exception_group<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        if py.version_info() < (3, 11) {
+            return Err(PyRuntimeError::new_err(
+                "as_exception_group() requires Python 3.11 or later",
+            ));
+        }
+        let group_cls = py
+            .import_bound(intern!(py, "builtins"))?
+            .getattr(intern!(py, "ExceptionGroup"))?;
+        let errors: Vec<(Vec<&LocItem>, &PyLineError)> = self
+            .line_errors
+            .iter()
+            .map(|line_error| (line_error.location.iter().collect(), line_error))
+            .collect();
+        let children = self.exception_group_children(py, &group_cls, &errors.iter().collect::<Vec<_>>(), 0)?;
+        let count = self.line_errors.len();
+        let plural = if count == 1 { "" } else { "s" };
+        let title = self.title.bind(py).str()?;
+        group_cls.call1((format!("{count} validation error{plural} for {title}"), children))
+    }
+
     fn __repr__(&self, py: Python) -> String {
-        self.display(py, None, self.hide_input)
+        self.display(py, None, self.hide_input, LocFormat::Dotted, None)
+    }
+
+    /// `str(error)`, with locations rendered in `loc_format` and messages in `locale`
+    #[pyo3(name = "display", signature = (*, loc_format = "dotted", locale = None))]
+    fn py_display(&self, py: Python, loc_format: &str, locale: Option<&str>) -> PyResult<String> {
+        Ok(self.display(py, None, self.hide_input, LocFormat::try_from(loc_format)?, locale))
     }
 
     fn __str__(&self, py: Python) -> String {
         self.__repr__(py)
     }
 
-    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, PyObject)> {
+    /// The title, line errors, input type and `hide_input`, line errors are pickled together up front so
+    /// inputs and context values are only replaced by their repr if that fails
+    fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        let pickle = pickle_module(py)?;
+        let line_errors = self.pickle_line_errors(py, false)?;
+        let line_errors = match pickle.call_method1(intern!(py, "dumps"), (line_errors,)) {
+            Ok(bytes) => bytes.unbind(),
+            Err(_) => self.pickle_line_errors(py, true)?.into_py(py),
+        };
+        Ok(PyTuple::new_bound(
+            py,
+            [
+                self.title.clone_ref(py),
+                line_errors,
+                self.input_type.into_py(py),
+                self.hide_input.into_py(py),
+            ],
+        ))
+    }
+
+    fn __setstate__(&mut self, state: &Bound<'_, PyTuple>) -> PyResult<()> {
+        let py = state.py();
+        let (title, line_errors, input_type, hide_input): (PyObject, Bound<'_, PyAny>, String, bool) =
+            state.extract()?;
+        let line_errors = match line_errors.downcast::<PyBytes>() {
+            Ok(bytes) => pickle_module(py)?.call_method1(intern!(py, "loads"), (bytes,))?,
+            Err(_) => line_errors,
+        };
+        self.line_errors = line_errors
+            .downcast::<PyList>()?
+            .iter()
+            .map(|error| PyLineError::from_pickle_state(&error))
+            .collect::<PyResult<_>>()?;
+        self.title = title;
+        self.input_type = InputType::try_from(input_type.as_str())?;
+        self.hide_input = hide_input;
+        Ok(())
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, PyObject, Bound<'py, PyTuple>)> {
         let py = slf.py();
-        let callable = slf.getattr("from_exception_data")?;
         let borrow = slf.try_borrow()?;
         let args = (
-            borrow.title.bind(py),
-            borrow.errors(py, include_url_env(py), true, true)?,
+            borrow.title.clone_ref(py),
+            PyList::empty_bound(py),
             borrow.input_type.into_py(py),
             borrow.hide_input,
         )
-            .into_py(slf.py());
-        Ok((callable, args))
+            .into_py(py);
+        Ok((slf.get_type(), args, borrow.__getstate__(py)?))
+    }
+}
+
+static PICKLE_MODULE: GILOnceCell<Py<PyModule>> = GILOnceCell::new();
+
+fn pickle_module(py: Python) -> PyResult<&Bound<'_, PyModule>> {
+    PICKLE_MODULE
+        .get_or_try_init(py, || Ok(py.import_bound(intern!(py, "pickle"))?.unbind()))
+        .map(|module| module.bind(py))
+}
+
+/// `value` if it can be pickled, otherwise its repr
+fn picklable_or_repr(value: Bound<'_, PyAny>) -> PyResult<Bound<'_, PyAny>> {
+    let py = value.py();
+    match pickle_module(py)?.call_method1(intern!(py, "dumps"), (&value,)) {
+        Ok(_) => Ok(value),
+        Err(_) => Ok(safe_repr(&value).to_string().into_py(py).into_bound(py)),
+    }
+}
+
+/// Object version of `ErrorDetails`, returned by `ValidationError.errors(as_objects=True)`,
+/// fields are computed on access.
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct ErrorDetail {
+    line_error: PyLineError,
+    input_type: InputType,
+    include_url: bool,
+    include_context: bool,
+    include_input: bool,
+    locale: Option<String>,
+}
+
+#[pymethods]
+impl ErrorDetail {
+    #[new]
+    #[pyo3(signature = (details, input_type = "python", include_url = true, include_context = true, include_input = true, locale = None))]
+    fn py_new(
+        details: &Bound<'_, PyAny>,
+        input_type: &str,
+        include_url: bool,
+        include_context: bool,
+        include_input: bool,
+        locale: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            line_error: PyLineError::try_from(details)?,
+            input_type: InputType::try_from(input_type)?,
+            include_url,
+            include_context,
+            include_input,
+            locale,
+        })
+    }
+
+    #[getter(r#type)]
+    fn error_type(&self) -> String {
+        self.line_error.error_type.type_string()
+    }
+
+    #[getter]
+    fn code(&self) -> String {
+        self.line_error.error_type.code()
+    }
+
+    #[getter]
+    fn loc(&self, py: Python) -> PyObject {
+        self.line_error.location.to_object(py)
+    }
+
+    fn field_path(&self) -> String {
+        self.line_error.location.field_path()
+    }
+
+    #[getter]
+    fn msg(&self, py: Python) -> PyResult<String> {
+        self.line_error
+            .error_type
+            .render_message(py, self.input_type, self.locale.as_deref())
+    }
+
+    #[getter]
+    fn input(&self, py: Python) -> Option<PyObject> {
+        self.include_input.then(|| self.line_error.input_value.clone_ref(py))
+    }
+
+    #[getter]
+    fn ctx(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
+        if self.include_context {
+            self.line_error.error_type.py_dict(py)
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[getter]
+    fn url(&self, py: Python) -> Option<String> {
+        match (&self.line_error.error_type, get_url_prefix(py, self.include_url)) {
+            (ErrorType::CustomError { .. }, _) | (_, None) => None,
+            (_, Some(url_prefix)) => Some(self.line_error.get_error_url(url_prefix)),
+        }
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!(
+            "ErrorDetail(type={}, loc={}, msg={}, input={})",
+            self.error_type().into_py(py).bind(py).repr()?,
+            self.loc(py).bind(py).repr()?,
+            self.msg(py)?.into_py(py).bind(py).repr()?,
+            safe_repr(self.line_error.input_value.bind(py)),
+        ))
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, PyObject)> {
+        let py = slf.py();
+        let borrow = slf.get();
+        let details = borrow
+            .line_error
+            .as_dict(py, None, true, borrow.input_type, true, None, false, false, false)?;
+        if let ErrorType::CustomError {
+            error_type,
+            message_template,
+            code,
+            context,
+        } = &borrow.line_error.error_type
+        {
+            // custom error types can't be looked up by name, so the `PydanticCustomError` is required to rebuild them
+            let custom_error = PydanticCustomError::py_new(
+                error_type.clone(),
+                message_template.clone(),
+                context.as_ref().map(|c| c.bind(py).clone()),
+                code.clone(),
+            );
+            details
+                .bind(py)
+                .set_item(intern!(py, "type"), Py::new(py, custom_error)?)?;
+        }
+        let args = (
+            details,
+            borrow.input_type.into_py(py),
+            borrow.include_url,
+            borrow.include_context,
+            borrow.include_input,
+            borrow.locale.clone(),
+        )
+            .into_py(py);
+        Ok((slf.get_type().into_any(), args))
     }
 }
 
@@ -400,9 +910,11 @@ pub fn pretty_py_line_errors<'a>(
     line_errors_iter: impl Iterator<Item = &'a PyLineError>,
     url_prefix: Option<&str>,
     hide_input: bool,
+    loc_format: LocFormat,
+    locale: Option<&str>,
 ) -> String {
     line_errors_iter
-        .map(|i| i.pretty(py, input_type, url_prefix, hide_input))
+        .map(|i| i.pretty(py, input_type, url_prefix, hide_input, loc_format, locale))
         .collect::<Result<Vec<_>, _>>()
         .unwrap_or_else(|err| vec![format!("[error formatting line errors: {err}]")])
         .join("\n")
@@ -416,25 +928,22 @@ pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
     input_value: PyObject,
+    source: Option<ErrorSource>,
 }
 
+impl_py_gc_traverse!(PyLineError {
+    error_type,
+    input_value,
+    source
+});
+
 impl IntoPy<PyLineError> for ValLineError {
     fn into_py(self, py: Python<'_>) -> PyLineError {
         PyLineError {
             error_type: self.error_type,
             location: self.location,
             input_value: self.input_value.to_object(py),
-        }
-    }
-}
-
-impl From<PyLineError> for ValLineError {
-    /// Used to extract line errors from a validation error for wrap functions
-    fn from(other: PyLineError) -> ValLineError {
-        ValLineError {
-            error_type: other.error_type,
-            location: other.location,
-            input_value: InputValue::Python(other.input_value),
+            source: self.source,
         }
     }
 }
@@ -472,15 +981,78 @@ impl TryFrom<&Bound<'_, PyAny>> for PyLineError {
             error_type,
             location,
             input_value,
+            source: None,
         })
     }
 }
 
 impl PyLineError {
+    /// Dict as accepted by `from_exception_data`, plus the `source` of the error, custom errors are stored as a
+    /// `PydanticCustomError` `type` so they can be rebuilt by `TryFrom`
+    fn pickle_state<'py>(&self, py: Python<'py>, repr_unpicklable: bool) -> PyResult<Bound<'py, PyDict>> {
+        let picklable = |value: Bound<'py, PyAny>| match repr_unpicklable {
+            true => picklable_or_repr(value),
+            false => Ok(value),
+        };
+        let context = match self.error_type.py_dict(py)? {
+            Some(context) => {
+                let state = PyDict::new_bound(py);
+                for (key, value) in context.bind(py).iter() {
+                    state.set_item(key, picklable(value)?)?;
+                }
+                Some(state)
+            }
+            None => None,
+        };
+        let dict = PyDict::new_bound(py);
+        match &self.error_type {
+            ErrorType::CustomError {
+                error_type,
+                message_template,
+                code,
+                ..
+            } => {
+                let custom_error =
+                    PydanticCustomError::py_new(error_type.clone(), message_template.clone(), context, code.clone());
+                dict.set_item("type", Py::new(py, custom_error)?)?;
+            }
+            other => {
+                dict.set_item("type", other.type_string())?;
+                if let Some(context) = context {
+                    dict.set_item("ctx", context)?;
+                }
+            }
+        }
+        dict.set_item("loc", self.location.to_object(py))?;
+        dict.set_item("input", picklable(self.input_value.bind(py).clone())?)?;
+        if let Some(source) = &self.source {
+            dict.set_item("source", (source.title.clone_ref(py), source.depth))?;
+        }
+        Ok(dict)
+    }
+
+    fn from_pickle_state(state: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut line_error = Self::try_from(state)?;
+        if let Some(source) = state.downcast::<PyDict>()?.get_item(intern!(state.py(), "source"))? {
+            let (title, depth) = source.extract()?;
+            line_error.source = Some(ErrorSource { title, depth });
+        }
+        Ok(line_error)
+    }
+
+    /// The title of the nested `ValidationError` this error was flattened from and the location it was embedded at
+    fn source_loc_prefix(&self) -> Option<(&PyObject, Location)> {
+        self.source.as_ref().map(|source| {
+            let prefix_len = self.location.iter().count().saturating_sub(source.depth);
+            (&source.title, self.location.outer(prefix_len))
+        })
+    }
+
     fn get_error_url(&self, url_prefix: &str) -> String {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn as_dict(
         &self,
         py: Python,
@@ -488,11 +1060,22 @@ impl PyLineError {
         include_context: bool,
         input_type: InputType,
         include_input: bool,
+        locale: Option<&str>,
+        include_code: bool,
+        loc_as_pointer: bool,
+        include_source: bool,
     ) -> PyResult<PyObject> {
         let dict = PyDict::new_bound(py);
         dict.set_item("type", self.error_type.type_string())?;
-        dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
+        if include_code {
+            dict.set_item("code", self.error_type.code())?;
+        }
+        if loc_as_pointer {
+            dict.set_item("loc", self.location.json_pointer())?;
+        } else {
+            dict.set_item("loc", self.location.to_object(py))?;
+        }
+        dict.set_item("msg", self.error_type.render_message(py, input_type, locale)?)?;
         if include_input {
             dict.set_item("input", &self.input_value)?;
         }
@@ -511,6 +1094,18 @@ impl PyLineError {
                 }
             }
         }
+        if include_source {
+            if let Some((title, loc_prefix)) = self.source_loc_prefix() {
+                let source = PyDict::new_bound(py);
+                source.set_item("title", title)?;
+                if loc_as_pointer {
+                    source.set_item("loc_prefix", loc_prefix.json_pointer())?;
+                } else {
+                    source.set_item("loc_prefix", loc_prefix.to_object(py))?;
+                }
+                dict.set_item("source", source)?;
+            }
+        }
         Ok(dict.into_py(py))
     }
 
@@ -520,11 +1115,13 @@ impl PyLineError {
         input_type: InputType,
         url_prefix: Option<&str>,
         hide_input: bool,
+        loc_format: LocFormat,
+        locale: Option<&str>,
     ) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
-        write!(output, "{}", self.location)?;
+        self.location.write_formatted(&mut output, loc_format)?;
 
-        let message = match self.error_type.render_message(py, input_type) {
+        let message = match self.error_type.render_message(py, input_type, locale) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {err})"),
         };
@@ -576,10 +1173,16 @@ struct ValidationErrorSerializer<'py> {
     py: Python<'py>,
     line_errors: &'py [PyLineError],
     url_prefix: Option<&'py str>,
+    url_types: &'py UrlTypes,
     include_context: bool,
     include_input: bool,
     extra: &'py Extra<'py>,
     input_type: &'py InputType,
+    locale: Option<&'py str>,
+    include_code: bool,
+    max_input_repr_len: Option<usize>,
+    loc_as_pointer: bool,
+    include_source: bool,
 }
 
 impl<'py> Serialize for ValidationErrorSerializer<'py> {
@@ -592,11 +1195,18 @@ impl<'py> Serialize for ValidationErrorSerializer<'py> {
             let line_s = PyLineErrorSerializer {
                 py: self.py,
                 line_error,
-                url_prefix: self.url_prefix,
+                url_prefix: self
+                    .url_prefix
+                    .filter(|_| self.url_types.allows(&line_error.error_type)),
                 include_context: self.include_context,
                 include_input: self.include_input,
                 extra: self.extra,
                 input_type: self.input_type,
+                locale: self.locale,
+                include_code: self.include_code,
+                max_input_repr_len: self.max_input_repr_len,
+                loc_as_pointer: self.loc_as_pointer,
+                include_source: self.include_source,
             };
             seq.serialize_element(&line_s)?;
         }
@@ -612,6 +1222,11 @@ struct PyLineErrorSerializer<'py> {
     include_input: bool,
     extra: &'py Extra<'py>,
     input_type: &'py InputType,
+    locale: Option<&'py str>,
+    include_code: bool,
+    max_input_repr_len: Option<usize>,
+    loc_as_pointer: bool,
+    include_source: bool,
 }
 
 impl<'py> Serialize for PyLineErrorSerializer<'py> {
@@ -620,28 +1235,54 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
+        // Don't add URLs for custom errors
+        let url_prefix = match self.line_error.error_type {
+            ErrorType::CustomError { .. } => None,
+            _ => self.url_prefix,
+        };
+        let source = self.line_error.source_loc_prefix().filter(|_| self.include_source);
+        let size = 3 + [
+            url_prefix.is_some(),
+            self.include_context,
+            self.include_input,
+            self.include_code,
+            source.is_some(),
+        ]
+        .into_iter()
+        .filter(|b| *b)
+        .count();
         let mut map = serializer.serialize_map(Some(size))?;
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
 
-        map.serialize_entry("loc", &self.line_error.location)?;
+        if self.include_code {
+            map.serialize_entry("code", &self.line_error.error_type.code())?;
+        }
+
+        if self.loc_as_pointer {
+            map.serialize_entry("loc", &self.line_error.location.json_pointer())?;
+        } else {
+            map.serialize_entry("loc", &self.line_error.location)?;
+        }
 
         let msg = self
             .line_error
             .error_type
-            .render_message(py, *self.input_type)
+            .render_message(py, *self.input_type, self.locale)
             .map_err(py_err_json::<S>)?;
         map.serialize_entry("msg", &msg)?;
 
         if self.include_input {
-            map.serialize_entry(
-                "input",
-                &self.extra.serialize_infer(self.line_error.input_value.bind(py)),
-            )?;
+            let input_value = self.line_error.input_value.bind(py);
+            let truncated = match self.max_input_repr_len {
+                Some(max_len) => truncate_input(input_value, self.extra, max_len).map_err(py_err_json::<S>)?,
+                None => TruncatedInput::Unchanged,
+            };
+            match truncated {
+                TruncatedInput::Unchanged => map.serialize_entry("input", &self.extra.serialize_infer(input_value))?,
+                TruncatedInput::Json(json) => map.serialize_entry("input", &RawJsonStr(&json))?,
+                TruncatedInput::Truncated(truncated) => map.serialize_entry("input", &truncated)?,
+            }
         }
 
         if self.include_context {
@@ -649,9 +1290,75 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
                 map.serialize_entry("ctx", &self.extra.serialize_infer(context.bind(py)))?;
             }
         }
-        if let Some(url_prefix) = self.url_prefix {
+        if let Some(url_prefix) = url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
+        if let Some((title, loc_prefix)) = source {
+            let source = ErrorSourceSerializer {
+                title: self.extra.serialize_infer(title.bind(py)),
+                loc_prefix: &loc_prefix,
+                loc_as_pointer: self.loc_as_pointer,
+            };
+            map.serialize_entry("source", &source)?;
+        }
         map.end()
     }
 }
+
+struct ErrorSourceSerializer<'a, T: Serialize> {
+    title: T,
+    loc_prefix: &'a Location,
+    loc_as_pointer: bool,
+}
+
+impl<T: Serialize> Serialize for ErrorSourceSerializer<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("title", &self.title)?;
+        if self.loc_as_pointer {
+            map.serialize_entry("loc_prefix", &self.loc_prefix.json_pointer())?;
+        } else {
+            map.serialize_entry("loc_prefix", self.loc_prefix)?;
+        }
+        map.end()
+    }
+}
+
+/// The input as written by `ValidationError.json()`
+enum TruncatedInput {
+    /// no `max_input_repr_len`, or a string no longer than it, serialized as is
+    Unchanged,
+    /// the JSON representation of anything other than a string, no longer than `max_len` bytes
+    Json(String),
+    /// the string or JSON representation cut down to `max_len` bytes the same way as in `pretty`
+    Truncated(String),
+}
+
+/// Strings are measured directly, anything else is serialized to JSON once and that text is either written
+/// as is or truncated.
+fn truncate_input(input_value: &Bound<'_, PyAny>, extra: &Extra, max_len: usize) -> PyResult<TruncatedInput> {
+    let text = if let Ok(py_str) = input_value.downcast::<PyString>() {
+        let text = py_str.to_cow()?;
+        if text.len() <= max_len {
+            return Ok(TruncatedInput::Unchanged);
+        }
+        text.into_owned()
+    } else {
+        let mut ser = crate::serializers::ser::PythonSerializer::new(Vec::new());
+        extra
+            .serialize_infer(input_value)
+            .serialize(&mut ser)
+            .map_err(json_py_err)?;
+        let json = String::from_utf8(ser.into_inner()).map_err(json_py_err)?;
+        if json.len() <= max_len {
+            return Ok(TruncatedInput::Json(json));
+        }
+        json
+    };
+    let mut truncated = String::with_capacity(max_len + 3);
+    write_truncated_to_limited_bytes(&mut truncated, &text, max_len).map_err(json_py_err)?;
+    Ok(TruncatedInput::Truncated(truncated))
+}
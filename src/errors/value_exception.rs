@@ -60,17 +60,24 @@ pub struct PydanticCustomError {
     error_type: String,
     message_template: String,
     context: Option<Py<PyDict>>,
+    code: Option<String>,
 }
 
 #[pymethods]
 impl PydanticCustomError {
     #[new]
-    #[pyo3(signature = (error_type, message_template, context = None))]
-    pub fn py_new(error_type: String, message_template: String, context: Option<Bound<'_, PyDict>>) -> Self {
+    #[pyo3(signature = (error_type, message_template, context = None, code = None))]
+    pub fn py_new(
+        error_type: String,
+        message_template: String,
+        context: Option<Bound<'_, PyDict>>,
+        code: Option<String>,
+    ) -> Self {
         Self {
             error_type,
             message_template,
             context: context.map(Bound::unbind),
+            code,
         }
     }
 
@@ -89,6 +96,11 @@ impl PydanticCustomError {
         self.context.as_ref().map(|c| c.clone_ref(py))
     }
 
+    #[getter]
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
     pub fn message(&self, py: Python) -> PyResult<String> {
         Self::format_message(&self.message_template, self.context.as_ref().map(|c| c.bind(py)))
     }
@@ -104,6 +116,19 @@ impl PydanticCustomError {
             None => Ok(format!("{msg} [type={}, context=None]", self.error_type)),
         }
     }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, PyObject)> {
+        let py = slf.py();
+        let borrow = slf.try_borrow()?;
+        let args = (
+            borrow.error_type.clone(),
+            borrow.message_template.clone(),
+            borrow.context(py),
+            borrow.code.clone(),
+        )
+            .into_py(py);
+        Ok((slf.get_type().into_any(), args))
+    }
 }
 
 impl PydanticCustomError {
@@ -111,6 +136,7 @@ impl PydanticCustomError {
         let error_type = ErrorType::CustomError {
             error_type: self.error_type,
             message_template: self.message_template,
+            code: self.code,
             context: self.context,
         };
         ValError::new(error_type, input)
@@ -166,7 +192,7 @@ impl PydanticKnownError {
     }
 
     pub fn message(&self, py: Python) -> PyResult<String> {
-        self.error_type.render_message(py, InputType::Python)
+        self.error_type.render_message(py, InputType::Python, None)
     }
 
     fn __str__(&self, py: Python) -> PyResult<String> {
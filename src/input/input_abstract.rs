@@ -11,7 +11,7 @@ use crate::validators::ValBytesMode;
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 use super::return_enums::{EitherBytes, EitherComplex, EitherInt, EitherString};
-use super::{EitherFloat, GenericIterator, ValidationMatch};
+use super::{EitherFloat, GenericIterator, JsonRawFloats, ValidationMatch};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InputType {
@@ -60,6 +60,31 @@ pub trait Input<'py>: fmt::Debug + ToPyObject {
         None
     }
 
+    /// Whether this is a JSON number literal with a fractional part or exponent, e.g. `1.0` or `1e3`
+    fn is_json_float(&self) -> bool {
+        false
+    }
+
+    /// The original text of a JSON float literal, if it was recorded in `raw_floats`
+    fn json_raw_float<'a>(&self, _raw_floats: &'a JsonRawFloats) -> Option<&'a str> {
+        None
+    }
+
+    /// Whether this is a bool, e.g. `True` or JSON `true`
+    fn is_bool(&self) -> bool {
+        false
+    }
+
+    /// Whether this is an integer which isn't a bool, e.g. `1` or JSON `1`
+    fn is_int(&self) -> bool {
+        false
+    }
+
+    /// Whether this is an empty string or collection, e.g. `''`, `[]` or JSON `{}`
+    fn is_empty_value(&self) -> bool {
+        false
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>>;
 
     type Arguments<'a>: Arguments<'py>
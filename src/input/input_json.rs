@@ -23,7 +23,7 @@ use super::return_enums::ValidationMatch;
 use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
 use super::{
     Arguments, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input,
-    KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidatedTuple,
+    JsonRawFloats, KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidatedTuple,
 };
 
 /// This is required but since JSON object keys are always strings, I don't think it can be called
@@ -53,6 +53,31 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         matches!(self, JsonValue::Null)
     }
 
+    fn is_json_float(&self) -> bool {
+        matches!(self, JsonValue::Float(_))
+    }
+
+    fn json_raw_float<'a>(&self, raw_floats: &'a JsonRawFloats) -> Option<&'a str> {
+        raw_floats.get(self)
+    }
+
+    fn is_bool(&self) -> bool {
+        matches!(self, JsonValue::Bool(_))
+    }
+
+    fn is_int(&self) -> bool {
+        matches!(self, JsonValue::Int(_) | JsonValue::BigInt(_))
+    }
+
+    fn is_empty_value(&self) -> bool {
+        match self {
+            JsonValue::Str(s) => s.is_empty(),
+            JsonValue::Array(array) => array.is_empty(),
+            JsonValue::Object(object) => object.is_empty(),
+            _ => false,
+        }
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         match self {
             JsonValue::Object(object) => {
@@ -1,5 +1,6 @@
 use std::str::from_utf8;
 
+use pyo3::ffi;
 use pyo3::intern;
 use pyo3::prelude::*;
 
@@ -62,6 +63,8 @@ impl From<&Bound<'_, PyAny>> for LocItem {
             py_str.to_string_lossy().as_ref().into()
         } else if let Some(key_int) = extract_i64(py_any) {
             key_int.into()
+        } else if let Ok(py_tuple) = py_any.downcast::<PyTuple>() {
+            LocItem::C(py_tuple.iter().map(Into::into).collect())
         } else {
             safe_repr(py_any).to_string().into()
         }
@@ -87,6 +90,27 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         Some(self)
     }
 
+    fn is_bool(&self) -> bool {
+        self.is_instance_of::<PyBool>()
+    }
+
+    fn is_int(&self) -> bool {
+        self.is_instance_of::<PyInt>() && !self.is_instance_of::<PyBool>()
+    }
+
+    fn is_empty_value(&self) -> bool {
+        // only builtin strings and collections are considered, arbitrary `__len__` methods aren't called
+        let is_sized = self.is_instance_of::<PyString>()
+            || self.is_instance_of::<PyBytes>()
+            || self.is_instance_of::<PyByteArray>()
+            || self.is_instance_of::<PyList>()
+            || self.is_instance_of::<PyTuple>()
+            || self.is_instance_of::<PyDict>()
+            || self.is_instance_of::<PySet>()
+            || self.is_instance_of::<PyFrozenSet>();
+        is_sized && self.len().is_ok_and(|len| len == 0)
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         self.downcast::<PyDict>()
             .ok()
@@ -253,6 +277,10 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                     float_as_int(self, self.extract::<f64>()?)
                 } else if let Ok(decimal) = self.validate_decimal(true, self.py()) {
                     decimal_as_int(self, &decimal.into_inner())
+                } else if let Some(int) = maybe_as_index(self) {
+                    // read before `__float__` so large NumPy-style integers aren't rounded, regardless of
+                    // `strict_number_protocol` which only applies in strict mode
+                    Ok(EitherInt::Py(int))
                 } else if let Ok(float) = self.extract::<f64>() {
                     float_as_int(self, float)
                 } else if let Some(enum_val) = maybe_as_enum(self) {
@@ -363,8 +391,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn lax_dict<'a>(&'a self) -> ValResult<GenericPyMapping<'a, 'py>> {
         if let Ok(dict) = self.downcast::<PyDict>() {
             Ok(GenericPyMapping::Dict(dict))
-        } else if let Ok(mapping) = self.downcast::<PyMapping>() {
-            Ok(GenericPyMapping::Mapping(mapping))
+        } else if is_mapping(self) {
+            Ok(GenericPyMapping::Mapping(self))
         } else {
             Err(ValError::new(ErrorTypeDefaults::DictType, self))
         }
@@ -379,10 +407,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             // if from_attributes, first try a dict, then mapping then from_attributes
             if let Ok(dict) = self.downcast::<PyDict>() {
                 return Ok(GenericPyMapping::Dict(dict));
-            } else if !strict {
-                if let Ok(mapping) = self.downcast::<PyMapping>() {
-                    return Ok(GenericPyMapping::Mapping(mapping));
-                }
+            } else if !strict && is_mapping(self) {
+                return Ok(GenericPyMapping::Mapping(self));
             }
 
             if from_attributes_applicable(self) {
@@ -670,6 +696,18 @@ fn from_attributes_applicable(obj: &Bound<'_, PyAny>) -> bool {
     !matches!(module_name.to_str(), Ok("builtins" | "datetime" | "collections"))
 }
 
+/// Whether `obj` is a `collections.abc.Mapping`, or isn't registered as one but implements the mapping
+/// protocol, i.e. its type has `keys` and `__getitem__`
+fn is_mapping(obj: &Bound<'_, PyAny>) -> bool {
+    if obj.downcast::<PyMapping>().is_ok() {
+        return true;
+    }
+    let py = obj.py();
+    let obj_type = obj.get_type();
+    obj_type.hasattr(intern!(py, "keys")).unwrap_or(false)
+        && obj_type.hasattr(intern!(py, "__getitem__")).unwrap_or(false)
+}
+
 /// Utility for extracting a string from a PyAny, if possible.
 fn maybe_as_string<'a>(v: &'a Bound<'_, PyAny>, unicode_error: ErrorType) -> ValResult<Option<&'a str>> {
     if let Ok(py_string) = v.downcast::<PyString>() {
@@ -696,8 +734,17 @@ fn bytearray_to_str<'py>(bytearray: &Bound<'py, PyByteArray>) -> PyResult<Bound<
     Ok(py_string)
 }
 
+/// Reads objects implementing `__index__`, like NumPy integer scalars, through the number protocol so large values
+/// aren't rounded via `__float__`, `None` if the protocol isn't implemented or `__index__` raises
+pub(crate) fn maybe_as_index<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+    if unsafe { ffi::PyIndex_Check(v.as_ptr()) } == 0 {
+        return None;
+    }
+    unsafe { Bound::from_owned_ptr_or_err(v.py(), ffi::PyNumber_Index(v.as_ptr())) }.ok()
+}
+
 /// Utility for extracting an enum value, if possible.
-fn maybe_as_enum<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+pub(crate) fn maybe_as_enum<'py>(v: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
     let py = v.py();
     let enum_meta_object = get_enum_meta_object(py);
     let meta_type = v.get_type().get_type();
@@ -785,7 +832,7 @@ impl<'py> KeywordArgs<'py> for PyKwargs<'py> {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum GenericPyMapping<'a, 'py> {
     Dict(&'a Bound<'py, PyDict>),
-    Mapping(&'a Bound<'py, PyMapping>),
+    Mapping(&'a Bound<'py, PyAny>),
     GetAttr(Bound<'py, PyAny>, Option<Bound<'py, PyDict>>),
 }
 
@@ -79,6 +79,13 @@ impl<'py> Input<'py> for StringMapping<'py> {
         }
     }
 
+    fn is_empty_value(&self) -> bool {
+        match self {
+            Self::String(s) => s.len().is_ok_and(|len| len == 0),
+            Self::Mapping(d) => d.is_empty(),
+        }
+    }
+
     fn as_kwargs(&self, _py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         None
     }
@@ -0,0 +1,19 @@
+use jiter::{Jiter, JsonResult, JsonValue};
+
+use super::json_progress::jiter_to_json_error;
+
+/// Parse a buffer of concatenated JSON documents, e.g. JSON lines, into each top-level value along with the
+/// text it was parsed from, whitespace between documents is skipped.
+///
+/// Errors are positioned relative to the whole buffer, including a trailing document which is incomplete.
+pub(crate) fn parse_json_documents(data: &[u8]) -> JsonResult<Vec<(JsonValue<'_>, &[u8])>> {
+    let mut jiter = Jiter::new(data).with_allow_inf_nan();
+    let mut documents = Vec::new();
+    // `finish` skips whitespace and only succeeds once nothing else is left
+    while jiter.finish().is_err() {
+        let start = jiter.current_index();
+        let value = jiter.next_value().map_err(jiter_to_json_error)?;
+        documents.push((value, jiter.slice_to_current(start)));
+    }
+    Ok(documents)
+}
@@ -0,0 +1,133 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use jiter::{Jiter, JiterError, JiterErrorType, JsonError, JsonErrorType, JsonResult, JsonValue, LazyIndexMap, Peek};
+use pyo3::prelude::*;
+use smallvec::SmallVec;
+
+/// How often (in bytes of input consumed) the progress callback is called
+const PROGRESS_INTERVAL: usize = 64 * 1024;
+/// Matches the recursion limit jiter uses when parsing a whole document
+const RECURSION_LIMIT: u8 = 200;
+
+enum ProgressError {
+    Json(JiterError),
+    Callback(PyErr),
+}
+
+impl From<JiterError> for ProgressError {
+    fn from(err: JiterError) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<PyErr> for ProgressError {
+    fn from(err: PyErr) -> Self {
+        Self::Callback(err)
+    }
+}
+
+struct ProgressParser<'j, 'a, 'py> {
+    jiter: Jiter<'j>,
+    on_progress: &'a Bound<'py, PyAny>,
+    next_report: usize,
+}
+
+impl<'j> ProgressParser<'j, '_, '_> {
+    fn report(&mut self) -> Result<(), ProgressError> {
+        let index = self.jiter.current_index();
+        if index >= self.next_report {
+            self.on_progress.call1((index,))?;
+            self.next_report = index + PROGRESS_INTERVAL;
+        }
+        Ok(())
+    }
+
+    fn take_value(&mut self, peek: Peek, depth: u8) -> Result<JsonValue<'j>, ProgressError> {
+        // arrays and objects are walked here so progress can be reported between their items,
+        // everything else is left to jiter
+        match peek {
+            Peek::Array => {
+                let mut op_peek = self.jiter.known_array()?;
+                let depth = self.check_depth(depth)?;
+                let mut array: SmallVec<[JsonValue<'j>; 8]> = SmallVec::new();
+                while let Some(peek) = op_peek {
+                    array.push(self.take_value(peek, depth)?);
+                    self.report()?;
+                    op_peek = self.jiter.array_step()?;
+                }
+                Ok(JsonValue::Array(Arc::new(array)))
+            }
+            Peek::Object => {
+                let mut op_key = self.jiter.known_object()?.map(str::to_owned);
+                let depth = self.check_depth(depth)?;
+                let mut object = LazyIndexMap::new();
+                while let Some(key) = op_key {
+                    let peek = self.jiter.peek()?;
+                    let value = self.take_value(peek, depth)?;
+                    object.insert(Cow::Owned(key), value);
+                    self.report()?;
+                    op_key = self.jiter.next_key()?.map(str::to_owned);
+                }
+                Ok(JsonValue::Object(Arc::new(object)))
+            }
+            _ => Ok(self.jiter.known_value(peek)?),
+        }
+    }
+
+    fn check_depth(&self, depth: u8) -> Result<u8, ProgressError> {
+        if depth >= RECURSION_LIMIT {
+            Err(ProgressError::Json(JiterError {
+                error_type: JiterErrorType::JsonError(JsonErrorType::RecursionLimitExceeded),
+                index: self.jiter.current_index(),
+            }))
+        } else {
+            Ok(depth + 1)
+        }
+    }
+}
+
+/// Parse JSON like `JsonValue::parse_with_config` (with `allow_inf_nan` and without partial parsing),
+/// calling `on_progress` with the number of bytes consumed so far at intervals while parsing,
+/// and once more with the full length when parsing is complete.
+///
+/// The outer `PyResult` holds any error raised by `on_progress`.
+pub(crate) fn parse_json_with_progress<'j>(
+    data: &'j [u8],
+    on_progress: &Bound<'_, PyAny>,
+) -> PyResult<JsonResult<JsonValue<'j>>> {
+    let mut parser = ProgressParser {
+        jiter: Jiter::new(data).with_allow_inf_nan(),
+        on_progress,
+        next_report: PROGRESS_INTERVAL,
+    };
+    let result = parser
+        .jiter
+        .peek()
+        .map_err(ProgressError::from)
+        .and_then(|peek| parser.take_value(peek, 0))
+        .and_then(|value| {
+            parser.jiter.finish()?;
+            Ok(value)
+        });
+    match result {
+        Ok(value) => {
+            on_progress.call1((data.len(),))?;
+            Ok(Ok(value))
+        }
+        Err(ProgressError::Json(err)) => Ok(Err(jiter_to_json_error(err))),
+        Err(ProgressError::Callback(err)) => Err(err),
+    }
+}
+
+pub(super) fn jiter_to_json_error(err: JiterError) -> JsonError {
+    let error_type = match err.error_type {
+        JiterErrorType::JsonError(error_type) => error_type,
+        // only the `known_*` methods are used, so jiter never checks the type of a value itself
+        wrong_type @ JiterErrorType::WrongType { .. } => JsonErrorType::InternalError(wrong_type.to_string()),
+    };
+    JsonError {
+        error_type,
+        index: err.index,
+    }
+}
@@ -0,0 +1,62 @@
+use ahash::AHashMap;
+use jiter::{Jiter, JiterResult, JsonValue, Peek};
+
+/// The original text of each float literal in a parsed JSON document, used by `validate_json` with
+/// `json_float_mode='decimal'` so decimals are created from the number as written rather than from an `f64`.
+///
+/// `JsonValue` can't hold the raw token, so floats are looked up by the address of their node in the parsed
+/// document, which must therefore not be moved between `collect` and validation.
+#[derive(Debug, Default)]
+pub struct JsonRawFloats(AHashMap<usize, String>);
+
+impl JsonRawFloats {
+    /// Walk `value`, the result of parsing all of `data` with `allow_inf_nan`, alongside `data` itself
+    pub fn collect(data: &[u8], value: &JsonValue<'_>) -> JiterResult<Self> {
+        let mut raw_floats = Self::default();
+        let mut jiter = Jiter::new(data).with_allow_inf_nan();
+        let peek = jiter.peek()?;
+        raw_floats.walk(&mut jiter, value, peek)?;
+        Ok(raw_floats)
+    }
+
+    pub fn get(&self, value: &JsonValue<'_>) -> Option<&str> {
+        self.0.get(&node_key(value)).map(String::as_str)
+    }
+
+    fn walk(&mut self, jiter: &mut Jiter, value: &JsonValue<'_>, peek: Peek) -> JiterResult<()> {
+        match value {
+            JsonValue::Array(array) => {
+                let mut op_peek = jiter.known_array()?;
+                for item in array.iter() {
+                    let Some(peek) = op_peek else { break };
+                    self.walk(jiter, item, peek)?;
+                    op_peek = jiter.array_step()?;
+                }
+            }
+            JsonValue::Object(object) => {
+                // `iter` rather than `iter_unique` so duplicate keys stay in step with the document
+                let mut op_key = jiter.known_object()?.is_some();
+                for (_, item) in object.iter() {
+                    if !op_key {
+                        break;
+                    }
+                    let peek = jiter.peek()?;
+                    self.walk(jiter, item, peek)?;
+                    op_key = jiter.next_key()?.is_some();
+                }
+            }
+            JsonValue::Float(_) => {
+                let raw = jiter.next_number_bytes()?;
+                // number literals are always ASCII
+                self.0
+                    .insert(node_key(value), String::from_utf8_lossy(raw).into_owned());
+            }
+            _ => jiter.known_skip(peek)?,
+        }
+        Ok(())
+    }
+}
+
+fn node_key(value: &JsonValue<'_>) -> usize {
+    value as *const JsonValue<'_> as usize
+}
@@ -7,6 +7,9 @@ mod input_abstract;
 mod input_json;
 mod input_python;
 mod input_string;
+mod json_documents;
+mod json_progress;
+mod json_raw_floats;
 mod return_enums;
 mod shared;
 
@@ -19,12 +22,16 @@ pub(crate) use input_abstract::{
     Arguments, BorrowInput, ConsumeIterator, Input, InputType, KeywordArgs, PositionalArgs, ValidatedDict,
     ValidatedList, ValidatedSet, ValidatedTuple,
 };
-pub(crate) use input_python::{downcast_python_input, input_as_python_instance};
+pub(crate) use input_python::{downcast_python_input, input_as_python_instance, maybe_as_enum, maybe_as_index};
 pub(crate) use input_string::StringMapping;
+pub(crate) use json_documents::parse_json_documents;
+pub(crate) use json_progress::parse_json_with_progress;
+pub(crate) use json_raw_floats::JsonRawFloats;
 pub(crate) use return_enums::{
     no_validator_iter_to_vec, py_string_str, validate_iter_to_set, validate_iter_to_vec, EitherBytes, EitherFloat,
-    EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch,
+    EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, SkipItems, ValidationMatch,
 };
+pub(crate) use shared::{add_parsing_reason, str_as_float};
 
 // Defined here as it's not exported by pyo3
 pub fn py_error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
@@ -12,7 +12,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 #[cfg(not(PyPy))]
 use pyo3::types::PyFunction;
-use pyo3::types::{PyBytes, PyComplex, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
+use pyo3::types::{PyBytes, PyComplex, PyFloat, PyFrozenSet, PyIterator, PyList, PySet, PyString};
 
 use serde::{ser::Error, Serialize, Serializer};
 
@@ -20,8 +20,8 @@ use crate::errors::{
     py_err_string, ErrorType, ErrorTypeDefaults, InputValue, ToErrorValue, ValError, ValLineError, ValResult,
 };
 use crate::py_gc::PyGcTraverse;
-use crate::tools::{extract_i64, extract_int, new_py_string, py_err};
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::tools::{extract_i64, extract_int, new_py_key_string, new_py_string, py_err};
+use crate::validators::{CombinedValidator, Exactness, ValidationState};
 
 use super::{py_error_on_minusone, BorrowInput, Input};
 
@@ -103,6 +103,24 @@ impl<'py, INPUT: Input<'py> + ?Sized> MaxLengthCheck<'_, INPUT> {
     }
 }
 
+/// Which items of a collection are dropped before validation, skipped items
+/// don't count towards the collection's length constraints
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SkipItems {
+    pub empty: bool,
+    pub none: bool,
+}
+
+impl SkipItems {
+    pub(crate) fn is_active(self) -> bool {
+        self.empty || self.none
+    }
+
+    fn skip<'py>(self, item: &(impl Input<'py> + ?Sized)) -> bool {
+        (self.none && item.is_none()) || (self.empty && item.is_empty_value())
+    }
+}
+
 macro_rules! any_next_error {
     ($py:expr, $err:ident, $input:expr, $index:ident) => {
         ValError::new_with_loc(
@@ -125,6 +143,7 @@ pub(crate) fn validate_iter_to_vec<'py>(
     validator: &CombinedValidator,
     state: &mut ValidationState<'_, 'py>,
     fail_fast: bool,
+    skip_items: SkipItems,
 ) -> ValResult<Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
@@ -136,7 +155,10 @@ pub(crate) fn validate_iter_to_vec<'py>(
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        if skip_items.skip(item.borrow_input()) {
+            continue;
+        }
+        match validator.validate_traced_at(py, item.borrow_input(), state, index) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
@@ -203,6 +225,7 @@ pub(crate) fn validate_iter_to_set<'py>(
     validator: &CombinedValidator,
     state: &mut ValidationState<'_, 'py>,
     fail_fast: bool,
+    skip_items: SkipItems,
 ) -> ValResult<()> {
     let mut errors: Vec<ValLineError> = Vec::new();
 
@@ -214,9 +237,30 @@ pub(crate) fn validate_iter_to_set<'py>(
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
-            Ok(item) => {
-                set.build_add(item)?;
+        if skip_items.skip(item.borrow_input()) {
+            continue;
+        }
+        match validator.validate_traced_at(py, item.borrow_input(), state, index) {
+            Ok(output) => {
+                let output_type = output.bind(py).get_type();
+                match set.build_add(output) {
+                    Ok(()) => (),
+                    // the validated item can't be hashed, e.g. a `dict`, report it like any other item error
+                    Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                        if !is_last_partial {
+                            let item_type = output_type.qualname()?.to_string();
+                            errors.push(ValLineError::new_with_loc(
+                                ErrorType::SetItemNotHashable {
+                                    item_type,
+                                    context: None,
+                                },
+                                item.borrow_input(),
+                                index,
+                            ));
+                        }
+                    }
+                    Err(err) => return Err(err.into()),
+                }
                 if let Some(max_length) = max_length {
                     if set.build_len() > max_length {
                         return Err(ValError::new(
@@ -259,8 +303,13 @@ pub(crate) fn no_validator_iter_to_vec<'py>(
     input: &(impl Input<'py> + ?Sized),
     iter: impl Iterator<Item = PyResult<impl BorrowInput<'py>>>,
     mut max_length_check: MaxLengthCheck<'_, impl Input<'py> + ?Sized>,
+    skip_items: SkipItems,
 ) -> ValResult<Vec<PyObject>> {
     iter.enumerate()
+        .filter_map(|(index, result)| match result {
+            Ok(v) if skip_items.skip(v.borrow_input()) => None,
+            result => Some((index, result)),
+        })
         .map(|(index, result)| {
             let v = result.map_err(|e| any_next_error!(py, e, input, index))?;
             max_length_check.incr()?;
@@ -270,12 +319,16 @@ pub(crate) fn no_validator_iter_to_vec<'py>(
 }
 
 pub(crate) fn iterate_mapping_items<'a, 'py>(
-    mapping: &'a Bound<'py, PyMapping>,
+    input: &'a Bound<'py, PyAny>,
 ) -> ValResult<impl Iterator<Item = ValResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> + 'a> {
-    let py = mapping.py();
-    let input = mapping.as_any();
-    let iterator = mapping
-        .items()
+    let py = input.py();
+    let items = if input.hasattr(intern!(py, "items")).unwrap_or(false) {
+        input.call_method0(intern!(py, "items"))
+    } else {
+        // objects implementing the mapping protocol needn't have `items`, so build it from `keys`
+        mapping_items_from_keys(input)
+    };
+    let iterator = items
         .map_err(|e| mapping_err(e, py, input))?
         .iter()
         .map_err(|e| mapping_err(e, py, input))?
@@ -294,6 +347,16 @@ pub(crate) fn iterate_mapping_items<'a, 'py>(
     Ok(iterator)
 }
 
+fn mapping_items_from_keys<'py>(mapping: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let items = PyList::empty_bound(mapping.py());
+    for key in mapping.call_method0(intern!(mapping.py(), "keys"))?.iter()? {
+        let key = key?;
+        let value = mapping.get_item(&key)?;
+        items.append((key, value))?;
+    }
+    Ok(items.into_any())
+}
+
 fn mapping_err<'py>(err: PyErr, py: Python<'py>, input: &'py (impl Input<'py> + ?Sized)) -> ValError {
     ValError::new(
         ErrorType::MappingType {
@@ -478,6 +541,14 @@ impl<'a> EitherString<'a> {
             Self::Py(py_string) => py_string.clone(),
         }
     }
+
+    /// `as_py_string` for strings used as dict keys, see `new_py_key_string`
+    pub fn as_py_key_string(&'a self, py: Python<'a>, cache_str: StringCacheMode) -> Bound<'a, PyString> {
+        match self {
+            Self::Cow(cow) => new_py_key_string(py, cow.as_ref(), cache_str),
+            Self::Py(py_string) => py_string.clone(),
+        }
+    }
 }
 
 impl<'a> From<&'a str> for EitherString<'a> {
@@ -2,11 +2,12 @@ use std::borrow::Cow;
 
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
+use pyo3::types::PyString;
 use pyo3::{intern, Py, PyAny, Python};
 
-use jiter::{JsonErrorType, NumberInt};
+use jiter::{JsonError, JsonErrorType, JsonValue, NumberInt};
 
-use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 
 use super::{EitherFloat, EitherInt, Input};
 static ENUM_META_OBJECT: GILOnceCell<Py<PyAny>> = GILOnceCell::new();
@@ -108,6 +109,82 @@ pub fn str_as_float<'py>(input: &(impl Input<'py> + ?Sized), str: &str) -> ValRe
     }
 }
 
+/// Add the reason `input` couldn't be parsed to the context of `int_parsing` and `float_parsing` errors
+/// as `reason`, the message is unchanged
+pub(crate) fn add_parsing_reason<'py>(py: Python<'py>, err: ValError, input: &(impl Input<'py> + ?Sized)) -> ValError {
+    let ValError::LineErrors(mut line_errors) = err else {
+        return err;
+    };
+    for line_error in &mut line_errors {
+        let parsing_reason: fn(&str) -> String = match line_error.error_type {
+            ErrorType::IntParsing { .. } => int_parsing_reason,
+            ErrorType::FloatParsing { .. } => float_parsing_reason,
+            _ => continue,
+        };
+        // parsing errors are only raised for strings, or bytes which are valid as strings
+        let Ok(either_str) = input.validate_str(false, false) else {
+            continue;
+        };
+        let reason = match either_str.into_inner().as_cow() {
+            Ok(str) => parsing_reason(&str),
+            Err(err) => return err,
+        };
+        if let Err(err) =
+            line_error
+                .error_type
+                .set_context_item(py, "reason", PyString::new_bound(py, &reason).as_any())
+        {
+            return err.into();
+        }
+    }
+    ValError::LineErrors(line_errors)
+}
+
+/// The reason `str` isn't an int, from the error parsing it with underscores removed
+fn int_parsing_reason(str: &str) -> String {
+    number_parsing_reason(str, |digits| NumberInt::try_from(digits).err())
+}
+
+/// The reason `str` isn't a float, from the error parsing it as a JSON number with underscores removed, values
+/// which are valid JSON but not numbers fail at their first character
+fn float_parsing_reason(str: &str) -> String {
+    number_parsing_reason(str, |digits| match JsonValue::parse(digits, true) {
+        Ok(JsonValue::Int(_) | JsonValue::BigInt(_) | JsonValue::Float(_)) => None,
+        Ok(_) => Some(JsonError {
+            error_type: JsonErrorType::InvalidNumber,
+            index: 0,
+        }),
+        Err(err) => Some(err),
+    })
+}
+
+/// Describe the error `parse` returns for `str` without surrounding whitespace, a leading `+` or underscores,
+/// positions are of characters in `str`
+fn number_parsing_reason(str: &str, parse: impl FnOnce(&[u8]) -> Option<JsonError>) -> String {
+    let trimmed = str.trim();
+    if trimmed.is_empty() {
+        return "empty string".to_string();
+    }
+    let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+    let stripped = strip_underscores(unsigned);
+    if stripped.is_none() && unsigned.contains('_') {
+        return "invalid underscore placement".to_string();
+    }
+    let digits = stripped.as_deref().unwrap_or(unsigned);
+    let Some(error) = parse(digits.as_bytes()) else {
+        return "invalid number format".to_string();
+    };
+    // underscores are single bytes, so skipping them maps the index in `digits` back to one in `unsigned`
+    let invalid = unsigned.char_indices().filter(|&(_, c)| c != '_').nth(error.index);
+    match invalid {
+        Some((index, _)) => {
+            let start = str.len() - str.trim_start().len() + trimmed.len() - unsigned.len();
+            format!("invalid digit at position {}", str[..start + index].chars().count())
+        }
+        None => "invalid number format".to_string(),
+    }
+}
+
 fn clean_int_str(mut s: &str) -> Option<Cow<str>> {
     let len_before = s.len();
 
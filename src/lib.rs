@@ -33,13 +33,14 @@ pub use self::url::{PyMultiHostUrl, PyUrl};
 pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
 pub use build_tools::SchemaError;
 pub use errors::{
-    list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
+    format_loc, is_known_error_type, list_all_errors, register_error_messages, set_include_url, ErrorDetail,
+    InvalidValue, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
 };
 pub use serializers::{
     to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
-    WarningsArg,
+    SerializerContext, WarningsArg,
 };
-pub use validators::{validate_core_schema, PySome, SchemaValidator};
+pub use validators::{validate_core_schema, PyRawJson, PySecret, PySome, SchemaValidator};
 
 use crate::input::Input;
 
@@ -115,8 +116,12 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("PydanticUndefined", PydanticUndefinedType::new(py))?;
     m.add_class::<PydanticUndefinedType>()?;
     m.add_class::<PySome>()?;
+    m.add_class::<PySecret>()?;
+    m.add_class::<PyRawJson>()?;
     m.add_class::<SchemaValidator>()?;
     m.add_class::<ValidationError>()?;
+    m.add_class::<ErrorDetail>()?;
+    m.add_class::<InvalidValue>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticCustomError>()?;
     m.add_class::<PydanticKnownError>()?;
@@ -128,11 +133,16 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMultiHostUrl>()?;
     m.add_class::<ArgsKwargs>()?;
     m.add_class::<SchemaSerializer>()?;
+    m.add_class::<SerializerContext>()?;
     m.add_class::<TzInfo>()?;
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
     m.add_function(wrap_pyfunction!(from_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
+    m.add_function(wrap_pyfunction!(is_known_error_type, m)?)?;
+    m.add_function(wrap_pyfunction!(set_include_url, m)?)?;
+    m.add_function(wrap_pyfunction!(register_error_messages, m)?)?;
+    m.add_function(wrap_pyfunction!(format_loc, m)?)?;
     m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
     Ok(())
 }
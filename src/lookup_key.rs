@@ -3,7 +3,7 @@ use std::fmt;
 
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use jiter::{JsonObject, JsonValue};
 
@@ -156,7 +156,7 @@ impl LookupKey {
 
     pub fn py_get_mapping_item<'py, 's>(
         &'s self,
-        dict: &Bound<'py, PyMapping>,
+        dict: &Bound<'py, PyAny>,
     ) -> ValResult<Option<(&'s LookupPath, Bound<'py, PyAny>)>> {
         match self {
             Self::Simple { py_key, path, .. } => match dict.get_item(py_key) {
@@ -180,7 +180,7 @@ impl LookupKey {
                 for path in path_choices {
                     // iterate over the path and plug each value into the py_any from the last step, starting with dict
                     // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.iter().try_fold((**dict).clone(), |d, loc| loc.py_get_item(&d)) {
+                    if let Some(v) = path.iter().try_fold(dict.clone(), |d, loc| loc.py_get_item(&d)) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
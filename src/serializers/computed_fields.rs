@@ -48,13 +48,14 @@ impl ComputedFields {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<()> {
-        if extra.round_trip {
+        if extra.round_trip || extra.exclude_computed {
             // Do not serialize computed fields
             return Ok(());
         }
         for computed_field in &self.0 {
             let field_extra = Extra {
                 field_name: Some(computed_field.property_name.as_str()),
+                field_path: extra.field_path.push(&computed_field.property_name),
                 ..*extra
             };
             computed_field.to_python(model, output_dict, filter, include, exclude, &field_extra)?;
@@ -71,7 +72,7 @@ impl ComputedFields {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<(), S::Error> {
-        if extra.round_trip {
+        if extra.round_trip || extra.exclude_computed {
             // Do not serialize computed fields
             return Ok(());
         }
@@ -89,10 +90,11 @@ impl ComputedFields {
                 }
                 let field_extra = Extra {
                     field_name: Some(computed_field.property_name.as_str()),
+                    field_path: extra.field_path.push(&computed_field.property_name),
                     ..*extra
                 };
                 let cfs = ComputedFieldSerializer {
-                    model,
+                    value: &value,
                     computed_field,
                     include: next_include.as_ref(),
                     exclude: next_exclude.as_ref(),
@@ -174,7 +176,7 @@ impl ComputedField {
 }
 
 pub(crate) struct ComputedFieldSerializer<'py> {
-    model: &'py Bound<'py, PyAny>,
+    value: &'py Bound<'py, PyAny>,
     computed_field: &'py ComputedField,
     include: Option<&'py Bound<'py, PyAny>>,
     exclude: Option<&'py Bound<'py, PyAny>>,
@@ -193,11 +195,9 @@ impl_py_gc_traverse!(ComputedFieldSerializer<'_> { computed_field });
 
 impl<'py> Serialize for ComputedFieldSerializer<'py> {
     fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let py = self.model.py();
-        let property_name_py = self.computed_field.property_name_py.bind(py);
-        let next_value = self.model.getattr(property_name_py).map_err(py_err_se_err)?;
+        // the property has already been read by the caller, so the getter is only called once per field
         let s = PydanticSerializer::new(
-            &next_value,
+            self.value,
             &self.computed_field.serializer,
             self.include,
             self.exclude,
@@ -13,6 +13,7 @@ use crate::input::EitherTimedelta;
 use crate::tools::SchemaDict;
 
 use super::errors::py_err_se_err;
+use super::extra::CollectWarnings;
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_field_names)]
@@ -89,6 +90,7 @@ serialization_mode! {
     "ser_json_timedelta",
     Iso8601 => "iso8601",
     Float => "float",
+    IntSeconds => "int_seconds",
 }
 
 serialization_mode! {
@@ -107,12 +109,36 @@ serialization_mode! {
     Strings => "strings",
 }
 
+serialization_mode! {
+    ComplexMode,
+    "ser_json_complex",
+    Str => "string",
+    Dict => "dict",
+}
+
 impl TimedeltaMode {
     fn total_seconds<'py>(py_timedelta: &Bound<'py, PyDelta>) -> PyResult<Bound<'py, PyAny>> {
         py_timedelta.call_method0(intern!(py_timedelta.py(), "total_seconds"))
     }
 
-    pub fn either_delta_to_json(self, py: Python, either_delta: &EitherTimedelta) -> PyResult<PyObject> {
+    /// Total seconds truncated towards zero, warning if that drops a fractional part
+    fn int_seconds(either_delta: &EitherTimedelta, warnings: &CollectWarnings) -> PyResult<i64> {
+        let d = either_delta.to_duration()?;
+        if d.microsecond != 0 {
+            warnings.custom_warning(format!(
+                "Expected `timedelta` with whole seconds but got `{d}` - sub-second precision was dropped"
+            ));
+        }
+        let seconds = i64::from(d.day) * 86_400 + i64::from(d.second);
+        Ok(if d.positive { seconds } else { -seconds })
+    }
+
+    pub fn either_delta_to_json(
+        self,
+        py: Python,
+        either_delta: &EitherTimedelta,
+        warnings: &CollectWarnings,
+    ) -> PyResult<PyObject> {
         match self {
             Self::Iso8601 => {
                 let d = either_delta.to_duration()?;
@@ -125,10 +151,16 @@ impl TimedeltaMode {
                 let seconds = Self::total_seconds(&py_timedelta)?;
                 Ok(seconds.into_py(py))
             }
+            Self::IntSeconds => Ok(Self::int_seconds(either_delta, warnings)?.into_py(py)),
         }
     }
 
-    pub fn json_key<'py>(self, py: Python, either_delta: &EitherTimedelta) -> PyResult<Cow<'py, str>> {
+    pub fn json_key<'py>(
+        self,
+        py: Python,
+        either_delta: &EitherTimedelta,
+        warnings: &CollectWarnings,
+    ) -> PyResult<Cow<'py, str>> {
         match self {
             Self::Iso8601 => {
                 let d = either_delta.to_duration()?;
@@ -139,6 +171,7 @@ impl TimedeltaMode {
                 let seconds: f64 = Self::total_seconds(&py_timedelta)?.extract()?;
                 Ok(seconds.to_string().into())
             }
+            Self::IntSeconds => Ok(Self::int_seconds(either_delta, warnings)?.to_string().into()),
         }
     }
 
@@ -147,6 +180,7 @@ impl TimedeltaMode {
         py: Python,
         either_delta: &EitherTimedelta,
         serializer: S,
+        warnings: &CollectWarnings,
     ) -> Result<S::Ok, S::Error> {
         match self {
             Self::Iso8601 => {
@@ -159,6 +193,10 @@ impl TimedeltaMode {
                 let seconds: f64 = seconds.extract().map_err(py_err_se_err)?;
                 serializer.serialize_f64(seconds)
             }
+            Self::IntSeconds => {
+                let seconds = Self::int_seconds(either_delta, warnings).map_err(py_err_se_err)?;
+                serializer.serialize_i64(seconds)
+            }
         }
     }
 }
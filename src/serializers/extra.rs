@@ -1,10 +1,10 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyString};
+use pyo3::types::{PyBool, PyDict, PyList, PyString, PyTuple};
 
 use serde::ser::Error;
 
@@ -17,6 +17,7 @@ use crate::recursion_guard::RecursionGuard;
 use crate::recursion_guard::RecursionState;
 use crate::tools::truncate_safe_repr;
 use crate::PydanticSerializationError;
+use crate::WarningsArg;
 
 /// this is ugly, would be much better if extra could be stored in `SerializationState`
 /// then `SerializationState` got a `serialize_infer` method, but I couldn't get it to work
@@ -104,6 +105,9 @@ impl SerializationState {
             serialize_unknown,
             fallback,
             duck_typing_ser_mode,
+            false,
+            false,
+            false,
             context,
         )
     }
@@ -134,9 +138,17 @@ pub(crate) struct Extra<'a> {
     // it will be None otherwise
     pub model: Option<&'a Bound<'a, PyAny>>,
     pub field_name: Option<&'a str>,
+    // the names of the fields leading to the value being serialized, used as the `loc` of warnings
+    pub field_path: FieldPath<'a>,
     pub serialize_unknown: bool,
     pub fallback: Option<&'a Bound<'a, PyAny>>,
     pub duck_typing_ser_mode: DuckTypingSerMode,
+    // whether `secret` values are serialized as their wrapped value, rather than hidden
+    pub dump_secret_values: bool,
+    // whether computed fields are skipped, so their getters aren't called
+    pub exclude_computed: bool,
+    // whether `InvalidValue` markers from `mark_invalid` validation are serialized as `{"__error__": {...}}`
+    pub inline_errors: bool,
     pub context: Option<&'a Bound<'a, PyAny>>,
 }
 
@@ -156,6 +168,9 @@ impl<'a> Extra<'a> {
         serialize_unknown: bool,
         fallback: Option<&'a Bound<'a, PyAny>>,
         duck_typing_ser_mode: DuckTypingSerMode,
+        dump_secret_values: bool,
+        exclude_computed: bool,
+        inline_errors: bool,
         context: Option<&'a Bound<'a, PyAny>>,
     ) -> Self {
         Self {
@@ -172,9 +187,13 @@ impl<'a> Extra<'a> {
             check: SerCheck::None,
             model: None,
             field_name: None,
+            field_path: FieldPath::Owned(&[]),
             serialize_unknown,
             fallback,
             duck_typing_ser_mode,
+            dump_secret_values,
+            exclude_computed,
+            inline_errors,
             context,
         }
     }
@@ -221,6 +240,32 @@ impl SerCheck {
     }
 }
 
+/// The names of the fields leading to the value being serialized, outermost first,
+/// built up as a linked list on the stack so entering a field doesn't allocate
+#[derive(Clone, Copy)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) enum FieldPath<'a> {
+    Owned(&'a [String]),
+    Field(&'a FieldPath<'a>, &'a str),
+}
+
+impl<'a> FieldPath<'a> {
+    pub fn push(&'a self, field_name: &'a str) -> Self {
+        Self::Field(self, field_name)
+    }
+
+    pub fn to_vec(self) -> Vec<String> {
+        match self {
+            Self::Owned(path) => path.to_vec(),
+            Self::Field(parent, field_name) => {
+                let mut path = parent.to_vec();
+                path.push((*field_name).to_string());
+                path
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub(crate) struct ExtraOwned {
@@ -236,9 +281,13 @@ pub(crate) struct ExtraOwned {
     check: SerCheck,
     pub model: Option<PyObject>,
     field_name: Option<String>,
+    field_path: Vec<String>,
     serialize_unknown: bool,
     pub fallback: Option<PyObject>,
     duck_typing_ser_mode: DuckTypingSerMode,
+    dump_secret_values: bool,
+    exclude_computed: bool,
+    inline_errors: bool,
     pub context: Option<PyObject>,
 }
 
@@ -257,9 +306,13 @@ impl ExtraOwned {
             check: extra.check,
             model: extra.model.map(|model| model.clone().into()),
             field_name: extra.field_name.map(ToString::to_string),
+            field_path: extra.field_path.to_vec(),
             serialize_unknown: extra.serialize_unknown,
             fallback: extra.fallback.map(|model| model.clone().into()),
             duck_typing_ser_mode: extra.duck_typing_ser_mode,
+            dump_secret_values: extra.dump_secret_values,
+            exclude_computed: extra.exclude_computed,
+            inline_errors: extra.inline_errors,
             context: extra.context.map(|model| model.clone().into()),
         }
     }
@@ -279,9 +332,13 @@ impl ExtraOwned {
             check: self.check,
             model: self.model.as_ref().map(|m| m.bind(py)),
             field_name: self.field_name.as_deref(),
+            field_path: FieldPath::Owned(&self.field_path),
             serialize_unknown: self.serialize_unknown,
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
             duck_typing_ser_mode: self.duck_typing_ser_mode,
+            dump_secret_values: self.dump_secret_values,
+            exclude_computed: self.exclude_computed,
+            inline_errors: self.inline_errors,
             context: self.context.as_ref().map(|m| m.bind(py)),
         }
     }
@@ -374,20 +431,45 @@ impl From<bool> for WarningsMode {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub(crate) struct CollectWarnings {
     mode: WarningsMode,
-    warnings: RefCell<Option<Vec<String>>>,
+    // whether warnings should be returned to the caller via `collected` instead of being emitted
+    collect: bool,
+    warnings: RefCell<Option<Vec<SerWarning>>>,
+}
+
+/// A single serialization warning, `loc` is the path of field names to the value being serialized
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+struct SerWarning {
+    loc: Vec<String>,
+    message: String,
 }
 
 impl CollectWarnings {
     pub(crate) fn new(mode: WarningsMode) -> Self {
         Self {
             mode,
+            collect: false,
+            warnings: RefCell::new(None),
+        }
+    }
+
+    /// When `collect` is set, warnings are stored and returned by `collected` rather than
+    /// passing through the `warnings` module
+    pub(crate) fn from_arg(warnings: WarningsArg, collect: bool) -> Self {
+        let mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        Self {
+            mode,
+            collect,
             warnings: RefCell::new(None),
         }
     }
 
     pub fn custom_warning(&self, warning: String) {
         if self.mode != WarningsMode::None {
-            self.add_warning(warning);
+            self.add_warning(Vec::new(), warning);
         }
     }
 
@@ -406,7 +488,7 @@ impl CollectWarnings {
                 "Expected `{field_type}` but got `{type_name}` with value `{value_str}` - serialized value may not be as expected"
             ))))
         } else {
-            self.fallback_warning(field_type, value);
+            self.fallback_warning(field_type, value, extra);
             Ok(())
         }
     }
@@ -426,12 +508,12 @@ impl CollectWarnings {
             // in particular, in future we could allow errors instead of warnings on fallback
             Err(S::Error::custom(UNEXPECTED_TYPE_SER_MARKER))
         } else {
-            self.fallback_warning(field_type, value);
+            self.fallback_warning(field_type, value, extra);
             Ok(())
         }
     }
 
-    fn fallback_warning(&self, field_type: &str, value: &Bound<'_, PyAny>) {
+    fn fallback_warning(&self, field_type: &str, value: &Bound<'_, PyAny>, extra: &Extra) {
         if self.mode != WarningsMode::None {
             let type_name = value
                 .get_type()
@@ -440,28 +522,42 @@ impl CollectWarnings {
 
             let value_str = truncate_safe_repr(value, None);
 
-            self.add_warning(format!(
-                "Expected `{field_type}` but got `{type_name}` with value `{value_str}` - serialized value may not be as expected"
-            ));
+            self.add_warning(
+                extra.field_path.to_vec(),
+                format!(
+                    "Expected `{field_type}` but got `{type_name}` with value `{value_str}` - serialized value may not be as expected"
+                ),
+            );
         }
     }
 
-    fn add_warning(&self, message: String) {
+    fn add_warning(&self, loc: Vec<String>, message: String) {
+        let warning = SerWarning { loc, message };
         let mut op_warnings = self.warnings.borrow_mut();
         if let Some(ref mut warnings) = *op_warnings {
-            warnings.push(message);
+            warnings.push(warning);
         } else {
-            *op_warnings = Some(vec![message]);
+            *op_warnings = Some(vec![warning]);
         }
     }
 
+    /// Discard the warnings recorded so far, so the collector can be reused
+    pub(crate) fn clear(&self) {
+        self.warnings.borrow_mut().take();
+    }
+
     pub fn final_check(&self, py: Python) -> PyResult<()> {
-        if self.mode == WarningsMode::None {
+        if self.mode == WarningsMode::None || self.collect {
             return Ok(());
         }
+        self.raise_or_warn(py)
+    }
+
+    fn raise_or_warn(&self, py: Python) -> PyResult<()> {
         match *self.warnings.borrow() {
             Some(ref warnings) => {
-                let message = format!("Pydantic serializer warnings:\n  {}", warnings.join("\n  "));
+                let messages: Vec<&str> = warnings.iter().map(|w| w.message.as_str()).collect();
+                let message = format!("Pydantic serializer warnings:\n  {}", messages.join("\n  "));
                 if self.mode == WarningsMode::Warn {
                     let user_warning_type = py.import_bound("builtins")?.getattr("UserWarning")?;
                     PyErr::warn_bound(py, &user_warning_type, &message, 0)
@@ -472,12 +568,73 @@ impl CollectWarnings {
             _ => Ok(()),
         }
     }
+
+    /// Return the warnings as a list of `{'loc': ..., 'msg': ...}` dicts,
+    /// in `error` mode warnings are still raised as an exception
+    pub fn collected<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        if self.mode == WarningsMode::Error {
+            self.raise_or_warn(py)?;
+        }
+        let list = PyList::empty_bound(py);
+        if let Some(ref warnings) = *self.warnings.borrow() {
+            for warning in warnings {
+                let dict = PyDict::new_bound(py);
+                dict.set_item(intern!(py, "loc"), PyTuple::new_bound(py, &warning.loc))?;
+                dict.set_item(intern!(py, "msg"), &warning.message)?;
+                list.append(dict)?;
+            }
+        }
+        Ok(list)
+    }
 }
 
 #[derive(Default, Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct SerRecursionState {
     guard: RefCell<RecursionState>,
+    // `max_depth` limits how many definition references, i.e. levels of a recursive schema, are followed,
+    // `definition_depth` is the number currently being serialized
+    max_depth: Option<usize>,
+    definition_depth: Cell<usize>,
+}
+
+impl SerRecursionState {
+    pub fn with_max_depth(max_depth: Option<usize>) -> Self {
+        Self {
+            max_depth,
+            ..Self::default()
+        }
+    }
+
+    /// Enter a definition reference, `None` if that would exceed `max_depth`, in which case the caller should
+    /// emit a placeholder instead of recursing
+    pub fn enter_definition(&self) -> Option<DefinitionDepthGuard<'_>> {
+        let depth = self.definition_depth.get();
+        match self.max_depth {
+            Some(max_depth) if depth >= max_depth => None,
+            _ => {
+                self.definition_depth.set(depth + 1);
+                Some(DefinitionDepthGuard(self))
+            }
+        }
+    }
+
+    pub fn max_depth_warning(&self) -> String {
+        format!(
+            "Maximum serialization depth of {} exceeded, serialized `None` instead",
+            self.max_depth.unwrap_or_default()
+        )
+    }
+}
+
+/// Leaves the definition reference entered by `SerRecursionState::enter_definition` when dropped
+pub(crate) struct DefinitionDepthGuard<'a>(&'a SerRecursionState);
+
+impl Drop for DefinitionDepthGuard<'_> {
+    fn drop(&mut self) {
+        let depth = &self.0.definition_depth;
+        depth.set(depth.get().saturating_sub(1));
+    }
 }
 
 impl ContainsRecursionState for &'_ Extra<'_> {
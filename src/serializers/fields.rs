@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
@@ -7,6 +8,7 @@ use ahash::AHashMap;
 use serde::ser::SerializeMap;
 use smallvec::SmallVec;
 
+use crate::errors::InvalidValue;
 use crate::serializers::extra::SerCheck;
 use crate::serializers::DuckTypingSerMode;
 use crate::tools::truncate_safe_repr;
@@ -15,7 +17,7 @@ use crate::PydanticSerializationUnexpectedValue;
 use super::computed_fields::ComputedFields;
 use super::errors::py_err_se_err;
 use super::extra::Extra;
-use super::filter::SchemaFilter;
+use super::filter::{KeyPatternFilter, SchemaFilter};
 use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeInfer};
 use super::shared::PydanticSerializer;
 use super::shared::{CombinedSerializer, TypeSerializer};
@@ -29,9 +31,11 @@ pub(super) struct SerField {
     // None serializer means exclude
     pub serializer: Option<CombinedSerializer>,
     pub required: bool,
+    // callable deciding per value whether the field should be excluded
+    pub exclude_if: Option<Py<PyAny>>,
 }
 
-impl_py_gc_traverse!(SerField { serializer });
+impl_py_gc_traverse!(SerField { serializer, exclude_if });
 
 impl SerField {
     pub fn new(
@@ -40,6 +44,7 @@ impl SerField {
         alias: Option<String>,
         serializer: Option<CombinedSerializer>,
         required: bool,
+        exclude_if: Option<Py<PyAny>>,
     ) -> Self {
         let alias_py = alias
             .as_ref()
@@ -50,6 +55,7 @@ impl SerField {
             alias_py,
             serializer,
             required,
+            exclude_if,
         }
     }
 
@@ -70,6 +76,13 @@ impl SerField {
         }
         Cow::Borrowed(key_str)
     }
+
+    fn should_exclude(&self, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        match self.exclude_if {
+            Some(ref exclude_if) => exclude_if.bind(value.py()).call1((value,))?.is_truthy(),
+            None => Ok(false),
+        }
+    }
 }
 
 fn exclude_default(value: &Bound<'_, PyAny>, extra: &Extra, serializer: &CombinedSerializer) -> PyResult<bool> {
@@ -83,6 +96,29 @@ fn exclude_default(value: &Bound<'_, PyAny>, extra: &Extra, serializer: &Combine
     Ok(false)
 }
 
+/// With `inline_errors`, a field left as an `InvalidValue` by `mark_invalid` validation is serialized as
+/// `{"__error__": {"input": ..., "errors": [...]}}`, with unknown types such as exceptions in error contexts
+/// serialized as strings, as in `ValidationError.json()`
+fn inline_error<'py, 'a>(
+    value: &Bound<'py, PyAny>,
+    extra: &Extra<'a>,
+) -> PyResult<Option<(Bound<'py, PyDict>, Extra<'a>)>> {
+    if !extra.inline_errors {
+        return Ok(None);
+    }
+    let Ok(invalid) = value.downcast::<InvalidValue>() else {
+        return Ok(None);
+    };
+    let py = value.py();
+    let error = PyDict::new_bound(py);
+    error.set_item(intern!(py, "__error__"), invalid.try_borrow()?.error_dict(py)?)?;
+    let error_extra = Extra {
+        serialize_unknown: true,
+        ..extra.clone()
+    };
+    Ok(Some((error, error_extra)))
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(super) enum FieldsMode {
     // typeddict with no extra items
@@ -102,6 +138,7 @@ pub struct GeneralFieldsSerializer {
     extra_serializer: Option<Box<CombinedSerializer>>,
     // isize because we look up filter via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    key_pattern: KeyPatternFilter,
     required_fields: usize,
 }
 
@@ -127,11 +164,26 @@ impl GeneralFieldsSerializer {
             mode,
             extra_serializer: extra_serializer.map(Box::new),
             filter: SchemaFilter::default(),
+            key_pattern: KeyPatternFilter::default(),
             computed_fields,
             required_fields,
         }
     }
 
+    pub(super) fn with_key_pattern(mut self, key_pattern: KeyPatternFilter) -> Self {
+        self.key_pattern = key_pattern;
+        self
+    }
+
+    fn excludes_key(
+        &self,
+        output_key: &str,
+        key: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        self.key_pattern.excludes(output_key, key, include, &self.filter)
+    }
+
     fn extract_dicts<'a>(&self, value: &Bound<'a, PyAny>) -> Option<(Bound<'a, PyDict>, Option<Bound<'a, PyDict>>)> {
         match self.mode {
             FieldsMode::ModelExtra => value.extract().ok(),
@@ -171,18 +223,25 @@ impl GeneralFieldsSerializer {
             }
             let field_extra = Extra {
                 field_name: Some(key_str),
+                field_path: extra.field_path.push(key_str),
                 ..extra
             };
             if let Some((next_include, next_exclude)) = self.filter.key_filter(&key, include, exclude)? {
                 if let Some(field) = op_field {
                     if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(&value, &field_extra, serializer)? {
-                            let value = serializer.to_python(
-                                &value,
-                                next_include.as_ref(),
-                                next_exclude.as_ref(),
-                                &field_extra,
-                            )?;
+                        if !self.excludes_key(&field.get_key_json(key_str, &field_extra), &key, include)?
+                            && !exclude_default(&value, &field_extra, serializer)?
+                            && !field.should_exclude(&value)?
+                        {
+                            let value = match inline_error(&value, &field_extra)? {
+                                Some((error, error_extra)) => infer_to_python(&error, None, None, &error_extra)?,
+                                None => serializer.to_python(
+                                    &value,
+                                    next_include.as_ref(),
+                                    next_exclude.as_ref(),
+                                    &field_extra,
+                                )?,
+                            };
                             let output_key = field.get_key_py(output_dict.py(), &field_extra);
                             output_dict.set_item(output_key, value)?;
                         }
@@ -192,13 +251,20 @@ impl GeneralFieldsSerializer {
                         used_req_fields += 1;
                     }
                 } else if self.mode == FieldsMode::TypedDictAllow {
-                    let value = match &self.extra_serializer {
-                        Some(serializer) => {
-                            serializer.to_python(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra)?
-                        }
-                        None => infer_to_python(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra)?,
-                    };
-                    output_dict.set_item(key, value)?;
+                    if !self.excludes_key(key_str, &key, include)? {
+                        let value = match &self.extra_serializer {
+                            Some(serializer) => serializer.to_python(
+                                &value,
+                                next_include.as_ref(),
+                                next_exclude.as_ref(),
+                                &field_extra,
+                            )?,
+                            None => {
+                                infer_to_python(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra)?
+                            }
+                        };
+                        output_dict.set_item(key, value)?;
+                    }
                 } else if field_extra.check == SerCheck::Strict {
                     let type_name = field_extra.model_type_name();
                     return Err(PydanticSerializationUnexpectedValue::new_err(Some(format!(
@@ -256,6 +322,7 @@ impl GeneralFieldsSerializer {
             let key_str = key_str(&key).map_err(py_err_se_err)?;
             let field_extra = Extra {
                 field_name: Some(key_str),
+                field_path: extra.field_path.push(key_str),
                 ..extra
             };
 
@@ -263,22 +330,34 @@ impl GeneralFieldsSerializer {
             if let Some((next_include, next_exclude)) = filter {
                 if let Some(field) = self.fields.get(key_str) {
                     if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(&value, &field_extra, serializer).map_err(py_err_se_err)? {
-                            let s = PydanticSerializer::new(
-                                &value,
-                                serializer,
-                                next_include.as_ref(),
-                                next_exclude.as_ref(),
-                                &field_extra,
-                            );
-                            let output_key = field.get_key_json(key_str, &field_extra);
-                            map.serialize_entry(&output_key, &s)?;
+                        let output_key = field.get_key_json(key_str, &field_extra);
+                        if !self.excludes_key(&output_key, &key, include).map_err(py_err_se_err)?
+                            && !exclude_default(&value, &field_extra, serializer).map_err(py_err_se_err)?
+                            && !field.should_exclude(&value).map_err(py_err_se_err)?
+                        {
+                            if let Some((error, error_extra)) =
+                                inline_error(&value, &field_extra).map_err(py_err_se_err)?
+                            {
+                                let s = SerializeInfer::new(&error, None, None, &error_extra);
+                                map.serialize_entry(&output_key, &s)?;
+                            } else {
+                                let s = PydanticSerializer::new(
+                                    &value,
+                                    serializer,
+                                    next_include.as_ref(),
+                                    next_exclude.as_ref(),
+                                    &field_extra,
+                                );
+                                map.serialize_entry(&output_key, &s)?;
+                            }
                         }
                     }
                 } else if self.mode == FieldsMode::TypedDictAllow {
                     let output_key = infer_json_key(&key, &field_extra).map_err(py_err_se_err)?;
-                    let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra);
-                    map.serialize_entry(&output_key, &s)?;
+                    if !self.excludes_key(&output_key, &key, include).map_err(py_err_se_err)? {
+                        let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra);
+                        map.serialize_entry(&output_key, &s)?;
+                    }
                 }
                 // no error case here since unions (which need the error case) use `to_python(..., mode='json')`
             }
@@ -374,6 +453,11 @@ impl TypeSerializer for GeneralFieldsSerializer {
                     continue;
                 }
                 if let Some((next_include, next_exclude)) = self.filter.key_filter(&key, include, exclude)? {
+                    if self.key_pattern.is_active()
+                        && self.excludes_key(&infer_json_key(&key, extra)?, &key, include)?
+                    {
+                        continue;
+                    }
                     let value = match &self.extra_serializer {
                         Some(serializer) => {
                             serializer.to_python(&value, next_include.as_ref(), next_exclude.as_ref(), extra)?
@@ -450,6 +534,9 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 let filter = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = filter {
                     let output_key = infer_json_key(&key, extra).map_err(py_err_se_err)?;
+                    if self.excludes_key(&output_key, &key, include).map_err(py_err_se_err)? {
+                        continue;
+                    }
                     let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), extra);
                     map.serialize_entry(&output_key, &s)?;
                 }
@@ -6,7 +6,9 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyDict, PySet};
+use regex::Regex;
 
+use crate::build_tools::py_schema_err;
 use crate::tools::SchemaDict;
 
 #[derive(Debug, Clone, Default)]
@@ -141,6 +143,51 @@ impl SchemaFilter<isize> {
     }
 }
 
+/// Drops output keys matching a schema's `exclude_key_pattern`, compiled when the serializer is built
+#[derive(Debug, Clone, Default)]
+pub(crate) struct KeyPatternFilter(Option<Regex>);
+
+impl KeyPatternFilter {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema.get_as::<String>(intern!(schema.py(), "exclude_key_pattern"))? {
+            Some(pattern) => match Regex::new(&pattern) {
+                Ok(regex) => Ok(Self(Some(regex))),
+                Err(e) => py_schema_err!("Invalid exclude_key_pattern: {}", e),
+            },
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// whether `output_key`, the key after alias resolution, should be dropped,
+    /// keys explicitly included by the schema or call-time `include` are always kept
+    pub fn excludes(
+        &self,
+        output_key: &str,
+        key: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        filter: &SchemaFilter<isize>,
+    ) -> PyResult<bool> {
+        match self.0 {
+            Some(ref regex) if regex.is_match(output_key) => {
+                if filter.explicit_include(key.hash()?) {
+                    return Ok(false);
+                }
+                match include {
+                    Some(include) if include.is_instance_of::<PyDict>() || include.is_instance_of::<PySet>() => {
+                        Ok(!include.contains(key)?)
+                    }
+                    _ => Ok(true),
+                }
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
 trait FilterLogic<T: Eq + Copy> {
     /// whether an `index`/`key` is explicitly included, this is combined with call-time `include` below
     fn explicit_include(&self, value: T) -> bool;
@@ -106,6 +106,9 @@ pub(crate) fn infer_to_python_known(
             extra.serialize_unknown,
             extra.fallback,
             extra.duck_typing_ser_mode,
+            extra.dump_secret_values,
+            extra.exclude_computed,
+            extra.inline_errors,
             extra.context,
         );
         serializer.serializer.to_python(value, include, exclude, &extra)
@@ -187,7 +190,7 @@ pub(crate) fn infer_to_python_known(
                 extra
                     .config
                     .timedelta_mode
-                    .either_delta_to_json(value.py(), &either_delta)?
+                    .either_delta_to_json(value.py(), &either_delta, extra.warnings)?
             }
             ObType::Url => {
                 let py_url: PyUrl = value.extract()?;
@@ -202,11 +205,16 @@ pub(crate) fn infer_to_python_known(
                 uuid.into_py(py)
             }
             ObType::PydanticSerializable => serialize_with_serializer()?,
-            ObType::Dataclass => {
-                serialize_pairs_python(py, any_dataclass_iter(value)?.0, include, exclude, extra, |k| {
-                    Ok(PyString::new_bound(py, &infer_json_key(&k, extra)?).into_any())
-                })?
-            }
+            ObType::Dataclass => serialize_pairs_python(
+                py,
+                any_dataclass_iter(value)?
+                    .0
+                    .filter(|r| include_dataclass_field(r, extra)),
+                include,
+                exclude,
+                extra,
+                |k| Ok(PyString::new_bound(py, &infer_json_key(&k, extra)?).into_any()),
+            )?,
             ObType::Enum => {
                 let v = value.getattr(intern!(py, "value"))?;
                 infer_to_python(&v, include, exclude, extra)?.into_py(py)
@@ -271,7 +279,16 @@ pub(crate) fn infer_to_python_known(
                 serialize_pairs_python(py, dict.iter().map(Ok), include, exclude, extra, Ok)?
             }
             ObType::PydanticSerializable => serialize_with_serializer()?,
-            ObType::Dataclass => serialize_pairs_python(py, any_dataclass_iter(value)?.0, include, exclude, extra, Ok)?,
+            ObType::Dataclass => serialize_pairs_python(
+                py,
+                any_dataclass_iter(value)?
+                    .0
+                    .filter(|r| include_dataclass_field(r, extra)),
+                include,
+                exclude,
+                extra,
+                Ok,
+            )?,
             ObType::Generator => {
                 let iter = super::type_serializers::generator::SerializationIterator::new(
                     value.downcast()?,
@@ -471,7 +488,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             extra
                 .config
                 .timedelta_mode
-                .timedelta_serialize(value.py(), &either_delta, serializer)
+                .timedelta_serialize(value.py(), &either_delta, serializer, extra.warnings)
         }
         ObType::Url => {
             let py_url: PyUrl = value.extract().map_err(py_err_se_err)?;
@@ -500,6 +517,9 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 extra.serialize_unknown,
                 extra.fallback,
                 extra.duck_typing_ser_mode,
+                extra.dump_secret_values,
+                extra.exclude_computed,
+                extra.inline_errors,
                 extra.context,
             );
             let pydantic_serializer =
@@ -508,6 +528,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Dataclass => {
             let (pairs_iter, fields_dict) = any_dataclass_iter(value).map_err(py_err_se_err)?;
+            let pairs_iter = pairs_iter.filter(|r| include_dataclass_field(r, extra));
             serialize_pairs_json(pairs_iter, fields_dict.len(), serializer, include, exclude, extra)
         }
         ObType::Uuid => {
@@ -649,7 +670,10 @@ pub(crate) fn infer_json_key_known<'a>(
         }
         ObType::Timedelta => {
             let either_delta = EitherTimedelta::try_from(key)?;
-            extra.config.timedelta_mode.json_key(key.py(), &either_delta)
+            extra
+                .config
+                .timedelta_mode
+                .json_key(key.py(), &either_delta, extra.warnings)
         }
         ObType::Url => {
             let py_url: PyUrl = key.extract()?;
@@ -706,6 +730,11 @@ pub(crate) fn infer_json_key_known<'a>(
     }
 }
 
+/// `exclude_none` applies to the fields of dataclasses serialized without a schema as it does with one
+fn include_dataclass_field(result: &PyResult<(Bound<'_, PyAny>, Bound<'_, PyAny>)>, extra: &Extra) -> bool {
+    !(extra.exclude_none && matches!(result, Ok((_, value)) if value.is_none()))
+}
+
 fn serialize_pairs_python<'py>(
     py: Python,
     pairs_iter: impl Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
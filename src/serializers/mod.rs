@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -14,7 +15,7 @@ pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValu
 use extra::{CollectWarnings, SerRecursionState, WarningsMode};
 pub(crate) use extra::{DuckTypingSerMode, Extra, SerMode, SerializationState};
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_json_bytes, to_json_file, BuildSerializer, TypeSerializer};
 
 mod computed_fields;
 mod config;
@@ -28,7 +29,7 @@ pub mod ser;
 mod shared;
 mod type_serializers;
 
-#[derive(FromPyObject)]
+#[derive(FromPyObject, Clone, Copy)]
 pub enum WarningsArg {
     Bool(bool),
     Literal(WarningsMode),
@@ -63,6 +64,9 @@ impl SchemaSerializer {
         serialize_unknown: bool,
         fallback: Option<&'a Bound<'a, PyAny>>,
         duck_typing_ser_mode: DuckTypingSerMode,
+        dump_secret_values: bool,
+        exclude_computed: bool,
+        inline_errors: bool,
         context: Option<&'a Bound<'a, PyAny>>,
     ) -> Extra<'b> {
         Extra::new(
@@ -79,9 +83,44 @@ impl SchemaSerializer {
             serialize_unknown,
             fallback,
             duck_typing_ser_mode,
+            dump_secret_values,
+            exclude_computed,
+            inline_errors,
             context,
         )
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn json_output(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        indent: Option<usize>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+        file: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        match file {
+            Some(file) => {
+                to_json_file(value, &self.serializer, include, exclude, extra, indent, file)?;
+                Ok(py.None())
+            }
+            None => {
+                let bytes = to_json_bytes(
+                    value,
+                    &self.serializer,
+                    include,
+                    exclude,
+                    extra,
+                    indent,
+                    self.expected_json_size.load(Ordering::Relaxed),
+                )?;
+                self.expected_json_size.store(bytes.len(), Ordering::Relaxed);
+                Ok(PyBytes::new_bound(py, &bytes).into())
+            }
+        }
+    }
 }
 
 #[pymethods]
@@ -107,7 +146,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+        fallback = None, serialize_as_any = false, context = None, collect_warnings = false, dump_secret_values = false,
+        exclude_computed = false, max_depth = None, inline_errors = false))]
     pub fn to_python(
         &self,
         py: Python,
@@ -124,14 +164,15 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        collect_warnings: bool,
+        dump_secret_values: bool,
+        exclude_computed: bool,
+        max_depth: Option<usize>,
+        inline_errors: bool,
     ) -> PyResult<PyObject> {
         let mode: SerMode = mode.into();
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
-        };
-        let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
+        let warnings = CollectWarnings::from_arg(warnings, collect_warnings);
+        let rec_guard = SerRecursionState::with_max_depth(max_depth);
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
         let extra = self.build_extra(
             py,
@@ -146,9 +187,15 @@ impl SchemaSerializer {
             false,
             fallback,
             duck_typing_ser_mode,
+            dump_secret_values,
+            exclude_computed,
+            inline_errors,
             context,
         );
         let v = self.serializer.to_python(value, include, exclude, &extra)?;
+        if collect_warnings {
+            return Ok((v, warnings.collected(py)?).into_py(py));
+        }
         warnings.final_check(py)?;
         Ok(v)
     }
@@ -156,7 +203,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+        fallback = None, serialize_as_any = false, context = None, collect_warnings = false, file = None,
+        dump_secret_values = false, exclude_computed = false, max_depth = None, inline_errors = false))]
     pub fn to_json(
         &self,
         py: Python,
@@ -173,13 +221,15 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        collect_warnings: bool,
+        file: Option<&Bound<'_, PyAny>>,
+        dump_secret_values: bool,
+        exclude_computed: bool,
+        max_depth: Option<usize>,
+        inline_errors: bool,
     ) -> PyResult<PyObject> {
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
-        };
-        let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
+        let warnings = CollectWarnings::from_arg(warnings, collect_warnings);
+        let rec_guard = SerRecursionState::with_max_depth(max_depth);
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
         let extra = self.build_extra(
             py,
@@ -194,23 +244,62 @@ impl SchemaSerializer {
             false,
             fallback,
             duck_typing_ser_mode,
+            dump_secret_values,
+            exclude_computed,
+            inline_errors,
             context,
         );
-        let bytes = to_json_bytes(
-            value,
-            &self.serializer,
-            include,
-            exclude,
-            &extra,
-            indent,
-            self.expected_json_size.load(Ordering::Relaxed),
-        )?;
+        let output = self.json_output(py, value, indent, include, exclude, &extra, file)?;
 
-        warnings.final_check(py)?;
+        if collect_warnings {
+            Ok((output, warnings.collected(py)?).into_py(py))
+        } else {
+            warnings.final_check(py)?;
+            Ok(output)
+        }
+    }
 
-        self.expected_json_size.store(bytes.len(), Ordering::Relaxed);
-        let py_bytes = PyBytes::new_bound(py, &bytes);
-        Ok(py_bytes.into())
+    /// Fix the serialization options once, so the returned `SerializerContext` can be called repeatedly
+    /// with only the value (and optionally mode, indent, include and exclude) to serialize
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (*, by_alias = true, exclude_unset = false, exclude_defaults = false, exclude_none = false,
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        dump_secret_values = false, exclude_computed = false, max_depth = None, inline_errors = false))]
+    pub fn serializer_context(
+        slf: &Bound<'_, Self>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+        dump_secret_values: bool,
+        exclude_computed: bool,
+        max_depth: Option<usize>,
+        inline_errors: bool,
+    ) -> SerializerContext {
+        SerializerContext {
+            serializer: slf.clone().unbind(),
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            fallback: fallback.map(|f| f.clone().unbind()),
+            duck_typing_ser_mode: DuckTypingSerMode::from_bool(serialize_as_any),
+            context: context.map(|c| c.clone().unbind()),
+            dump_secret_values,
+            exclude_computed,
+            inline_errors,
+            warnings_arg: warnings,
+            max_depth,
+            warnings: CollectWarnings::from_arg(warnings, false),
+            rec_guard: SerRecursionState::with_max_depth(max_depth),
+            in_use: Cell::new(false),
+        }
     }
 
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
@@ -239,12 +328,132 @@ impl SchemaSerializer {
     }
 }
 
+/// Reusable serialization options returned by `SchemaSerializer.serializer_context()`, the warnings collector and
+/// recursion guard are built once and reset after each call
+#[pyclass(module = "pydantic_core._pydantic_core")]
+pub struct SerializerContext {
+    serializer: Py<SchemaSerializer>,
+    by_alias: bool,
+    exclude_unset: bool,
+    exclude_defaults: bool,
+    exclude_none: bool,
+    round_trip: bool,
+    fallback: Option<PyObject>,
+    duck_typing_ser_mode: DuckTypingSerMode,
+    context: Option<PyObject>,
+    dump_secret_values: bool,
+    exclude_computed: bool,
+    inline_errors: bool,
+    warnings_arg: WarningsArg,
+    max_depth: Option<usize>,
+    warnings: CollectWarnings,
+    rec_guard: SerRecursionState,
+    // set while serializing, so calls made meanwhile, e.g. by a `fallback` function, use state of their own
+    in_use: Cell<bool>,
+}
+
+impl SerializerContext {
+    fn serialize<R>(
+        &self,
+        py: Python,
+        mode: &SerMode,
+        f: impl FnOnce(&SchemaSerializer, &Extra) -> PyResult<R>,
+    ) -> PyResult<R> {
+        if self.in_use.replace(true) {
+            let warnings = CollectWarnings::from_arg(self.warnings_arg, false);
+            let rec_guard = SerRecursionState::with_max_depth(self.max_depth);
+            let output = self.serialize_with(py, mode, &warnings, &rec_guard, f)?;
+            warnings.final_check(py)?;
+            return Ok(output);
+        }
+        let result = self
+            .serialize_with(py, mode, &self.warnings, &self.rec_guard, f)
+            .and_then(|output| self.warnings.final_check(py).map(|()| output));
+        self.warnings.clear();
+        self.in_use.set(false);
+        result
+    }
+
+    fn serialize_with<R>(
+        &self,
+        py: Python,
+        mode: &SerMode,
+        warnings: &CollectWarnings,
+        rec_guard: &SerRecursionState,
+        f: impl FnOnce(&SchemaSerializer, &Extra) -> PyResult<R>,
+    ) -> PyResult<R> {
+        let serializer = self.serializer.get();
+        let extra = serializer.build_extra(
+            py,
+            mode,
+            self.by_alias,
+            warnings,
+            self.exclude_unset,
+            self.exclude_defaults,
+            self.exclude_none,
+            self.round_trip,
+            rec_guard,
+            false,
+            self.fallback.as_ref().map(|f| f.bind(py)),
+            self.duck_typing_ser_mode,
+            self.dump_secret_values,
+            self.exclude_computed,
+            self.inline_errors,
+            self.context.as_ref().map(|c| c.bind(py)),
+        );
+        f(serializer, &extra)
+    }
+}
+
+#[pymethods]
+impl SerializerContext {
+    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None))]
+    pub fn to_python(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        mode: Option<&str>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let mode: SerMode = mode.into();
+        self.serialize(py, &mode, |serializer, extra| {
+            serializer.serializer.to_python(value, include, exclude, extra)
+        })
+    }
+
+    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None))]
+    pub fn to_json(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        indent: Option<usize>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.serialize(py, &SerMode::Json, |serializer, extra| {
+            serializer.json_output(py, value, indent, include, exclude, extra, None)
+        })
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.serializer)?;
+        if let Some(ref fallback) = self.fallback {
+            visit.call(fallback)?;
+        }
+        if let Some(ref context) = self.context {
+            visit.call(context)?;
+        }
+        Ok(())
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
 #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
     inf_nan_mode = "constants", serialize_unknown = false, fallback = None, serialize_as_any = false,
-    context = None))]
+    context = None, file = None))]
 pub fn to_json(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -261,6 +470,7 @@ pub fn to_json(
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    file: Option<&Bound<'_, PyAny>>,
 ) -> PyResult<PyObject> {
     let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
@@ -276,10 +486,18 @@ pub fn to_json(
         context,
     );
     let serializer = type_serializers::any::AnySerializer.into();
-    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+    let output = match file {
+        Some(file) => {
+            to_json_file(value, &serializer, include, exclude, &extra, indent, file)?;
+            py.None()
+        }
+        None => {
+            let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+            PyBytes::new_bound(py, &bytes).into()
+        }
+    };
     state.final_check(py)?;
-    let py_bytes = PyBytes::new_bound(py, &bytes);
-    Ok(py_bytes.into())
+    Ok(output)
 }
 
 #[allow(clippy::too_many_arguments)]
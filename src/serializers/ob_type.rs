@@ -94,10 +94,11 @@ impl ObTypeLookup {
                 .getattr("GeneratorType")
                 .unwrap()
                 .to_object(py),
+            // `PurePath` rather than `Path` so `PurePosixPath` and `PureWindowsPath` are also matched
             path_object: py
                 .import_bound("pathlib")
                 .unwrap()
-                .getattr("Path")
+                .getattr("PurePath")
                 .unwrap()
                 .to_object(py),
             pattern_object: py.import_bound("re").unwrap().getattr("Pattern").unwrap().to_object(py),
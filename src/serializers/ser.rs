@@ -16,6 +16,20 @@ macro_rules! tri {
 
 type Result<T> = std::result::Result<T, PythonSerializerError>;
 const TOKEN: &str = "$serde_json::private::Number";
+const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
+
+/// JSON text written to the output as is, used to re-emit the original document of a `json` schema with `keep_raw`
+pub(crate) struct RawJsonStr<'a>(pub &'a str);
+
+impl Serialize for RawJsonStr<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct(RAW_VALUE_TOKEN, 1)?;
+        s.serialize_field(RAW_VALUE_TOKEN, self.0)?;
+        s.end()
+    }
+}
+
 pub struct PythonSerializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
@@ -355,7 +369,8 @@ where
 
     fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
         match name {
-            TOKEN => Ok(Compound::Number { ser: self }),
+            // raw values are written just like the text of arbitrary precision numbers
+            TOKEN | RAW_VALUE_TOKEN => Ok(Compound::Number { ser: self }),
             _ => self.serialize_map(Some(len)),
         }
     }
@@ -611,7 +626,7 @@ where
         match self {
             Compound::Map { .. } => serde::ser::SerializeMap::serialize_entry(self, key, value),
             Compound::Number { ser, .. } => {
-                if key == TOKEN {
+                if key == TOKEN || key == RAW_VALUE_TOKEN {
                     tri!(value.serialize(NumberStrEmitter(ser)));
                     Ok(())
                 } else {
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::io;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyOSError, PyTypeError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use enum_dispatch::enum_dispatch;
@@ -90,12 +91,15 @@ combined_serializer! {
     find_only: {
         super::type_serializers::other::ChainBuilder;
         super::type_serializers::other::CustomErrorBuilder;
+        super::type_serializers::other::ConstraintsBuilder;
+        super::type_serializers::other::SelfValidatingBuilder;
         super::type_serializers::other::CallBuilder;
         super::type_serializers::other::LaxOrStrictBuilder;
         super::type_serializers::other::ArgumentsBuilder;
         super::type_serializers::other::IsInstanceBuilder;
         super::type_serializers::other::IsSubclassBuilder;
         super::type_serializers::other::CallableBuilder;
+        super::type_serializers::other::FractionBuilder;
         super::type_serializers::definitions::DefinitionsSerializerBuilder;
         super::type_serializers::dataclass::DataclassArgsBuilder;
         super::type_serializers::function::FunctionBeforeSerializerBuilder;
@@ -143,6 +147,8 @@ combined_serializer! {
         Recursive: super::type_serializers::definitions::DefinitionRefSerializer;
         Tuple: super::type_serializers::tuple::TupleSerializer;
         Complex: super::type_serializers::complex::ComplexSerializer;
+        Secret: super::type_serializers::secret::SecretSerializer;
+        Base64: super::type_serializers::base64::Base64Serializer;
     }
 }
 
@@ -254,6 +260,8 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Tuple(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Complex(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Secret(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Base64(inner) => inner.py_gc_traverse(visit),
         }
     }
 }
@@ -349,9 +357,44 @@ pub(crate) fn to_json_bytes(
     indent: Option<usize>,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
+    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    to_json_writer(value, serializer, include, exclude, extra, indent, writer)
+}
+
+/// Serialize `value` as JSON directly into a Python file-like object by calling its `write` method,
+/// the output is written in chunks rather than built up in memory first.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_file(
+    value: &Bound<'_, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+    indent: Option<usize>,
+    file: &Bound<'_, PyAny>,
+) -> PyResult<()> {
+    let mut writer = PyFileWriter::new(file);
+    let result = to_json_writer(value, serializer, include, exclude, extra, indent, &mut writer).map(drop);
+    // an exception raised by `write` is propagated as is, rather than wrapped in a serialization error
+    if let Some(err) = writer.error.take() {
+        return Err(err);
+    }
+    result?;
+    writer.write_buffer()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn to_json_writer<W: io::Write>(
+    value: &Bound<'_, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+    indent: Option<usize>,
+    writer: W,
+) -> PyResult<W> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
 
-    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
     let bytes = match indent {
         Some(indent) => {
             let indent = vec![b' '; indent];
@@ -369,6 +412,70 @@ pub(crate) fn to_json_bytes(
     Ok(bytes)
 }
 
+const FILE_WRITE_CHUNK_SIZE: usize = 8192;
+
+/// Adapter between serde's `io::Write` and the `write` method of a Python file-like object.
+struct PyFileWriter<'a, 'py> {
+    file: &'a Bound<'py, PyAny>,
+    buffer: Vec<u8>,
+    error: Option<PyErr>,
+}
+
+impl<'a, 'py> PyFileWriter<'a, 'py> {
+    fn new(file: &'a Bound<'py, PyAny>) -> Self {
+        Self {
+            file,
+            buffer: Vec::with_capacity(FILE_WRITE_CHUNK_SIZE),
+            error: None,
+        }
+    }
+
+    fn write_buffer(&mut self) -> PyResult<()> {
+        let py = self.file.py();
+        let mut written = 0;
+        while written < self.buffer.len() {
+            let remaining = &self.buffer[written..];
+            let result = self
+                .file
+                .call_method1(intern!(py, "write"), (PyBytes::new_bound(py, remaining),))?;
+            // raw streams may write fewer bytes than requested, other writers may return `None`
+            if result.is_none() {
+                written = self.buffer.len();
+            } else {
+                let count: usize = result.extract()?;
+                if count == 0 {
+                    return py_err!(PyOSError; "write() returned 0, unable to write JSON to file");
+                }
+                written += count.min(remaining.len());
+            }
+        }
+        self.buffer.clear();
+        Ok(())
+    }
+
+    fn write_buffer_io(&mut self) -> io::Result<()> {
+        self.write_buffer().map_err(|err| {
+            let io_err = io::Error::other(err.to_string());
+            self.error = Some(err);
+            io_err
+        })
+    }
+}
+
+impl io::Write for PyFileWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= FILE_WRITE_CHUNK_SIZE {
+            self.write_buffer_io()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffer_io()
+    }
+}
+
 pub(super) fn any_dataclass_iter<'a, 'py>(
     dataclass: &'a Bound<'py, PyAny>,
 ) -> PyResult<(
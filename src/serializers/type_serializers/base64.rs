@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use ::base64::engine::general_purpose::GeneralPurpose;
+use ::base64::Engine;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::validators::base64_engine;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
+};
+
+/// Re-encodes the decoded bytes from a `base64` schema, in both python and JSON mode, so dumped values
+/// validate again to the same bytes
+#[derive(Debug)]
+pub struct Base64Serializer {
+    engine: &'static GeneralPurpose,
+}
+
+impl BuildSerializer for Base64Serializer {
+    const EXPECTED_TYPE: &'static str = "base64";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {
+            engine: base64_engine(schema)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(Base64Serializer {});
+
+impl TypeSerializer for Base64Serializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        match value.downcast::<PyBytes>() {
+            Ok(py_bytes) => Ok(self.engine.encode(py_bytes.as_bytes()).into_py(value.py())),
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        match key.downcast::<PyBytes>() {
+            Ok(py_bytes) => Ok(Cow::Owned(self.engine.encode(py_bytes.as_bytes()))),
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.downcast::<PyBytes>() {
+            Ok(py_bytes) => serializer.serialize_str(&self.engine.encode(py_bytes.as_bytes())),
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -1,23 +1,29 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyComplex, PyDict};
+use serde::ser::SerializeMap;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::{ComplexMode, FromConfig};
 
 use super::{infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer};
 
 #[derive(Debug, Clone)]
-pub struct ComplexSerializer {}
+pub struct ComplexSerializer {
+    complex_mode: ComplexMode,
+}
 
 impl BuildSerializer for ComplexSerializer {
     const EXPECTED_TYPE: &'static str = "complex";
     fn build(
         _schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let complex_mode = ComplexMode::from_config(config)?;
+        Ok(Self { complex_mode }.into())
     }
 }
 
@@ -33,10 +39,18 @@ impl TypeSerializer for ComplexSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         match value.downcast::<PyComplex>() {
-            Ok(py_complex) => Ok(match extra.mode {
-                SerMode::Json => complex_to_str(py_complex).into_py(py),
-                _ => value.into_py(py),
-            }),
+            Ok(py_complex) => match extra.mode {
+                SerMode::Json => match self.complex_mode {
+                    ComplexMode::Str => Ok(complex_to_str(py_complex).into_py(py)),
+                    ComplexMode::Dict => {
+                        let dict = PyDict::new_bound(py);
+                        dict.set_item(intern!(py, "real"), py_complex.real())?;
+                        dict.set_item(intern!(py, "imag"), py_complex.imag())?;
+                        Ok(dict.into_py(py))
+                    }
+                },
+                _ => Ok(value.into_py(py)),
+            },
             Err(_) => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
@@ -57,10 +71,18 @@ impl TypeSerializer for ComplexSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyComplex>() {
-            Ok(py_complex) => {
-                let s = complex_to_str(py_complex);
-                Ok(serializer.collect_str::<String>(&s)?)
-            }
+            Ok(py_complex) => match self.complex_mode {
+                ComplexMode::Str => {
+                    let s = complex_to_str(py_complex);
+                    Ok(serializer.collect_str::<String>(&s)?)
+                }
+                ComplexMode::Dict => {
+                    let mut map = serializer.serialize_map(Some(2))?;
+                    map.serialize_entry("real", &py_complex.real())?;
+                    map.serialize_entry("imag", &py_complex.imag())?;
+                    map.end()
+                }
+            },
             Err(_) => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
@@ -1,32 +1,93 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{IntoPyDict, PyDict, PyType};
 
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
 use crate::serializers::ob_type::{IsType, ObType};
+use crate::tools::SchemaDict;
+use crate::validators::decimal::get_decimal_type;
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
 };
 
 #[derive(Debug)]
-pub struct DecimalSerializer {}
+pub struct DecimalSerializer {
+    // `Decimal` with the exponent from `ser_decimal_places`, e.g. `Decimal('0.01')` for 2 places
+    quantum: Option<Py<PyAny>>,
+    places: i64,
+}
+
+static DECIMAL_CONTEXT_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn get_decimal_context_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    DECIMAL_CONTEXT_TYPE
+        .get_or_try_init(py, || {
+            py.import_bound(intern!(py, "decimal"))?
+                .getattr(intern!(py, "Context"))?
+                .extract()
+        })
+        .map(|context_type| context_type.bind(py))
+}
 
 impl BuildSerializer for DecimalSerializer {
     const EXPECTED_TYPE: &'static str = "decimal";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let py = schema.py();
+        let places = schema.get_as::<u32>(intern!(py, "ser_decimal_places"))?;
+        let quantum = match places {
+            Some(places) => {
+                let exponent = -i64::from(places);
+                Some(get_decimal_type(py).call1(((0, (1,), exponent),))?.unbind())
+            }
+            None => None,
+        };
+        Ok(Self {
+            quantum,
+            places: places.map_or(0, i64::from),
+        }
+        .into())
     }
 }
 
-impl_py_gc_traverse!(DecimalSerializer {});
+impl_py_gc_traverse!(DecimalSerializer { quantum });
+
+impl DecimalSerializer {
+    /// `value` quantized to `ser_decimal_places`, formatted without an exponent so JSON always has that many
+    /// places, `None` if `ser_decimal_places` isn't set
+    fn quantize<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Option<(Bound<'py, PyAny>, String)>> {
+        let Some(quantum) = &self.quantum else {
+            return Ok(None);
+        };
+        let py = value.py();
+        // infinities and NaN can't be quantized
+        let quantized = if value.call_method0(intern!(py, "is_finite"))?.is_truthy()? {
+            // the default context only has 28 digits of precision, so use one with enough digits for the
+            // integer part, the places and a carry from rounding
+            let adjusted: i64 = value.call_method0(intern!(py, "adjusted"))?.extract()?;
+            let prec = (adjusted + self.places + 2).max(1);
+            let kwargs = [(intern!(py, "prec"), prec)].into_py_dict_bound(py);
+            let context = get_decimal_context_type(py)?.call((), Some(&kwargs))?;
+            value.call_method1(intern!(py, "quantize"), (quantum, py.None(), context))?
+        } else {
+            value.clone()
+        };
+        let fixed = quantized
+            .call_method1(intern!(py, "__format__"), (intern!(py, "f"),))?
+            .extract()?;
+        Ok(Some((quantized, fixed)))
+    }
+}
 
 impl TypeSerializer for DecimalSerializer {
     fn to_python(
@@ -36,9 +97,15 @@ impl TypeSerializer for DecimalSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let _py = value.py();
+        let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
+            IsType::Exact | IsType::Subclass => match self.quantize(value)? {
+                Some((quantized, fixed)) => match extra.mode {
+                    SerMode::Json => Ok(fixed.into_py(py)),
+                    _ => Ok(quantized.unbind()),
+                },
+                None => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
+            },
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
@@ -48,7 +115,10 @@ impl TypeSerializer for DecimalSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_json_key_known(ObType::Decimal, key, extra),
+            IsType::Exact | IsType::Subclass => match self.quantize(key)? {
+                Some((_, fixed)) => Ok(Cow::Owned(fixed)),
+                None => infer_json_key_known(ObType::Decimal, key, extra),
+            },
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -65,9 +135,10 @@ impl TypeSerializer for DecimalSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => {
-                infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra)
-            }
+            IsType::Exact | IsType::Subclass => match self.quantize(value).map_err(py_err_se_err)? {
+                Some((_, fixed)) => serializer.serialize_str(&fixed),
+                None => infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra),
+            },
             IsType::False => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
@@ -91,6 +91,10 @@ impl TypeSerializer for DefinitionRefSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         mut extra: &Extra,
     ) -> PyResult<PyObject> {
+        let Some(_depth_guard) = extra.rec_guard.enter_definition() else {
+            extra.warnings.custom_warning(extra.rec_guard.max_depth_warning());
+            return Ok(value.py().None());
+        };
         self.definition.read(|comb_serializer| {
             let comb_serializer = comb_serializer.unwrap();
             let mut guard = extra.recursion_guard(value, self.definition.id())?;
@@ -110,6 +114,10 @@ impl TypeSerializer for DefinitionRefSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         mut extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
+        let Some(_depth_guard) = extra.rec_guard.enter_definition() else {
+            extra.warnings.custom_warning(extra.rec_guard.max_depth_warning());
+            return serializer.serialize_none();
+        };
         self.definition.read(|comb_serializer| {
             let comb_serializer = comb_serializer.unwrap();
             let mut guard = extra
@@ -11,8 +11,8 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, SerMode, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, KeyPatternFilter,
+    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug)]
@@ -21,6 +21,7 @@ pub struct DictSerializer {
     value_serializer: Box<CombinedSerializer>,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
+    key_pattern: KeyPatternFilter,
     name: String,
 }
 
@@ -41,13 +42,16 @@ impl BuildSerializer for DictSerializer {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
-        let filter = match schema.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization"))? {
+        let (filter, key_pattern) = match schema.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization"))? {
             Some(ser) => {
                 let include = ser.get_item(intern!(py, "include"))?;
                 let exclude = ser.get_item(intern!(py, "exclude"))?;
-                SchemaFilter::from_set_hash(include.as_ref(), exclude.as_ref())?
+                (
+                    SchemaFilter::from_set_hash(include.as_ref(), exclude.as_ref())?,
+                    KeyPatternFilter::from_schema(&ser)?,
+                )
             }
-            None => SchemaFilter::default(),
+            None => (SchemaFilter::default(), KeyPatternFilter::default()),
         };
         let name = format!(
             "{}[{}, {}]",
@@ -59,6 +63,7 @@ impl BuildSerializer for DictSerializer {
             key_serializer: Box::new(key_serializer),
             value_serializer: Box::new(value_serializer),
             filter,
+            key_pattern,
             name,
         }
         .into())
@@ -87,6 +92,12 @@ impl TypeSerializer for DictSerializer {
                 for (key, value) in py_dict.iter() {
                     let op_next = self.filter.key_filter(&key, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        if self.key_pattern.is_active() {
+                            let json_key = self.key_serializer.json_key(&key, extra)?;
+                            if self.key_pattern.excludes(&json_key, &key, include, &self.filter)? {
+                                continue;
+                            }
+                        }
                         let key = match extra.mode {
                             SerMode::Json => self.key_serializer.json_key(&key, extra)?.into_py(py),
                             _ => self.key_serializer.to_python(&key, None, None, extra)?,
@@ -126,7 +137,14 @@ impl TypeSerializer for DictSerializer {
                 for (key, value) in py_dict.iter() {
                     let op_next = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        let key = key_serializer.json_key(&key, extra).map_err(py_err_se_err)?;
+                        let json_key = key_serializer.json_key(&key, extra).map_err(py_err_se_err)?;
+                        if self
+                            .key_pattern
+                            .excludes(&json_key, &key, include, &self.filter)
+                            .map_err(py_err_se_err)?
+                        {
+                            continue;
+                        }
                         let value_serialize = PydanticSerializer::new(
                             &value,
                             value_serializer,
@@ -134,7 +152,7 @@ impl TypeSerializer for DictSerializer {
                             next_exclude.as_ref(),
                             extra,
                         );
-                        map.serialize_entry(&key, &value_serialize)?;
+                        map.serialize_entry(&json_key, &value_serialize)?;
                     }
                 }
                 map.end()
@@ -6,9 +6,12 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use serde::ser::Error;
+use serde::Serialize;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::ser::RawJsonStr;
 use crate::tools::SchemaDict;
+use crate::validators::PyRawJson;
 
 use super::any::AnySerializer;
 use super::{
@@ -52,6 +55,15 @@ impl TypeSerializer for JsonSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
+        if let Ok(raw_json) = value.downcast::<PyRawJson>() {
+            let raw_json = raw_json.get();
+            let py = value.py();
+            return if extra.round_trip {
+                Ok(raw_json.raw(py).clone().into_any().unbind())
+            } else {
+                self.serializer.to_python(raw_json.value(py), include, exclude, extra)
+            };
+        }
         if extra.round_trip {
             let bytes = to_json_bytes(value, &self.serializer, include, exclude, extra, None, 0)?;
             let py = value.py();
@@ -63,6 +75,9 @@ impl TypeSerializer for JsonSerializer {
     }
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        if let Ok(raw_json) = key.downcast::<PyRawJson>() {
+            return Ok(Cow::Owned(raw_json.get().raw(key.py()).to_str()?.to_string()));
+        }
         if extra.round_trip {
             let bytes = to_json_bytes(key, &self.serializer, None, None, extra, None, 0)?;
             let py = key.py();
@@ -81,6 +96,20 @@ impl TypeSerializer for JsonSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
+        if let Ok(raw_json) = value.downcast::<PyRawJson>() {
+            let raw_json = raw_json.get();
+            let py = value.py();
+            let raw = raw_json.raw(py).to_str().map_err(py_err_se_err)?;
+            return if extra.round_trip {
+                serializer.serialize_str(raw)
+            } else if raw_json.is_strict_json() {
+                RawJsonStr(raw).serialize(serializer)
+            } else {
+                // `NaN` and `Infinity` literals aren't valid JSON, so are left to `ser_json_inf_nan`
+                self.serializer
+                    .serde_serialize(raw_json.value(py), serializer, include, exclude, extra)
+            };
+        }
         if extra.round_trip {
             let bytes =
                 to_json_bytes(value, &self.serializer, include, exclude, extra, None, 0).map_err(py_err_se_err)?;
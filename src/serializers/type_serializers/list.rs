@@ -3,11 +3,13 @@ use std::borrow::Cow;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use pyo3::PyTypeInfo;
 
 use serde::ser::SerializeSeq;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
+use crate::validators::get_deque_type;
 
 use super::any::AnySerializer;
 use super::{
@@ -47,6 +49,19 @@ impl BuildSerializer for ListSerializer {
 
 impl_py_gc_traverse!(ListSerializer { item_serializer });
 
+/// deques are created by list schemas with `output_type='deque'`, they're serialized like a copy as a list
+fn as_py_list<'py>(value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyList>> {
+    let py = value.py();
+    match value.downcast::<PyList>() {
+        Ok(py_list) => Some(py_list.clone()),
+        Err(_) if value.is_instance(get_deque_type(py)).unwrap_or(false) => PyList::type_object_bound(py)
+            .call1((value,))
+            .and_then(|list| Ok(list.downcast_into::<PyList>()?))
+            .ok(),
+        Err(_) => None,
+    }
+}
+
 impl TypeSerializer for ListSerializer {
     fn to_python(
         &self,
@@ -55,8 +70,8 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match as_py_list(value) {
+            Some(py_list) => {
                 let py = value.py();
                 let item_serializer = self.item_serializer.as_ref();
 
@@ -74,7 +89,7 @@ impl TypeSerializer for ListSerializer {
                 }
                 Ok(items.into_py(py))
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
             }
@@ -93,8 +108,8 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match as_py_list(value) {
+            Some(py_list) => {
                 let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
                 let item_serializer = self.item_serializer.as_ref();
 
@@ -116,7 +131,7 @@ impl TypeSerializer for ListSerializer {
                 }
                 seq.end()
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
             }
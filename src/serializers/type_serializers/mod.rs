@@ -1,4 +1,5 @@
 pub mod any;
+pub mod base64;
 pub mod bytes;
 pub mod complex;
 pub mod dataclass;
@@ -18,6 +19,7 @@ pub mod literal;
 pub mod model;
 pub mod nullable;
 pub mod other;
+pub mod secret;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
@@ -32,9 +34,9 @@ pub mod with_default;
 use super::computed_fields::ComputedFields;
 use super::config::utf8_py_error;
 use super::errors::{py_err_se_err, PydanticSerializationError};
-use super::extra::{Extra, ExtraOwned, SerCheck, SerMode};
+use super::extra::{CollectWarnings, Extra, ExtraOwned, SerCheck, SerMode, WarningsMode};
 use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
-use super::filter::{AnyFilter, SchemaFilter};
+use super::filter::{AnyFilter, KeyPatternFilter, SchemaFilter};
 use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
 use super::ob_type::{IsType, ObType};
 use super::shared::{to_json_bytes, BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
@@ -7,6 +7,7 @@ use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
+use super::format::ToStringSerializer;
 use super::{BuildSerializer, CombinedSerializer};
 
 pub struct ChainBuilder;
@@ -44,6 +45,36 @@ impl BuildSerializer for CustomErrorBuilder {
     }
 }
 
+pub struct ConstraintsBuilder;
+
+impl BuildSerializer for ConstraintsBuilder {
+    const EXPECTED_TYPE: &'static str = "constraints";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        CombinedSerializer::build(&sub_schema, config, definitions)
+    }
+}
+
+pub struct SelfValidatingBuilder;
+
+impl BuildSerializer for SelfValidatingBuilder {
+    const EXPECTED_TYPE: &'static str = "self-validating";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        CombinedSerializer::build(&sub_schema, config, definitions)
+    }
+}
+
 pub struct CallBuilder;
 
 impl BuildSerializer for CallBuilder {
@@ -91,6 +122,21 @@ impl BuildSerializer for ArgumentsBuilder {
     }
 }
 
+pub struct FractionBuilder;
+
+impl BuildSerializer for FractionBuilder {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        // fractions are serialized to JSON via `str()`, e.g. "3/4", which round-trips through validation
+        ToStringSerializer::build(schema, config, definitions)
+    }
+}
+
 macro_rules! any_build_serializer {
     ($struct_name:ident, $expected_type:literal) => {
         pub struct $struct_name;
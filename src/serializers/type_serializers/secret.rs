@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+use crate::validators::{PySecret, SECRET_PLACEHOLDER};
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    TypeSerializer,
+};
+
+/// `Secret`s are hidden behind a placeholder in JSON and kept as `Secret`s in python mode,
+/// unless `dump_secret_values` is set, then the wrapped value is serialized with the inner schema
+#[derive(Debug)]
+pub struct SecretSerializer {
+    serializer: Box<CombinedSerializer>,
+}
+
+impl BuildSerializer for SecretSerializer {
+    const EXPECTED_TYPE: &'static str = "secret";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        Ok(Self {
+            serializer: Box::new(CombinedSerializer::build(&sub_schema, config, definitions)?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(SecretSerializer { serializer });
+
+impl TypeSerializer for SecretSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match value.downcast::<PySecret>() {
+            Ok(secret) if extra.dump_secret_values => {
+                self.serializer
+                    .to_python(secret.get().value(py), include, exclude, extra)
+            }
+            Ok(_) => match extra.mode {
+                SerMode::Json => Ok(SECRET_PLACEHOLDER.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        match key.downcast::<PySecret>() {
+            Ok(secret) if extra.dump_secret_values => Ok(Cow::Owned(
+                self.serializer
+                    .json_key(secret.get().value(key.py()), extra)?
+                    .into_owned(),
+            )),
+            Ok(_) => Ok(Cow::Borrowed(SECRET_PLACEHOLDER)),
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.downcast::<PySecret>() {
+            Ok(secret) if extra.dump_secret_values => {
+                self.serializer
+                    .serde_serialize(secret.get().value(value.py()), serializer, include, exclude, extra)
+            }
+            Ok(_) => serializer.serialize_str(SECRET_PLACEHOLDER),
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -11,10 +11,45 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SerMode,
-    TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, to_json_bytes, BuildSerializer, CollectWarnings,
+    CombinedSerializer, Extra, PydanticSerializer, SerMode, TypeSerializer, WarningsMode,
 };
 
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum RoundTripKey {
+    Json(Vec<u8>),
+    // for items which can't be serialized to JSON: (type name, repr)
+    Fallback(String, String),
+}
+
+/// With `round_trip` set, items are serialized to JSON in a deterministic order: sorted by their JSON representation,
+/// falling back to type name then repr for items which can't be serialized
+fn round_trip_order<'py>(
+    items: impl Iterator<Item = Bound<'py, PyAny>>,
+    item_serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+) -> PyResult<Vec<Bound<'py, PyAny>>> {
+    // warnings are raised when the items are actually serialized, not while computing the sort keys
+    let warnings = CollectWarnings::new(WarningsMode::None);
+    let key_extra = Extra {
+        warnings: &warnings,
+        ..*extra
+    };
+    let mut keyed = items
+        .map(|item| {
+            let key = match to_json_bytes(&item, item_serializer, include, exclude, &key_extra, None, 16) {
+                Ok(json) => RoundTripKey::Json(json),
+                Err(_) => RoundTripKey::Fallback(item.get_type().qualname()?.to_string(), item.repr()?.to_string()),
+            };
+            Ok((key, item))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(keyed.into_iter().map(|(_, item)| item).collect())
+}
+
 macro_rules! build_serializer {
     ($struct_name:ident, $expected_type:literal, $py_type:ty) => {
         #[derive(Debug)]
@@ -61,12 +96,23 @@ macro_rules! build_serializer {
                         let item_serializer = self.item_serializer.as_ref();
 
                         let mut items = Vec::with_capacity(py_set.len());
-                        for element in py_set.iter() {
-                            items.push(item_serializer.to_python(&element, include, exclude, extra)?);
-                        }
                         match extra.mode {
-                            SerMode::Json => Ok(PyList::new_bound(py, items).into_py(py)),
-                            _ => Ok(<$py_type>::new_bound(py, &items)?.into_py(py)),
+                            SerMode::Json => {
+                                let elements = match extra.round_trip {
+                                    true => round_trip_order(py_set.iter(), item_serializer, include, exclude, extra)?,
+                                    false => py_set.iter().collect(),
+                                };
+                                for element in elements {
+                                    items.push(item_serializer.to_python(&element, include, exclude, extra)?);
+                                }
+                                Ok(PyList::new_bound(py, items).into_py(py))
+                            }
+                            _ => {
+                                for element in py_set.iter() {
+                                    items.push(item_serializer.to_python(&element, include, exclude, extra)?);
+                                }
+                                Ok(<$py_type>::new_bound(py, &items)?.into_py(py))
+                            }
                         }
                     }
                     Err(_) => {
@@ -93,7 +139,12 @@ macro_rules! build_serializer {
                         let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
                         let item_serializer = self.item_serializer.as_ref();
 
-                        for value in py_set.iter() {
+                        let values = match extra.round_trip {
+                            true => round_trip_order(py_set.iter(), item_serializer, include, exclude, extra)
+                                .map_err(py_err_se_err)?,
+                            false => py_set.iter().collect(),
+                        };
+                        for value in values {
                             let item_serialize =
                                 PydanticSerializer::new(&value, item_serializer, include, exclude, extra);
                             seq.serialize_element(&item_serialize)?;
@@ -4,7 +4,8 @@ use num_bigint::BigInt;
 
 use pyo3::exceptions::PyKeyError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyString, PyType};
 use pyo3::{intern, FromPyObject};
 
 use crate::input::Int;
@@ -87,6 +88,22 @@ macro_rules! py_err {
 }
 pub(crate) use py_err;
 
+/// Look up a type from the `collections` module, caching it in `cell`
+pub(crate) fn get_collections_type<'py>(
+    py: Python<'py>,
+    cell: &'py GILOnceCell<Py<PyType>>,
+    name: &str,
+) -> &'py Bound<'py, PyType> {
+    cell.get_or_init(py, || {
+        py.import_bound("collections")
+            .and_then(|collections_module| collections_module.getattr(name))
+            .unwrap()
+            .extract()
+            .unwrap()
+    })
+    .bind(py)
+}
+
 pub fn function_name(f: &Bound<'_, PyAny>) -> PyResult<String> {
     match f.getattr(intern!(f.py(), "__name__")) {
         Ok(name) => name.extract(),
@@ -155,6 +172,15 @@ pub(crate) fn new_py_string<'py>(py: Python<'py>, s: &str, cache_str: StringCach
     }
 }
 
+/// Like `new_py_string` but for dict keys, which are also cached with `cache_strings='keys'`, so validating many
+/// dicts with the same keys reuses one python string per key
+pub(crate) fn new_py_key_string<'py>(py: Python<'py>, s: &str, cache_str: StringCacheMode) -> Bound<'py, PyString> {
+    match cache_str {
+        StringCacheMode::All | StringCacheMode::Keys => new_py_string(py, s, StringCacheMode::All),
+        StringCacheMode::None => new_py_string(py, s, StringCacheMode::None),
+    }
+}
+
 // TODO: is_utf8_char_boundary, floor_char_boundary and ceil_char_boundary
 // with builtin methods once https://github.com/rust-lang/rust/issues/93743 is resolved
 // These are just copy pasted from the current implementation
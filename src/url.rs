@@ -36,7 +36,7 @@ impl PyUrl {
 fn build_schema_validator(py: Python, schema_type: &str) -> SchemaValidator {
     let schema = PyDict::new_bound(py);
     schema.set_item("type", schema_type).unwrap();
-    SchemaValidator::py_new(py, &schema, None).unwrap()
+    SchemaValidator::py_new(py, &schema, None, None).unwrap()
 }
 
 #[pymethods]
@@ -45,7 +45,20 @@ impl PyUrl {
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_URL
             .get_or_init(py, || build_schema_validator(py, "url"))
-            .validate_python(py, url, None, None, None, None, false.into())?;
+            .validate_python(
+                py,
+                url,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )?;
         schema_obj.extract(py)
     }
 
@@ -225,7 +238,20 @@ impl PyMultiHostUrl {
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
             .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
-            .validate_python(py, url, None, None, None, None, false.into())?;
+            .validate_python(
+                py,
+                url,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                false,
+                false,
+                false,
+                None,
+                false,
+            )?;
         schema_obj.extract(py)
     }
 
@@ -223,15 +223,25 @@ impl Validator for ArgumentsValidator {
                         parameter.name.clone(),
                     ));
                 }
-                (Some(pos_value), None) => match parameter.validator.validate(py, pos_value.borrow_input(), state) {
-                    Ok(value) => output_args.push(value),
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                (Some(pos_value), None) => {
+                    match parameter
+                        .validator
+                        .validate_traced_at(py, pos_value.borrow_input(), state, index)
+                    {
+                        Ok(value) => output_args.push(value),
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 (None, Some((lookup_path, kw_value))) => {
-                    match parameter.validator.validate(py, kw_value.borrow_input(), state) {
+                    match parameter.validator.validate_traced_at(
+                        py,
+                        kw_value.borrow_input(),
+                        state,
+                        lookup_path.first_key(),
+                    ) {
                         Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
@@ -276,7 +286,7 @@ impl Validator for ArgumentsValidator {
             if len > self.positional_params_count {
                 if let Some(ref validator) = self.var_args_validator {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
-                        match validator.validate(py, item.borrow_input(), state) {
+                        match validator.validate_traced_at(py, item.borrow_input(), state, index) {
                             Ok(value) => output_args.push(value),
                             Err(ValError::LineErrors(line_errors)) => {
                                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -323,18 +333,21 @@ impl Validator for ArgumentsValidator {
                     if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                         match self.var_kwargs_mode {
                             VarKwargsMode::Uniform => match &self.var_kwargs_validator {
-                                Some(validator) => match validator.validate(py, value.borrow_input(), state) {
-                                    Ok(value) => {
-                                        output_kwargs
-                                            .set_item(either_str.as_py_string(py, state.cache_str()), value)?;
-                                    }
-                                    Err(ValError::LineErrors(line_errors)) => {
-                                        for err in line_errors {
-                                            errors.push(err.with_outer_location(raw_key.clone()));
+                                Some(validator) => {
+                                    match validator.validate_traced_at(py, value.borrow_input(), state, raw_key.clone())
+                                    {
+                                        Ok(value) => {
+                                            output_kwargs
+                                                .set_item(either_str.as_py_string(py, state.cache_str()), value)?;
                                         }
+                                        Err(ValError::LineErrors(line_errors)) => {
+                                            for err in line_errors {
+                                                errors.push(err.with_outer_location(raw_key.clone()));
+                                            }
+                                        }
+                                        Err(err) => return Err(err),
                                     }
-                                    Err(err) => return Err(err),
-                                },
+                                }
                                 None => {
                                     if let ExtraBehavior::Forbid = self.extra {
                                         errors.push(ValLineError::new_with_loc(
@@ -361,7 +374,7 @@ impl Validator for ArgumentsValidator {
                 .var_kwargs_validator
                 .as_ref()
                 .unwrap()
-                .validate(py, remaining_kwargs.as_any(), state)
+                .validate_traced(py, remaining_kwargs.as_any(), state)
             {
                 Ok(value) => {
                     output_kwargs.update(value.downcast_bound::<PyDict>(py).unwrap().as_mapping())?;
@@ -0,0 +1,86 @@
+use ::base64::engine::general_purpose::{GeneralPurpose, STANDARD, URL_SAFE};
+use ::base64::engine::{DecodePaddingMode, GeneralPurposeConfig};
+use ::base64::{alphabet, Engine};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+const STANDARD_OPTIONAL_PADDING: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+const URL_SAFE_OPTIONAL_PADDING: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// The base64 engine for a `base64` schema, shared by the validator and serializer,
+/// output is always padded, `allow_missing_padding` only affects decoding
+pub(crate) fn base64_engine(schema: &Bound<'_, PyDict>) -> PyResult<&'static GeneralPurpose> {
+    let py = schema.py();
+    let url_safe = schema.get_as(intern!(py, "url_safe"))?.unwrap_or(false);
+    let allow_missing_padding = schema.get_as(intern!(py, "allow_missing_padding"))?.unwrap_or(false);
+    Ok(match (url_safe, allow_missing_padding) {
+        (false, false) => &STANDARD,
+        (false, true) => &STANDARD_OPTIONAL_PADDING,
+        (true, false) => &URL_SAFE,
+        (true, true) => &URL_SAFE_OPTIONAL_PADDING,
+    })
+}
+
+/// Validates a base64 encoded string, the decoded `bytes` are the output
+#[derive(Debug)]
+pub struct Base64Validator {
+    strict: bool,
+    engine: &'static GeneralPurpose,
+}
+
+impl BuildValidator for Base64Validator {
+    const EXPECTED_TYPE: &'static str = "base64";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            engine: base64_engine(schema)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(Base64Validator {});
+
+impl Validator for Base64Validator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let either_str = input.validate_str(state.strict_or(self.strict), false)?.unpack(state);
+        match self.engine.decode(either_str.as_cow()?.as_bytes()) {
+            Ok(decoded) => Ok(PyBytes::new_bound(py, &decoded).into_py(py)),
+            Err(err) => Err(ValError::new(
+                ErrorType::Base64Decode {
+                    error: err.to_string(),
+                    context: None,
+                },
+                input,
+            )),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
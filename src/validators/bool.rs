@@ -1,15 +1,19 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::is_strict;
-use crate::errors::ValResult;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
+use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    allow_bool_from_int: bool,
+    numeric_strict: bool,
 }
 
 impl BuildValidator for BoolValidator {
@@ -22,6 +26,10 @@ impl BuildValidator for BoolValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            allow_bool_from_int: schema
+                .get_as(intern!(schema.py(), "allow_bool_from_int"))?
+                .unwrap_or(true),
+            numeric_strict: schema.get_as(intern!(schema.py(), "numeric_strict"))?.unwrap_or(false),
         }
         .into())
     }
@@ -36,10 +44,21 @@ impl Validator for BoolValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        // without `allow_bool_from_int`, ints are rejected even in lax mode
+        if !self.allow_bool_from_int && input.is_int() {
+            return Err(ValError::new(ErrorTypeDefaults::BoolType, input));
+        }
+        let strict = state.strict_or(self.strict);
+        if self.numeric_strict && !strict {
+            if let Some(bool) = numeric_as_bool(py, input)? {
+                state.floor_exactness(Exactness::Lax);
+                return Ok(bool.into_py(py));
+            }
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
         input
-            .validate_bool(state.strict_or(self.strict))
+            .validate_bool(strict)
             .map(|val_match| val_match.unpack(state).into_py(py))
     }
 
@@ -47,3 +66,27 @@ impl Validator for BoolValidator {
         Self::EXPECTED_TYPE
     }
 }
+
+/// For `numeric_strict`, ints and numeric strings are only booleans if they're exactly `0` or `1`, floats never are,
+/// any other number is a `bool_parsing` error with the number in the context.
+/// `None` means the input isn't a number and is left to the usual boolean parsing.
+fn numeric_as_bool<'py>(py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> ValResult<Option<bool>> {
+    if let Ok(either_str) = input.validate_str(true, false) {
+        let str = either_str.into_inner();
+        match str.as_cow()?.as_ref() {
+            "0" => return Ok(Some(false)),
+            "1" => return Ok(Some(true)),
+            s if s.parse::<f64>().is_err() => return Ok(None),
+            _ => (),
+        }
+    } else if let Ok(int) = input.validate_int(true) {
+        if let Some(bool) = int.into_inner().as_bool() {
+            return Ok(Some(bool));
+        }
+    } else if input.validate_float(true).is_err() {
+        return Ok(None);
+    }
+    let mut error_type = ErrorType::BoolParsing { context: None };
+    error_type.set_context_item(py, "value", input.to_object(py).bind(py))?;
+    Err(ValError::new(error_type, input))
+}
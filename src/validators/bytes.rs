@@ -1,10 +1,11 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
+use crate::serializers::BytesMode;
 
 use crate::tools::SchemaDict;
 
@@ -59,12 +60,37 @@ impl Validator for BytesValidator {
     }
 }
 
+/// What `min_length` and `max_length` are compared against when strings are decoded with `val_json_bytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LengthTarget {
+    /// the number of decoded bytes
+    Decoded,
+    /// the number of characters in the source string
+    Source,
+}
+
+impl LengthTarget {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema
+            .get_as::<Bound<'_, PyString>>(intern!(schema.py(), "length_target"))?
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+        {
+            None | Some("decoded") => Ok(Self::Decoded),
+            Some("source") => Ok(Self::Source),
+            Some(other) => py_schema_err!("Invalid length_target: '{}'", other),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
     bytes_mode: ValBytesMode,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    length_target: LengthTarget,
 }
 
 impl_py_gc_traverse!(BytesConstrainedValidator {});
@@ -79,14 +105,37 @@ impl Validator for BytesConstrainedValidator {
         let either_bytes = input
             .validate_bytes(state.strict_or(self.strict), self.bytes_mode)?
             .unpack(state);
-        let len = either_bytes.len()?;
+        // without an encoding the source string and the bytes are the same, so only the decoded length is used
+        let source_len = match (self.length_target, self.bytes_mode.ser) {
+            (LengthTarget::Source, BytesMode::Base64 | BytesMode::Hex) => input
+                .exact_str()
+                .ok()
+                .map(|s| s.as_cow().map(|s| s.chars().count()))
+                .transpose()?,
+            _ => None,
+        };
+        let len = match source_len {
+            Some(len) => len,
+            None => either_bytes.len()?,
+        };
+        // the unit is only included if it differs from the default of bytes
+        let context = || -> PyResult<Option<Py<PyDict>>> {
+            match source_len {
+                Some(_) => {
+                    let context = PyDict::new_bound(py);
+                    context.set_item(intern!(py, "unit"), intern!(py, "characters"))?;
+                    Ok(Some(context.unbind()))
+                }
+                None => Ok(None),
+            }
+        };
 
         if let Some(min_length) = self.min_length {
             if len < min_length {
                 return Err(ValError::new(
                     ErrorType::BytesTooShort {
                         min_length,
-                        context: None,
+                        context: context()?,
                     },
                     input,
                 ));
@@ -97,7 +146,7 @@ impl Validator for BytesConstrainedValidator {
                 return Err(ValError::new(
                     ErrorType::BytesTooLong {
                         max_length,
-                        context: None,
+                        context: context()?,
                     },
                     input,
                 ));
@@ -119,6 +168,7 @@ impl BytesConstrainedValidator {
             bytes_mode: ValBytesMode::from_config(config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            length_target: LengthTarget::from_schema(schema)?,
         }
         .into())
     }
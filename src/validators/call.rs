@@ -81,7 +81,10 @@ impl Validator for CallValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let args = self.arguments_validator.validate(py, input, state)?.into_bound(py);
+        let args = self
+            .arguments_validator
+            .validate_traced(py, input, state)?
+            .into_bound(py);
 
         let return_value = if let Ok((args, kwargs)) = args.extract::<(Bound<PyTuple>, Bound<PyDict>)>() {
             self.function.call_bound(py, args, Some(&kwargs))?
@@ -94,7 +97,7 @@ impl Validator for CallValidator {
 
         if let Some(return_validator) = &self.return_validator {
             return_validator
-                .validate(py, return_value.bind(py), state)
+                .validate_traced_at(py, return_value.bind(py), state, "return")
                 .map_err(|e| e.with_outer_location("return"))
         } else {
             Ok(return_value.to_object(py))
@@ -78,9 +78,9 @@ impl Validator for ChainValidator {
     ) -> ValResult<PyObject> {
         let mut steps_iter = self.steps.iter();
         let first_step = steps_iter.next().unwrap();
-        let value = first_step.validate(py, input, state)?;
+        let value = first_step.validate_traced(py, input, state)?;
 
-        steps_iter.try_fold(value, |v, step| step.validate(py, v.bind(py), state))
+        steps_iter.try_fold(value, |v, step| step.validate_traced(py, v.bind(py), state))
     }
 
     fn get_name(&self) -> &str {
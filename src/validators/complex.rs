@@ -1,11 +1,13 @@
 use pyo3::exceptions::PyValueError;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyComplex, PyDict, PyString, PyType};
 
 use crate::build_tools::is_strict;
-use crate::errors::{ErrorTypeDefaults, ToErrorValue, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, Number, ToErrorValue, ValError, ValResult};
 use crate::input::Input;
+use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -20,6 +22,7 @@ pub fn get_complex_type(py: Python) -> &Bound<'_, PyType> {
 #[derive(Debug)]
 pub struct ComplexValidator {
     strict: bool,
+    max_abs: Option<f64>,
 }
 
 impl BuildValidator for ComplexValidator {
@@ -31,6 +34,7 @@ impl BuildValidator for ComplexValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            max_abs: schema.get_as(intern!(schema.py(), "max_abs"))?,
         }
         .into())
     }
@@ -46,6 +50,19 @@ impl Validator for ComplexValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let res = input.validate_complex(self.strict, py)?.unpack(state);
+        if let Some(max_abs) = self.max_abs {
+            let [real, imag] = res.as_f64(py);
+            let abs = real.hypot(imag);
+            if abs.is_nan() || abs > max_abs {
+                return Err(ValError::new(
+                    ErrorType::ComplexMaxAbs {
+                        max_abs: Number::Float(max_abs),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
         Ok(res.into_py(py))
     }
 
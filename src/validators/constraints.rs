@@ -0,0 +1,178 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict, PyFloat, PyInt, PyList, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, Number, ValError, ValResult};
+use crate::input::Input;
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+use super::float::float_is_multiple_of;
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Wraps a (generally numeric) validator and applies a list of constraints to its output, in order,
+/// stopping at the first constraint which fails.
+#[derive(Debug)]
+pub struct ConstraintsValidator {
+    validator: Box<CombinedValidator>,
+    constraints: Vec<Constraint>,
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+enum Constraint {
+    Gt(Py<PyAny>),
+    Ge(Py<PyAny>),
+    Lt(Py<PyAny>),
+    Le(Py<PyAny>),
+    MultipleOf(Py<PyAny>),
+}
+
+impl PyGcTraverse for Constraint {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        match self {
+            Self::Gt(v) | Self::Ge(v) | Self::Lt(v) | Self::Le(v) | Self::MultipleOf(v) => v.py_gc_traverse(visit),
+        }
+    }
+}
+
+impl Constraint {
+    fn build(constraint: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = constraint.py();
+        let kind: Bound<'_, PyString> = constraint.get_as_req(intern!(py, "kind"))?;
+        let value: Py<PyAny> = constraint.get_as_req(intern!(py, "value"))?;
+        match kind.to_str()? {
+            "gt" => Ok(Self::Gt(value)),
+            "ge" => Ok(Self::Ge(value)),
+            "lt" => Ok(Self::Lt(value)),
+            "le" => Ok(Self::Le(value)),
+            "multiple_of" => Ok(Self::MultipleOf(value)),
+            other => py_schema_err!("Unknown constraint kind: '{}'", other),
+        }
+    }
+
+    fn check<'py>(&self, value: &Bound<'py, PyAny>, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        let py = value.py();
+        match self {
+            Self::Gt(gt) => {
+                if !value.gt(gt)? {
+                    return Err(ValError::new(
+                        ErrorType::GreaterThan {
+                            gt: constraint_number(gt.bind(py)),
+                            context: Some([("gt", gt)].into_py_dict_bound(py).into()),
+                        },
+                        input,
+                    ));
+                }
+            }
+            Self::Ge(ge) => {
+                if !value.ge(ge)? {
+                    return Err(ValError::new(
+                        ErrorType::GreaterThanEqual {
+                            ge: constraint_number(ge.bind(py)),
+                            context: Some([("ge", ge)].into_py_dict_bound(py).into()),
+                        },
+                        input,
+                    ));
+                }
+            }
+            Self::Lt(lt) => {
+                if !value.lt(lt)? {
+                    return Err(ValError::new(
+                        ErrorType::LessThan {
+                            lt: constraint_number(lt.bind(py)),
+                            context: Some([("lt", lt)].into_py_dict_bound(py).into()),
+                        },
+                        input,
+                    ));
+                }
+            }
+            Self::Le(le) => {
+                if !value.le(le)? {
+                    return Err(ValError::new(
+                        ErrorType::LessThanEqual {
+                            le: constraint_number(le.bind(py)),
+                            context: Some([("le", le)].into_py_dict_bound(py).into()),
+                        },
+                        input,
+                    ));
+                }
+            }
+            Self::MultipleOf(multiple_of) => {
+                let is_multiple = match (value.downcast::<PyFloat>(), multiple_of.extract::<f64>(py)) {
+                    (Ok(float), Ok(multiple_of)) => float_is_multiple_of(float.value(), multiple_of),
+                    _ => value.rem(multiple_of)?.eq(0)?,
+                };
+                if !is_multiple {
+                    return Err(ValError::new(
+                        ErrorType::MultipleOf {
+                            multiple_of: constraint_number(multiple_of.bind(py)),
+                            context: Some([("multiple_of", multiple_of)].into_py_dict_bound(py).into()),
+                        },
+                        input,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Ints and floats are kept as is for the error message, anything else (e.g. `Decimal`) uses its `str()`
+fn constraint_number(value: &Bound<'_, PyAny>) -> Number {
+    if value.is_instance_of::<PyInt>() || value.is_instance_of::<PyFloat>() {
+        if let Ok(number) = value.extract() {
+            return number;
+        }
+    }
+    Number::String(value.to_string())
+}
+
+impl BuildValidator for ConstraintsValidator {
+    const EXPECTED_TYPE: &'static str = "constraints";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
+        let constraints = schema
+            .get_as_req::<Bound<'_, PyList>>(intern!(py, "constraints"))?
+            .iter()
+            .map(|constraint| Constraint::build(constraint.downcast()?))
+            .collect::<PyResult<Vec<_>>>()?;
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            validator,
+            constraints,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(ConstraintsValidator { validator, constraints });
+
+impl Validator for ConstraintsValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let output = self.validator.validate_traced(py, input, state)?;
+        let value = output.bind(py);
+        for constraint in &self.constraints {
+            constraint.check(value, input)?;
+        }
+        Ok(output)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
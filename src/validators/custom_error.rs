@@ -44,6 +44,7 @@ impl CustomError {
                 error_type,
                 schema.get_as_req::<String>(intern!(py, "custom_error_message"))?,
                 context,
+                None,
             );
             Ok(Some(Self::Custom(error)))
         }
@@ -95,7 +96,7 @@ impl Validator for CustomErrorValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         self.validator
-            .validate(py, input, state)
+            .validate_traced(py, input, state)
             .map_err(|_| self.custom_error.as_val_error(input))
     }
 
@@ -29,6 +29,7 @@ struct Field {
     lookup_key: LookupKey,
     validator: CombinedValidator,
     frozen: bool,
+    examples: Option<Py<PyList>>,
 }
 
 #[derive(Debug)]
@@ -109,6 +110,7 @@ impl BuildValidator for DataclassArgsValidator {
                 init: field.get_as(intern!(py, "init"))?.unwrap_or(true),
                 init_only: field.get_as(intern!(py, "init_only"))?.unwrap_or(false),
                 frozen: field.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                examples: field.get_as(intern!(py, "examples"))?,
             });
         }
 
@@ -134,7 +136,7 @@ impl BuildValidator for DataclassArgsValidator {
     }
 }
 
-impl_py_gc_traverse!(Field { validator });
+impl_py_gc_traverse!(Field { validator, examples });
 
 impl_py_gc_traverse!(DataclassArgsValidator { fields });
 
@@ -218,7 +220,11 @@ impl Validator for DataclassArgsValidator {
                     ));
                 }
                 // found a positional argument, validate it
-                (Some(pos_value), None) => match field.validator.validate(py, pos_value.borrow_input(), state) {
+                (Some(pos_value), None) => match field
+                    .validator
+                    .validate_traced_at(py, pos_value.borrow_input(), state, index)
+                    .map_err(|err| err.with_examples(py, field.examples.as_ref()))
+                {
                     Ok(value) => {
                         set_item!(field, value);
                         fields_set_count += 1;
@@ -229,7 +235,11 @@ impl Validator for DataclassArgsValidator {
                     Err(err) => return Err(err),
                 },
                 // found a keyword argument, validate it
-                (None, Some((lookup_path, kw_value))) => match field.validator.validate(py, kw_value, state) {
+                (None, Some((lookup_path, kw_value))) => match field
+                    .validator
+                    .validate_traced_at(py, kw_value, state, lookup_path.first_key())
+                    .map_err(|err| err.with_examples(py, field.examples.as_ref()))
+                {
                     Ok(value) => {
                         set_item!(field, value);
                         fields_set_count += 1;
@@ -311,7 +321,12 @@ impl Validator for DataclassArgsValidator {
                                     ExtraBehavior::Ignore => {}
                                     ExtraBehavior::Allow => {
                                         if let Some(ref validator) = self.extras_validator {
-                                            match validator.validate(py, value.borrow_input(), state) {
+                                            match validator.validate_traced_at(
+                                                py,
+                                                value.borrow_input(),
+                                                state,
+                                                raw_key.clone(),
+                                            ) {
                                                 Ok(value) => {
                                                     output_dict.set_item(
                                                         either_str.as_py_string(py, state.cache_str()),
@@ -396,11 +411,16 @@ impl Validator for DataclassArgsValidator {
                 }
             }
 
-            match field.validator.validate(
-                py,
-                field_value,
-                &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone())),
-            ) {
+            match field
+                .validator
+                .validate_traced_at(
+                    py,
+                    field_value,
+                    &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone())),
+                    field_name,
+                )
+                .map_err(|err| err.with_examples(py, field.examples.as_ref()))
+            {
                 Ok(output) => ok(output),
                 Err(ValError::LineErrors(line_errors)) => {
                     let errors = line_errors
@@ -481,6 +501,16 @@ impl BuildValidator for DataclassValidator {
 
         let fields = schema.get_as_req(intern!(py, "fields"))?;
 
+        let frozen = match schema.get_as(intern!(py, "frozen"))? {
+            Some(frozen) => frozen,
+            // otherwise follow `@dataclass(frozen=True)` on the class itself
+            None => class
+                .getattr(intern!(py, "__dataclass_params__"))
+                .and_then(|params| params.getattr(intern!(py, "frozen")))
+                .and_then(|frozen| frozen.extract())
+                .unwrap_or(false),
+        };
+
         Ok(Self {
             strict: is_strict(schema, config)?,
             validator: Box::new(validator),
@@ -495,7 +525,7 @@ impl BuildValidator for DataclassValidator {
                     .transpose()?,
             )?,
             name,
-            frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
+            frozen,
             slots: schema.get_as(intern!(py, "slots"))?.unwrap_or(false),
         }
         .into())
@@ -545,7 +575,7 @@ impl Validator for DataclassValidator {
         if let Some(py_input) = py_instance_input {
             if self.revalidate.should_revalidate(py_input, class) || force_revalidate {
                 let input_dict = self.dataclass_to_dict(py_input)?;
-                let val_output = self.validator.validate(py, input_dict.as_any(), state)?;
+                let val_output = self.validator.validate_traced(py, input_dict.as_any(), state)?;
                 let dc = create_class(self.class.bind(py))?;
                 self.set_dict_call(py, &dc, val_output, input)?;
                 Ok(dc.into())
@@ -561,7 +591,7 @@ impl Validator for DataclassValidator {
                 input,
             ))
         } else {
-            let val_output = self.validator.validate(py, input, state)?;
+            let val_output = self.validator.validate_traced(py, input, state)?;
             state.floor_exactness(Exactness::Strict);
             let dc = create_class(self.class.bind(py))?;
             self.set_dict_call(py, &dc, val_output, input)?;
@@ -620,7 +650,7 @@ impl DataclassValidator {
         // we need to set `self_instance` to None for nested validators as we don't want to operate on the self_instance
         // instance anymore
         let state = &mut state.rebind_extra(|extra| extra.self_instance = None);
-        let val_output = self.validator.validate(py, input, state)?;
+        let val_output = self.validator.validate_traced(py, input, state)?;
 
         self.set_dict_call(py, self_instance, val_output, input)?;
 
@@ -645,6 +675,7 @@ impl DataclassValidator {
         input: &(impl Input<'py> + ?Sized),
     ) -> ValResult<()> {
         let (dc_dict, post_init_kwargs): (Bound<'_, PyAny>, Bound<'_, PyAny>) = val_output.extract(py)?;
+        // `force_setattr` bypasses `__setattr__`, so this also works for frozen dataclasses
         if self.slots {
             let dc_dict = dc_dict.downcast::<PyDict>()?;
             for (key, value) in dc_dict.iter() {
@@ -1,7 +1,7 @@
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{IntoPyDict, PyDict, PyTuple, PyType};
+use pyo3::types::{IntoPyDict, PyDict, PyString, PyTuple, PyType};
 use pyo3::{prelude::*, PyTypeInfo};
 
 use crate::build_tools::{is_strict, schema_or_config_same};
@@ -9,9 +9,11 @@ use crate::errors::ErrorType;
 use crate::errors::ValResult;
 use crate::errors::{ErrorTypeDefaults, Number};
 use crate::errors::{ToErrorValue, ValError};
-use crate::input::Input;
+use crate::input::{Input, ValidationMatch};
 use crate::tools::SchemaDict;
 
+use super::float::LocaleFormat;
+use super::fraction::get_fraction_type;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 static DECIMAL_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
@@ -32,6 +34,7 @@ pub fn get_decimal_type(py: Python) -> &Bound<'_, PyType> {
 pub struct DecimalValidator {
     strict: bool,
     allow_inf_nan: bool,
+    locale_format: Option<LocaleFormat>,
     check_digits: bool,
     multiple_of: Option<Py<PyAny>>,
     le: Option<Py<PyAny>>,
@@ -61,6 +64,7 @@ impl BuildValidator for DecimalValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan,
+            locale_format: LocaleFormat::from_schema(schema)?,
             check_digits: decimal_places.is_some() || max_digits.is_some(),
             decimal_places,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
@@ -82,6 +86,28 @@ impl_py_gc_traverse!(DecimalValidator {
     gt
 });
 
+/// `decimal % multiple_of`, which is exact whatever the scale of either; `Decimal` can't compute it when the
+/// quotient has more digits than the context precision, so finite values fall back to `Fraction`s then
+fn decimal_remainder<'py>(decimal: &Bound<'py, PyAny>, multiple_of: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = decimal.py();
+    match decimal.rem(multiple_of) {
+        Ok(remainder) => Ok(remainder),
+        Err(err) => {
+            if !decimal.call_method0(intern!(py, "is_finite"))?.extract::<bool>()? {
+                return Err(err);
+            }
+            let fraction_type = get_fraction_type(py);
+            let remainder = fraction_type
+                .call1((decimal,))?
+                .rem(fraction_type.call1((multiple_of,))?)?;
+            let decimal_type = get_decimal_type(py);
+            decimal_type
+                .call1((remainder.getattr(intern!(py, "numerator"))?,))?
+                .div(decimal_type.call1((remainder.getattr(intern!(py, "denominator"))?,))?)
+        }
+    }
+}
+
 fn extract_decimal_digits_info(decimal: &Bound<'_, PyAny>, normalized: bool) -> ValResult<(u64, u64)> {
     let py = decimal.py();
     let mut normalized_decimal: Option<Bound<'_, PyAny>> = None;
@@ -122,7 +148,30 @@ impl Validator for DecimalValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let decimal = input.validate_decimal(state.strict_or(self.strict), py)?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let localized = match self.locale_format {
+            Some(locale_format) => locale_format.parse(
+                py,
+                input,
+                strict,
+                |context| ErrorType::DecimalParsing { context },
+                |str| create_decimal(PyString::new_bound(py, str).as_any(), input),
+            )?,
+            None => None,
+        };
+        let raw_float = state
+            .extra()
+            .json_raw_floats
+            .and_then(|raw_floats| input.json_raw_float(raw_floats));
+        let decimal = match (localized, raw_float) {
+            (Some(decimal), _) => ValidationMatch::lax(decimal),
+            // JSON floats are exact decimals with `json_float_mode='decimal'`, so they're created from the literal
+            (None, Some(raw_float)) => {
+                ValidationMatch::strict(create_decimal(PyString::new_bound(py, raw_float).as_any(), input)?)
+            }
+            (None, None) => input.validate_decimal(strict, py)?,
+        }
+        .unpack(state);
 
         if !self.allow_inf_nan || self.check_digits {
             if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
@@ -181,18 +230,16 @@ impl Validator for DecimalValidator {
         }
 
         if let Some(multiple_of) = &self.multiple_of {
-            // fraction = (decimal / multiple_of) % 1
-            let fraction = unsafe {
-                let division = decimal.div(multiple_of)?;
-                let one = 1.to_object(py);
-                Bound::from_owned_ptr_or_err(py, pyo3::ffi::PyNumber_Remainder(division.as_ptr(), one.as_ptr()))?
-            };
+            let remainder = decimal_remainder(&decimal, multiple_of.bind(py))?;
             let zero = 0.to_object(py);
-            if !fraction.eq(&zero)? {
+            if !remainder.eq(&zero)? {
+                let context = PyDict::new_bound(py);
+                context.set_item("multiple_of", multiple_of)?;
+                context.set_item("remainder", remainder)?;
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.to_string().into(),
-                        context: Some([("multiple_of", multiple_of)].into_py_dict_bound(py).into()),
+                        context: Some(context.into()),
                     },
                     input,
                 ));
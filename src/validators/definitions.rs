@@ -1,13 +1,13 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyString;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{IntoPyDict, PyDict, PyList};
 
 use crate::definitions::DefinitionRef;
-use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ToErrorValue, ValError, ValResult};
 use crate::input::Input;
 
-use crate::recursion_guard::RecursionGuard;
+use crate::recursion_guard::{RecursionError, RecursionGuard};
 use crate::tools::SchemaDict;
 
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -82,12 +82,15 @@ impl Validator for DefinitionRefValidator {
             let validator = validator.unwrap();
             if let Some(id) = input.as_python().map(py_identity) {
                 // Python objects can be cyclic, so need recursion guard
-                let Ok(mut guard) = RecursionGuard::new(state, id, self.definition.id()) else {
-                    return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
+                let mut guard = match RecursionGuard::new(state, id, self.definition.id()) {
+                    Ok(guard) => guard,
+                    Err(err) => return Err(recursion_error(py, err, id, input)),
                 };
-                validator.validate(py, input, guard.state())
+                validator
+                    .validate_traced(py, input, guard.state())
+                    .map_err(|err| add_cycle_loc(py, err, id))
             } else {
-                validator.validate(py, input, state)
+                validator.validate_traced(py, input, state)
             }
         })
     }
@@ -102,10 +105,14 @@ impl Validator for DefinitionRefValidator {
     ) -> ValResult<PyObject> {
         self.definition.read(|validator| {
             let validator = validator.unwrap();
-            let Ok(mut guard) = RecursionGuard::new(state, py_identity(obj), self.definition.id()) else {
-                return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, obj));
+            let id = py_identity(obj);
+            let mut guard = match RecursionGuard::new(state, id, self.definition.id()) {
+                Ok(guard) => guard,
+                Err(err) => return Err(recursion_error(py, err, id, obj)),
             };
-            validator.validate_assignment(py, obj, field_name, field_value, guard.state())
+            validator
+                .validate_assignment(py, obj, field_name, field_value, guard.state())
+                .map_err(|err| add_cycle_loc(py, err, id))
         })
     }
 
@@ -117,3 +124,43 @@ impl Validator for DefinitionRefValidator {
 fn py_identity(obj: &Bound<'_, PyAny>) -> usize {
     obj.as_ptr() as usize
 }
+
+fn recursion_error(py: Python, err: RecursionError, obj_id: usize, input: impl ToErrorValue) -> ValError {
+    match err {
+        RecursionError::Cyclic => {
+            // `cycle_loc` is added by `add_cycle_loc` as the error passes back through the frame which
+            // first entered this object
+            let context = [("object_id", obj_id)].into_py_dict_bound(py);
+            ValError::new(
+                ErrorType::RecursionLoop {
+                    context: Some(context.unbind()),
+                },
+                input,
+            )
+        }
+        RecursionError::Depth => ValError::new(ErrorTypeDefaults::RecursionLoop, input),
+    }
+}
+
+/// Record where a cycle back to `obj_id` was found, relative to the object itself, on any recursion errors
+/// for that object. This only runs on the error path so costs nothing when the input is not cyclic.
+fn add_cycle_loc(py: Python, err: ValError, obj_id: usize) -> ValError {
+    if let ValError::LineErrors(line_errors) = &err {
+        for line_error in line_errors {
+            let ErrorType::RecursionLoop { context: Some(context) } = &line_error.error_type else {
+                continue;
+            };
+            let context = context.bind(py);
+            let is_this_object = match context.get_item(intern!(py, "object_id")) {
+                Ok(Some(id)) => id.extract::<usize>().is_ok_and(|id| id == obj_id),
+                _ => false,
+            };
+            if is_this_object && !context.contains(intern!(py, "cycle_loc")).unwrap_or(true) {
+                if let Err(e) = context.set_item(intern!(py, "cycle_loc"), line_error.location.to_object(py)) {
+                    return ValError::InternalErr(e);
+                }
+            }
+        }
+    }
+    err
+}
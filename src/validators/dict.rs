@@ -1,14 +1,15 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyString, PyType};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
-use crate::input::{Input, ValidatedDict};
+use crate::input::{Input, InputType, ValidatedDict};
 
-use crate::tools::SchemaDict;
+use crate::tools::{get_collections_type, SchemaDict};
 
 use super::any::AnyValidator;
 use super::list::length_check;
@@ -21,9 +22,72 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    required_keys: Vec<String>,
+    output_type: DictOutputType,
+    on_key_collision: KeyCollision,
     name: String,
 }
 
+static ORDERED_DICT_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static COUNTER_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// The mapping created from the validated items, see `output_type` on dict schemas
+#[derive(Debug, Clone, Copy)]
+enum DictOutputType {
+    Dict,
+    OrderedDict,
+    Counter,
+}
+
+impl DictOutputType {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema
+            .get_as::<Bound<'_, PyString>>(intern!(schema.py(), "output_type"))?
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+        {
+            None | Some("dict") => Ok(Self::Dict),
+            Some("ordered_dict") => Ok(Self::OrderedDict),
+            Some("counter") => Ok(Self::Counter),
+            Some(s) => py_schema_err!("Invalid dict output_type: '{}'", s),
+        }
+    }
+
+    fn create(self, py: Python, dict: PyObject) -> ValResult<PyObject> {
+        let cls = match self {
+            Self::Dict => return Ok(dict),
+            Self::OrderedDict => get_collections_type(py, &ORDERED_DICT_TYPE, "OrderedDict"),
+            Self::Counter => get_collections_type(py, &COUNTER_TYPE, "Counter"),
+        };
+        Ok(cls.call1((dict,))?.unbind())
+    }
+}
+
+/// What to do when distinct input keys are the same after validation, see `on_key_collision` on dict schemas
+#[derive(Debug, Clone, Copy)]
+enum KeyCollision {
+    Error,
+    LastWins,
+    FirstWins,
+}
+
+impl KeyCollision {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        match schema
+            .get_as::<Bound<'_, PyString>>(intern!(schema.py(), "on_key_collision"))?
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+        {
+            None | Some("error") => Ok(Self::Error),
+            Some("last_wins") => Ok(Self::LastWins),
+            Some("first_wins") => Ok(Self::FirstWins),
+            Some(s) => py_schema_err!("Invalid dict on_key_collision: '{}'", s),
+        }
+    }
+}
+
 impl BuildValidator for DictValidator {
     const EXPECTED_TYPE: &'static str = "dict";
 
@@ -53,6 +117,9 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            required_keys: schema.get_as(intern!(py, "required_keys"))?.unwrap_or_default(),
+            output_type: DictOutputType::from_schema(schema)?,
+            on_key_collision: KeyCollision::from_schema(schema)?,
             name,
         }
         .into())
@@ -73,15 +140,29 @@ impl Validator for DictValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
-        dict.iterate(ValidateToDict {
+        // when validated keys are always the same as the input keys, distinct keys can't collide, so keys seen
+        // twice are duplicates in the input, e.g. in JSON, which are handled like `last_wins`
+        let keys_unchanged = match *self.key_validator {
+            CombinedValidator::Any(_) => true,
+            CombinedValidator::Str(_) => state.extra().input_type == InputType::Json,
+            _ => false,
+        };
+        let on_key_collision = match keys_unchanged {
+            true => KeyCollision::LastWins,
+            false => self.on_key_collision,
+        };
+        let output = dict.iterate(ValidateToDict {
             py,
             input,
             min_length: self.min_length,
             max_length: self.max_length,
+            required_keys: &self.required_keys,
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
+            on_key_collision,
             state,
-        })?
+        })??;
+        self.output_type.create(py, output)
     }
 
     fn get_name(&self) -> &str {
@@ -94,8 +175,10 @@ struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     input: &'a I,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    required_keys: &'a [String],
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
+    on_key_collision: KeyCollision,
     state: &'a mut ValidationState<'s, 'py>,
 }
 
@@ -110,39 +193,108 @@ where
         let output = PyDict::new_bound(self.py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let allow_partial = self.state.allow_partial;
+        let mut required_found = vec![false; self.required_keys.len()];
+        // input keys which aren't equal to their validated key, used to tell collisions apart from duplicate keys
+        // in the input, e.g. in JSON
+        let original_keys = PyDict::new_bound(self.py);
 
         for (_, is_last_partial, item_result) in self.state.enumerate_last_partial(iterator) {
             self.state.allow_partial = false.into();
             let (key, value) = item_result?;
-            let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
-                Ok(value) => Some(value),
-                Err(ValError::LineErrors(line_errors)) => {
-                    for err in line_errors {
-                        // these are added in reverse order so [key] is shunted along by the second call
-                        errors.push(err.with_outer_location("[key]").with_outer_location(key.clone()));
+            let output_key =
+                match self
+                    .key_validator
+                    .validate_traced_at(self.py, key.borrow_input(), self.state, key.clone())
+                {
+                    Ok(value) => Some(value),
+                    Err(ValError::LineErrors(line_errors)) => {
+                        for err in line_errors {
+                            // these are added in reverse order so [key] is shunted along by the second call
+                            errors.push(err.with_outer_location("[key]").with_outer_location(key.clone()));
+                        }
+                        None
+                    }
+                    Err(ValError::Omit) => continue,
+                    Err(err) => return Err(err),
+                };
+            if !self.required_keys.is_empty() {
+                // a key which fails validation is still present, so fall back to the input key
+                let found_key = match &output_key {
+                    Some(output_key) => output_key.clone_ref(self.py),
+                    None => key.borrow_input().to_object(self.py),
+                };
+                if let Ok(key_str) = found_key.extract::<&str>(self.py) {
+                    if let Some(index) = self.required_keys.iter().position(|r| r == key_str) {
+                        required_found[index] = true;
                     }
-                    None
                 }
-                Err(ValError::Omit) => continue,
-                Err(err) => return Err(err),
-            };
+            }
             self.state.allow_partial = match is_last_partial {
                 true => allow_partial,
                 false => false.into(),
             };
-            let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
-                Ok(value) => value,
-                Err(ValError::LineErrors(line_errors)) => {
-                    if !is_last_partial {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(key.clone())));
+            let output_value =
+                match self
+                    .value_validator
+                    .validate_traced_at(self.py, value.borrow_input(), self.state, key.clone())
+                {
+                    Ok(value) => value,
+                    Err(ValError::LineErrors(line_errors)) => {
+                        if !is_last_partial {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(key.clone())));
+                        }
+                        continue;
                     }
-                    continue;
+                    Err(ValError::Omit) => continue,
+                    Err(err) => return Err(err),
+                };
+            let Some(output_key) = output_key else { continue };
+            if matches!(self.on_key_collision, KeyCollision::LastWins) {
+                output.set_item(output_key, output_value)?;
+                continue;
+            }
+            let output_key = output_key.into_bound(self.py);
+            let input_key = key.borrow_input().to_object(self.py).into_bound(self.py);
+            let is_new_key = match self.on_key_collision {
+                // the earlier value is kept, so check before setting
+                KeyCollision::FirstWins => !output.contains(&output_key)?,
+                // set straight away and compare lengths, to keep to a single lookup for keys which don't collide
+                _ => {
+                    let len = output.len();
+                    output.set_item(&output_key, &output_value)?;
+                    output.len() > len
                 }
-                Err(ValError::Omit) => continue,
-                Err(err) => return Err(err),
             };
-            if let Some(key) = output_key {
-                output.set_item(key, output_value)?;
+            if is_new_key {
+                if !input_key.is(&output_key) && !input_key.eq(&output_key)? {
+                    original_keys.set_item(&output_key, input_key)?;
+                }
+                if matches!(self.on_key_collision, KeyCollision::FirstWins) {
+                    output.set_item(output_key, output_value)?;
+                }
+            } else {
+                let original_key = original_keys
+                    .get_item(&output_key)?
+                    .unwrap_or_else(|| output_key.clone());
+                if original_key.eq(&input_key)? {
+                    output.set_item(output_key, output_value)?;
+                } else if matches!(self.on_key_collision, KeyCollision::Error) {
+                    let keys = format!("{}, {}", original_key.repr()?, input_key.repr()?);
+                    errors.push(
+                        ValLineError::new_with_loc(
+                            ErrorType::DictKeyCollision { keys, context: None },
+                            key.borrow_input(),
+                            "[key]",
+                        )
+                        .with_outer_location(key.clone()),
+                    );
+                }
+            }
+        }
+
+        for (key, found) in self.required_keys.iter().zip(required_found) {
+            if !found {
+                errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, self.input, key));
             }
         }
 
@@ -2,11 +2,11 @@ use std::cmp::Ordering;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{IntoPyDict, PyDict, PyString};
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{add_parsing_reason, str_as_float, EitherFloat, Input, ValidationMatch};
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -31,17 +31,28 @@ impl BuildValidator for FloatBuilder {
         } else {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
-                allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                allow_inf_nan: allow_inf_nan(schema, config)?,
+                locale_format: LocaleFormat::from_schema(schema)?,
             }
             .into())
         }
     }
 }
 
+/// `finite=True` forbids inf and nan whatever `allow_inf_nan` is set to
+fn allow_inf_nan(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    let py = schema.py();
+    if schema.get_as(intern!(py, "finite"))?.unwrap_or(false) {
+        return Ok(false);
+    }
+    Ok(schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true))
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    locale_format: Option<LocaleFormat>,
 }
 
 impl BuildValidator for FloatValidator {
@@ -52,10 +63,10 @@ impl BuildValidator for FloatValidator {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
-            allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_inf_nan: allow_inf_nan(schema, config)?,
+            locale_format: LocaleFormat::from_schema(schema)?,
         }
         .into())
     }
@@ -70,7 +81,7 @@ impl Validator for FloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let either_float = validate_float(py, input, state.strict_or(self.strict), self.locale_format)?.unpack(state);
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
@@ -86,6 +97,7 @@ impl Validator for FloatValidator {
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    locale_format: Option<LocaleFormat>,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -102,15 +114,13 @@ impl Validator for ConstrainedFloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let either_float = validate_float(py, input, state.strict_or(self.strict), self.locale_format)?.unpack(state);
         let float: f64 = either_float.as_f64();
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
         if let Some(multiple_of) = self.multiple_of {
-            let rem = float % multiple_of;
-            let threshold = float.abs() / 1e9;
-            if rem.abs() > threshold && (rem - multiple_of).abs() > threshold {
+            if !float_is_multiple_of(float, multiple_of) {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.into(),
@@ -182,7 +192,8 @@ impl BuildValidator for ConstrainedFloatValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
-            allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            allow_inf_nan: allow_inf_nan(schema, config)?,
+            locale_format: LocaleFormat::from_schema(schema)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -192,3 +203,125 @@ impl BuildValidator for ConstrainedFloatValidator {
         .into())
     }
 }
+
+fn validate_float<'a, 'py: 'a>(
+    py: Python<'py>,
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    locale_format: Option<LocaleFormat>,
+) -> ValResult<ValidationMatch<EitherFloat<'a>>> {
+    if let Some(locale_format) = locale_format {
+        let parsing_error = |context| ErrorType::FloatParsing { context };
+        if let Some(float) = locale_format.parse(py, input, strict, parsing_error, |str| str_as_float(input, str))? {
+            return Ok(ValidationMatch::lax(float));
+        }
+    }
+    input
+        .validate_float(strict)
+        .map_err(|err| add_parsing_reason(py, err, input))
+}
+
+/// Separators used to parse localized number strings like `"1.234,56"`
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LocaleFormat {
+    group: char,
+    decimal: char,
+}
+
+impl LocaleFormat {
+    pub(crate) fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(locale_format) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "locale_format"))? else {
+            return Ok(None);
+        };
+        let get_char = |key: &Bound<'_, PyString>| -> PyResult<char> {
+            let value: String = locale_format.get_as_req(key)?;
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if !c.is_ascii_digit() => Ok(c),
+                _ => py_schema_err!("`locale_format` {} must be a single non-digit character", key),
+            }
+        };
+        let group = get_char(intern!(py, "group"))?;
+        let decimal = get_char(intern!(py, "decimal"))?;
+        if group == decimal {
+            return py_schema_err!("`locale_format` group and decimal must be different characters");
+        }
+        Ok(Some(Self { group, decimal }))
+    }
+
+    /// In lax mode, converts a string input to the plain `1234.56` format and calls `parse` with it,
+    /// `Ok(None)` means the input isn't a string and should be validated as normal.
+    ///
+    /// Malformed strings raise `parsing_error` with the expected format in the context.
+    pub(crate) fn parse<'py, T>(
+        self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        strict: bool,
+        parsing_error: impl FnOnce(Option<Py<PyDict>>) -> ErrorType,
+        parse: impl FnOnce(&str) -> ValResult<T>,
+    ) -> ValResult<Option<T>> {
+        if strict {
+            return Ok(None);
+        }
+        let Ok(val_match) = input.validate_str(true, false) else {
+            return Ok(None);
+        };
+        let either_str = val_match.into_inner();
+        match self.translate(&either_str.as_cow()?).map(|str| parse(&str)) {
+            Some(Ok(value)) => Ok(Some(value)),
+            Some(Err(ValError::InternalErr(err))) => Err(ValError::InternalErr(err)),
+            _ => {
+                let expected_format = format!("1{}234{}56", self.group, self.decimal);
+                let context = [("expected_format", expected_format)].into_py_dict_bound(py);
+                Err(ValError::new(parsing_error(Some(context.unbind())), input))
+            }
+        }
+    }
+
+    /// Strip grouping characters and replace the decimal character with `.`, `None` if `str` is malformed,
+    /// e.g. repeated or trailing grouping characters, grouping after the decimal character or a stray `.`
+    fn translate(self, str: &str) -> Option<String> {
+        let mut plain = String::with_capacity(str.len());
+        let mut seen_decimal = false;
+        // starting as if after a grouping character rejects leading grouping characters
+        let mut after_group = true;
+        for c in str.trim().chars() {
+            if c == self.group {
+                if seen_decimal || after_group {
+                    return None;
+                }
+                after_group = true;
+                continue;
+            }
+            if c == self.decimal {
+                if seen_decimal || (after_group && !plain.is_empty()) {
+                    return None;
+                }
+                seen_decimal = true;
+                plain.push('.');
+            } else if c == '.' {
+                return None;
+            } else {
+                plain.push(c);
+            }
+            after_group = false;
+        }
+        if after_group {
+            return None;
+        }
+        Some(plain)
+    }
+}
+
+/// Whether `float` is a multiple of `multiple_of`, allowing for floating point error relative to `float`,
+/// `inf` and `nan` are let through, they're only rejected by `allow_inf_nan`
+pub(crate) fn float_is_multiple_of(float: f64, multiple_of: f64) -> bool {
+    if !float.is_finite() {
+        return true;
+    }
+    let rem = float % multiple_of;
+    let threshold = float.abs() / 1e9;
+    rem.abs() <= threshold || (rem - multiple_of).abs() <= threshold
+}
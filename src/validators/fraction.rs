@@ -0,0 +1,186 @@
+use pyo3::exceptions::{PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{IntoPyDict, PyDict, PyFloat, PyString, PyType};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ErrorTypeDefaults, Number, ToErrorValue, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+static FRACTION_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub fn get_fraction_type(py: Python) -> &Bound<'_, PyType> {
+    FRACTION_TYPE
+        .get_or_init(py, || {
+            py.import_bound("fractions")
+                .and_then(|fractions_module| fractions_module.getattr("Fraction"))
+                .unwrap()
+                .extract()
+                .unwrap()
+        })
+        .bind(py)
+}
+
+#[derive(Debug, Clone)]
+pub struct FractionValidator {
+    strict: bool,
+    allow_float: bool,
+    le: Option<Py<PyAny>>,
+    lt: Option<Py<PyAny>>,
+    ge: Option<Py<PyAny>>,
+    gt: Option<Py<PyAny>>,
+}
+
+impl BuildValidator for FractionValidator {
+    const EXPECTED_TYPE: &'static str = "fraction";
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        // bounds are converted to `Fraction` up front so they can be given as ints, strings or fractions
+        let get_bound = |key: &Bound<'_, PyString>| -> PyResult<Option<Py<PyAny>>> {
+            match schema.get_as::<Bound<'_, PyAny>>(key)? {
+                Some(bound) => Ok(Some(get_fraction_type(py).call1((bound,))?.unbind())),
+                None => Ok(None),
+            }
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            allow_float: schema.get_as(intern!(py, "allow_float"))?.unwrap_or(false),
+            le: get_bound(intern!(py, "le"))?,
+            lt: get_bound(intern!(py, "lt"))?,
+            ge: get_bound(intern!(py, "ge"))?,
+            gt: get_bound(intern!(py, "gt"))?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(FractionValidator { le, lt, ge, gt });
+
+impl FractionValidator {
+    fn validate_fraction<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        strict: bool,
+    ) -> ValResult<Bound<'py, PyAny>> {
+        if let Some(py_input) = input.as_python() {
+            if py_input.is_instance(get_fraction_type(py))? {
+                return Ok(py_input.clone());
+            } else if strict {
+                return Err(ValError::new(ErrorTypeDefaults::FractionType, input));
+            }
+        }
+
+        // JSON has no fraction type, so integers and strings are accepted even in strict mode
+        if input.is_int() {
+            let int = input.validate_int(strict)?.into_inner();
+            return create_fraction(&int.into_py(py).into_bound(py), input);
+        }
+
+        let is_float = input.is_json_float() || input.as_python().is_some_and(PyAnyMethods::is_instance_of::<PyFloat>);
+        if is_float {
+            if !self.allow_float {
+                return Err(ValError::new(ErrorTypeDefaults::FractionType, input));
+            }
+            let float = input.validate_float(strict)?.into_inner().as_f64();
+            if !float.is_finite() {
+                return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
+            }
+            // `Fraction(float)` is exact, e.g. `0.5` becomes `1/2`
+            return create_fraction(&float.into_py(py).into_bound(py), input);
+        }
+
+        match input.validate_str(strict, false) {
+            Ok(val_match) => {
+                let either_str = val_match.into_inner();
+                create_fraction(PyString::new_bound(py, &either_str.as_cow()?).as_any(), input)
+            }
+            Err(ValError::LineErrors(_)) => Err(ValError::new(ErrorTypeDefaults::FractionType, input)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl Validator for FractionValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let fraction = self.validate_fraction(py, input, state.strict_or(self.strict))?;
+
+        if let Some(le) = &self.le {
+            if !fraction.le(le)? {
+                return Err(ValError::new(
+                    ErrorType::LessThanEqual {
+                        le: Number::String(le.to_string()),
+                        context: Some([("le", le)].into_py_dict_bound(py).into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(lt) = &self.lt {
+            if !fraction.lt(lt)? {
+                return Err(ValError::new(
+                    ErrorType::LessThan {
+                        lt: Number::String(lt.to_string()),
+                        context: Some([("lt", lt)].into_py_dict_bound(py).into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ge) = &self.ge {
+            if !fraction.ge(ge)? {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual {
+                        ge: Number::String(ge.to_string()),
+                        context: Some([("ge", ge)].into_py_dict_bound(py).into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(gt) = &self.gt {
+            if !fraction.gt(gt)? {
+                return Err(ValError::new(
+                    ErrorType::GreaterThan {
+                        gt: Number::String(gt.to_string()),
+                        context: Some([("gt", gt)].into_py_dict_bound(py).into()),
+                    },
+                    input,
+                ));
+            }
+        }
+
+        Ok(fraction.into())
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+pub(crate) fn create_fraction<'py>(arg: &Bound<'py, PyAny>, input: impl ToErrorValue) -> ValResult<Bound<'py, PyAny>> {
+    let py = arg.py();
+    get_fraction_type(py).call1((arg,)).map_err(|err| {
+        // malformed strings raise `ValueError`, a zero denominator e.g. "1/0" raises `ZeroDivisionError`
+        if err.is_instance_of::<PyValueError>(py) || err.is_instance_of::<PyZeroDivisionError>(py) {
+            ValError::new(ErrorTypeDefaults::FractionParsing, input)
+        } else if err.is_instance_of::<PyTypeError>(py) {
+            ValError::new(ErrorTypeDefaults::FractionType, input)
+        } else {
+            ValError::InternalErr(err)
+        }
+    })
+}
@@ -1,11 +1,11 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet};
 
-use crate::errors::ValResult;
-use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
+use crate::errors::{ValError, ValResult};
+use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, SkipItems, ValidatedSet};
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::list::{min_length_check, CoerceScalar};
 use super::set::set_build;
 use super::validation_state::ValidationState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
@@ -18,6 +18,8 @@ pub struct FrozenSetValidator {
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    skip_items: SkipItems,
+    coerce_scalar: CoerceScalar,
 }
 
 impl BuildValidator for FrozenSetValidator {
@@ -34,9 +36,13 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
+        let collection = match input.validate_frozenset(state.strict_or(self.strict)) {
+            Ok(collection) => Some(collection.unpack(state)),
+            Err(ValError::LineErrors(_)) if self.coerce_scalar.applies(input) => None,
+            Err(err) => return Err(err),
+        };
         let f_set = PyFrozenSet::empty_bound(py)?;
-        collection.iterate(ValidateToFrozenSet {
+        let consumer = ValidateToFrozenSet {
             py,
             input,
             f_set: &f_set,
@@ -44,7 +50,13 @@ impl Validator for FrozenSetValidator {
             item_validator: &self.item_validator,
             state,
             fail_fast: self.fail_fast,
-        })??;
+            skip_items: self.skip_items,
+        };
+        match collection {
+            Some(collection) => collection.iterate(consumer)??,
+            // validate the input as the only item
+            None => consumer.consume_iterator(std::iter::once(PyResult::Ok(input)))?,
+        }
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py(py))
     }
@@ -62,6 +74,7 @@ struct ValidateToFrozenSet<'a, 's, 'py, I: Input<'py> + ?Sized> {
     item_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
     fail_fast: bool,
+    skip_items: SkipItems,
 }
 
 impl<'py, T, I> ConsumeIterator<PyResult<T>> for ValidateToFrozenSet<'_, '_, 'py, I>
@@ -81,6 +94,7 @@ where
             self.item_validator,
             self.state,
             self.fail_fast,
+            self.skip_items,
         )
     }
 }
@@ -123,7 +123,7 @@ impl Validator for FunctionBeforeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let validate = |v, s: &mut ValidationState<'_, 'py>| self.validator.validate(py, &v, s);
+        let validate = |v, s: &mut ValidationState<'_, 'py>| self.validator.validate_traced(py, &v, s);
         self._validate(validate, py, input, state)
     }
     fn validate_assignment<'py>(
@@ -189,7 +189,7 @@ impl Validator for FunctionAfterValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let validate = |v: &_, s: &mut ValidationState<'_, 'py>| self.validator.validate(py, v, s);
+        let validate = |v: &_, s: &mut ValidationState<'_, 'py>| self.validator.validate_traced(py, v, s);
         self._validate(validate, py, input, state)
     }
     fn validate_assignment<'py>(
@@ -352,7 +352,11 @@ impl Validator for FunctionWrapValidator {
         };
         let handler = Bound::new(py, handler)?;
         let result = self._validate(handler.as_any(), py, input, state);
-        state.exactness = handler.borrow_mut().validator.exactness;
+        let mut handler = handler.borrow_mut();
+        state.exactness = handler.validator.exactness;
+        if result.is_ok() {
+            state.ignored_extras.append(&mut handler.validator.ignored_extras);
+        }
         result
     }
 
@@ -376,7 +380,14 @@ impl Validator for FunctionWrapValidator {
             updated_field_name: field_name.to_string(),
             updated_field_value: field_value.to_object(py),
         };
-        self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
+        let handler = Bound::new(py, handler)?;
+        let result = self._validate(handler.as_any(), py, obj, state);
+        if result.is_ok() {
+            state
+                .ignored_extras
+                .append(&mut handler.borrow_mut().validator.ignored_extras);
+        }
+        result
     }
 
     fn get_name(&self) -> &str {
@@ -479,8 +490,8 @@ pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValE
             pydantic_value_error.into_val_error(input)
         } else if let Ok(pydantic_error_type) = error_value.extract::<PydanticKnownError>() {
             pydantic_error_type.into_val_error(input)
-        } else if let Ok(validation_error) = err.value_bound(py).extract::<ValidationError>() {
-            validation_error.into_val_error()
+        } else if let Ok(validation_error) = error_value.downcast::<ValidationError>() {
+            validation_error.borrow().to_val_error(py)
         } else {
             py_err_string!(py, err, error_value, ValueError, input)
         }
@@ -505,7 +516,7 @@ pub struct ValidationInfo {
 }
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+    pub(crate) fn new(py: Python, extra: &Extra, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
         Self {
             config: config.clone_ref(py),
             context: extra.context.map(|ctx| ctx.clone().into()),
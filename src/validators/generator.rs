@@ -12,6 +12,7 @@ use crate::tools::SchemaDict;
 use crate::ValidationError;
 
 use super::list::get_items_schema;
+use super::validation_state::{audit_ignored_extras, IgnoredExtras};
 use super::{
     BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ValidationState, Validator,
 };
@@ -149,9 +150,14 @@ impl ValidatorIterator {
                                     ));
                                 }
                             }
-                            validator
-                                .validate(py, next.borrow_input(), Some(index.into()))
-                                .map(Some)
+                            let output = validator.validate(py, next.borrow_input(), Some(index.into()))?;
+                            // items are validated after the generator is returned, so keys ignored by
+                            // `extra_audit` models are recorded as each item is validated
+                            audit_ignored_extras(
+                                validator.context.as_ref().map(|context| context.bind(py)),
+                                std::mem::take(&mut validator.ignored_extras),
+                            )?;
+                            Ok(Some(output))
                         }
                         None => Ok(Some(next.to_object(py))),
                     },
@@ -222,10 +228,14 @@ pub struct InternalValidator {
     data: Option<Py<PyDict>>,
     strict: Option<bool>,
     from_attributes: Option<bool>,
+    partial: bool,
+    mark_invalid: bool,
     context: Option<PyObject>,
     self_instance: Option<PyObject>,
     recursion_guard: RecursionState,
     pub(crate) exactness: Option<Exactness>,
+    // keys ignored by `extra_audit` models during successful validations, taken by the validator which created this
+    pub(crate) ignored_extras: IgnoredExtras,
     validation_mode: InputType,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
@@ -254,10 +264,13 @@ impl InternalValidator {
             data: extra.data.as_ref().map(|d| d.clone().into()),
             strict: extra.strict,
             from_attributes: extra.from_attributes,
+            partial: extra.partial,
+            mark_invalid: extra.mark_invalid,
             context: extra.context.map(|d| d.into_py(py)),
             self_instance: extra.self_instance.map(|d| d.into_py(py)),
             recursion_guard: state.recursion_guard.clone(),
             exactness: state.exactness,
+            ignored_extras: Vec::new(),
             validation_mode: extra.input_type,
             hide_input_in_errors,
             validation_error_cause,
@@ -278,9 +291,14 @@ impl InternalValidator {
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
             from_attributes: self.from_attributes,
+            partial: self.partial,
+            mark_invalid: self.mark_invalid,
             context: self.context.as_ref().map(|data| data.bind(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
+            json_raw_floats: None,
+            on_validator_event: None,
+            validator_stats: None,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
         state.exactness = self.exactness;
@@ -299,6 +317,9 @@ impl InternalValidator {
                 )
             });
         self.exactness = state.exactness;
+        if result.is_ok() {
+            self.ignored_extras.append(&mut state.ignored_extras);
+        }
         result
     }
 
@@ -313,13 +334,18 @@ impl InternalValidator {
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
             from_attributes: self.from_attributes,
+            partial: self.partial,
+            mark_invalid: self.mark_invalid,
             context: self.context.as_ref().map(|data| data.bind(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
+            json_raw_floats: None,
+            on_validator_event: None,
+            validator_stats: None,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
         state.exactness = self.exactness;
-        let result = self.validator.validate(py, input, &mut state).map_err(|e| {
+        let result = self.validator.validate_traced(py, input, &mut state).map_err(|e| {
             ValidationError::from_val_error(
                 py,
                 self.name.to_object(py),
@@ -331,6 +357,9 @@ impl InternalValidator {
             )
         });
         self.exactness = state.exactness;
+        if result.is_ok() {
+            self.ignored_extras.append(&mut state.ignored_extras);
+        }
         result
     }
 }
@@ -4,8 +4,8 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, Int};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{add_parsing_reason, maybe_as_index, EitherInt, Input, Int, ValidationMatch};
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -13,6 +13,9 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    json_number_strict: bool,
+    allow_int_from_bool: bool,
+    strict_number_protocol: bool,
 }
 
 impl BuildValidator for IntValidator {
@@ -34,6 +37,9 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                json_number_strict: schema.get_as(intern!(py, "json_number_strict"))?.unwrap_or(false),
+                allow_int_from_bool: schema.get_as(intern!(py, "allow_int_from_bool"))?.unwrap_or(true),
+                strict_number_protocol: schema.get_as(intern!(py, "strict_number_protocol"))?.unwrap_or(false),
             }
             .into())
         }
@@ -49,8 +55,9 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_int(state.strict_or(self.strict))
+        check_json_number(input, self.json_number_strict)?;
+        check_bool_input(input, self.allow_int_from_bool)?;
+        validate_int(py, input, state.strict_or(self.strict), self.strict_number_protocol)
             .map(|val_match| val_match.unpack(state).into_py(py))
     }
 
@@ -62,6 +69,9 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    json_number_strict: bool,
+    allow_int_from_bool: bool,
+    strict_number_protocol: bool,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
@@ -78,7 +88,10 @@ impl Validator for ConstrainedIntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        check_json_number(input, self.json_number_strict)?;
+        check_bool_input(input, self.allow_int_from_bool)?;
+        let either_int =
+            validate_int(py, input, state.strict_or(self.strict), self.strict_number_protocol)?.unpack(state);
         let int_value = either_int.as_int()?;
 
         if let Some(ref multiple_of) = self.multiple_of {
@@ -149,6 +162,9 @@ impl ConstrainedIntValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            json_number_strict: schema.get_as(intern!(py, "json_number_strict"))?.unwrap_or(false),
+            allow_int_from_bool: schema.get_as(intern!(py, "allow_int_from_bool"))?.unwrap_or(true),
+            strict_number_protocol: schema.get_as(intern!(py, "strict_number_protocol"))?.unwrap_or(false),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -158,3 +174,51 @@ impl ConstrainedIntValidator {
         .into())
     }
 }
+
+/// With `strict_number_protocol`, Python objects implementing `__index__` such as NumPy integer scalars are also
+/// accepted in strict mode, lax mode always reads them through the number protocol
+#[inline]
+fn validate_int<'a, 'py: 'a>(
+    py: Python<'py>,
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    strict_number_protocol: bool,
+) -> ValResult<ValidationMatch<EitherInt<'a>>> {
+    match input.validate_int(strict) {
+        Ok(val_match) => Ok(val_match),
+        Err(err) => validate_int_fallback(py, input, err, strict && strict_number_protocol),
+    }
+}
+
+#[cold]
+fn validate_int_fallback<'a, 'py: 'a>(
+    py: Python<'py>,
+    input: &'a (impl Input<'py> + ?Sized),
+    err: ValError,
+    number_protocol: bool,
+) -> ValResult<ValidationMatch<EitherInt<'a>>> {
+    if number_protocol && !input.is_bool() {
+        if let Some(int) = input.as_python().and_then(maybe_as_index) {
+            return Ok(ValidationMatch::strict(EitherInt::Py(int)));
+        }
+    }
+    Err(add_parsing_reason(py, err, input))
+}
+
+/// With `json_number_strict`, JSON float literals such as `1.0` are rejected even when they have no fractional part
+fn check_json_number<'py>(input: &(impl Input<'py> + ?Sized), json_number_strict: bool) -> ValResult<()> {
+    if json_number_strict && input.is_json_float() {
+        Err(ValError::new(ErrorTypeDefaults::IntType, input))
+    } else {
+        Ok(())
+    }
+}
+
+/// Without `allow_int_from_bool`, bools are rejected even in lax mode
+fn check_bool_input<'py>(input: &(impl Input<'py> + ?Sized), allow_int_from_bool: bool) -> ValResult<()> {
+    if !allow_int_from_bool && input.is_bool() {
+        Err(ValError::new(ErrorTypeDefaults::IntType, input))
+    } else {
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString, PyType};
+use pyo3::{PyTraverseError, PyVisit};
 
 use jiter::{FloatMode, JsonValue, PythonParse};
 
@@ -12,9 +15,88 @@ use crate::tools::SchemaDict;
 use super::config::ValBytesMode;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+/// The output of a `json` schema with `keep_raw=True`, the validated value alongside the exact JSON text
+/// it was parsed from, which is what's emitted again when serializing to JSON
+#[pyclass(module = "pydantic_core._pydantic_core", name = "RawJson", frozen)]
+#[derive(Debug)]
+pub struct PyRawJson {
+    #[pyo3(get)]
+    value: PyObject,
+    #[pyo3(get)]
+    raw: Py<PyString>,
+    // whether `raw` is strict JSON, without `NaN` or `Infinity` literals, otherwise the value is serialized instead
+    strict_json: bool,
+}
+
+impl PyRawJson {
+    pub(crate) fn value<'py>(&self, py: Python<'py>) -> &Bound<'py, PyAny> {
+        self.value.bind(py)
+    }
+
+    pub(crate) fn raw<'py>(&self, py: Python<'py>) -> &Bound<'py, PyString> {
+        self.raw.bind(py)
+    }
+
+    pub(crate) fn is_strict_json(&self) -> bool {
+        self.strict_json
+    }
+}
+
+#[pymethods]
+impl PyRawJson {
+    #[new]
+    pub fn py_new(py: Python, value: PyObject, raw: Py<PyString>) -> PyResult<Self> {
+        let strict_json = JsonValue::parse(raw.bind(py).to_str()?.as_bytes(), false).is_ok();
+        Ok(Self {
+            value,
+            raw,
+            strict_json,
+        })
+    }
+
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp, py: Python<'_>) -> PyResult<PyObject> {
+        let Ok(other) = other.downcast::<Self>() else {
+            return Ok(py.NotImplemented());
+        };
+        let other = other.get();
+        let eq = self.raw(py).as_any().eq(other.raw(py))? && self.value(py).eq(other.value(py))?;
+        match op {
+            CompareOp::Eq => Ok(eq.into_py(py)),
+            CompareOp::Ne => Ok((!eq).into_py(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        self.raw(py).hash()
+    }
+
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("RawJson({}, {})", self.value(py).repr()?, self.raw(py).repr()?))
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (_item, /))]
+    pub fn __class_getitem__(cls: Py<PyType>, _item: &Bound<'_, PyAny>) -> Py<PyType> {
+        cls
+    }
+
+    pub fn __reduce__(&self, py: Python) -> (Py<PyType>, (PyObject, Py<PyString>)) {
+        (
+            py.get_type_bound::<Self>().unbind(),
+            (self.value.clone_ref(py), self.raw.clone_ref(py)),
+        )
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.value)
+    }
+}
+
 #[derive(Debug)]
 pub struct JsonValidator {
     validator: Option<Box<CombinedValidator>>,
+    keep_raw: bool,
     name: String,
 }
 
@@ -41,7 +123,12 @@ impl BuildValidator for JsonValidator {
             Self::EXPECTED_TYPE,
             validator.as_ref().map_or("any", |v| v.get_name())
         );
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            keep_raw: schema.get_as(intern!(schema.py(), "keep_raw"))?.unwrap_or(false),
+            name,
+        }
+        .into())
     }
 }
 
@@ -54,31 +141,62 @@ impl Validator for JsonValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if self.keep_raw && state.allow_partial.is_active() {
+            return Err(ValError::InternalErr(PyValueError::new_err(
+                "`keep_raw` json schemas cannot be used with `allow_partial`",
+            )));
+        }
         let v_match = validate_json_bytes(input)?;
         let json_either_bytes = v_match.unpack(state);
         let json_bytes = json_either_bytes.as_slice();
-        match self.validator {
+        // with `keep_raw` the document is first parsed as strict JSON, so we know if the raw text can be
+        // emitted as is, and only parsed again allowing `NaN` and `Infinity` if that fails
+        let mut strict_json = true;
+        let value = match self.validator {
             Some(ref validator) => {
-                let json_value = JsonValue::parse_with_config(json_bytes, true, state.allow_partial)
-                    .map_err(|e| map_json_err(input, e, json_bytes))?;
+                let json_value = match JsonValue::parse_with_config(json_bytes, !self.keep_raw, state.allow_partial) {
+                    Err(_) if self.keep_raw => {
+                        strict_json = false;
+                        JsonValue::parse_with_config(json_bytes, true, state.allow_partial)
+                    }
+                    result => result,
+                }
+                .map_err(|e| map_json_err(input, e, json_bytes))?;
                 let mut json_state = state.rebind_extra(|e| {
                     e.input_type = InputType::Json;
                 });
-                validator.validate(py, &json_value, &mut json_state)
+                validator.validate_traced(py, &json_value, &mut json_state)?
             }
             None => {
-                let parse_builder = PythonParse {
-                    allow_inf_nan: true,
+                let parse_builder = |allow_inf_nan| PythonParse {
+                    allow_inf_nan,
                     cache_mode: state.cache_str(),
                     partial_mode: state.allow_partial,
                     catch_duplicate_keys: false,
                     float_mode: FloatMode::Float,
                 };
-                let obj = parse_builder
-                    .python_parse(py, json_bytes)
-                    .map_err(|e| map_json_err(input, e, json_bytes))?;
-                Ok(obj.unbind())
+                let obj = match parse_builder(!self.keep_raw).python_parse(py, json_bytes) {
+                    Err(_) if self.keep_raw => {
+                        strict_json = false;
+                        parse_builder(true).python_parse(py, json_bytes)
+                    }
+                    result => result,
+                }
+                .map_err(|e| map_json_err(input, e, json_bytes))?;
+                obj.unbind()
+            }
+        };
+        if self.keep_raw {
+            // the document was parsed successfully, so is valid UTF-8
+            let raw = PyString::new_bound(py, &String::from_utf8_lossy(json_bytes)).unbind();
+            Ok(PyRawJson {
+                value,
+                raw,
+                strict_json,
             }
+            .into_py(py))
+        } else {
+            Ok(value)
         }
     }
 
@@ -99,6 +217,24 @@ pub fn validate_json_bytes<'a, 'py>(
     }
 }
 
+/// A cheap guard against very large documents, checked before any parsing is done
+pub fn check_json_size<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    json_bytes: &[u8],
+    max_input_size: Option<usize>,
+) -> ValResult<()> {
+    match max_input_size {
+        Some(max_input_size) if json_bytes.len() > max_input_size => Err(ValError::new(
+            ErrorType::JsonTooLarge {
+                max_input_size,
+                context: None,
+            },
+            input,
+        )),
+        _ => Ok(()),
+    }
+}
+
 fn map_bytes_error(line_error: ValLineError) -> ValLineError {
     match line_error.error_type {
         ErrorType::BytesType { .. } => {
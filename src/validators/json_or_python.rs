@@ -56,8 +56,8 @@ impl Validator for JsonOrPython {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         match state.extra().input_type {
-            InputType::Python => self.python.validate(py, input, state),
-            _ => self.json.validate(py, input, state),
+            InputType::Python => self.python.validate_traced(py, input, state),
+            _ => self.json.validate_traced(py, input, state),
         }
     }
 
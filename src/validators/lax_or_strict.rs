@@ -1,8 +1,8 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
@@ -14,6 +14,8 @@ use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuild
 #[derive(Debug)]
 pub struct LaxOrStrictValidator {
     strict: bool,
+    // `(key, name)`, the strict validator is used when `context[key]` contains `name`
+    strict_context: Option<(Py<PyString>, Py<PyString>)>,
     lax_validator: Box<CombinedValidator>,
     strict_validator: Box<CombinedValidator>,
     name: String,
@@ -34,6 +36,15 @@ impl BuildValidator for LaxOrStrictValidator {
         let strict_schema = schema.get_as_req(intern!(py, "strict_schema"))?;
         let strict_validator = Box::new(build_validator(&strict_schema, config, definitions)?);
 
+        let strict_context = match (
+            schema.get_as(intern!(py, "strict_context_key"))?,
+            schema.get_as(intern!(py, "strict_context_name"))?,
+        ) {
+            (Some(key), Some(name)) => Some((key, name)),
+            (None, None) => None,
+            _ => return py_schema_err!("`strict_context_key` and `strict_context_name` must be set together"),
+        };
+
         let name = format!(
             "{}[lax={},strict={}]",
             Self::EXPECTED_TYPE,
@@ -42,6 +53,7 @@ impl BuildValidator for LaxOrStrictValidator {
         );
         Ok(Self {
             strict: is_strict(schema, config)?,
+            strict_context,
             lax_validator,
             strict_validator,
             name,
@@ -62,19 +74,19 @@ impl Validator for LaxOrStrictValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        if state.strict_or(self.strict) {
-            self.strict_validator.validate(py, input, state)
+        if self.strict_from_context(py, state) || state.strict_or(self.strict) {
+            self.strict_validator.validate_traced(py, input, state)
         } else {
             // horrible edge case: if doing smart union validation, we need to try the strict validator
             // anyway and prefer that if it succeeds
             if state.exactness.is_some() {
-                if let Ok(strict_result) = self.strict_validator.validate(py, input, state) {
+                if let Ok(strict_result) = self.strict_validator.validate_traced(py, input, state) {
                     return Ok(strict_result);
                 }
                 // this is now known to be not strict
                 state.floor_exactness(Exactness::Lax);
             }
-            self.lax_validator.validate(py, input, state)
+            self.lax_validator.validate_traced(py, input, state)
         }
     }
 
@@ -82,3 +94,17 @@ impl Validator for LaxOrStrictValidator {
         &self.name
     }
 }
+
+impl LaxOrStrictValidator {
+    /// Whether the validation context selects strict mode for this validator, a context without the key
+    /// or which can't be queried leaves the mode to `strict`
+    fn strict_from_context(&self, py: Python<'_>, state: &ValidationState<'_, '_>) -> bool {
+        let (Some((key, name)), Some(context)) = (&self.strict_context, state.extra().context) else {
+            return false;
+        };
+        context
+            .get_item(key.bind(py))
+            .and_then(|strict_names| strict_names.contains(name.bind(py)))
+            .unwrap_or(false)
+    }
+}
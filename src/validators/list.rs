@@ -1,13 +1,16 @@
 use std::sync::OnceLock;
 
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyBool, PyByteArray, PyBytes, PyDict, PyInt, PyList, PyString, PyType};
 
-use crate::errors::ValResult;
+use crate::build_tools::py_schema_err;
+use crate::errors::{ValError, ValResult};
 use crate::input::{
-    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
+    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, SkipItems,
+    ValidatedList,
 };
-use crate::tools::SchemaDict;
+use crate::tools::{get_collections_type, SchemaDict};
 
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -19,6 +22,51 @@ pub struct ListValidator {
     max_length: Option<usize>,
     name: OnceLock<String>,
     fail_fast: bool,
+    skip_items: SkipItems,
+    coerce_scalar: CoerceScalar,
+    exact_items: Option<ExactItems>,
+    one_based_index: bool,
+    output_type: ListOutputType,
+}
+
+static DEQUE_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub(crate) fn get_deque_type(py: Python) -> &Bound<'_, PyType> {
+    get_collections_type(py, &DEQUE_TYPE, "deque")
+}
+
+/// The container created from the validated items, see `output_type` on list schemas
+#[derive(Debug, Clone, Copy)]
+enum ListOutputType {
+    List,
+    Deque { maxlen: Option<usize> },
+}
+
+impl ListOutputType {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let maxlen: Option<usize> = schema.get_as(pyo3::intern!(py, "maxlen"))?;
+        match schema
+            .get_as::<Bound<'_, PyString>>(pyo3::intern!(py, "output_type"))?
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+        {
+            None | Some("list") if maxlen.is_some() => {
+                py_schema_err!("`maxlen` can only be used with `output_type='deque'`")
+            }
+            None | Some("list") => Ok(Self::List),
+            Some("deque") => Ok(Self::Deque { maxlen }),
+            Some(s) => py_schema_err!("Invalid list output_type: '{}'", s),
+        }
+    }
+
+    fn create(self, py: Python, list: PyObject) -> ValResult<PyObject> {
+        match self {
+            Self::List => Ok(list),
+            Self::Deque { maxlen } => Ok(get_deque_type(py).call1((list, maxlen))?.unbind()),
+        }
+    }
 }
 
 pub fn get_items_schema(
@@ -94,6 +142,112 @@ macro_rules! min_length_check {
 }
 pub(crate) use min_length_check;
 
+pub(crate) fn get_skip_items(schema: &Bound<'_, PyDict>) -> PyResult<SkipItems> {
+    let py = schema.py();
+    Ok(SkipItems {
+        empty: schema.get_as(pyo3::intern!(py, "skip_empty"))?.unwrap_or(false),
+        none: schema.get_as(pyo3::intern!(py, "skip_none"))?.unwrap_or(false),
+    })
+}
+
+/// Whether a single value is validated as a collection of one item, see `coerce_scalar` on collection schemas
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CoerceScalar {
+    enabled: bool,
+    str_like: bool,
+}
+
+impl CoerceScalar {
+    /// `str`, `bytes` and `bytearray` are iterable, so they're only wrapped if `coerce_scalar_str` is also set
+    pub(crate) fn applies<'py>(self, input: &(impl Input<'py> + ?Sized)) -> bool {
+        if !self.enabled {
+            false
+        } else if is_str_like(input) {
+            self.str_like
+        } else {
+            input.validate_iter().is_err()
+        }
+    }
+}
+
+fn is_str_like<'py>(input: &(impl Input<'py> + ?Sized)) -> bool {
+    match input.as_python() {
+        Some(obj) => {
+            obj.is_instance_of::<PyString>() || obj.is_instance_of::<PyBytes>() || obj.is_instance_of::<PyByteArray>()
+        }
+        None => input.validate_str(true, false).is_ok(),
+    }
+}
+
+pub(crate) fn get_coerce_scalar(schema: &Bound<'_, PyDict>) -> PyResult<CoerceScalar> {
+    let py = schema.py();
+    Ok(CoerceScalar {
+        enabled: schema.get_as(pyo3::intern!(py, "coerce_scalar"))?.unwrap_or(false),
+        str_like: schema.get_as(pyo3::intern!(py, "coerce_scalar_str"))?.unwrap_or(false),
+    })
+}
+
+/// Item validators which return an exact instance of their builtin type unchanged, lists of such instances
+/// are checked with a tight loop in strict mode instead of dispatching to the item validator for each item
+#[derive(Debug, Clone, Copy)]
+struct ExactItems {
+    item_type: ExactItemType,
+    strict: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ExactItemType {
+    Int,
+    Str,
+    Bool,
+}
+
+impl ExactItemType {
+    fn of(validator: &CombinedValidator) -> Option<Self> {
+        match validator {
+            CombinedValidator::Int(_) => Some(Self::Int),
+            CombinedValidator::Str(_) => Some(Self::Str),
+            CombinedValidator::Bool(_) => Some(Self::Bool),
+            _ => None,
+        }
+    }
+
+    fn is_exact_instance(self, item: &Bound<'_, PyAny>) -> bool {
+        match self {
+            Self::Int => item.is_exact_instance_of::<PyInt>(),
+            Self::Str => item.is_exact_instance_of::<PyString>(),
+            Self::Bool => item.is_exact_instance_of::<PyBool>(),
+        }
+    }
+}
+
+impl ExactItems {
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        item_validator: Option<&CombinedValidator>,
+    ) -> PyResult<Option<Self>> {
+        let Some(item_type) = item_validator.and_then(ExactItemType::of) else {
+            return Ok(None);
+        };
+        let items_schema: Bound<'_, PyDict> = schema.get_as_req(pyo3::intern!(schema.py(), "items_schema"))?;
+        Ok(Some(Self {
+            item_type,
+            strict: crate::build_tools::is_strict(&items_schema, config)?,
+        }))
+    }
+
+    /// `true` if every item is an exact instance, stopping at the first item which isn't, always `false` when
+    /// validator events or stats are being recorded since they must see every item validator run
+    fn all_exact(self, list: &Bound<'_, PyList>, state: &ValidationState<'_, '_>) -> bool {
+        let extra = state.extra();
+        extra.on_validator_event.is_none()
+            && extra.validator_stats.is_none()
+            && state.strict_or(self.strict)
+            && list.iter().all(|item| self.item_type.is_exact_instance(&item))
+    }
+}
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -104,6 +258,7 @@ impl BuildValidator for ListValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let item_validator = get_items_schema(schema, config, definitions)?.map(Box::new);
+        let exact_items = ExactItems::build(schema, config, item_validator.as_deref())?;
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
             item_validator,
@@ -111,6 +266,11 @@ impl BuildValidator for ListValidator {
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
             name: OnceLock::new(),
             fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            skip_items: get_skip_items(schema)?,
+            coerce_scalar: get_coerce_scalar(schema)?,
+            exact_items,
+            one_based_index: schema.get_as(pyo3::intern!(py, "one_based_index"))?.unwrap_or(false),
+            output_type: ListOutputType::from_schema(schema)?,
         }
         .into())
     }
@@ -125,22 +285,70 @@ impl Validator for ListValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
+        let list = self.validate_to_list(py, input, state)?;
+        self.output_type.create(py, list)
+    }
+
+    fn get_name(&self) -> &str {
+        // The logic here is a little janky, it's done to try to cache the formatted name
+        // while also trying to render definitions correctly when possible.
+        //
+        // Probably an opportunity for a future refactor
+        match self.name.get() {
+            Some(s) => s.as_str(),
+            None => {
+                let name = self.item_validator.as_ref().map_or("any", |v| v.get_name());
+                if name == "..." {
+                    // when inner name is not initialized yet, don't cache it here
+                    "list[...]"
+                } else {
+                    self.name.get_or_init(|| format!("list[{name}]")).as_str()
+                }
+            }
+        }
+    }
+}
+
+impl ListValidator {
+    fn validate_to_list<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let seq = match input.validate_list(state.strict_or(self.strict)) {
+            Ok(seq) => seq.unpack(state),
+            Err(ValError::LineErrors(_)) if self.coerce_scalar.applies(input) => {
+                return self.validate_scalar(py, input, state);
+            }
+            Err(err) => return Err(err),
+        };
 
         let actual_length = seq.len();
+        if let (Some(py_list), Some(exact_items)) = (seq.as_py_list(), self.exact_items) {
+            // items validated unchanged, so the list can be copied, falls back to validating each item otherwise
+            if !self.skip_items.is_active() && exact_items.all_exact(py_list, state) {
+                length_check!(input, "List", self.min_length, self.max_length, py_list);
+                return Ok(py_list.get_slice(0, usize::MAX).into_py(py));
+            }
+        }
+
         let output = match self.item_validator {
-            Some(ref v) => seq.iterate(ValidateToVec {
-                py,
-                input,
-                actual_length,
-                max_length: self.max_length,
-                field_type: "List",
-                item_validator: v,
-                state,
-                fail_fast: self.fail_fast,
-            })??,
+            Some(ref v) => seq
+                .iterate(ValidateToVec {
+                    py,
+                    input,
+                    actual_length,
+                    max_length: self.max_length,
+                    field_type: "List",
+                    item_validator: v,
+                    state,
+                    fail_fast: self.fail_fast,
+                    skip_items: self.skip_items,
+                })?
+                .map_err(|err| self.index_errors(err))?,
             None => {
-                if let Some(py_list) = seq.as_py_list() {
+                if let Some(py_list) = seq.as_py_list().filter(|_| !self.skip_items.is_active()) {
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
                     let list_copy = py_list.get_slice(0, usize::MAX);
                     return Ok(list_copy.into_py(py));
@@ -152,31 +360,59 @@ impl Validator for ListValidator {
                     actual_length,
                     max_length: self.max_length,
                     field_type: "List",
-                })??
+                    skip_items: self.skip_items,
+                })?
+                .map_err(|err| self.index_errors(err))?
             }
         };
         min_length_check!(input, "List", self.min_length, output);
         Ok(output.into_py(py))
     }
 
-    fn get_name(&self) -> &str {
-        // The logic here is a little janky, it's done to try to cache the formatted name
-        // while also trying to render definitions correctly when possible.
-        //
-        // Probably an opportunity for a future refactor
-        match self.name.get() {
-            Some(s) => s.as_str(),
-            None => {
-                let name = self.item_validator.as_ref().map_or("any", |v| v.get_name());
-                if name == "..." {
-                    // when inner name is not initialized yet, don't cache it here
-                    "list[...]"
-                } else {
-                    self.name.get_or_init(|| format!("list[{name}]")).as_str()
-                }
-            }
+    /// Errors from validating items, with indices counted from 1 if `one_based_index` is set
+    fn index_errors(&self, err: ValError) -> ValError {
+        if self.one_based_index {
+            err.with_one_based_index()
+        } else {
+            err
         }
     }
+
+    /// Validate `input` as the only item of the list
+    fn validate_scalar<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let item = std::iter::once(PyResult::Ok(input));
+        let output = match self.item_validator {
+            Some(ref v) => ValidateToVec {
+                py,
+                input,
+                actual_length: Some(1),
+                max_length: self.max_length,
+                field_type: "List",
+                item_validator: v,
+                state,
+                fail_fast: self.fail_fast,
+                skip_items: self.skip_items,
+            }
+            .consume_iterator(item)
+            .map_err(|err| self.index_errors(err))?,
+            None => ToVec {
+                py,
+                input,
+                actual_length: Some(1),
+                max_length: self.max_length,
+                field_type: "List",
+                skip_items: self.skip_items,
+            }
+            .consume_iterator(item)?,
+        };
+        min_length_check!(input, "List", self.min_length, output);
+        Ok(output.into_py(py))
+    }
 }
 
 struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
@@ -188,6 +424,7 @@ struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
     item_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
     fail_fast: bool,
+    skip_items: SkipItems,
 }
 
 // pretty arbitrary default capacity when creating vecs from iteration
@@ -200,7 +437,12 @@ where
     type Output = ValResult<Vec<PyObject>>;
     fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<Vec<PyObject>> {
         let capacity = self.actual_length.unwrap_or(DEFAULT_CAPACITY);
-        let max_length_check = MaxLengthCheck::new(self.max_length, self.field_type, self.input, self.actual_length);
+        let max_length_check = MaxLengthCheck::new(
+            self.max_length,
+            self.field_type,
+            self.input,
+            error_actual_length(self.actual_length, self.skip_items),
+        );
         validate_iter_to_vec(
             self.py,
             iterator,
@@ -209,6 +451,7 @@ where
             self.item_validator,
             self.state,
             self.fail_fast,
+            self.skip_items,
         )
     }
 }
@@ -219,6 +462,7 @@ struct ToVec<'a, 'py, I: Input<'py> + ?Sized> {
     actual_length: Option<usize>,
     max_length: Option<usize>,
     field_type: &'static str,
+    skip_items: SkipItems,
 }
 
 impl<'py, T, I: Input<'py> + ?Sized> ConsumeIterator<PyResult<T>> for ToVec<'_, 'py, I>
@@ -227,7 +471,17 @@ where
 {
     type Output = ValResult<Vec<PyObject>>;
     fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<Vec<PyObject>> {
-        let max_length_check = MaxLengthCheck::new(self.max_length, self.field_type, self.input, self.actual_length);
-        no_validator_iter_to_vec(self.py, self.input, iterator, max_length_check)
+        let max_length_check = MaxLengthCheck::new(
+            self.max_length,
+            self.field_type,
+            self.input,
+            error_actual_length(self.actual_length, self.skip_items),
+        );
+        no_validator_iter_to_vec(self.py, self.input, iterator, max_length_check, self.skip_items)
     }
 }
+
+/// The input length is only meaningful in a `too_long` error if no items are skipped
+fn error_actual_length(actual_length: Option<usize>, skip_items: SkipItems) -> Option<usize> {
+    actual_length.filter(|_| !skip_items.is_active())
+}
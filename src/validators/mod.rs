@@ -1,25 +1,27 @@
+use std::cell::RefCell;
 use std::fmt::Debug;
 
 use enum_dispatch::enum_dispatch;
 use jiter::{PartialMode, StringCacheMode};
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyIterator, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::errors::{LocItem, ValError, ValLineError, ValResult, ValidationError};
+use crate::input::{parse_json_documents, parse_json_with_progress, Input, InputType, JsonRawFloats, StringMapping};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
-use crate::tools::SchemaDict;
+use crate::tools::{py_err, SchemaDict};
 pub(crate) use config::ValBytesMode;
 
 mod any;
 mod arguments;
+mod base64;
 mod bool;
 mod bytes;
 mod call;
@@ -27,6 +29,7 @@ mod callable;
 mod chain;
 pub(crate) mod complex;
 mod config;
+mod constraints;
 mod custom_error;
 mod dataclass;
 mod date;
@@ -36,6 +39,7 @@ mod definitions;
 mod dict;
 mod enum_;
 mod float;
+mod fraction;
 mod frozenset;
 mod function;
 mod generator;
@@ -51,6 +55,8 @@ mod model;
 mod model_fields;
 mod none;
 mod nullable;
+mod secret;
+mod self_validating;
 mod set;
 mod string;
 mod time;
@@ -63,6 +69,11 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
+pub(crate) use self::base64::base64_engine;
+pub use self::json::PyRawJson;
+pub(crate) use self::list::get_deque_type;
+pub use self::secret::PySecret;
+pub(crate) use self::secret::SECRET_PLACEHOLDER;
 pub use self::validation_state::{Exactness, ValidationState};
 pub use with_default::DefaultType;
 
@@ -110,6 +121,8 @@ pub struct SchemaValidator {
     // reconstructing the object for cloudpickle support (see `__reduce__`).
     py_schema: Py<PyAny>,
     py_config: Option<Py<PyDict>>,
+    // shallow-merged with the context passed to each validation call, per-call keys take precedence
+    default_context: Option<Py<PyDict>>,
     #[pyo3(get)]
     title: PyObject,
     hide_input_in_errors: bool,
@@ -120,8 +133,13 @@ pub struct SchemaValidator {
 #[pymethods]
 impl SchemaValidator {
     #[new]
-    #[pyo3(signature = (schema, config=None))]
-    pub fn py_new(py: Python, schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+    #[pyo3(signature = (schema, config=None, default_context=None))]
+    pub fn py_new(
+        py: Python,
+        schema: &Bound<'_, PyAny>,
+        config: Option<&Bound<'_, PyDict>>,
+        default_context: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
         let mut definitions_builder = DefinitionsBuilder::new();
 
         let validator = build_validator(schema, config, &mut definitions_builder)?;
@@ -149,6 +167,7 @@ impl SchemaValidator {
             definitions,
             py_schema,
             py_config,
+            default_context: default_context.map(|c| c.clone().unbind()),
             title,
             hide_input_in_errors,
             validation_error_cause,
@@ -156,16 +175,20 @@ impl SchemaValidator {
         })
     }
 
-    pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
+    pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
         let cls = slf.get_type().into();
-        let init_args = (slf.get().py_schema.to_object(py), slf.get().py_config.to_object(py));
+        let init_args = (
+            slf.get().py_schema.to_object(py),
+            slf.get().py_config.to_object(py),
+            slf.get().default_context.to_object(py),
+        );
         Ok((cls, init_args))
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off))]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, partial=false, mark_invalid=false, return_fields_set=false, on_validator_event=None, return_validator_stats=false))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -175,18 +198,34 @@ impl SchemaValidator {
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
+        partial: bool,
+        mark_invalid: bool,
+        return_fields_set: bool,
+        on_validator_event: Option<&Bound<'_, PyAny>>,
+        return_validator_stats: bool,
     ) -> PyResult<PyObject> {
-        self._validate(
-            py,
-            input,
-            InputType::Python,
-            strict,
-            from_attributes,
-            context,
-            self_instance,
-            allow_partial,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+        let validator_stats = return_validator_stats.then(ValidatorStats::default);
+        let output = self
+            ._validate(
+                py,
+                input,
+                InputType::Python,
+                strict,
+                from_attributes,
+                partial,
+                mark_invalid,
+                context,
+                self_instance,
+                allow_partial,
+                return_fields_set,
+                on_validator_event,
+                validator_stats.as_ref(),
+            )
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        match validator_stats {
+            Some(validator_stats) => Ok((output, validator_stats.into_dict(py)?).to_object(py)),
+            None => Ok(output),
+        }
     }
 
     #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
@@ -205,9 +244,14 @@ impl SchemaValidator {
             InputType::Python,
             strict,
             from_attributes,
+            false,
+            false,
             context,
             self_instance,
             false.into(),
+            false,
+            None,
+            None,
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -217,7 +261,8 @@ impl SchemaValidator {
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, allow_partial=PartialMode::Off))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, allow_partial=PartialMode::Off, max_input_size=None, on_progress=None, partial=false, mark_invalid=false, json_float_mode="float", json_mode="document"))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -226,23 +271,73 @@ impl SchemaValidator {
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
+        max_input_size: Option<usize>,
+        on_progress: Option<&Bound<'_, PyAny>>,
+        partial: bool,
+        mark_invalid: bool,
+        json_float_mode: &str,
+        json_mode: &str,
     ) -> PyResult<PyObject> {
+        if on_progress.is_some() && allow_partial.is_active() {
+            return py_err!(PyValueError; "`on_progress` cannot be used with `allow_partial`");
+        }
+        let decimal_floats = match json_float_mode {
+            "float" => false,
+            "decimal" => true,
+            _ => return py_err!(PyValueError; "Invalid json_float_mode, should be `'float'` or `'decimal'`"),
+        };
+        if decimal_floats && allow_partial.is_active() {
+            return py_err!(PyValueError; "`json_float_mode='decimal'` cannot be used with `allow_partial`");
+        }
+        let concatenated = match json_mode {
+            "document" => false,
+            "concatenated" => true,
+            _ => return py_err!(PyValueError; "Invalid json_mode, should be `'document'` or `'concatenated'`"),
+        };
+        if concatenated && (allow_partial.is_active() || on_progress.is_some() || self_instance.is_some()) {
+            return py_err!(
+                PyValueError;
+                "`json_mode='concatenated'` cannot be used with `allow_partial`, `on_progress` or `self_instance`"
+            );
+        }
         let r = match json::validate_json_bytes(input) {
-            Ok(v_match) => self._validate_json(
-                py,
-                input,
-                v_match.into_inner().as_slice(),
-                strict,
-                context,
-                self_instance,
-                allow_partial,
-            ),
+            Ok(v_match) => {
+                let json_either_bytes = v_match.into_inner();
+                let json_bytes = json_either_bytes.as_slice();
+                json::check_json_size(input, json_bytes, max_input_size).and_then(|()| {
+                    if concatenated {
+                        return self._validate_json_documents(
+                            py,
+                            input,
+                            json_bytes,
+                            strict,
+                            context,
+                            partial,
+                            mark_invalid,
+                            decimal_floats,
+                        );
+                    }
+                    self._validate_json(
+                        py,
+                        input,
+                        json_bytes,
+                        strict,
+                        context,
+                        self_instance,
+                        allow_partial,
+                        on_progress,
+                        partial,
+                        mark_invalid,
+                        decimal_floats,
+                    )
+                })
+            }
             Err(err) => Err(err),
         };
         r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, allow_partial=PartialMode::Off))]
+    #[pyo3(signature = (input, *, strict=None, context=None, allow_partial=PartialMode::Off, partial=false))]
     pub fn validate_strings(
         &self,
         py: Python,
@@ -250,11 +345,26 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
+        partial: bool,
     ) -> PyResult<PyObject> {
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None, allow_partial) {
+        match self._validate(
+            py,
+            &string_mapping,
+            t,
+            strict,
+            None,
+            partial,
+            false,
+            context,
+            None,
+            allow_partial,
+            false,
+            None,
+            None,
+        ) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
@@ -272,21 +382,155 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
+        let merged_context = self.merge_context(py, context)?;
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
             strict,
             from_attributes,
-            context,
+            partial: false,
+            mark_invalid: false,
+            context: merged_context.as_ref(),
             self_instance: None,
             cache_str: self.cache_str,
+            json_raw_floats: None,
+            on_validator_event: None,
+            validator_stats: None,
         };
 
         let guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, guard, false.into());
-        self.validator
+        let output = self
+            .validator
             .validate_assignment(py, &obj, field_name, &field_value, &mut state)
-            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        state.commit_ignored_extras(context)?;
+        Ok(output)
+    }
+
+    /// Apply `patch` to a shallow copy of `base`, validating each field in `patch` as `validate_assignment` would,
+    /// fields not in `patch` are left as they are and errors from every invalid field are raised together
+    #[pyo3(signature = (base, patch, *, strict=None, from_attributes=None, context=None))]
+    pub fn validate_update(
+        &self,
+        py: Python,
+        base: &Bound<'_, PyAny>,
+        patch: &Bound<'_, PyDict>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let merged_context = self.merge_context(py, context)?;
+        let extra = Extra::new(
+            strict,
+            from_attributes,
+            false,
+            merged_context.as_ref(),
+            None,
+            InputType::Python,
+            self.cache_str,
+        );
+
+        let guard = &mut RecursionState::default();
+        let mut state = ValidationState::new(extra, guard, false.into());
+        let mut obj = py
+            .import_bound(intern!(py, "copy"))?
+            .call_method1(intern!(py, "copy"), (base,))?;
+        // a shallow copy shares the fields set with `base`, which `validate_assignment` adds to in place
+        let fields_set_key = intern!(py, "__pydantic_fields_set__");
+        if let Ok(fields_set) = obj.getattr(fields_set_key) {
+            if fields_set.is_instance_of::<PySet>() {
+                model::force_setattr(py, &obj, fields_set_key, fields_set.call_method0(intern!(py, "copy"))?)?;
+            }
+        }
+        let mut errors = Vec::new();
+        for (field_name, field_value) in patch {
+            let field_name = field_name.downcast::<PyString>()?.to_str()?;
+            match self
+                .validator
+                .validate_assignment(py, &obj, field_name, &field_value, &mut state)
+            {
+                Ok(output) => obj = output.into_bound(py),
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => return Err(self.prepare_validation_err(py, err, InputType::Python)),
+            }
+        }
+
+        if errors.is_empty() {
+            state.commit_ignored_extras(context)?;
+            Ok(obj.unbind())
+        } else {
+            Err(self.prepare_validation_err(py, ValError::LineErrors(errors), InputType::Python))
+        }
+    }
+
+    /// Eagerly validate each item of `inputs` against the schema in one call, sharing setup between items;
+    /// the first invalid item raises, with its index prepended to error locations, unless `return_errors` is set,
+    /// then invalid items have their `ValidationError` in their place in the returned list
+    #[pyo3(signature = (inputs, *, strict=None, from_attributes=None, context=None, return_errors=false))]
+    pub fn validate_many<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        return_errors: bool,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let merged_context = self.merge_context(py, context)?;
+        let extra = Extra::new(
+            strict,
+            from_attributes,
+            false,
+            merged_context.as_ref(),
+            None,
+            InputType::Python,
+            self.cache_str,
+        );
+        let guard = &mut RecursionState::default();
+        let output = PyList::empty_bound(py);
+        for (index, item) in inputs.iter()?.enumerate() {
+            let item = item?;
+            let mut state = ValidationState::new(extra.clone(), guard, false.into());
+            match self.validator.validate_traced(py, &item, &mut state) {
+                Ok(value) => {
+                    state.commit_ignored_extras(context)?;
+                    output.append(value)?;
+                }
+                Err(err @ ValError::LineErrors(_)) if return_errors => {
+                    output.append(self.prepare_validation_err(py, err, InputType::Python).into_value(py))?;
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    let line_errors = line_errors
+                        .into_iter()
+                        .map(|err| err.with_outer_location(index))
+                        .collect();
+                    return Err(self.prepare_validation_err(py, ValError::LineErrors(line_errors), InputType::Python));
+                }
+                Err(err) => return Err(self.prepare_validation_err(py, err, InputType::Python)),
+            }
+        }
+        Ok(output)
+    }
+
+    /// Lazily validate each item of `iterable`, yielding `(index, result)` where `result` is either the validated
+    /// item or the `ValidationError` for that item, so one invalid item doesn't stop the rest being validated
+    #[pyo3(signature = (iterable, *, strict=None, from_attributes=None, context=None))]
+    pub fn iter_validate(
+        slf: &Bound<'_, Self>,
+        iterable: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<IterValidator> {
+        Ok(IterValidator {
+            validator: slf.clone().unbind(),
+            iterator: iterable.iter()?.unbind(),
+            index: 0,
+            strict,
+            from_attributes,
+            context: context.map(|c| c.clone().unbind()),
+        })
     }
 
     #[pyo3(signature = (*, strict=None, context=None))]
@@ -296,14 +540,20 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
+        let context = self.merge_context(py, context)?;
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
             strict,
             from_attributes: None,
-            context,
+            partial: false,
+            mark_invalid: false,
+            context: context.as_ref(),
             self_instance: None,
             cache_str: self.cache_str,
+            json_raw_floats: None,
+            on_validator_event: None,
+            validator_stats: None,
         };
         let recursion_guard = &mut RecursionState::default();
         let mut state = ValidationState::new(extra, recursion_guard, false.into());
@@ -337,6 +587,9 @@ impl SchemaValidator {
         if let Some(ref py_config) = self.py_config {
             visit.call(py_config)?;
         }
+        if let Some(ref default_context) = self.default_context {
+            visit.call(default_context)?;
+        }
         Ok(())
     }
 }
@@ -350,24 +603,63 @@ impl SchemaValidator {
         input_type: InputType,
         strict: Option<bool>,
         from_attributes: Option<bool>,
+        partial: bool,
+        mark_invalid: bool,
         context: Option<&Bound<'py, PyAny>>,
         self_instance: Option<&Bound<'py, PyAny>>,
         allow_partial: PartialMode,
+        return_fields_set: bool,
+        on_validator_event: Option<&Bound<'py, PyAny>>,
+        validator_stats: Option<&ValidatorStats>,
     ) -> ValResult<PyObject> {
-        let mut recursion_guard = RecursionState::default();
-        let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-            ),
-            &mut recursion_guard,
-            allow_partial,
+        let merged_context = self.merge_context(py, context)?;
+        let mut extra = Extra::new(
+            strict,
+            from_attributes,
+            partial,
+            merged_context.as_ref(),
+            self_instance,
+            input_type,
+            self.cache_str,
         );
-        self.validator.validate(py, input, &mut state)
+        extra.mark_invalid = mark_invalid;
+        extra.on_validator_event = on_validator_event;
+        extra.validator_stats = validator_stats;
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(extra, &mut recursion_guard, allow_partial);
+        let output = if return_fields_set {
+            self.validate_with_fields_set(py, input, &mut state)?
+        } else {
+            self.validator.validate_traced(py, input, &mut state)?
+        };
+        state.commit_ignored_extras(context)?;
+        Ok(output)
+    }
+
+    /// With `return_fields_set`, model and typed dict roots return `(value, fields_set)`
+    fn validate_with_fields_set<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match self.validator {
+            CombinedValidator::TypedDict(_) => {
+                state.fields_set = Some(Vec::new());
+                let output = self.validator.validate_traced(py, input, state)?;
+                let fields_set = PySet::new_bound(py, &state.fields_set.take().unwrap_or_default())?;
+                Ok((output, fields_set).to_object(py))
+            }
+            CombinedValidator::Model(_) => {
+                let output = self.validator.validate_traced(py, input, state)?;
+                let fields_set = output
+                    .bind(py)
+                    .getattr(intern!(py, "__pydantic_fields_set__"))?
+                    .call_method0(intern!(py, "copy"))?;
+                Ok((output, fields_set).to_object(py))
+            }
+            _ => self.validator.validate_traced(py, input, state),
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -380,21 +672,152 @@ impl SchemaValidator {
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
+        on_progress: Option<&Bound<'_, PyAny>>,
+        partial: bool,
+        mark_invalid: bool,
+        decimal_floats: bool,
     ) -> ValResult<PyObject> {
-        let json_value = jiter::JsonValue::parse_with_config(json_data, true, allow_partial)
-            .map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(
+        let json_result = match on_progress {
+            Some(on_progress) => parse_json_with_progress(json_data, on_progress)?,
+            None => jiter::JsonValue::parse_with_config(json_data, true, allow_partial),
+        };
+        let json_value = json_result.map_err(|e| json::map_json_err(input, e, json_data))?;
+        self._validate_json_value(
             py,
             &json_value,
-            InputType::Json,
+            json_data,
             strict,
-            None,
             context,
             self_instance,
             allow_partial,
+            partial,
+            mark_invalid,
+            decimal_floats,
         )
     }
 
+    /// Validate each document of a buffer of concatenated JSON against the root schema, errors from every
+    /// document are raised together with the document's index prepended to their location.
+    #[allow(clippy::too_many_arguments)]
+    fn _validate_json_documents(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        json_data: &[u8],
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        partial: bool,
+        mark_invalid: bool,
+        decimal_floats: bool,
+    ) -> ValResult<PyObject> {
+        let documents = parse_json_documents(json_data).map_err(|e| json::map_json_err(input, e, json_data))?;
+        let mut output = Vec::with_capacity(documents.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, (json_value, document)) in documents.iter().enumerate() {
+            match self._validate_json_value(
+                py,
+                json_value,
+                document,
+                strict,
+                context,
+                None,
+                PartialMode::Off,
+                partial,
+                mark_invalid,
+                decimal_floats,
+            ) {
+                Ok(item) => output.push(item),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        if errors.is_empty() {
+            Ok(PyList::new_bound(py, output).into_py(py))
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn _validate_json_value(
+        &self,
+        py: Python,
+        json_value: &jiter::JsonValue<'_>,
+        json_data: &[u8],
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        allow_partial: PartialMode,
+        partial: bool,
+        mark_invalid: bool,
+        decimal_floats: bool,
+    ) -> ValResult<PyObject> {
+        if !decimal_floats {
+            return self._validate(
+                py,
+                json_value,
+                InputType::Json,
+                strict,
+                None,
+                partial,
+                mark_invalid,
+                context,
+                self_instance,
+                allow_partial,
+                false,
+                None,
+                None,
+            );
+        }
+
+        // the data has already been parsed successfully, so walking it again can't fail
+        let raw_floats = JsonRawFloats::collect(json_data, json_value)
+            .map_err(|e| ValError::InternalErr(PyValueError::new_err(e.to_string())))?;
+        let merged_context = self.merge_context(py, context)?;
+        let mut extra = Extra::new(
+            strict,
+            None,
+            partial,
+            merged_context.as_ref(),
+            self_instance,
+            InputType::Json,
+            self.cache_str,
+        );
+        extra.mark_invalid = mark_invalid;
+        extra.json_raw_floats = Some(&raw_floats);
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(extra, &mut recursion_guard, allow_partial);
+        let output = self.validator.validate_traced(py, json_value, &mut state)?;
+        state.commit_ignored_extras(context)?;
+        Ok(output)
+    }
+
+    /// Combine `default_context` with the context passed to a validation call, a dict passed per call is
+    /// merged on top of the defaults while any other context object replaces them entirely.
+    fn merge_context<'py>(
+        &self,
+        py: Python<'py>,
+        context: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let Some(default_context) = &self.default_context else {
+            return Ok(context.cloned());
+        };
+        // the defaults are copied so validators mutating `info.context` can't leak state between calls
+        let merged = default_context.bind(py).copy()?;
+        match context {
+            None => Ok(Some(merged.into_any())),
+            Some(context) => match context.downcast::<PyDict>() {
+                Ok(context_dict) => {
+                    merged.update(context_dict.as_mapping())?;
+                    Ok(Some(merged.into_any()))
+                }
+                Err(_) => Ok(Some(context.clone())),
+            },
+        }
+    }
+
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
         ValidationError::from_val_error(
             py,
@@ -408,6 +831,83 @@ impl SchemaValidator {
     }
 }
 
+/// Iterator returned by `SchemaValidator.iter_validate()`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug)]
+pub struct IterValidator {
+    validator: Py<SchemaValidator>,
+    iterator: Py<PyIterator>,
+    index: usize,
+    strict: Option<bool>,
+    from_attributes: Option<bool>,
+    context: Option<PyObject>,
+}
+
+#[pymethods]
+impl IterValidator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<(usize, PyObject)>> {
+        let Some(item) = self.iterator.bind(py).clone().next() else {
+            return Ok(None);
+        };
+        let item = item?;
+        let index = self.index;
+        self.index += 1;
+
+        let validator = self.validator.get();
+        let context = self.context.as_ref().map(|c| c.bind(py));
+        let result = match validator._validate(
+            py,
+            &item,
+            InputType::Python,
+            self.strict,
+            self.from_attributes,
+            false,
+            false,
+            context,
+            None,
+            false.into(),
+            false,
+            None,
+            None,
+        ) {
+            Ok(output) => output,
+            // validation errors are yielded rather than raised so the caller can carry on with the next item
+            Err(err @ ValError::LineErrors(_)) => validator
+                .prepare_validation_err(py, err, InputType::Python)
+                .into_value(py)
+                .into_py(py),
+            Err(err) => return Err(validator.prepare_validation_err(py, err, InputType::Python)),
+        };
+        Ok(Some((index, result)))
+    }
+
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        format!(
+            "IterValidator(index={}, title={:?})",
+            self.index,
+            self.validator.get().title.extract::<&str>(py).unwrap_or_default()
+        )
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.validator)?;
+        visit.call(&self.iterator)?;
+        if let Some(ref context) = self.context {
+            visit.call(context)?;
+        }
+        Ok(())
+    }
+}
+
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
 #[derive(Debug, Clone)]
@@ -428,11 +928,11 @@ impl<'py> SelfValidator<'py> {
         let py = schema.py();
         let mut recursion_guard = RecursionState::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, None, None, None, InputType::Python, true.into()),
+            Extra::new(strict, None, false, None, None, InputType::Python, true.into()),
             &mut recursion_guard,
             false.into(),
         );
-        match self.validator.validator.validate(py, schema, &mut state) {
+        match self.validator.validator.validate_traced(py, schema, &mut state) {
             Ok(schema_obj) => Ok(schema_obj.into_bound(py)),
             Err(e) => Err(SchemaError::from_val_error(py, e)),
         }
@@ -456,6 +956,7 @@ impl<'py> SelfValidator<'py> {
             definitions,
             py_schema: py.None(),
             py_config: None,
+            default_context: None,
             title: "Self Schema".into_py(py),
             hide_input_in_errors: false,
             validation_error_cause: false,
@@ -595,6 +1096,12 @@ pub fn build_validator(
         generator::GeneratorValidator,
         // custom error
         custom_error::CustomErrorValidator,
+        // constraints applied to the output of another validator
+        constraints::ConstraintsValidator,
+        // inputs which validate themselves
+        self_validating::SelfValidatingValidator,
+        // values hidden from reprs and serialization
+        secret::SecretValidator,
         // json data
         json::JsonValidator,
         // url types
@@ -606,6 +1113,8 @@ pub fn build_validator(
         definitions::DefinitionRefValidator,
         definitions::DefinitionsValidatorBuilder,
         complex::ComplexValidator,
+        fraction::FractionValidator,
+        base64::Base64Validator,
     )
 }
 
@@ -621,18 +1130,31 @@ pub struct Extra<'a, 'py> {
     pub strict: Option<bool>,
     /// Validation time setting of `from_attributes`
     pub from_attributes: Option<bool>,
+    /// Validation time setting of `partial`, typed dicts treat all keys as optional and don't fill in defaults
+    pub partial: bool,
+    /// Validation time setting of `mark_invalid`, model and typed dict fields which fail validation are set to an
+    /// `InvalidValue` holding their errors instead of raising
+    pub mark_invalid: bool,
     /// context used in validator functions
     pub context: Option<&'a Bound<'py, PyAny>>,
     /// This is an instance of the model or dataclass being validated, when validation is performed from `__init__`
     self_instance: Option<&'a Bound<'py, PyAny>>,
     /// Whether to use a cache of short strings to accelerate python string construction
     cache_str: StringCacheMode,
+    /// The original text of JSON float literals, when validating JSON with `json_float_mode='decimal'`
+    pub json_raw_floats: Option<&'a JsonRawFloats>,
+    /// Called with `('enter', name, location)` and `('exit', name, location)` around each validator, see
+    /// `validate_python`
+    pub on_validator_event: Option<&'a Bound<'py, PyAny>>,
+    /// Counts of the validators run, with `validate_python(..., return_validator_stats=True)`
+    pub validator_stats: Option<&'a ValidatorStats>,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
     pub fn new(
         strict: Option<bool>,
         from_attributes: Option<bool>,
+        partial: bool,
         context: Option<&'a Bound<'py, PyAny>>,
         self_instance: Option<&'a Bound<'py, PyAny>>,
         input_type: InputType,
@@ -643,9 +1165,14 @@ impl<'a, 'py> Extra<'a, 'py> {
             data: None,
             strict,
             from_attributes,
+            partial,
+            mark_invalid: false,
             context,
             self_instance,
             cache_str,
+            json_raw_floats: None,
+            on_validator_event: None,
+            validator_stats: None,
         }
     }
 }
@@ -657,9 +1184,14 @@ impl Extra<'_, '_> {
             data: self.data.clone(),
             strict: Some(true),
             from_attributes: self.from_attributes,
+            partial: self.partial,
+            mark_invalid: self.mark_invalid,
             context: self.context,
             self_instance: self.self_instance,
             cache_str: self.cache_str,
+            json_raw_floats: self.json_raw_floats,
+            on_validator_event: self.on_validator_event,
+            validator_stats: self.validator_stats,
         }
     }
 }
@@ -748,6 +1280,12 @@ pub enum CombinedValidator {
     Generator(generator::GeneratorValidator),
     // custom error
     CustomError(custom_error::CustomErrorValidator),
+    // constraints applied to the output of another validator
+    Constraints(constraints::ConstraintsValidator),
+    // inputs which validate themselves
+    SelfValidating(self_validating::SelfValidatingValidator),
+    // values hidden from reprs and serialization
+    Secret(secret::SecretValidator),
     // json data
     Json(json::JsonValidator),
     // url types
@@ -760,6 +1298,88 @@ pub enum CombinedValidator {
     // input dependent
     JsonOrPython(json_or_python::JsonOrPython),
     Complex(complex::ComplexValidator),
+    Fraction(fraction::FractionValidator),
+    Base64(base64::Base64Validator),
+}
+
+impl CombinedValidator {
+    /// `Validator::validate`, used to run nested validators so `validator_stats` are recorded and the
+    /// `on_validator_event` callback from `validate_python` is called as validation enters and exits them
+    #[inline]
+    pub fn validate_traced<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let extra = state.extra();
+        if extra.validator_stats.is_none() && extra.on_validator_event.is_none() {
+            return Validator::validate(self, py, input, state);
+        }
+        self.validate_with_events(py, input, state)
+    }
+
+    /// `validate_traced` for an item of the input, e.g. a list index or a dict key, `loc_item` is only converted
+    /// when an `on_validator_event` callback is set, to extend the location passed to it
+    #[inline]
+    pub fn validate_traced_at<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+        loc_item: impl Into<LocItem>,
+    ) -> ValResult<PyObject> {
+        if state.extra().on_validator_event.is_none() {
+            return self.validate_traced(py, input, state);
+        }
+        state.event_location.push(loc_item.into());
+        let result = self.validate_with_events(py, input, state);
+        state.event_location.pop();
+        result
+    }
+
+    fn validate_with_events<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(validator_stats) = state.extra().validator_stats {
+            validator_stats.record(self.get_name());
+        }
+        let Some(on_validator_event) = state.extra().on_validator_event else {
+            return Validator::validate(self, py, input, state);
+        };
+        let name = self.get_name();
+        let location = PyTuple::new_bound(py, &state.event_location);
+        on_validator_event.call1((intern!(py, "enter"), name, &location))?;
+        let result = Validator::validate(self, py, input, state);
+        on_validator_event.call1((intern!(py, "exit"), name, location))?;
+        result
+    }
+}
+
+/// How many times each validator ran during one `validate_python` call, in the order they first ran
+#[derive(Debug, Default)]
+pub struct ValidatorStats(RefCell<Vec<(String, usize)>>);
+
+impl ValidatorStats {
+    fn record(&self, name: &str) {
+        let mut counts = self.0.borrow_mut();
+        // there are few distinct validator names in a schema, so a linear search is fine
+        match counts.iter_mut().find(|(n, _)| n == name) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((name.to_string(), 1)),
+        }
+    }
+
+    fn into_dict(self, py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for (name, count) in self.0.into_inner() {
+            dict.set_item(name, count)?;
+        }
+        Ok(dict)
+    }
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
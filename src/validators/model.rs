@@ -54,6 +54,8 @@ impl Revalidate {
 pub struct ModelValidator {
     revalidate: Revalidate,
     validator: Box<CombinedValidator>,
+    // validates the output of root validators again when `revalidate_after_root` is set
+    revalidate_after_root: Option<Box<CombinedValidator>>,
     class: Py<PyType>,
     generic_origin: Option<Py<PyType>>,
     post_init: Option<Py<PyString>>,
@@ -62,8 +64,23 @@ pub struct ModelValidator {
     root_model: bool,
     undefined: PyObject,
     name: String,
+    // field names in the `__slots__` of the class or its bases, these are set as attributes rather than
+    // included in `__dict__`
+    slots: Vec<Py<PyString>>,
+    // false if instances have no `__dict__` because every class in the MRO defines `__slots__`
+    has_dict: bool,
+    // private attributes initialized from their defaults after the fields, stored in `__pydantic_private__`
+    private_fields: Vec<PrivateField>,
 }
 
+#[derive(Debug)]
+struct PrivateField {
+    name: Py<PyString>,
+    validator: CombinedValidator,
+}
+
+impl_py_gc_traverse!(PrivateField { validator });
+
 impl BuildValidator for ModelValidator {
     const EXPECTED_TYPE: &'static str = "model";
 
@@ -78,9 +95,36 @@ impl BuildValidator for ModelValidator {
 
         let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let generic_origin: Option<Bound<'_, PyType>> = schema.get_as(intern!(py, "generic_origin"))?;
-        let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
+        let sub_schema: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
         let validator = build_validator(&sub_schema, config.as_ref(), definitions)?;
+        let revalidate_after_root = match schema.get_as(intern!(py, "revalidate_after_root"))?.unwrap_or(false) {
+            true => schema_beneath_root_validators(&sub_schema)?
+                .map(|inner_schema| build_validator(&inner_schema, config.as_ref(), definitions).map(Box::new))
+                .transpose()?,
+            false => None,
+        };
         let name = class.getattr(intern!(py, "__name__"))?.extract()?;
+        let root_model = schema.get_as(intern!(py, "root_model"))?.unwrap_or(false);
+        let field_names = match root_model {
+            true => Some(vec![ROOT_FIELD.to_owned()]),
+            false => model_field_names(&sub_schema)?,
+        };
+        let (slots, has_dict) = model_slots(&class, field_names.as_deref())?;
+
+        let mut private_fields = Vec::new();
+        if let Some(private_fields_dict) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "private_fields"))? {
+            for (private_name, private_schema) in private_fields_dict {
+                let private_name: Bound<'_, PyString> = private_name.extract()?;
+                let validator = build_validator(&private_schema, config.as_ref(), definitions)?;
+                if !matches!(validator, CombinedValidator::WithDefault(_)) {
+                    return py_schema_err!("Private field \"{}\" must have a default", private_name);
+                }
+                private_fields.push(PrivateField {
+                    name: private_name.unbind(),
+                    validator,
+                });
+            }
+        }
 
         Ok(Self {
             revalidate: Revalidate::from_str(
@@ -94,15 +138,19 @@ impl BuildValidator for ModelValidator {
                 .transpose()?,
             )?,
             validator: Box::new(validator),
+            revalidate_after_root,
             class: class.into(),
             generic_origin: generic_origin.map(std::convert::Into::into),
             post_init: schema.get_as(intern!(py, "post_init"))?,
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
             custom_init: schema.get_as(intern!(py, "custom_init"))?.unwrap_or(false),
-            root_model: schema.get_as(intern!(py, "root_model"))?.unwrap_or(false),
+            root_model,
             undefined: PydanticUndefinedType::new(py).to_object(py),
             // Get the class's `__name__`, not using `class.qualname()`
             name,
+            slots,
+            has_dict,
+            private_fields,
         }
         .into())
     }
@@ -111,9 +159,114 @@ impl BuildValidator for ModelValidator {
 impl_py_gc_traverse!(ModelValidator {
     class,
     generic_origin,
-    validator
+    validator,
+    revalidate_after_root,
+    private_fields
 });
 
+/// Field names in the `__slots__` of `class` and its bases, and whether instances have a `__dict__`,
+/// without a `__dict__` pydantic's own attributes like `__pydantic_fields_set__` must be slots too.
+/// Other slots, e.g. for caches, are left alone unless `field_names` is `None`
+fn model_slots(class: &Bound<'_, PyType>, field_names: Option<&[String]>) -> PyResult<(Vec<Py<PyString>>, bool)> {
+    let py = class.py();
+    let mut slots = Vec::new();
+    let mut all_slots = Vec::new();
+    for base in class.getattr(intern!(py, "__mro__"))?.downcast::<PyTuple>()? {
+        let Some(base_slots) = base
+            .getattr(intern!(py, DUNDER_DICT))?
+            .get_item(intern!(py, "__slots__"))
+            .ok()
+        else {
+            continue;
+        };
+        // `__slots__` can be a single string or an iterable of strings
+        let base_slots: Vec<Bound<'_, PyString>> = match base_slots.downcast::<PyString>() {
+            Ok(slot) => vec![slot.clone()],
+            Err(_) => base_slots
+                .iter()?
+                .map(|slot| slot?.extract())
+                .collect::<PyResult<_>>()?,
+        };
+        for slot in base_slots {
+            let name = slot.to_str()?.to_owned();
+            let is_field = match field_names {
+                Some(field_names) => field_names.contains(&name),
+                None => !matches!(
+                    name.as_str(),
+                    DUNDER_DICT
+                        | "__weakref__"
+                        | DUNDER_FIELDS_SET_KEY
+                        | DUNDER_MODEL_EXTRA_KEY
+                        | DUNDER_MODEL_PRIVATE_KEY
+                ),
+            };
+            if is_field {
+                slots.push(slot.unbind());
+            }
+            all_slots.push(name);
+        }
+    }
+    // classes without `__slots__`, e.g. builtins, keep the default of setting `__dict__`
+    let has_dict = all_slots.is_empty() || class.getattr(intern!(py, "__dictoffset__"))?.extract::<isize>()? != 0;
+    if !has_dict {
+        for required in [DUNDER_FIELDS_SET_KEY, DUNDER_MODEL_EXTRA_KEY, DUNDER_MODEL_PRIVATE_KEY] {
+            if !all_slots.iter().any(|slot| slot == required) {
+                let name = class.getattr(intern!(py, "__name__"))?;
+                return py_schema_err!(
+                    "`{}` has no `__dict__`, so `__slots__` must include '{}', '{}' and '{}'",
+                    name,
+                    DUNDER_FIELDS_SET_KEY,
+                    DUNDER_MODEL_EXTRA_KEY,
+                    DUNDER_MODEL_PRIVATE_KEY
+                );
+            }
+        }
+    }
+    Ok((slots, has_dict))
+}
+
+/// The field names of the `model-fields` schema beneath any function validators, `None` if there isn't one,
+/// e.g. the fields are behind a `definition-ref`
+fn model_field_names(schema: &Bound<'_, PyDict>) -> PyResult<Option<Vec<String>>> {
+    let py = schema.py();
+    let mut current = schema.clone();
+    loop {
+        let schema_type: Bound<'_, PyString> = current.get_as_req(intern!(py, "type"))?;
+        match schema_type.to_str()? {
+            "model-fields" => {
+                let fields: Bound<'_, PyDict> = current.get_as_req(intern!(py, "fields"))?;
+                return fields
+                    .keys()
+                    .iter()
+                    .map(|name| name.extract())
+                    .collect::<PyResult<_>>()
+                    .map(Some);
+            }
+            "function-before" | "function-after" | "function-wrap" => {
+                current = current.get_as_req(intern!(py, "schema"))?;
+            }
+            _ => return Ok(None),
+        }
+    }
+}
+
+/// The schema wrapped by any `function-after` and `function-wrap` root validators, `None` if there are none
+fn schema_beneath_root_validators<'py>(schema: &Bound<'py, PyDict>) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = schema.py();
+    let mut inner_schema: Option<Bound<'py, PyDict>> = None;
+    let mut current = schema.clone();
+    loop {
+        let schema_type: Bound<'_, PyString> = current.get_as_req(intern!(py, "type"))?;
+        match schema_type.to_str()? {
+            "function-after" | "function-wrap" => {
+                current = current.get_as_req(intern!(py, "schema"))?;
+                inner_schema = Some(current.clone());
+            }
+            _ => return Ok(inner_schema),
+        }
+    }
+}
+
 impl Validator for ModelValidator {
     fn validate<'py>(
         &self,
@@ -159,13 +312,13 @@ impl Validator for ModelValidator {
                     self.validate_construct(py, &inner_input, Some(&fields_set), state)
                 } else {
                     // get dict here so from_attributes logic doesn't apply
-                    let dict = py_input.getattr(intern!(py, DUNDER_DICT))?;
+                    let dict = self.get_model_dict(py_input)?;
                     let model_extra = py_input.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?;
 
                     let inner_input = if PyAnyMethods::is_none(&model_extra) {
-                        dict
+                        dict.into_any()
                     } else {
-                        let full_model_dict = dict.downcast::<PyDict>()?.copy()?;
+                        let full_model_dict = dict.copy()?;
                         full_model_dict.update(model_extra.downcast()?)?;
                         full_model_dict.into_any()
                     };
@@ -202,13 +355,13 @@ impl Validator for ModelValidator {
                     field_name.to_string(),
                 ))
             } else {
-                let output = self.validator.validate(py, field_value, state)?;
+                let output = self.validator.validate_traced(py, field_value, state)?;
 
                 force_setattr(py, model, intern!(py, ROOT_FIELD), output)?;
                 Ok(model.into_py(py))
             };
         }
-        let old_dict = model.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+        let old_dict = self.get_model_dict(model)?;
 
         let input_dict = old_dict.copy()?;
         if let Ok(old_extra) = model.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?.downcast::<PyDict>() {
@@ -233,7 +386,7 @@ impl Validator for ModelValidator {
             }
         }
 
-        force_setattr(py, model, intern!(py, DUNDER_DICT), validated_dict.to_object(py))?;
+        self.set_model_dict(model, &validated_dict)?;
         force_setattr(
             py,
             model,
@@ -260,7 +413,7 @@ impl ModelValidator {
         // we need to set `self_instance` to None for nested validators as we don't want to operate on self_instance
         // anymore
         let state = &mut state.rebind_extra(|extra| extra.self_instance = None);
-        let output = self.validator.validate(py, input, state)?;
+        let output = self.validate_inner(py, input, state)?;
 
         if self.root_model {
             let fields_set = if input.to_object(py).is(&self.undefined) {
@@ -270,10 +423,12 @@ impl ModelValidator {
             };
             force_setattr(py, self_instance, intern!(py, DUNDER_FIELDS_SET_KEY), &fields_set)?;
             force_setattr(py, self_instance, intern!(py, ROOT_FIELD), &output)?;
+            self.set_private_attrs(py, self_instance, None, state)?;
         } else {
             let (model_dict, model_extra, fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
                 output.extract(py)?;
-            set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
+            self.set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
+            self.set_private_attrs(py, self_instance, Some(&model_dict), state)?;
         }
         self.call_post_init(py, self_instance.clone(), input, state.extra())
     }
@@ -299,7 +454,7 @@ impl ModelValidator {
             }
         }
 
-        let output = self.validator.validate(py, input, state)?;
+        let output = self.validate_inner(py, input, state)?;
 
         let instance = create_class(self.class.bind(py))?;
 
@@ -311,15 +466,142 @@ impl ModelValidator {
             };
             force_setattr(py, &instance, intern!(py, DUNDER_FIELDS_SET_KEY), &fields_set)?;
             force_setattr(py, &instance, intern!(py, ROOT_FIELD), output)?;
+            self.set_private_attrs(py, &instance, None, state)?;
         } else {
             let (model_dict, model_extra, val_fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
                 output.extract(py)?;
             let fields_set = existing_fields_set.unwrap_or(&val_fields_set);
-            set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+            self.set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+            self.set_private_attrs(py, &instance, Some(&model_dict), state)?;
         }
         self.call_post_init(py, instance, input, state.extra())
     }
 
+    /// Run the inner validator, then with `revalidate_after_root` validate the root validators' output
+    /// once more with the schema they wrap, so invariants hold after root validators transform the data
+    fn validate_inner<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let output = self.validator.validate_traced(py, input, state)?;
+        let Some(ref revalidator) = self.revalidate_after_root else {
+            return Ok(output);
+        };
+        if self.root_model {
+            return revalidator.validate_traced(py, output.bind(py), state);
+        }
+
+        let (model_dict, model_extra, fields_set): (Bound<PyDict>, Bound<PyAny>, Bound<PyAny>) = output.extract(py)?;
+        let inner_input = if PyAnyMethods::is_none(&model_extra) {
+            model_dict
+        } else {
+            let full_model_dict = model_dict.copy()?;
+            full_model_dict.update(model_extra.downcast()?)?;
+            full_model_dict
+        };
+        let revalidated = revalidator.validate_traced(py, inner_input.as_any(), state)?;
+        let (model_dict, model_extra, _): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) = revalidated.extract(py)?;
+        // the fields set by the input are the ones from the first pass, not every field of the revalidated dict
+        Ok((model_dict, model_extra, fields_set).to_object(py))
+    }
+
+    fn set_model_attrs(
+        &self,
+        instance: &Bound<'_, PyAny>,
+        model_dict: &Bound<'_, PyAny>,
+        model_extra: &Bound<'_, PyAny>,
+        fields_set: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let py = instance.py();
+        self.set_model_dict(instance, model_dict)?;
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)?;
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
+        force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
+        Ok(())
+    }
+
+    /// Validate the defaults of private fields into `__pydantic_private__`, default factories which take data are
+    /// given the validated fields, errors are located by the private name
+    fn set_private_attrs<'py>(
+        &self,
+        py: Python<'py>,
+        instance: &Bound<'py, PyAny>,
+        model_dict: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        if self.private_fields.is_empty() {
+            return Ok(());
+        }
+        let data = model_dict
+            .and_then(|model_dict| model_dict.downcast::<PyDict>().ok())
+            .cloned();
+        let state = &mut state.rebind_extra(|extra| extra.data = data);
+        let private = PyDict::new_bound(py);
+        let mut errors = Vec::new();
+        for private_field in &self.private_fields {
+            let private_name = private_field.name.bind(py);
+            match private_field
+                .validator
+                .default_value(py, Some(private_name.to_str()?), state)
+            {
+                Ok(Some(value)) => private.set_item(private_name, value)?,
+                Ok(None) => (),
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => return Err(err),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), private)?;
+        Ok(())
+    }
+
+    /// Set the validated fields on `instance`, fields with a slot are set as attributes and the rest form `__dict__`
+    fn set_model_dict(&self, instance: &Bound<'_, PyAny>, model_dict: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = instance.py();
+        if self.has_dict && self.slots.is_empty() {
+            return force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict);
+        }
+        let model_dict = model_dict.downcast::<PyDict>()?;
+        if !self.has_dict {
+            // without a `__dict__` there's nowhere else fields could go, so setting a field without a slot fails
+            for (field_name, value) in model_dict {
+                force_setattr(py, instance, field_name, value)?;
+            }
+            return Ok(());
+        }
+        let dict = model_dict.copy()?;
+        for slot in &self.slots {
+            if let Some(value) = dict.get_item(slot)? {
+                force_setattr(py, instance, slot, value)?;
+                dict.del_item(slot)?;
+            }
+        }
+        force_setattr(py, instance, intern!(py, DUNDER_DICT), dict)
+    }
+
+    /// The fields of `instance`, from `__dict__` and any slots, slots which were never set are skipped
+    fn get_model_dict<'py>(&self, instance: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+        let py = instance.py();
+        let dict = match (self.has_dict, self.slots.is_empty()) {
+            (true, true) => return Ok(instance.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?),
+            (true, false) => instance
+                .getattr(intern!(py, DUNDER_DICT))?
+                .downcast::<PyDict>()?
+                .copy()?,
+            (false, _) => PyDict::new_bound(py),
+        };
+        for slot in &self.slots {
+            if let Ok(value) = instance.getattr(slot.bind(py)) {
+                dict.set_item(slot, value)?;
+            }
+        }
+        Ok(dict)
+    }
+
     fn call_post_init<'py>(
         &self,
         py: Python<'py>,
@@ -357,20 +639,6 @@ pub(super) fn create_class<'py>(class: &Bound<'py, PyType>) -> PyResult<Bound<'p
     }
 }
 
-fn set_model_attrs(
-    instance: &Bound<'_, PyAny>,
-    model_dict: &Bound<'_, PyAny>,
-    model_extra: &Bound<'_, PyAny>,
-    fields_set: &Bound<'_, PyAny>,
-) -> PyResult<()> {
-    let py = instance.py();
-    force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict)?;
-    force_setattr(py, instance, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)?;
-    force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
-    force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
-    Ok(())
-}
-
 pub(super) fn force_setattr<N, V>(py: Python<'_>, obj: &Bound<'_, PyAny>, attr_name: N, value: V) -> PyResult<()>
 where
     N: ToPyObject,
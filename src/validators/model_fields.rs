@@ -1,14 +1,14 @@
 use pyo3::exceptions::PyKeyError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyType};
 
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
 use crate::errors::LocItem;
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, InvalidValue, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedDict, ValidationMatch};
 use crate::lookup_key::LookupKey;
@@ -23,9 +23,10 @@ struct Field {
     name_py: Py<PyString>,
     validator: CombinedValidator,
     frozen: bool,
+    examples: Option<Py<PyList>>,
 }
 
-impl_py_gc_traverse!(Field { validator });
+impl_py_gc_traverse!(Field { validator, examples });
 
 #[derive(Debug)]
 pub struct ModelFieldsValidator {
@@ -33,6 +34,8 @@ pub struct ModelFieldsValidator {
     model_name: String,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    // record the keys dropped by `extra_behavior='ignore'` in the validation context
+    extra_audit: bool,
     strict: bool,
     from_attributes: bool,
     loc_by_alias: bool,
@@ -59,6 +62,11 @@ impl BuildValidator for ModelFieldsValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extra_audit = match (schema.get_as(intern!(py, "extra_audit"))?, &extra_behavior) {
+            (Some(true), ExtraBehavior::Ignore) => true,
+            (Some(true), _) => return py_schema_err!("extra_audit can only be used if extra_behavior=ignore"),
+            (_, _) => false,
+        };
         let model_name: String = schema
             .get_as(intern!(py, "model_name"))?
             .unwrap_or_else(|| "Model".to_string());
@@ -89,9 +97,11 @@ impl BuildValidator for ModelFieldsValidator {
             fields.push(Field {
                 name: field_name.to_string(),
                 lookup_key,
-                name_py: field_name_py.into(),
+                // interned so output dicts of every validator with this field share a single key string
+                name_py: PyString::intern_bound(py, field_name).into(),
                 validator,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                examples: field_info.get_as(intern!(py, "examples"))?,
             });
         }
 
@@ -100,6 +110,7 @@ impl BuildValidator for ModelFieldsValidator {
             model_name,
             extra_behavior,
             extras_validator,
+            extra_audit,
             strict,
             from_attributes,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
@@ -122,6 +133,7 @@ impl Validator for ModelFieldsValidator {
     ) -> ValResult<PyObject> {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
+        let ignored_extras_start = state.ignored_extras.len();
 
         let strict = state.strict_or(self.strict);
         let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
@@ -158,7 +170,7 @@ impl Validator for ModelFieldsValidator {
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
         let mut used_keys: Option<AHashSet<&str>> =
-            if self.extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr() {
+            if (self.extra_behavior == ExtraBehavior::Ignore && !self.extra_audit) || dict.is_py_get_attr() {
                 None
             } else {
                 Some(AHashSet::with_capacity(self.fields.len()))
@@ -184,13 +196,26 @@ impl Validator for ModelFieldsValidator {
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    match field
+                        .validator
+                        .validate_traced_at(py, value.borrow_input(), state, lookup_path.first_key())
+                        .map_err(|err| err.with_examples(py, field.examples.as_ref()))
+                    {
                         Ok(value) => {
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
                             fields_set_count += 1;
                         }
                         Err(ValError::Omit) => continue,
+                        Err(ValError::LineErrors(line_errors)) if state.extra().mark_invalid => {
+                            // the field holds its input and errors rather than failing the whole model
+                            let input_type = state.extra().input_type;
+                            let input = value.borrow_input().to_object(py);
+                            let invalid = InvalidValue::new(py, input, line_errors, input_type);
+                            model_dict.set_item(&field.name_py, Py::new(py, invalid)?)?;
+                            fields_set_vec.push(field.name_py.clone_ref(py));
+                            fields_set_count += 1;
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -230,11 +255,13 @@ impl Validator for ModelFieldsValidator {
             }
         }
 
+        let mut ignored_extras: Vec<String> = Vec::new();
         if let Some(used_keys) = used_keys {
             struct ValidateToModelExtra<'a, 's, 'py> {
                 py: Python<'py>,
                 used_keys: AHashSet<&'a str>,
                 errors: &'a mut Vec<ValLineError>,
+                ignored_extras: &'a mut Vec<String>,
                 fields_set_vec: &'a mut Vec<Py<PyString>>,
                 extra_behavior: ExtraBehavior,
                 extras_validator: Option<&'a CombinedValidator>,
@@ -281,16 +308,16 @@ impl Validator for ModelFieldsValidator {
                         match self.extra_behavior {
                             ExtraBehavior::Forbid => {
                                 self.errors.push(ValLineError::new_with_loc(
-                                    ErrorTypeDefaults::ExtraForbidden,
+                                    ErrorType::extra_forbidden(value.to_object(self.py).bind(self.py))?,
                                     value,
                                     raw_key.clone(),
                                 ));
                             }
-                            ExtraBehavior::Ignore => {}
+                            ExtraBehavior::Ignore => self.ignored_extras.push(cow.into_owned()),
                             ExtraBehavior::Allow => {
-                                let py_key = either_str.as_py_string(self.py, self.state.cache_str());
+                                let py_key = either_str.as_py_key_string(self.py, self.state.cache_str());
                                 if let Some(validator) = self.extras_validator {
-                                    match validator.validate(self.py, value, self.state) {
+                                    match validator.validate_traced_at(self.py, value, self.state, raw_key.clone()) {
                                         Ok(value) => {
                                             model_extra_dict.set_item(&py_key, value)?;
                                             self.fields_set_vec.push(py_key.into());
@@ -317,6 +344,7 @@ impl Validator for ModelFieldsValidator {
                 py,
                 used_keys,
                 errors: &mut errors,
+                ignored_extras: &mut ignored_extras,
                 fields_set_vec: &mut fields_set_vec,
                 extra_behavior: self.extra_behavior,
                 extras_validator: self.extras_validator.as_deref(),
@@ -329,10 +357,15 @@ impl Validator for ModelFieldsValidator {
         }
 
         if !errors.is_empty() {
+            // keys ignored by nested models which were valid aren't kept if this model is invalid
+            state.ignored_extras.truncate(ignored_extras_start);
             Err(ValError::LineErrors(errors))
         } else {
             let fields_set = PySet::new_bound(py, &fields_set_vec)?;
             state.add_fields_set(fields_set_count);
+            state
+                .ignored_extras
+                .extend(ignored_extras.into_iter().map(|key| (self.model_name.clone(), key)));
 
             // if we have extra=allow, but we didn't create a dict because we were validating
             // from attributes, set it now so __pydantic_extra__ is always a dict if extra=allow
@@ -392,7 +425,12 @@ impl Validator for ModelFieldsValidator {
                     ));
                 }
 
-                prepare_result(field.validator.validate(py, field_value, state))?
+                prepare_result(
+                    field
+                        .validator
+                        .validate_traced_at(py, field_value, state, field_name)
+                        .map_err(|err| err.with_examples(py, field.examples.as_ref())),
+                )?
             } else {
                 // Handle extra (unknown) field
                 // We partially use the extra_behavior for initialization / validation
@@ -401,7 +439,9 @@ impl Validator for ModelFieldsValidator {
                 // unless the user explicitly set extra_behavior to 'allow'
                 match self.extra_behavior {
                     ExtraBehavior::Allow => match self.extras_validator {
-                        Some(ref validator) => prepare_result(validator.validate(py, field_value, state))?,
+                        Some(ref validator) => {
+                            prepare_result(validator.validate_traced_at(py, field_value, state, field_name))?
+                        }
                         None => get_updated_dict(field_value.to_object(py))?,
                     },
                     ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
@@ -41,7 +41,7 @@ impl Validator for NullableValidator {
     ) -> ValResult<PyObject> {
         match input.is_none() {
             true => Ok(py.None()),
-            false => self.validator.validate(py, input, state),
+            false => self.validator.validate_traced(py, input, state),
         }
     }
 
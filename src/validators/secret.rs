@@ -0,0 +1,139 @@
+use pyo3::basic::CompareOp;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::errors::{InputValue, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// What secret values are shown as, both in their `repr()` and when serialized
+pub(crate) const SECRET_PLACEHOLDER: &str = "**********";
+
+#[pyclass(module = "pydantic_core._pydantic_core", name = "Secret", frozen)]
+#[derive(Debug)]
+pub struct PySecret {
+    value: PyObject,
+}
+
+impl PySecret {
+    pub(crate) fn value<'py>(&self, py: Python<'py>) -> &Bound<'py, PyAny> {
+        self.value.bind(py)
+    }
+}
+
+#[pymethods]
+impl PySecret {
+    #[new]
+    pub fn py_new(value: PyObject) -> Self {
+        Self { value }
+    }
+
+    pub fn get_secret_value(&self, py: Python) -> PyObject {
+        self.value.clone_ref(py)
+    }
+
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp, py: Python<'_>) -> PyResult<PyObject> {
+        let Ok(other) = other.downcast::<Self>() else {
+            return Ok(py.NotImplemented());
+        };
+        match op {
+            CompareOp::Eq => Ok(self.value(py).eq(other.get().value(py))?.into_py(py)),
+            CompareOp::Ne => Ok(self.value(py).ne(other.get().value(py))?.into_py(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        self.value(py).hash()
+    }
+
+    fn __len__(&self, py: Python) -> PyResult<usize> {
+        self.value(py).len()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Secret('{SECRET_PLACEHOLDER}')")
+    }
+
+    pub fn __str__(&self) -> &'static str {
+        SECRET_PLACEHOLDER
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (_item, /))]
+    pub fn __class_getitem__(cls: Py<PyType>, _item: &Bound<'_, PyAny>) -> Py<PyType> {
+        cls
+    }
+
+    pub fn __reduce__(&self, py: Python) -> (Py<PyType>, (PyObject,)) {
+        (py.get_type_bound::<Self>().unbind(), (self.value.clone_ref(py),))
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.value)
+    }
+}
+
+/// Validates the wrapped value and returns it inside a `Secret`, `Secret` inputs are unwrapped first
+#[derive(Debug)]
+pub struct SecretValidator {
+    validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for SecretValidator {
+    const EXPECTED_TYPE: &'static str = "secret";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self { validator, name }.into())
+    }
+}
+
+impl_py_gc_traverse!(SecretValidator { validator });
+
+impl Validator for SecretValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let result = match input
+            .as_python()
+            .and_then(|py_input| py_input.downcast::<PySecret>().ok())
+        {
+            Some(secret) => self.validator.validate_traced(py, secret.get().value(py), state),
+            None => self.validator.validate_traced(py, input, state),
+        };
+        match result {
+            Ok(output) => Ok(PySecret::py_new(output).into_py(py)),
+            // wrap the input of errors too, so the value doesn't show up in `ValidationError`'s message
+            Err(ValError::LineErrors(line_errors)) => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|mut line_error| {
+                        let value = PySecret::py_new(line_error.input_value.to_object(py));
+                        line_error.input_value = InputValue::Python(value.into_py(py));
+                        line_error
+                    })
+                    .collect(),
+            )),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
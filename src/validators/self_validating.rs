@@ -0,0 +1,75 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::function::{convert_err, ValidationInfo};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Objects whose type defines this method validate themselves, it's called with a `ValidationInfo`
+/// and its return value is used as the validated value
+const VALIDATE_METHOD: &str = "__pydantic_validate__";
+
+/// Delegates validation to the input's `__pydantic_validate__` method when its type defines one,
+/// anything else is validated by the wrapped validator.
+#[derive(Debug)]
+pub struct SelfValidatingValidator {
+    validator: Box<CombinedValidator>,
+    config: PyObject,
+    name: String,
+}
+
+impl BuildValidator for SelfValidatingValidator {
+    const EXPECTED_TYPE: &'static str = "self-validating";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            validator,
+            config: match config {
+                Some(c) => c.clone().into(),
+                None => py.None(),
+            },
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(SelfValidatingValidator { validator, config });
+
+impl Validator for SelfValidatingValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let method_name = intern!(py, VALIDATE_METHOD);
+        match input.as_python() {
+            // looked up on the type so instance attributes can't opt in by accident
+            Some(py_input) if py_input.get_type().hasattr(method_name)? => {
+                let info = ValidationInfo::new(py, state.extra(), &self.config, None);
+                py_input
+                    .call_method1(method_name, (info,))
+                    .map(Bound::unbind)
+                    .map_err(|err| convert_err(py, err, input))
+            }
+            _ => self.validator.validate_traced(py, input, state),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
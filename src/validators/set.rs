@@ -1,11 +1,11 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
-use crate::errors::ValResult;
-use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
+use crate::errors::{ValError, ValResult};
+use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, SkipItems, ValidatedSet};
 use crate::tools::SchemaDict;
 
-use super::list::min_length_check;
+use super::list::{min_length_check, CoerceScalar};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -16,6 +16,8 @@ pub struct SetValidator {
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    skip_items: SkipItems,
+    coerce_scalar: CoerceScalar,
 }
 
 macro_rules! set_build {
@@ -44,6 +46,8 @@ macro_rules! set_build {
                 max_length,
                 name,
                 fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+                skip_items: crate::validators::list::get_skip_items(schema)?,
+                coerce_scalar: crate::validators::list::get_coerce_scalar(schema)?,
             }
             .into())
         }
@@ -65,9 +69,13 @@ impl Validator for SetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
+        let collection = match input.validate_set(state.strict_or(self.strict)) {
+            Ok(collection) => Some(collection.unpack(state)),
+            Err(ValError::LineErrors(_)) if self.coerce_scalar.applies(input) => None,
+            Err(err) => return Err(err),
+        };
         let set = PySet::empty_bound(py)?;
-        collection.iterate(ValidateToSet {
+        let consumer = ValidateToSet {
             py,
             input,
             set: &set,
@@ -75,7 +83,13 @@ impl Validator for SetValidator {
             item_validator: &self.item_validator,
             state,
             fail_fast: self.fail_fast,
-        })??;
+            skip_items: self.skip_items,
+        };
+        match collection {
+            Some(collection) => collection.iterate(consumer)??,
+            // validate the input as the only item
+            None => consumer.consume_iterator(std::iter::once(PyResult::Ok(input)))?,
+        }
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
     }
@@ -93,6 +107,7 @@ struct ValidateToSet<'a, 's, 'py, I: Input<'py> + ?Sized> {
     item_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
     fail_fast: bool,
+    skip_items: SkipItems,
 }
 
 impl<'py, T, I> ConsumeIterator<PyResult<T>> for ValidateToSet<'_, '_, 'py, I>
@@ -112,6 +127,7 @@ where
             self.item_validator,
             self.state,
             self.fail_fast,
+            self.skip_items,
         )
     }
 }
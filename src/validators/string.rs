@@ -1,11 +1,17 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use ahash::AHashMap;
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::sync::GILProtected;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
 
 use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{maybe_as_enum, EitherString, Input};
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -14,6 +20,7 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 pub struct StrValidator {
     strict: bool,
     coerce_numbers_to_str: bool,
+    coerce_enum: bool,
 }
 
 impl BuildValidator for StrValidator {
@@ -32,6 +39,7 @@ impl BuildValidator for StrValidator {
             Ok(Self {
                 strict: con_str_validator.strict,
                 coerce_numbers_to_str: con_str_validator.coerce_numbers_to_str,
+                coerce_enum: con_str_validator.coerce_enum,
             }
             .into())
         }
@@ -47,9 +55,14 @@ impl Validator for StrValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)
-            .map(|val_match| val_match.unpack(state).as_py_string(py, state.cache_str()).into_py(py))
+        let strict = state.strict_or(self.strict);
+        let enum_value = coerce_enum_value(input, self.coerce_enum)?;
+        let val_match = match &enum_value {
+            Some(value) => value.validate_str(strict, self.coerce_numbers_to_str)?,
+            None => input.validate_str(strict, self.coerce_numbers_to_str)?,
+        };
+        let either_str = val_match.unpack(state);
+        Ok(either_str.as_py_string(py, state.cache_str()).into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -65,9 +78,18 @@ pub struct StrConstrainedValidator {
     max_length: Option<usize>,
     min_length: Option<usize>,
     strip_whitespace: bool,
+    collapse_whitespace: bool,
+    collapse_unicode_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
+    forbid_control_chars: bool,
+    allow_control_whitespace: bool,
     coerce_numbers_to_str: bool,
+    coerce_enum: bool,
+    gt: Option<String>,
+    ge: Option<String>,
+    lt: Option<String>,
+    le: Option<String>,
 }
 
 impl_py_gc_traverse!(StrConstrainedValidator {});
@@ -79,17 +101,27 @@ impl Validator for StrConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_str = input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let enum_value = coerce_enum_value(input, self.coerce_enum)?;
+        let either_str = match &enum_value {
+            Some(value) => value.validate_str(strict, self.coerce_numbers_to_str)?,
+            None => input.validate_str(strict, self.coerce_numbers_to_str)?,
+        }
+        .unpack(state);
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
         if self.strip_whitespace {
             str = str.trim();
         }
+        let collapsed = if self.collapse_whitespace {
+            collapse_whitespace(str, self.collapse_unicode_whitespace)
+        } else {
+            Cow::Borrowed(str)
+        };
+        let str = collapsed.as_ref();
 
         let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
-            Some(str.chars().count())
+            Some(char_count(&either_str, str))
         } else {
             None
         };
@@ -128,11 +160,35 @@ impl Validator for StrConstrainedValidator {
             }
         }
 
-        let py_string = if self.to_lower {
-            state.maybe_cached_str(py, &str.to_lowercase())
+        if self.forbid_control_chars {
+            let control_char = str
+                .chars()
+                .enumerate()
+                .find(|(_, c)| c.is_control() && !(self.allow_control_whitespace && matches!(c, '\t' | '\n' | '\r')));
+            if let Some((position, c)) = control_char {
+                return Err(ValError::new(
+                    ErrorType::StringControlChar {
+                        code_point: format!("U+{:04X}", c as u32),
+                        position,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        let transformed = if self.to_lower {
+            Some(str.to_lowercase())
         } else if self.to_upper {
-            state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+            Some(str.to_uppercase())
+        } else {
+            None
+        };
+        // bounds are checked against the transformed string
+        let str = transformed.as_deref().unwrap_or(str);
+        self.check_bounds(str, input)?;
+
+        let py_string = if transformed.is_some() || self.strip_whitespace || self.collapse_whitespace {
             state.maybe_cached_str(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
@@ -179,13 +235,26 @@ impl StrConstrainedValidator {
             intern!(py, "str_strip_whitespace"),
         )?
         .unwrap_or(false);
+        let collapse_whitespace: bool = schema.get_as(intern!(py, "collapse_whitespace"))?.unwrap_or(false);
+        let collapse_unicode_whitespace: bool = schema
+            .get_as(intern!(py, "collapse_unicode_whitespace"))?
+            .unwrap_or(true);
         let to_lower: bool =
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
             schema_or_config(schema, config, intern!(py, "to_upper"), intern!(py, "str_to_upper"))?.unwrap_or(false);
 
+        let forbid_control_chars: bool = schema.get_as(intern!(py, "forbid_control_chars"))?.unwrap_or(false);
+        let allow_control_whitespace: bool = schema.get_as(intern!(py, "allow_control_whitespace"))?.unwrap_or(false);
+
         let coerce_numbers_to_str: bool =
             schema_or_config_same(schema, config, intern!(py, "coerce_numbers_to_str"))?.unwrap_or(false);
+        let coerce_enum: bool = schema.get_as(intern!(py, "coerce_enum"))?.unwrap_or(false);
+
+        let gt: Option<String> = schema.get_as(intern!(py, "gt"))?;
+        let ge: Option<String> = schema.get_as(intern!(py, "ge"))?;
+        let lt: Option<String> = schema.get_as(intern!(py, "lt"))?;
+        let le: Option<String> = schema.get_as(intern!(py, "le"))?;
 
         Ok(Self {
             strict: is_strict(schema, config)?,
@@ -193,22 +262,158 @@ impl StrConstrainedValidator {
             min_length,
             max_length,
             strip_whitespace,
+            collapse_whitespace,
+            collapse_unicode_whitespace,
             to_lower,
             to_upper,
+            forbid_control_chars,
+            allow_control_whitespace,
             coerce_numbers_to_str,
+            coerce_enum,
+            gt,
+            ge,
+            lt,
+            le,
         })
     }
 
+    /// Comparison is by code point, the same as comparing `str`s in Python, so isn't locale-aware
+    fn check_bounds<'py>(&self, str: &str, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        if let Some(gt) = &self.gt {
+            if str <= gt.as_str() {
+                return Err(ValError::new(
+                    ErrorType::GreaterThan {
+                        gt: gt.clone().into(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ge) = &self.ge {
+            if str < ge.as_str() {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual {
+                        ge: ge.clone().into(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(lt) = &self.lt {
+            if str >= lt.as_str() {
+                return Err(ValError::new(
+                    ErrorType::LessThan {
+                        lt: lt.clone().into(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(le) = &self.le {
+            if str > le.as_str() {
+                return Err(ValError::new(
+                    ErrorType::LessThanEqual {
+                        le: le.clone().into(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     // whether any of the constraints/customisations are actually enabled
-    // except strict and coerce_numbers_to_str which can be set on StrValidator
+    // except strict, coerce_numbers_to_str and coerce_enum which can be set on StrValidator
     fn has_constraints_set(&self) -> bool {
         self.pattern.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
             || self.strip_whitespace
+            || self.collapse_whitespace
             || self.to_lower
             || self.to_upper
+            || self.forbid_control_chars
+            || self.gt.is_some()
+            || self.ge.is_some()
+            || self.lt.is_some()
+            || self.le.is_some()
+    }
+}
+
+/// With `coerce_enum`, enum members are replaced by their value before the string checks, in strict mode too,
+/// members whose value isn't a string are rejected
+fn coerce_enum_value<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    coerce_enum: bool,
+) -> ValResult<Option<Bound<'py, PyAny>>> {
+    if !coerce_enum {
+        return Ok(None);
+    }
+    match input.as_python().and_then(maybe_as_enum) {
+        Some(value) if value.is_instance_of::<PyString>() => Ok(Some(value)),
+        Some(_) => Err(ValError::new(ErrorTypeDefaults::StringType, input)),
+        None => Ok(None),
+    }
+}
+
+/// Replaces each run of whitespace in `str` with a single space, only ASCII whitespace counts unless `unicode` is set
+fn collapse_whitespace(str: &str, unicode: bool) -> Cow<'_, str> {
+    let is_whitespace = |c: char| {
+        if unicode {
+            c.is_whitespace()
+        } else {
+            c.is_ascii_whitespace()
+        }
+    };
+    let mut after_whitespace = false;
+    let unchanged = str.chars().all(|c| {
+        let whitespace = is_whitespace(c);
+        let kept = !whitespace || (c == ' ' && !after_whitespace);
+        after_whitespace = whitespace;
+        kept
+    });
+    if unchanged {
+        return Cow::Borrowed(str);
     }
+
+    let mut collapsed = String::with_capacity(str.len());
+    let mut in_whitespace = false;
+    for c in str.chars() {
+        if is_whitespace(c) {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    Cow::Owned(collapsed)
+}
+
+/// The number of characters in `str`, taken from `either_str`; when that's a python string known to be ASCII,
+/// which also holds after stripping whitespace, the byte length is used instead of iterating over code points
+fn char_count(either_str: &EitherString<'_>, str: &str) -> usize {
+    match either_str {
+        EitherString::Py(py_str) if is_ascii(py_str) => str.len(),
+        _ => str.chars().count(),
+    }
+}
+
+#[cfg(not(PyPy))]
+fn is_ascii(py_str: &Bound<'_, PyString>) -> bool {
+    // Safety: `py_str` is a valid `str` object, which are always in the canonical representation
+    unsafe { pyo3::ffi::PyUnicode_IS_ASCII(py_str.as_ptr()) != 0 }
+}
+
+#[cfg(PyPy)]
+fn is_ascii(_py_str: &Bound<'_, PyString>) -> bool {
+    false
 }
 
 #[derive(Debug, Clone)]
@@ -219,7 +424,7 @@ struct Pattern {
 
 #[derive(Debug, Clone)]
 enum RegexEngine {
-    RustRegex(Regex),
+    RustRegex(Arc<Regex>),
     PythonRe(PyObject),
 }
 
@@ -228,6 +433,27 @@ impl RegexEngine {
     const PYTHON_RE: &'static str = "python-re";
 }
 
+/// Compiled rust regexes keyed by pattern, so validators with the same pattern share one `Regex`
+type RegexCache = AHashMap<String, Arc<Regex>>;
+
+static RUST_REGEX_CACHE: GILProtected<RefCell<Option<RegexCache>>> = GILProtected::new(RefCell::new(None));
+
+/// Patterns beyond this many are still compiled but not cached, so the cache can't grow without bound
+const RUST_REGEX_CACHE_CAPACITY: usize = 1000;
+
+fn compile_rust_regex(py: Python<'_>, pattern: &str) -> PyResult<Arc<Regex>> {
+    let mut cache = RUST_REGEX_CACHE.get(py).borrow_mut();
+    let cache = cache.get_or_insert_with(AHashMap::new);
+    if let Some(regex) = cache.get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Arc::new(Regex::new(pattern).map_err(|e| py_schema_error_type!("{}", e))?);
+    if cache.len() < RUST_REGEX_CACHE_CAPACITY {
+        cache.insert(pattern.to_owned(), regex.clone());
+    }
+    Ok(regex)
+}
+
 impl Pattern {
     fn extract_pattern_str(pattern: &Bound<'_, PyAny>) -> PyResult<String> {
         if pattern.is_instance_of::<PyString>() {
@@ -258,9 +484,7 @@ impl Pattern {
             })
         } else {
             let engine = match engine {
-                RegexEngine::RUST_REGEX => {
-                    RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
-                }
+                RegexEngine::RUST_REGEX => RegexEngine::RustRegex(compile_rust_regex(py, &pattern_str)?),
                 RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
             };
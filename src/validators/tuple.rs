@@ -2,14 +2,16 @@ use std::collections::VecDeque;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyDict, PyFrozenSet, PyIterator, PyList, PySet, PyString, PyTuple, PyType};
 
-use crate::build_tools::is_strict;
-use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{plural_s, py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedTuple};
 use crate::tools::SchemaDict;
 
+use super::function::convert_err;
+use super::list::{get_coerce_scalar, CoerceScalar};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -21,6 +23,47 @@ pub struct TupleValidator {
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    allow_from: Option<Vec<TupleInputKind>>,
+    coerce_scalar: CoerceScalar,
+    one_based_index: bool,
+    // a `NamedTuple` created from the validated items rather than a plain tuple
+    cls: Option<Py<PyType>>,
+}
+
+/// Kinds of python input which the tuple validator may consume in lax mode, see `allow_from`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TupleInputKind {
+    List,
+    Tuple,
+    Set,
+    Generator,
+}
+
+impl TupleInputKind {
+    fn from_str(kind: &str) -> PyResult<Self> {
+        match kind {
+            "list" => Ok(Self::List),
+            "tuple" => Ok(Self::Tuple),
+            "set" => Ok(Self::Set),
+            "generator" => Ok(Self::Generator),
+            _ => py_schema_err!("Invalid tuple allow_from kind: '{}'", kind),
+        }
+    }
+
+    /// `None` for iterables which aren't iterators, e.g. `dict.keys()`, no `allow_from` kind covers those
+    fn of(obj: &Bound<'_, PyAny>) -> Option<Self> {
+        if obj.is_instance_of::<PyTuple>() {
+            Some(Self::Tuple)
+        } else if obj.is_instance_of::<PyList>() {
+            Some(Self::List)
+        } else if obj.is_instance_of::<PySet>() || obj.is_instance_of::<PyFrozenSet>() {
+            Some(Self::Set)
+        } else if obj.downcast::<PyIterator>().is_ok() {
+            Some(Self::Generator)
+        } else {
+            None
+        }
+    }
 }
 
 impl BuildValidator for TupleValidator {
@@ -45,6 +88,21 @@ impl BuildValidator for TupleValidator {
         }
         let name = format!("tuple[{}]", validator_names.join(", "));
 
+        let cls: Option<Bound<'_, PyType>> = schema.get_as(intern!(py, "cls"))?;
+        if let Some(ref cls) = cls {
+            check_named_tuple(cls, validators.len(), variadic_item_index)?;
+        }
+
+        let allow_from = schema
+            .get_as::<Bound<'_, PyList>>(intern!(py, "allow_from"))?
+            .map(|kinds| {
+                kinds
+                    .iter()
+                    .map(|kind| TupleInputKind::from_str(kind.downcast::<PyString>()?.to_str()?))
+                    .collect::<PyResult<Vec<_>>>()
+            })
+            .transpose()?;
+
         Ok(Self {
             strict: is_strict(schema, config)?,
             validators,
@@ -53,12 +111,43 @@ impl BuildValidator for TupleValidator {
             max_length: schema.get_as(intern!(py, "max_length"))?,
             name,
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            allow_from,
+            coerce_scalar: get_coerce_scalar(schema)?,
+            one_based_index: schema.get_as(intern!(py, "one_based_index"))?.unwrap_or(false),
+            cls: cls.map(Bound::unbind),
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(TupleValidator { validators });
+/// `cls` must be a named tuple with a field for each item
+fn check_named_tuple(cls: &Bound<'_, PyType>, item_count: usize, variadic_item_index: Option<usize>) -> PyResult<()> {
+    let py = cls.py();
+    let fields = match cls.getattr(intern!(py, "_fields")) {
+        Ok(fields) if cls.is_subclass_of::<PyTuple>()? => fields,
+        _ => return py_schema_err!("`cls` must be a named tuple, not `{}`", cls.qualname()?),
+    };
+    if variadic_item_index.is_some() {
+        return py_schema_err!(
+            "`variadic_item_index` can't be used with the named tuple `{}`",
+            cls.qualname()?
+        );
+    }
+    let field_count = fields.len()?;
+    if field_count != item_count {
+        return py_schema_err!(
+            "named tuple `{}` has {} field{} but `items_schema` has {} item{}",
+            cls.qualname()?,
+            field_count,
+            plural_s(field_count),
+            item_count,
+            plural_s(item_count)
+        );
+    }
+    Ok(())
+}
+
+impl_py_gc_traverse!(TupleValidator { validators, cls });
 
 impl TupleValidator {
     #[allow(clippy::too_many_arguments)]
@@ -77,14 +166,16 @@ impl TupleValidator {
         // Validate the head:
         for validator in item_validators {
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
-                    Ok(item) => self.push_output_item(input, output, item, actual_length)?,
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                Some((index, input_item)) => {
+                    match validator.validate_traced_at(py, input_item.borrow_input(), state, index) {
+                        Ok(item) => self.push_output_item(input, output, item, actual_length)?,
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(ValError::Omit) => (),
+                        Err(err) => return Err(err),
                     }
-                    Err(ValError::Omit) => (),
-                    Err(err) => return Err(err),
-                },
+                }
                 None => {
                     let index = collection_iter.next_calls() - 1;
                     if let Some(value) = validator.default_value(py, Some(index), state)? {
@@ -145,7 +236,7 @@ impl TupleValidator {
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
-                    match variable_validator.validate(py, input_item.borrow_input(), state) {
+                    match variable_validator.validate_traced_at(py, input_item.borrow_input(), state, index) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -176,7 +267,12 @@ impl TupleValidator {
                     let buffered_item = tail_buffer.pop_front().unwrap();
                     tail_buffer.push_back(input_item);
 
-                    match variable_validator.validate(py, buffered_item.borrow_input(), state) {
+                    match variable_validator.validate_traced_at(
+                        py,
+                        buffered_item.borrow_input(),
+                        state,
+                        buffer_item_index,
+                    ) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
@@ -241,6 +337,15 @@ impl TupleValidator {
         Ok(output)
     }
 
+    /// Errors from validating items, with indices counted from 1 if `one_based_index` is set
+    fn index_errors(&self, err: ValError) -> ValError {
+        if self.one_based_index {
+            err.with_one_based_index()
+        } else {
+            err
+        }
+    }
+
     fn push_output_item<'py>(
         &self,
         input: &(impl Input<'py> + ?Sized),
@@ -276,19 +381,37 @@ impl Validator for TupleValidator {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
 
-        let collection = input.validate_tuple(state.strict_or(self.strict))?.unpack(state);
-        let actual_length = collection.len();
+        let strict = state.strict_or(self.strict);
+        if let (false, Some(allow_from), Some(obj)) = (strict, &self.allow_from, input.as_python()) {
+            let allowed = TupleInputKind::of(obj).is_some_and(|kind| allow_from.contains(&kind));
+            if !allowed && !self.coerce_scalar.applies(input) {
+                return Err(ValError::new(ErrorTypeDefaults::TupleType, input));
+            }
+        }
+
+        let collection = match input.validate_tuple(strict) {
+            Ok(collection) => Some(collection.unpack(state)),
+            Err(ValError::LineErrors(_)) if self.coerce_scalar.applies(input) => None,
+            Err(err) => return Err(err),
+        };
+        let actual_length = collection.as_ref().map_or(Some(1), ValidatedTuple::len);
 
         let mut errors: Vec<ValLineError> = Vec::new();
 
-        let output = collection.iterate(ValidateToTuple {
+        let consumer = ValidateToTuple {
             py,
             input,
             actual_length,
             validator: self,
             errors: &mut errors,
             state,
-        })??;
+        };
+        let output = match collection {
+            Some(collection) => collection.iterate(consumer)?,
+            // validate the input as the only item
+            None => consumer.consume_iterator(std::iter::once(PyResult::Ok(input))),
+        }
+        .map_err(|err| self.index_errors(err))?;
 
         if let Some(min_length) = self.min_length {
             let actual_length = output.len();
@@ -305,10 +428,16 @@ impl Validator for TupleValidator {
             }
         }
 
-        if errors.is_empty() {
-            Ok(PyTuple::new_bound(py, output).into_py(py))
+        if !errors.is_empty() {
+            Err(self.index_errors(ValError::LineErrors(errors)))
+        } else if let Some(ref cls) = self.cls {
+            let args = PyTuple::new_bound(py, output);
+            match cls.bind(py).call1(args) {
+                Ok(instance) => Ok(instance.unbind()),
+                Err(err) => Err(convert_err(py, err, input)),
+            }
         } else {
-            Err(ValError::LineErrors(errors))
+            Ok(PyTuple::new_bound(py, output).into_py(py))
         }
     }
 
@@ -1,11 +1,11 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
 use crate::errors::LocItem;
-use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, InvalidValue, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::ValidationMatch;
@@ -24,9 +24,10 @@ struct TypedDictField {
     name_py: Py<PyString>,
     required: bool,
     validator: CombinedValidator,
+    examples: Option<Py<PyList>>,
 }
 
-impl_py_gc_traverse!(TypedDictField { validator });
+impl_py_gc_traverse!(TypedDictField { validator, examples });
 
 #[derive(Debug)]
 pub struct TypedDictValidator {
@@ -119,9 +120,11 @@ impl BuildValidator for TypedDictValidator {
             fields.push(TypedDictField {
                 name: field_name.to_string(),
                 lookup_key,
-                name_py: field_name_py.into(),
+                // interned so output dicts of every validator with this field share a single key string
+                name_py: PyString::intern_bound(py, field_name).into(),
                 validator,
                 required,
+                examples: field_info.get_as(intern!(py, "examples"))?,
             });
         }
         Ok(Self {
@@ -169,6 +172,7 @@ impl Validator for TypedDictValidator {
                 Some(AHashSet::with_capacity(self.fields.len()))
             };
 
+        let mut fields_set = state.fields_set.take();
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
             let mut fields_set_count: usize = 0;
@@ -203,17 +207,35 @@ impl Validator for TypedDictValidator {
                         true => allow_partial,
                         false => false.into(),
                     };
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    match field
+                        .validator
+                        .validate_traced_at(py, value.borrow_input(), state, lookup_path.first_key())
+                        .map_err(|err| err.with_examples(py, field.examples.as_ref()))
+                    {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
                             fields_set_count += 1;
+                            if let Some(ref mut fields_set) = fields_set {
+                                fields_set.push(field.name_py.clone_ref(py));
+                            }
                         }
                         Err(ValError::Omit) => continue,
+                        // errors in the last field are ignored with partial validation, unless it's required
+                        Err(ValError::LineErrors(_)) if is_last_partial && !field.required => {}
+                        Err(ValError::LineErrors(line_errors)) if state.extra().mark_invalid => {
+                            // the field holds its input and errors rather than failing the whole typed dict
+                            let input_type = state.extra().input_type;
+                            let input = value.borrow_input().to_object(py);
+                            let invalid = InvalidValue::new(py, input, line_errors, input_type);
+                            output_dict.set_item(&field.name_py, Py::new(py, invalid)?)?;
+                            fields_set_count += 1;
+                            if let Some(ref mut fields_set) = fields_set {
+                                fields_set.push(field.name_py.clone_ref(py));
+                            }
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
-                            if !is_last_partial || field.required {
-                                for err in line_errors {
-                                    errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
-                                }
+                            for err in line_errors {
+                                errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
                             }
                         }
                         Err(err) => return Err(err),
@@ -221,6 +243,11 @@ impl Validator for TypedDictValidator {
                     continue;
                 }
 
+                if state.extra().partial {
+                    // partial validation: missing keys are left out rather than defaulted or reported
+                    continue;
+                }
+
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
@@ -253,6 +280,7 @@ impl Validator for TypedDictValidator {
 
             state.add_fields_set(fields_set_count);
         }
+        state.fields_set = fields_set;
 
         if let Some(used_keys) = used_keys {
             struct ValidateExtras<'a, 's, 'py> {
@@ -303,14 +331,14 @@ impl Validator for TypedDictValidator {
                         match self.extra_behavior {
                             ExtraBehavior::Forbid => {
                                 self.errors.push(ValLineError::new_with_loc(
-                                    ErrorTypeDefaults::ExtraForbidden,
+                                    ErrorType::extra_forbidden(value.to_object(self.py).bind(self.py))?,
                                     value,
                                     raw_key.clone(),
                                 ));
                             }
                             ExtraBehavior::Ignore => {}
                             ExtraBehavior::Allow => {
-                                let py_key = either_str.as_py_string(self.py, self.state.cache_str());
+                                let py_key = either_str.as_py_key_string(self.py, self.state.cache_str());
                                 if let Some(validator) = self.extras_validator {
                                     let last_partial = self.partial_last_key.as_ref().map_or(false, |last_key| {
                                         let key_loc: LocItem = raw_key.clone().into();
@@ -320,7 +348,7 @@ impl Validator for TypedDictValidator {
                                         true => self.allow_partial,
                                         false => false.into(),
                                     };
-                                    match validator.validate(self.py, value, self.state) {
+                                    match validator.validate_traced_at(self.py, value, self.state, raw_key.clone()) {
                                         Ok(value) => {
                                             self.output_dict.set_item(py_key, value)?;
                                         }
@@ -364,6 +392,62 @@ impl Validator for TypedDictValidator {
         }
     }
 
+    fn validate_assignment<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let dict = obj.downcast::<PyDict>()?;
+
+        // as with model fields, validators see the rest of the dict as `info.data`
+        let data_dict = dict.copy()?;
+        if data_dict.contains(field_name)? {
+            data_dict.del_item(field_name)?;
+        }
+        let state = &mut state.rebind_extra(move |extra| extra.data = Some(data_dict));
+
+        let result = if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
+            field
+                .validator
+                .validate_traced_at(py, field_value, state, field_name)
+                .map_err(|err| err.with_examples(py, field.examples.as_ref()))
+        } else {
+            match (self.extra_behavior, &self.extras_validator) {
+                (ExtraBehavior::Allow, Some(validator)) => {
+                    validator.validate_traced_at(py, field_value, state, field_name)
+                }
+                (ExtraBehavior::Allow, None) => Ok(field_value.to_object(py)),
+                (ExtraBehavior::Forbid | ExtraBehavior::Ignore, _) => {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::NoSuchAttribute {
+                            attribute: field_name.to_string(),
+                            context: None,
+                        },
+                        field_value,
+                        field_name.to_string(),
+                    ))
+                }
+            }
+        };
+
+        match result {
+            Ok(output) => {
+                dict.set_item(field_name, output)?;
+                Ok(dict.to_object(py))
+            }
+            Err(ValError::LineErrors(line_errors)) => Err(ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|e| e.with_outer_location(field_name))
+                    .collect(),
+            )),
+            Err(err) => Err(err),
+        }
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
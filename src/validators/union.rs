@@ -16,6 +16,7 @@ use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
 use super::literal::LiteralLookup;
+use super::validation_state::IgnoredExtras;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
 };
@@ -101,6 +102,18 @@ impl BuildValidator for UnionValidator {
 }
 
 impl UnionValidator {
+    fn validate_mode<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match self.mode {
+            UnionMode::Smart => self.validate_smart(py, input, state),
+            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
+        }
+    }
+
     fn validate_smart<'py>(
         &self,
         py: Python<'py>,
@@ -113,7 +126,9 @@ impl UnionValidator {
         let strict = state.strict_or(self.strict);
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
-        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
+        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>, IgnoredExtras)> = None;
+        // keys ignored by `extra_audit` models are only kept for the choice which is used
+        let ignored_extras_start = state.ignored_extras.len();
 
         for (choice, label) in &self.choices {
             let state = &mut state.rebind_extra(|extra| {
@@ -123,7 +138,7 @@ impl UnionValidator {
             });
             state.exactness = Some(Exactness::Exact);
             state.fields_set_count = None;
-            let result = choice.validate(py, input, state);
+            let result = choice.validate_traced(py, input, state);
             match result {
                 Ok(new_success) => match (state.exactness, state.fields_set_count) {
                     (Some(Exactness::Exact), None) => {
@@ -151,19 +166,21 @@ impl UnionValidator {
                         let new_success_is_best_match: bool =
                             best_match
                                 .as_ref()
-                                .map_or(true, |(_, cur_exactness, cur_fields_set_count)| {
+                                .map_or(true, |(_, cur_exactness, cur_fields_set_count, _)| {
                                     match (*cur_fields_set_count, new_fields_set_count) {
                                         (Some(cur), Some(new)) if cur != new => cur < new,
                                         _ => *cur_exactness < new_exactness,
                                     }
                                 });
 
+                        let ignored_extras = state.ignored_extras.split_off(ignored_extras_start);
                         if new_success_is_best_match {
-                            best_match = Some((new_success, new_exactness, new_fields_set_count));
+                            best_match = Some((new_success, new_exactness, new_fields_set_count, ignored_extras));
                         }
                     }
                 },
                 Err(ValError::LineErrors(lines)) => {
+                    state.ignored_extras.truncate(ignored_extras_start);
                     // if we don't yet know this validation will succeed, record the error
                     if best_match.is_none() {
                         errors.push(choice, label.as_deref(), lines);
@@ -177,8 +194,9 @@ impl UnionValidator {
         state.exactness = old_exactness;
         state.fields_set_count = old_fields_set_count;
 
-        if let Some((best_match, exactness, fields_set_count)) = best_match {
+        if let Some((best_match, exactness, fields_set_count, ignored_extras)) = best_match {
             state.floor_exactness(exactness);
+            state.ignored_extras.extend(ignored_extras);
             if let Some(count) = fields_set_count {
                 state.add_fields_set(count);
             }
@@ -205,9 +223,13 @@ impl UnionValidator {
             state
         };
 
+        let ignored_extras_start = state.ignored_extras.len();
         for (validator, label) in &self.choices {
-            match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
+            match validator.validate_traced(py, input, state) {
+                Err(ValError::LineErrors(lines)) => {
+                    state.ignored_extras.truncate(ignored_extras_start);
+                    errors.push(validator, label.as_deref(), lines);
+                }
                 otherwise => return otherwise,
             };
         }
@@ -230,9 +252,16 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        match self.mode {
-            UnionMode::Smart => self.validate_smart(py, input, state),
-            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
+        if state.extra().mark_invalid {
+            // with `mark_invalid` every model or typed dict choice would "succeed" with `InvalidValue` fields,
+            // so choices are tried without it first and marked values are only used if no choice is valid
+            let result = self.validate_mode(py, input, &mut state.rebind_extra(|extra| extra.mark_invalid = false));
+            match result {
+                Err(ValError::LineErrors(_)) => self.validate_mode(py, input, state),
+                otherwise => otherwise,
+            }
+        } else {
+            self.validate_mode(py, input, state)
         }
     }
 
@@ -319,26 +348,42 @@ impl BuildValidator for TaggedUnionValidator {
         let discriminator = Discriminator::new(py, &schema.get_as_req(intern!(py, "discriminator"))?)?;
         let discriminator_repr = discriminator.to_string_py(py)?;
 
+        let max_tags_in_error: Option<usize> = schema.get_as(intern!(py, "max_tags_in_error"))?;
+        if max_tags_in_error == Some(0) {
+            return py_schema_err!("`max_tags_in_error` must be greater than 0");
+        }
+
         let choices = PyDict::new_bound(py);
         let mut tags_repr = String::with_capacity(50);
         let mut descr = String::with_capacity(50);
         let mut first = true;
         let schema_choices: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
         let mut lookup_map = Vec::with_capacity(choices.len());
-        for (choice_key, choice_schema) in schema_choices {
+        for (index, (choice_key, choice_schema)) in schema_choices.iter().enumerate() {
             let validator = build_validator(&choice_schema, config, definitions)?;
-            let tag_repr = choice_key.repr()?.to_string();
+            // only the tags listed in `union_tag_invalid` errors are truncated, all choices are still validated
+            if max_tags_in_error.map_or(true, |max_tags| index < max_tags) {
+                let tag_repr = choice_key.repr()?.to_string();
+                if index == 0 {
+                    write!(tags_repr, "{tag_repr}").unwrap();
+                } else {
+                    write!(tags_repr, ", {tag_repr}").unwrap();
+                }
+            }
             if first {
                 first = false;
-                write!(tags_repr, "{tag_repr}").unwrap();
                 descr.push_str(validator.get_name());
             } else {
-                write!(tags_repr, ", {tag_repr}").unwrap();
                 // no spaces in get_name() output to make loc easy to read
                 write!(descr, ",{}", validator.get_name()).unwrap();
             }
             lookup_map.push((choice_key, validator));
         }
+        if let Some(max_tags) = max_tags_in_error {
+            if lookup_map.len() > max_tags {
+                write!(tags_repr, " and {} more", lookup_map.len() - max_tags).unwrap();
+            }
+        }
 
         let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
 
@@ -405,7 +450,7 @@ impl TaggedUnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
-            return match validator.validate(py, input, state) {
+            return match validator.validate_traced(py, input, state) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(err.with_outer_location(tag)),
             };
@@ -1,8 +1,10 @@
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyList, PyString};
 
 use jiter::{PartialMode, StringCacheMode};
 
+use crate::errors::LocItem;
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
@@ -15,6 +17,9 @@ pub enum Exactness {
     Exact,
 }
 
+/// Keys dropped by models with `extra_audit`, as `(model_name, key)`
+pub type IgnoredExtras = Vec<(String, String)>;
+
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
@@ -25,6 +30,14 @@ pub struct ValidationState<'a, 'py> {
     pub fields_set_count: Option<usize>,
     // True if `allow_partial=true` and we're validating the last element of a sequence or mapping.
     pub allow_partial: PartialMode,
+    // Names of the fields set by the input for `validate_python(return_fields_set=True)`, collected by the outermost
+    // typed dict which takes this so nested typed dicts don't add their fields.
+    pub fields_set: Option<Vec<Py<PyString>>>,
+    // Keys dropped by models with `extra_audit` as `(model_name, key)`, only written to the context once validation
+    // succeeds, union choices which aren't used and models which fail validation discard the keys they added.
+    pub ignored_extras: IgnoredExtras,
+    // Location in the input of the validator being run, only kept while an `on_validator_event` callback is set.
+    pub event_location: Vec<LocItem>,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -36,6 +49,9 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             exactness: None,
             fields_set_count: None,
             allow_partial,
+            fields_set: None,
+            ignored_extras: Vec::new(),
+            event_location: Vec::new(),
             extra,
         }
     }
@@ -92,6 +108,36 @@ impl<'a, 'py> ValidationState<'a, 'py> {
     pub fn maybe_cached_str(&self, py: Python<'py>, s: &str) -> Bound<'py, PyString> {
         new_py_string(py, s, self.extra.cache_str)
     }
+
+    /// Write `ignored_extras` to the context passed by the caller, rather than the one merged with `default_context`,
+    /// once validation has succeeded
+    pub fn commit_ignored_extras(&mut self, context: Option<&Bound<'py, PyAny>>) -> PyResult<()> {
+        audit_ignored_extras(context, std::mem::take(&mut self.ignored_extras))
+    }
+}
+
+/// Append `ignored_extras` to `context['ignored_extras']` as `(model_name, key)` pairs, the list is created if it's
+/// missing, nothing is recorded unless the context is a dict
+pub fn audit_ignored_extras(context: Option<&Bound<'_, PyAny>>, ignored_extras: IgnoredExtras) -> PyResult<()> {
+    if ignored_extras.is_empty() {
+        return Ok(());
+    }
+    let Some(context) = context.and_then(|context| context.downcast::<PyDict>().ok()) else {
+        return Ok(());
+    };
+    let py = context.py();
+    let list = match context.get_item(intern!(py, "ignored_extras"))? {
+        Some(list) => list,
+        None => {
+            let list = PyList::empty_bound(py).into_any();
+            context.set_item(intern!(py, "ignored_extras"), &list)?;
+            list
+        }
+    };
+    for entry in ignored_extras {
+        list.call_method1(intern!(py, "append"), (entry,))?;
+    }
+    Ok(())
 }
 
 impl ContainsRecursionState for ValidationState<'_, '_> {
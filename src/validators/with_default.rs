@@ -16,11 +16,16 @@ use crate::tools::SchemaDict;
 use crate::PydanticUndefinedType;
 
 static COPY_DEEPCOPY: GILOnceCell<PyObject> = GILOnceCell::new();
+static COPY_COPY: GILOnceCell<PyObject> = GILOnceCell::new();
 
 fn get_deepcopy(py: Python) -> PyResult<PyObject> {
     Ok(py.import_bound("copy")?.getattr("deepcopy")?.into_py(py))
 }
 
+fn get_copy(py: Python) -> PyResult<PyObject> {
+    Ok(py.import_bound("copy")?.getattr("copy")?.into_py(py))
+}
+
 #[derive(Debug, Clone)]
 pub enum DefaultType {
     None,
@@ -84,13 +89,33 @@ enum OnError {
     Default,
 }
 
+/// How the default value is copied each time it's used, so mutable defaults aren't shared between validations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CopyDefault {
+    None,
+    Shallow,
+    Deep,
+}
+
+impl CopyDefault {
+    fn copy(self, py: Python, value: PyObject) -> PyResult<PyObject> {
+        match self {
+            Self::None => Ok(value),
+            Self::Shallow => COPY_COPY.get_or_init(py, || get_copy(py).unwrap()).call1(py, (value,)),
+            Self::Deep => COPY_DEEPCOPY
+                .get_or_init(py, || get_deepcopy(py).unwrap())
+                .call1(py, (value,)),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct WithDefaultValidator {
     default: DefaultType,
     on_error: OnError,
     validator: Box<CombinedValidator>,
     validate_default: bool,
-    copy_default: bool,
+    copy_default: CopyDefault,
     name: String,
     undefined: PyObject,
 }
@@ -127,10 +152,22 @@ impl BuildValidator for WithDefaultValidator {
         let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
         let validator = Box::new(build_validator(&sub_schema, config, definitions)?);
 
-        let copy_default = if let DefaultType::Default(default_obj) = &default {
-            default_obj.bind(py).hash().is_err()
-        } else {
-            false
+        let copy_default = match schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "copy_on_default"))?
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+        {
+            Some("none") => CopyDefault::None,
+            Some("shallow") => CopyDefault::Shallow,
+            Some("deep") => CopyDefault::Deep,
+            // by default, unhashable (so probably mutable) defaults like lists and dicts are deep copied
+            None => match &default {
+                DefaultType::Default(default_obj) if default_obj.bind(py).hash().is_err() => CopyDefault::Deep,
+                _ => CopyDefault::None,
+            },
+            // schema validation means other values are impossible
+            _ => unreachable!(),
         };
 
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
@@ -160,7 +197,7 @@ impl Validator for WithDefaultValidator {
         if input.to_object(py).is(&self.undefined) {
             Ok(self.default_value(py, None::<usize>, state)?.unwrap())
         } else {
-            match self.validator.validate(py, input, state) {
+            match self.validator.validate_traced(py, input, state) {
                 Ok(v) => Ok(v),
                 Err(e) => match e {
                     ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
@@ -182,12 +219,7 @@ impl Validator for WithDefaultValidator {
     ) -> ValResult<Option<PyObject>> {
         match self.default.default_value(py, &state.extra().data)? {
             Some(stored_dft) => {
-                let dft: Py<PyAny> = if self.copy_default {
-                    let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
-                    deepcopy_func.call1(py, (&stored_dft,))?.into_py(py)
-                } else {
-                    stored_dft
-                };
+                let dft = self.copy_default.copy(py, stored_dft)?;
                 if self.validate_default {
                     match self.validate(py, dft.bind(py), state) {
                         Ok(v) => Ok(Some(v)),
@@ -1472,3 +1472,26 @@ def test_enum_str_core(benchmark):
     assert v.validate_python('apple') is FooStr.a
 
     benchmark(v.validate_python, 'apple')
+
+
+@pytest.mark.benchmark(group='dict keys')
+@pytest.mark.parametrize('cache_strings', ['keys', False])
+def test_typed_dict_extra_keys_json(benchmark, cache_strings):
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {'id': core_schema.typed_dict_field(core_schema.int_schema())}, extra_behavior='allow'
+            )
+        ),
+        {'cache_strings': cache_strings},
+    )
+    json_data = json.dumps([{'id': i, 'name': 'x', 'value': i} for i in range(100_000)])
+    benchmark(v.validate_json, json_data)
+
+
+@pytest.mark.benchmark(group='str length')
+@pytest.mark.parametrize('char', ['a', 'é'], ids=['ascii', 'non-ascii'])
+def test_str_max_length_large(benchmark, char):
+    v = SchemaValidator(core_schema.str_schema(max_length=2_000_000))
+    input_str = char * 1_000_000
+    benchmark(v.validate_python, input_str)
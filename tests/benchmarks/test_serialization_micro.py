@@ -62,6 +62,15 @@ class TestBenchmarkSimpleModel:
         assert json.loads(core_serializer.to_json(m)) == self.data
         benchmark(core_serializer.to_json, m)
 
+    @pytest.mark.benchmark(group='serialize simple model - JSON')
+    def test_core_json_serializer_context(
+        self, core_validator: SchemaValidator, core_serializer: SchemaSerializer, benchmark
+    ):
+        m = core_validator.validate_python(self.data)
+        ctx = core_serializer.serializer_context(exclude_none=True, warnings=False)
+        assert json.loads(ctx.to_json(m)) == self.data
+        benchmark(ctx.to_json, m)
+
 
 @pytest.mark.benchmark(group='list-of-str')
 def test_json_direct_list_str(benchmark):
@@ -8,8 +8,9 @@ from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from enum import Enum
 from math import inf, isinf, isnan, nan
-from pathlib import Path
-from typing import ClassVar
+from pathlib import Path, PurePosixPath, PureWindowsPath
+from typing import Any, ClassVar, Optional
+from uuid import UUID, uuid1, uuid4
 
 import pytest
 from dirty_equals import HasRepr, IsList
@@ -129,6 +130,40 @@ def test_any_json(any_serializer, value, expected_json):
     assert any_serializer.to_python(value, mode='json') == json.loads(expected_json)
 
 
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (PurePosixPath('/foo/bar/spam.svg'), '/foo/bar/spam.svg'),
+        (PureWindowsPath(r'C:\foo\bar\spam.svg'), r'C:\foo\bar\spam.svg'),
+        (PurePosixPath('foo'), 'foo'),
+    ],
+    ids=repr,
+)
+def test_any_pure_path(any_serializer, value, expected):
+    assert any_serializer.to_python(value) is value
+    assert any_serializer.to_python(value, mode='json') == expected
+    assert json.loads(any_serializer.to_json(value)) == expected
+    assert any_serializer.to_python({value: 1}, mode='json') == {expected: 1}
+
+
+@pytest.mark.parametrize(
+    'value',
+    [
+        UUID('ebcdab58-6eb8-46fb-a190-d07a33e9eac8'),
+        UUID('{12345678-1234-5678-1234-567812345678}'),
+        UUID(int=0),
+        uuid1(),
+        uuid4(),
+    ],
+    ids=repr,
+)
+def test_any_uuid(any_serializer, value):
+    assert any_serializer.to_python(value) is value
+    assert any_serializer.to_python(value, mode='json') == str(value)
+    assert any_serializer.to_json(value) == f'"{value}"'.encode()
+    assert any_serializer.to_python({value: 1}, mode='json') == {str(value): 1}
+
+
 def test_other_type():
     """Types with no serializer, fall back to any serializer"""
     v = SchemaSerializer(core_schema.is_instance_schema(int))
@@ -536,6 +571,24 @@ def test_dataclass_classvar(any_serializer):
     assert any_serializer.to_json(foo2) == b'{"a":2,"b":"b"}'
 
 
+def test_dataclass_include_exclude_none(any_serializer):
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+        b: Optional[str] = None
+        c: Any = None
+
+    foo = Foo(1, c=Foo(2, 'x'))
+    assert any_serializer.to_python(foo) == {'a': 1, 'b': None, 'c': {'a': 2, 'b': 'x', 'c': None}}
+    assert any_serializer.to_python(foo, exclude_none=True) == {'a': 1, 'c': {'a': 2, 'b': 'x'}}
+    assert any_serializer.to_python(foo, mode='json', exclude_none=True) == {'a': 1, 'c': {'a': 2, 'b': 'x'}}
+    assert any_serializer.to_json(foo, exclude_none=True) == b'{"a":1,"c":{"a":2,"b":"x"}}'
+    assert to_json(foo, exclude_none=True) == b'{"a":1,"c":{"a":2,"b":"x"}}'
+
+    assert any_serializer.to_python(foo, include={'a': ..., 'c': {'b'}}) == {'a': 1, 'c': {'b': 'x'}}
+    assert any_serializer.to_json(foo, exclude={'b': ..., 'c': {'a'}}) == b'{"a":1,"c":{"b":"x","c":null}}'
+
+
 @pytest.mark.skipif(sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10')
 def test_dataclass_slots(any_serializer):
     @dataclasses.dataclass(slots=True)
@@ -0,0 +1,37 @@
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def test_base64():
+    s = SchemaSerializer(core_schema.base64_schema())
+    assert s.to_python(b'hello?') == 'aGVsbG8/'
+    assert s.to_python(b'hello?', mode='json') == 'aGVsbG8/'
+    assert s.to_json(b'hello?') == b'"aGVsbG8/"'
+    assert s.to_json(b'hi') == b'"aGk="'
+
+
+def test_base64_url_safe():
+    s = SchemaSerializer(core_schema.base64_schema(url_safe=True))
+    assert s.to_json(b'hello?') == b'"aGVsbG8_"'
+
+
+def test_base64_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.base64_schema(), core_schema.int_schema()))
+    assert s.to_json({b'hi': 1}) == b'{"aGk=":1}'
+
+
+@pytest.mark.parametrize('url_safe', [False, True])
+def test_round_trip(url_safe):
+    schema = core_schema.base64_schema(url_safe=url_safe, allow_missing_padding=True)
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    value = bytes(range(256))
+    assert v.validate_python(s.to_python(value)) == value
+    assert v.validate_json(s.to_json(value)) == value
+
+
+def test_unexpected_type():
+    s = SchemaSerializer(core_schema.base64_schema())
+    with pytest.warns(UserWarning, match='Expected `base64` but got `str`'):
+        assert s.to_python('x') == 'x'
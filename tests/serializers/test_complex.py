@@ -2,7 +2,7 @@ import math
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 @pytest.mark.parametrize(
@@ -37,3 +37,16 @@ def test_complex_json(value, expected):
         assert math.isnan(c.real)
     else:
         assert c.imag == value.imag
+
+
+def test_complex_json_dict():
+    v = SchemaSerializer(core_schema.complex_schema(), {'ser_json_complex': 'dict'})
+    assert v.to_python(complex(1, 2)) == complex(1, 2)
+    assert v.to_python(complex(1, 2), mode='json') == {'real': 1.0, 'imag': 2.0}
+    assert v.to_json(complex(1, 2)) == b'{"real":1.0,"imag":2.0}'
+    assert v.to_json(complex(0, -1.5)) == b'{"real":0.0,"imag":-1.5}'
+
+
+def test_complex_json_invalid_mode():
+    with pytest.raises(SchemaError, match='Invalid ComplexMode serialization mode: `foobar`'):
+        SchemaSerializer(core_schema.complex_schema(), {'ser_json_complex': 'foobar'})
@@ -71,6 +71,27 @@ def test_serialization_exclude():
         assert j == b'{"a":"hello"}'
 
 
+def test_serialization_exclude_if():
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.str_schema()),
+                core_schema.dataclass_field(
+                    name='b', schema=core_schema.bytes_schema(), serialization_exclude_if=lambda v: v == b''
+                ),
+            ],
+        ),
+        ['a', 'b'],
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(Foo(a='hello', b=b'more')) == {'a': 'hello', 'b': b'more'}
+    assert s.to_python(Foo(a='hello', b=b'')) == {'a': 'hello'}
+    assert s.to_python(Foo(a='hello', b=b''), mode='json') == {'a': 'hello'}
+    assert json.loads(s.to_json(Foo(a='hello', b=b''))) == {'a': 'hello'}
+
+
 def test_serialization_alias():
     schema = core_schema.dataclass_schema(
         Foo,
@@ -59,3 +59,48 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (Decimal('1'), '1.00'),
+        (Decimal('1.005'), '1.00'),
+        (Decimal('1.015'), '1.02'),
+        (Decimal('-0.5'), '-0.50'),
+        (Decimal('1E+3'), '1000.00'),
+        (Decimal('0'), '0.00'),
+        (Decimal('Infinity'), 'Infinity'),
+        (Decimal('NaN'), 'NaN'),
+    ],
+)
+def test_ser_decimal_places(value, expected):
+    v = SchemaSerializer(core_schema.decimal_schema(ser_decimal_places=2))
+    output = v.to_python(value)
+    assert isinstance(output, Decimal)
+    assert str(output) == expected
+    assert v.to_python(value, mode='json') == expected
+    assert v.to_json(value).decode() == f'"{expected}"'
+
+
+def test_ser_decimal_places_no_exponent():
+    v = SchemaSerializer(core_schema.decimal_schema(ser_decimal_places=10))
+    assert v.to_python(Decimal('1E-7'), mode='json') == '0.0000001000'
+    assert v.to_python(Decimal('1E-7')) == Decimal('1E-7')
+
+    v = SchemaSerializer(core_schema.decimal_schema(ser_decimal_places=0))
+    assert v.to_json(Decimal('2.5')) == b'"2"'
+
+
+def test_ser_decimal_places_precision():
+    v = SchemaSerializer(core_schema.decimal_schema(ser_decimal_places=2))
+    assert v.to_json(Decimal('1e30')) == b'"1000000000000000000000000000000.00"'
+    assert v.to_json(Decimal('12345678901234567890123456789.125')) == b'"12345678901234567890123456789.12"'
+    assert v.to_python(Decimal('9' * 30 + '.999')) == Decimal('1' + '0' * 30 + '.00')
+
+
+def test_ser_decimal_places_key():
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.decimal_schema(ser_decimal_places=1), core_schema.int_schema())
+    )
+    assert v.to_json({Decimal('1'): 1}) == b'{"1.0":1}'
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
 
 
 def test_branch_nullable():
@@ -122,3 +122,60 @@ def test_recursive_function_deeper_ref():
         )
     )
     assert s.to_python({'a': {'b': {'b': {}}}}) == {'a': {'b': {'b': {}}}}
+
+
+def test_max_depth():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'sub_branch': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                        ),
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    v = {'name': 'a', 'sub_branch': {'name': 'b', 'sub_branch': {'name': 'c', 'sub_branch': None}}}
+    assert s.to_python(v, max_depth=3) == v
+
+    with pytest.warns(UserWarning, match='Maximum serialization depth of 2 exceeded, serialized `None` instead'):
+        assert s.to_python(v, max_depth=2) == {'name': 'a', 'sub_branch': {'name': 'b', 'sub_branch': None}}
+    with pytest.warns(UserWarning, match='Maximum serialization depth of 2 exceeded'):
+        assert s.to_json(v, max_depth=2) == b'{"name":"a","sub_branch":{"name":"b","sub_branch":null}}'
+    with pytest.raises(PydanticSerializationError, match='Maximum serialization depth of 1 exceeded'):
+        s.to_json(v, max_depth=1, warnings='error')
+
+    ctx = s.serializer_context(max_depth=1, warnings=False)
+    assert ctx.to_python(v) == {'name': 'a', 'sub_branch': None}
+
+
+def test_max_depth_cyclic():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'sub_branch': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                        ),
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    v = {'name': 'a'}
+    v['sub_branch'] = {'name': 'b', 'sub_branch': v}
+    # the placeholder is emitted before the cycle is reached
+    with pytest.warns(UserWarning, match='Maximum serialization depth of 2 exceeded'):
+        assert s.to_python(v, max_depth=2) == {'name': 'a', 'sub_branch': {'name': 'b', 'sub_branch': None}}
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_python(v, max_depth=3)
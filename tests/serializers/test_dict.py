@@ -158,3 +158,39 @@ def test_include_error(include_value, error_msg):
         validate_core_schema(
             core_schema.dict_schema(serialization=core_schema.filter_dict_schema(include=include_value))
         )
+
+
+def test_exclude_key_pattern():
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.str_schema(),
+            core_schema.int_schema(),
+            serialization=core_schema.filter_dict_schema(exclude_key_pattern='^_'),
+        )
+    )
+    assert s.to_python({'_a': 1, 'b': 2, '_c': 3}) == {'b': 2}
+    assert s.to_python({'_a': 1, 'b': 2, '_c': 3}, mode='json') == {'b': 2}
+    assert s.to_json({'_a': 1, 'b': 2, '_c': 3}) == b'{"b":2}'
+    # an explicit include wins over the pattern
+    assert s.to_python({'_a': 1, 'b': 2, '_c': 3}, include={'_c', 'b'}) == {'b': 2, '_c': 3}
+    assert s.to_json({'_a': 1, 'b': 2, '_c': 3}, include={'_c'}) == b'{"_c":3}'
+
+
+def test_exclude_key_pattern_schema_include():
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.int_schema(),
+            core_schema.int_schema(),
+            serialization=core_schema.filter_dict_schema(include={1, 2, 10}, exclude_key_pattern=r'^\d$'),
+        )
+    )
+    # keys are matched in their serialized form
+    assert s.to_python({1: 1, 2: 2, 3: 3, 10: 10}) == {1: 1, 2: 2, 10: 10}
+    assert s.to_json({1: 1, 3: 3, 10: 10, 11: 11}) == b'{"1":1,"10":10}'
+
+
+def test_exclude_key_pattern_invalid():
+    with pytest.raises(SchemaError, match='Invalid exclude_key_pattern: regex parse error'):
+        SchemaSerializer(
+            core_schema.dict_schema(serialization=core_schema.filter_dict_schema(exclude_key_pattern='(oops'))
+        )
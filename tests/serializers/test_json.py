@@ -1,4 +1,6 @@
-from pydantic_core import SchemaSerializer, core_schema
+import pytest
+
+from pydantic_core import RawJson, SchemaSerializer, SchemaValidator, core_schema
 
 
 def test_json_int():
@@ -45,3 +47,41 @@ def test_custom_serializer():
     assert s.to_python({1: 2}, mode='json', round_trip=True) == '{"1":2}'
     assert s.to_json({1: 2}) == b'{"1":2}'
     assert s.to_json({1: 2}, round_trip=True) == b'"{\\"1\\":2}"'
+
+
+def test_keep_raw():
+    schema = core_schema.json_schema(core_schema.dict_schema(values_schema=core_schema.float_schema()), keep_raw=True)
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    raw = '{"b": 2.50,  "a": 1e3}'
+    output = v.validate_python(raw)
+
+    assert s.to_json(output) == raw.encode()
+    assert s.to_python(output) == {'b': 2.5, 'a': 1000.0}
+    assert s.to_python(output, mode='json') == {'b': 2.5, 'a': 1000.0}
+    assert s.to_python(output, round_trip=True) == raw
+    assert s.to_json(output, round_trip=True) == b'"{\\"b\\": 2.50,  \\"a\\": 1e3}"'
+    assert v.validate_json(s.to_json(output, round_trip=True)) == output
+
+    s = SchemaSerializer(core_schema.list_schema(schema))
+    assert s.to_json([output, output]) == f'[{raw},{raw}]'.encode()
+    assert s.to_json([output], indent=2) == b'[\n  ' + raw.encode() + b'\n]'
+
+
+@pytest.mark.parametrize('validator', [None, core_schema.list_schema(core_schema.float_schema())])
+def test_keep_raw_inf_nan(validator):
+    schema = core_schema.json_schema(validator, keep_raw=True)
+    output = SchemaValidator(schema).validate_python('[1.5, NaN, Infinity]')
+    assert output.raw == '[1.5, NaN, Infinity]'
+    assert SchemaSerializer(schema).to_json(output) == b'[1.5,null,null]'
+    assert SchemaSerializer(schema, {'ser_json_inf_nan': 'constants'}).to_json(output) == b'[1.5,NaN,Infinity]'
+
+    assert SchemaSerializer(schema).to_json(RawJson([float('nan')], '[NaN]')) == b'[null]'
+    assert SchemaSerializer(schema).to_json(RawJson([1], '[1 ]')) == b'[1 ]'
+
+
+def test_keep_raw_dict_key():
+    schema = core_schema.json_schema(keep_raw=True)
+    s = SchemaSerializer(core_schema.dict_schema(schema, core_schema.int_schema()))
+    key = SchemaValidator(schema).validate_python('[1, 2]')
+    assert s.to_json({key: 1}) == b'{"[1, 2]":1}'
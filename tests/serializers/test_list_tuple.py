@@ -1,5 +1,6 @@
 import json
 import re
+from collections import deque
 from functools import partial
 
 import pytest
@@ -447,3 +448,10 @@ def test_tuple_wrong_size_union():
 
     with pytest.warns(UserWarning, match='Unexpected extra items present in tuple'):
         s.to_json((1.0, 2.0, 3.0, 4.0))
+
+
+def test_list_deque():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema(), output_type='deque'))
+    assert s.to_python(deque([1, 2])) == [1, 2]
+    assert s.to_python(deque([1, 2, 3]), mode='json', exclude={0}) == [2, 3]
+    assert s.to_json(deque([1, 2], maxlen=5)) == b'[1,2]'
@@ -250,6 +250,68 @@ def test_model_wrong_warn():
         assert s.to_python({'foo': 1, 'bar': b'more'}) == {'foo': 1, 'bar': b'more'}
 
 
+def test_collect_warnings():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(core_schema.bytes_schema()),
+                }
+            ),
+        )
+    )
+    m = BasicModel(foo='wrong', bar=b'more')
+
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        value, collected = s.to_python(m, collect_warnings=True)
+        assert value == {'foo': 'wrong', 'bar': b'more'}
+        assert collected == [
+            {
+                'loc': ('foo',),
+                'msg': "Expected `int` but got `str` with value `'wrong'` - serialized value may not be as expected",
+            }
+        ]
+
+        json_value, collected = s.to_json(m, collect_warnings=True)
+        assert json_value == b'{"foo":"wrong","bar":"more"}'
+        assert [w['loc'] for w in collected] == [('foo',)]
+
+        assert s.to_python(BasicModel(foo=1, bar=b'more'), collect_warnings=True) == ({'foo': 1, 'bar': b'more'}, [])
+        assert s.to_python(m, warnings=False, collect_warnings=True) == ({'foo': 'wrong', 'bar': b'more'}, [])
+
+    with pytest.raises(PydanticSerializationError, match='Expected `int` but got `str`'):
+        s.to_python(m, warnings='error', collect_warnings=True)
+
+
+def test_collect_warnings_nested():
+    inner_schema = core_schema.model_schema(
+        BasicModel, core_schema.model_fields_schema({'foo': core_schema.model_field(core_schema.int_schema())})
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'inner': core_schema.model_field(inner_schema),
+                    'items': core_schema.model_field(core_schema.list_schema(inner_schema)),
+                }
+            ),
+        )
+    )
+    m = BasicModel(inner=BasicModel(foo='a'), items=[BasicModel(foo=1), BasicModel(foo='b')])
+
+    value, collected = s.to_python(m, collect_warnings=True)
+    assert value == {'inner': {'foo': 'a'}, 'items': [{'foo': 1}, {'foo': 'b'}]}
+    assert [w['loc'] for w in collected] == [('inner', 'foo'), ('items', 'foo')]
+
+    json_value, collected = s.to_json(m, collect_warnings=True)
+    assert json_value == b'{"inner":{"foo":"a"},"items":[{"foo":1},{"foo":"b"}]}'
+    assert [w['loc'] for w in collected] == [('inner', 'foo'), ('items', 'foo')]
+
+
 def test_exclude_none():
     s = SchemaSerializer(
         core_schema.model_schema(
@@ -275,6 +337,55 @@ def test_exclude_none():
     assert s.to_json(BasicModel(foo=None, bar=b'more'), exclude_none=True) == b'{"bar":"more"}'
 
 
+def test_exclude_if():
+    calls = []
+
+    def is_empty(value):
+        calls.append(value)
+        return not value
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema(), serialization_exclude_if=is_empty),
+                    'bar': core_schema.model_field(core_schema.str_schema(), serialization_exclude_if=is_empty),
+                    'spam': core_schema.model_field(core_schema.bytes_schema()),
+                }
+            ),
+        )
+    )
+    assert s.to_python(BasicModel(foo=1, bar='x', spam=b'')) == {'foo': 1, 'bar': 'x', 'spam': b''}
+    assert s.to_python(BasicModel(foo=0, bar='', spam=b'')) == {'spam': b''}
+    assert s.to_python(BasicModel(foo=0, bar='x', spam=b''), mode='json') == {'bar': 'x', 'spam': ''}
+    assert s.to_json(BasicModel(foo=0, bar='x', spam=b'')) == b'{"bar":"x","spam":""}'
+    assert s.to_json(BasicModel(foo=1, bar='', spam=b'')) == b'{"foo":1,"spam":""}'
+
+    # fields removed by `include`/`exclude` never reach the predicate
+    calls.clear()
+    assert s.to_python(BasicModel(foo=0, bar='x', spam=b''), exclude={'foo'}) == {'bar': 'x', 'spam': b''}
+    assert calls == ['x']
+
+
+def test_exclude_if_error():
+    def check(value):
+        raise ValueError('broken predicate')
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {'foo': core_schema.model_field(core_schema.int_schema(), serialization_exclude_if=check)}
+            ),
+        )
+    )
+    with pytest.raises(ValueError, match='broken predicate'):
+        s.to_python(BasicModel(foo=1))
+    with pytest.raises(PydanticSerializationError, match='broken predicate'):
+        s.to_json(BasicModel(foo=1))
+
+
 class FieldsSetModel:
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
 
@@ -747,6 +858,72 @@ def test_computed_field_exclude_none():
     assert s.to_json(Model(3, 4), exclude_none=True) == b'{"width":3,"height":4,"Area":12}'
 
 
+def test_computed_field_getter_not_called_when_excluded():
+    calls = []
+
+    @dataclasses.dataclass
+    class Model:
+        width: int
+
+        @property
+        def area(self) -> int:
+            calls.append('area')
+            return self.width**2
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'width': core_schema.model_field(core_schema.int_schema())},
+                computed_fields=[core_schema.computed_field('area', core_schema.int_schema())],
+            ),
+        )
+    )
+    assert s.to_python(Model(3), exclude={'area'}) == {'width': 3}
+    assert s.to_python(Model(3), include={'width'}, mode='json') == {'width': 3}
+    assert s.to_json(Model(3), exclude={'area'}) == b'{"width":3}'
+    assert calls == []
+
+    # when included, the getter is only called once per serialization
+    assert s.to_python(Model(3)) == {'width': 3, 'area': 9}
+    assert calls == ['area']
+    assert s.to_json(Model(3), exclude_none=True) == b'{"width":3,"area":9}'
+    assert calls == ['area', 'area']
+
+
+def test_exclude_computed():
+    calls = []
+
+    @dataclasses.dataclass
+    class Model:
+        width: int
+
+        @property
+        def area(self) -> int:
+            calls.append('area')
+            return self.width**2
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'width': core_schema.model_field(core_schema.int_schema())},
+                computed_fields=[core_schema.computed_field('area', core_schema.int_schema())],
+            ),
+        )
+    )
+    assert s.to_python(Model(3), exclude_computed=True) == {'width': 3}
+    assert s.to_python(Model(3), mode='json', exclude_computed=True) == {'width': 3}
+    assert s.to_json(Model(3), exclude_computed=True) == b'{"width":3}'
+    # independent of include
+    assert s.to_python(Model(3), include={'width', 'area'}, exclude_computed=True) == {'width': 3}
+    assert s.serializer_context(exclude_computed=True).to_json(Model(3)) == b'{"width":3}'
+    assert calls == []
+
+    assert s.to_python(Model(3)) == {'width': 3, 'area': 9}
+    assert calls == ['area']
+
+
 def test_computed_field_exclude_none_different_order():
     # verify that order of computed fields doesn't matter
     # issue originally reported via: https://github.com/pydantic/pydantic/issues/8691
@@ -1152,3 +1329,40 @@ def test_warn_on_missing_field() -> None:
     with pytest.warns(UserWarning, match='Expected 2 fields but got 1 for type `.*AModel` with value `.*`.+'):
         value = BasicModel(root=AModel(type='a'))
         s.to_python(value)
+
+
+def test_exclude_key_pattern():
+    class Model:
+        __slots__ = ('__pydantic_extra__', '__dict__')
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.int_schema(), serialization_alias='internal_b'),
+                    'internal_c': core_schema.model_field(core_schema.int_schema(), serialization_alias='c'),
+                },
+                extra_behavior='allow',
+                exclude_key_pattern='^internal_',
+            ),
+            extra_behavior='allow',
+        )
+    )
+    m = Model()
+    m.__dict__ = {'a': 1, 'b': 2, 'internal_c': 3}
+    m.__pydantic_extra__ = {'internal_d': 4, 'e': 5}
+
+    # keys are matched after alias resolution
+    assert s.to_python(m) == {'a': 1, 'c': 3, 'e': 5}
+    assert s.to_python(m, by_alias=False) == {'a': 1, 'b': 2, 'e': 5}
+    assert json.loads(s.to_json(m)) == {'a': 1, 'c': 3, 'e': 5}
+    assert json.loads(s.to_json(m, by_alias=False)) == {'a': 1, 'b': 2, 'e': 5}
+    # an explicit include wins over the pattern
+    assert s.to_python(m, by_alias=False, include={'a', 'internal_c', 'internal_d'}) == {
+        'a': 1,
+        'internal_c': 3,
+        'internal_d': 4,
+    }
+    assert json.loads(s.to_json(m, include={'b': True})) == {'internal_b': 2}
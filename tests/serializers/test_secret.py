@@ -0,0 +1,65 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, Secret, core_schema
+
+
+def test_secret():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.str_schema()))
+    secret = Secret('hunter2')
+    assert s.to_python(secret) is secret
+    assert s.to_python(secret, mode='json') == '**********'
+    assert s.to_json(secret) == b'"**********"'
+
+
+def test_dump_secret_values():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.str_schema()))
+    secret = Secret('hunter2')
+    assert s.to_python(secret, dump_secret_values=True) == 'hunter2'
+    assert s.to_python(secret, mode='json', dump_secret_values=True) == 'hunter2'
+    assert s.to_json(secret, dump_secret_values=True) == b'"hunter2"'
+
+
+def test_inner_schema_used():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.list_schema(core_schema.bytes_schema())))
+    secret = Secret([b'a', b'b'])
+    assert s.to_json(secret) == b'"**********"'
+    assert s.to_python(secret, mode='json', dump_secret_values=True) == ['a', 'b']
+    assert s.to_json(secret, dump_secret_values=True) == b'["a","b"]'
+
+
+def test_in_typed_dict():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'password': core_schema.typed_dict_field(core_schema.secret_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    value = {'name': 'alice', 'password': Secret('hunter2')}
+    assert json.loads(s.to_json(value)) == {'name': 'alice', 'password': '**********'}
+    assert json.loads(s.to_json(value, dump_secret_values=True)) == {'name': 'alice', 'password': 'hunter2'}
+
+
+def test_dict_keys():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.secret_schema(core_schema.int_schema()), core_schema.int_schema())
+    )
+    assert s.to_json({Secret(1): 1}) == b'{"**********":1}'
+    assert s.to_json({Secret(1): 1}, dump_secret_values=True) == b'{"1":1}'
+
+
+def test_not_a_secret():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.str_schema()))
+    with pytest.warns(UserWarning, match='Expected `secret` but got `str`'):
+        assert s.to_json('plain') == b'"plain"'
+
+
+def test_secret_bytes():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.bytes_schema()))
+    secret = Secret(b'token')
+    assert s.to_python(secret, mode='json') == '**********'
+    assert s.to_python(secret, dump_secret_values=True) == b'token'
+    assert s.to_json(secret, dump_secret_values=True) == b'"token"'
@@ -0,0 +1,79 @@
+import json
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+def test_serializer_context():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='A'),
+                'b': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.bytes_schema())),
+            }
+        )
+    )
+    ctx = s.serializer_context(by_alias=False, exclude_none=True)
+    assert ctx.to_python({'a': 1, 'b': None}) == {'a': 1}
+    assert ctx.to_python({'a': 2, 'b': b'x'}) == {'a': 2, 'b': b'x'}
+    assert ctx.to_python({'a': 2, 'b': b'x'}, mode='json') == {'a': 2, 'b': 'x'}
+    assert ctx.to_json({'a': 3, 'b': None}) == b'{"a":3}'
+    assert ctx.to_json({'a': 3, 'b': b'y'}, indent=2) == b'{\n  "a": 3,\n  "b": "y"\n}'
+
+    default_ctx = s.serializer_context()
+    assert default_ctx.to_python({'a': 1, 'b': None}) == {'A': 1, 'b': None}
+
+
+def test_include_exclude():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    ctx = s.serializer_context()
+    assert ctx.to_python({'a': 1, 'b': 2}, include={'a'}) == {'a': 1}
+    assert ctx.to_json({'a': 1, 'b': 2}, exclude={'a'}) == b'{"b":2}'
+
+
+def test_context_and_fallback():
+    def ser(value, info):
+        return value * info.context['multiplier']
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(ser, info_arg=True))
+        )
+    )
+    ctx = s.serializer_context(context={'multiplier': 3})
+    assert ctx.to_python([1, 2]) == [3, 6]
+    assert json.loads(ctx.to_json([1, 2])) == [3, 6]
+
+    class Foo:
+        pass
+
+    s = SchemaSerializer(core_schema.any_schema())
+    ctx = s.serializer_context(fallback=lambda v: 'foo')
+    assert ctx.to_json(Foo()) == b'"foo"'
+
+
+def test_warnings():
+    s = SchemaSerializer(core_schema.int_schema())
+    ctx = s.serializer_context(warnings='error')
+    with pytest.raises(PydanticSerializationError, match='Expected `int` but got `str`'):
+        ctx.to_python('x')
+
+    ctx = s.serializer_context(warnings=False)
+    assert ctx.to_json('x') == b'"x"'
+
+    # warnings from one call aren't carried over to the next
+    ctx = s.serializer_context(warnings='error')
+    with pytest.raises(PydanticSerializationError):
+        ctx.to_json('x')
+    assert ctx.to_json(1) == b'1'
+
+
+def test_nested_calls():
+    class Foo:
+        items = [1, 2]
+
+    s = SchemaSerializer(core_schema.any_schema())
+    ctx = s.serializer_context(fallback=lambda v: ctx.to_python(v.items))
+    assert ctx.to_json(Foo()) == b'[1,2]'
+    assert ctx.to_python([Foo()], mode='json') == [[1, 2]]
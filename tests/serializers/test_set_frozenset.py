@@ -3,7 +3,7 @@ import json
 import pytest
 from dirty_equals import IsList
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema
 
 
 def test_set_any():
@@ -49,3 +49,31 @@ def test_set_fallback(input_value, json_output, warning_type):
 
     with pytest.warns(UserWarning, match=f'Expected {warning_type} - serialized value may not be as expected'):
         assert json.loads(v.to_json(input_value)) == json_output
+
+
+@pytest.mark.parametrize('schema_func', [core_schema.set_schema, core_schema.frozenset_schema])
+def test_round_trip_sorted(schema_func):
+    s = SchemaSerializer(schema_func(core_schema.any_schema()))
+    value = {'c', 'a', 'b', 3, 1, 20}
+    ordered = ['a', 'b', 'c', 1, 20, 3]
+    if schema_func is core_schema.frozenset_schema:
+        value = frozenset(value)
+    # items are ordered by their JSON representation
+    assert s.to_python(value, mode='json', round_trip=True) == ordered
+    assert s.to_json(value, round_trip=True) == b'["a","b","c",1,20,3]'
+
+    v = SchemaValidator(schema_func(core_schema.any_schema()))
+    assert v.validate_json(s.to_json(value, round_trip=True)) == value
+
+
+def test_round_trip_sorted_unserializable():
+    class Unserializable:
+        def __repr__(self):
+            return 'Unserializable()'
+
+    s = SchemaSerializer(core_schema.set_schema(core_schema.any_schema()))
+    value = {Unserializable(), 'b', 'a'}
+    assert s.to_json(value, round_trip=True, fallback=repr) == b'["Unserializable()","a","b"]'
+    # items which can't be serialized are still ordered, the error comes from serializing them
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.to_json(value, round_trip=True)
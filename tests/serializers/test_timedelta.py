@@ -2,7 +2,14 @@ from datetime import timedelta
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaSerializer,
+    SchemaValidator,
+    core_schema,
+    to_json,
+    to_jsonable_python,
+)
 
 try:
     import pandas
@@ -38,6 +45,40 @@ def test_timedelta_float():
     assert v.to_json(timedelta(seconds=4, microseconds=500_000)) == b'4.5'
 
 
+def test_timedelta_int_seconds():
+    v = SchemaSerializer(core_schema.timedelta_schema(), config={'ser_json_timedelta': 'int_seconds'})
+    assert v.to_python(timedelta(days=1, seconds=4)) == timedelta(days=1, seconds=4)
+    assert v.to_python(timedelta(days=1, seconds=4), mode='json') == 86404
+    assert v.to_json(timedelta(days=1, seconds=4)) == b'86404'
+    assert v.to_json(-timedelta(days=1, seconds=4)) == b'-86404'
+
+    # the output validates back to the same timedelta
+    assert SchemaValidator(core_schema.timedelta_schema()).validate_json(b'86404') == timedelta(days=1, seconds=4)
+
+    with pytest.warns(UserWarning, match='sub-second precision was dropped'):
+        assert v.to_json(timedelta(seconds=4, microseconds=500_000)) == b'4'
+    # truncated towards zero
+    with pytest.warns(UserWarning, match='sub-second precision was dropped'):
+        assert v.to_python(-timedelta(seconds=4, microseconds=500_000), mode='json') == -4
+    with pytest.raises(PydanticSerializationError, match='sub-second precision was dropped'):
+        v.to_json(timedelta(microseconds=1), warnings='error')
+    assert v.to_json(timedelta(microseconds=1), warnings=False) == b'0'
+
+
+def test_timedelta_int_seconds_key():
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()),
+        config={'ser_json_timedelta': 'int_seconds'},
+    )
+    assert v.to_json({timedelta(minutes=2): 1}) == b'{"120":1}'
+    assert v.to_python({timedelta(minutes=2): 1}, mode='json') == {'120': 1}
+
+
+def test_to_json_int_seconds():
+    assert to_json(timedelta(hours=1), timedelta_mode='int_seconds') == b'3600'
+    assert to_jsonable_python({'a': timedelta(hours=1)}, timedelta_mode='int_seconds') == {'a': 3600}
+
+
 def test_timedelta_key():
     v = SchemaSerializer(core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()))
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}) == {timedelta(days=2, hours=3, minutes=4): 1}
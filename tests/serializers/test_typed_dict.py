@@ -103,6 +103,21 @@ def test_include_exclude_schema():
     assert json.loads(s.to_json(value)) == {'1': 1, '3': 3}
 
 
+def test_exclude_if():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_exclude_if=lambda v: v == 0),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert s.to_python({'a': 1, 'b': 0}) == {'a': 1, 'b': 0}
+    assert s.to_python({'a': 0, 'b': 0}) == {'b': 0}
+    assert s.to_python({'a': 0, 'b': 0}, mode='json') == {'b': 0}
+    assert json.loads(s.to_json({'a': 0, 'b': 0})) == {'b': 0}
+
+
 def test_alias():
     s = SchemaSerializer(
         core_schema.typed_dict_schema(
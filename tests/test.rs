@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use _pydantic_core::{SchemaSerializer, SchemaValidator, WarningsArg};
+    use _pydantic_core::{list_all_errors, SchemaSerializer, SchemaValidator, WarningsArg};
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
 
@@ -99,6 +99,12 @@ a = A()
                     None,
                     false,
                     None,
+                    false,
+                    None,
+                    false,
+                    false,
+                    None,
+                    false,
                 )
                 .unwrap();
             let serialized: &[u8] = serialized.extract(py).unwrap();
@@ -127,9 +133,22 @@ json_input = '{"a": "something"}'
             py.run_bound(code, None, Some(&locals)).unwrap();
             let schema = locals.get_item("schema").unwrap().unwrap();
             let json_input = locals.get_item("json_input").unwrap().unwrap();
-            let binding = SchemaValidator::py_new(py, &schema, None)
+            let binding = SchemaValidator::py_new(py, &schema, None, None)
                 .unwrap()
-                .validate_json(py, &json_input, None, None, None, false.into())
+                .validate_json(
+                    py,
+                    &json_input,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    "float",
+                    "document",
+                )
                 .unwrap();
             let validation_result: Bound<'_, PyAny> = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -203,6 +222,12 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    false,
+                    None,
+                    false,
+                    false,
+                    None,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -224,10 +249,139 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    false,
+                    None,
+                    false,
+                    false,
+                    None,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
             assert_eq!(repr, "b'{\"a\":\"something\"}'");
         });
     }
+
+    #[test]
+    fn test_error_type_strings() {
+        // error types are persisted by users, so renaming or removing one has to fail here first
+        let expected = [
+            "no_such_attribute",
+            "json_invalid",
+            "json_type",
+            "needs_python_object",
+            "json_too_large",
+            "recursion_loop",
+            "missing",
+            "frozen_field",
+            "frozen_instance",
+            "extra_forbidden",
+            "invalid_key",
+            "get_attribute_error",
+            "model_type",
+            "model_attributes_type",
+            "dataclass_type",
+            "dataclass_exact_type",
+            "none_required",
+            "greater_than",
+            "greater_than_equal",
+            "less_than",
+            "less_than_equal",
+            "multiple_of",
+            "finite_number",
+            "too_short",
+            "too_long",
+            "iterable_type",
+            "iteration_error",
+            "string_type",
+            "string_sub_type",
+            "string_unicode",
+            "string_too_short",
+            "string_too_long",
+            "string_pattern_mismatch",
+            "string_control_char",
+            "enum",
+            "dict_type",
+            "mapping_type",
+            "dict_key_collision",
+            "list_type",
+            "tuple_type",
+            "set_type",
+            "set_item_not_hashable",
+            "bool_type",
+            "bool_parsing",
+            "int_type",
+            "int_parsing",
+            "int_parsing_size",
+            "int_from_float",
+            "float_type",
+            "float_parsing",
+            "bytes_type",
+            "bytes_too_short",
+            "bytes_too_long",
+            "bytes_invalid_encoding",
+            "value_error",
+            "assertion_error",
+            "literal_error",
+            "date_type",
+            "date_parsing",
+            "date_from_datetime_parsing",
+            "date_from_datetime_inexact",
+            "date_past",
+            "date_future",
+            "time_type",
+            "time_parsing",
+            "datetime_type",
+            "datetime_parsing",
+            "datetime_object_invalid",
+            "datetime_from_date_parsing",
+            "datetime_past",
+            "datetime_future",
+            "timezone_naive",
+            "timezone_aware",
+            "timezone_offset",
+            "time_delta_type",
+            "time_delta_parsing",
+            "frozen_set_type",
+            "is_instance_of",
+            "is_subclass_of",
+            "callable_type",
+            "union_tag_invalid",
+            "union_tag_not_found",
+            "arguments_type",
+            "missing_argument",
+            "unexpected_keyword_argument",
+            "missing_keyword_only_argument",
+            "unexpected_positional_argument",
+            "missing_positional_only_argument",
+            "multiple_argument_values",
+            "url_type",
+            "url_parsing",
+            "url_syntax_violation",
+            "url_too_long",
+            "url_scheme",
+            "uuid_type",
+            "uuid_parsing",
+            "uuid_version",
+            "decimal_type",
+            "decimal_parsing",
+            "decimal_max_digits",
+            "decimal_max_places",
+            "decimal_whole_digits",
+            "complex_type",
+            "complex_str_parsing",
+            "complex_max_abs",
+            "fraction_type",
+            "fraction_parsing",
+            "base64_decode",
+        ];
+        Python::with_gil(|py| {
+            let error_types: Vec<String> = list_all_errors(py)
+                .unwrap()
+                .iter()
+                .map(|error| error.get_item("type").unwrap().extract().unwrap())
+                .collect();
+            assert_eq!(error_types, expected);
+        });
+    }
 }
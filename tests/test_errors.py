@@ -1,4 +1,5 @@
 import enum
+import json
 import os
 import pickle
 import re
@@ -13,12 +14,18 @@ from dirty_equals import HasRepr, IsInstance, IsJson, IsStr
 
 from pydantic_core import (
     CoreConfig,
+    ErrorDetail,
     PydanticCustomError,
     PydanticKnownError,
     PydanticOmit,
     SchemaValidator,
     ValidationError,
     core_schema,
+    error_types,
+    format_loc,
+    is_known_error_type,
+    register_error_messages,
+    set_include_url,
 )
 from pydantic_core._pydantic_core import list_all_errors
 
@@ -257,6 +264,8 @@ all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
+    ('json_too_large', 'JSON input should be at most 42 bytes', {'max_input_size': 42}),
+    ('json_too_large', 'JSON input should be at most 1 byte', {'max_input_size': 1}),
     (
         'needs_python_object',
         'Cannot check `isinstance` when validating from json, use a JsonOrPython validator instead',
@@ -296,17 +305,28 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    (
+        'string_control_char',
+        'String should not contain control characters, found U+0000 at position 3',
+        {'code_point': 'U+0000', 'position': 3},
+    ),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
     ('string_too_long', 'String should have at most 1 character', {'max_length': 1}),
     ('dict_type', 'Input should be a valid dictionary', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
+    (
+        'dict_key_collision',
+        "Dictionary keys should be unique after validation, got colliding keys '1', '01'",
+        {'keys': "'1', '01'"},
+    ),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
     ('list_type', 'Input should be a valid list', None),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
+    ('set_item_not_hashable', 'Set items should be hashable, got an unhashable `dict`', {'item_type': 'dict'}),
     ('bool_type', 'Input should be a valid boolean', None),
     ('bool_parsing', 'Input should be a valid boolean, unable to interpret input', None),
     ('int_type', 'Input should be a valid integer', None),
@@ -410,6 +430,10 @@ all_errors = [
         'Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex',
         None,
     ),
+    ('complex_max_abs', 'Complex input should have an absolute value less than or equal to 2.5', {'max_abs': 2.5}),
+    ('fraction_type', 'Fraction input should be an integer, string or Fraction object', None),
+    ('fraction_parsing', 'Input should be a valid fraction', None),
+    ('base64_decode', 'Input should be valid base64, Foobar', {'error': 'Foobar'}),
 ]
 
 
@@ -427,6 +451,25 @@ def test_all_errors_covered():
     assert actual_types == listed_types
 
 
+def test_error_type_constants():
+    constants = {name: value for name, value in vars(error_types).items() if name.isupper()}
+    known_types = constants.pop('KNOWN_ERROR_TYPES')
+    # constant names are fixed, so a renamed error type fails here rather than breaking persisted types
+    assert all(value == name.lower() for name, value in constants.items())
+    assert set(constants.values()) == known_types
+    assert {e['type'] for e in list_all_errors()} == known_types
+    assert error_types.GREATER_THAN == 'greater_than'
+    assert error_types.MISSING == 'missing'
+
+
+def test_is_known_error_type():
+    assert all(is_known_error_type(e['type']) for e in list_all_errors())
+    assert is_known_error_type(error_types.INT_PARSING)
+    assert not is_known_error_type('my_error')
+    assert not is_known_error_type('Int_Parsing')
+    assert not is_known_error_type('')
+
+
 def test_error_decimal():
     e = PydanticKnownError('greater_than', {'gt': Decimal('42.1')})
     assert e.message() == 'Input should be greater than 42.1'
@@ -921,12 +964,14 @@ def test_error_json_loc():
                 'loc': ['a', 2],
                 'msg': 'Input should be a valid integer, unable to parse string as an integer',
                 'input': 'x',
+                'ctx': {'reason': 'invalid digit at position 0'},
             },
             {
                 'type': 'int_parsing',
                 'loc': ['b', 1],
                 'msg': 'Input should be a valid integer, unable to parse string as an integer',
                 'input': 'y',
+                'ctx': {'reason': 'invalid digit at position 0'},
             },
         ]
     )
@@ -1075,6 +1120,7 @@ def test_loc_with_dots(pydantic_version):
             'loc': ('foo.bar', 0),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     # insert_assert(str(exc_info.value))
@@ -1119,6 +1165,103 @@ def test_validation_error_pickle() -> None:
     assert original.errors() == roundtripped.errors()
 
 
+def test_validation_error_pickle_state() -> None:
+    s = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_json('"x"')
+
+    original = exc_info.value
+    roundtripped = pickle.loads(pickle.dumps(original))
+    assert roundtripped.title == 'int'
+    assert roundtripped.errors() == original.errors()
+    assert str(roundtripped) == str(original)
+    assert 'input_type=str' in str(roundtripped)
+
+
+def test_validation_error_pickle_custom_error() -> None:
+    def f(input_value):
+        raise PydanticCustomError('my_error', 'this is a custom error {foo}', {'foo': 'bar'}, 'E1')
+
+    s = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(1)
+
+    roundtripped = pickle.loads(pickle.dumps(exc_info.value))
+    # insert_assert(roundtripped.errors(include_code=True))
+    assert roundtripped.errors(include_code=True) == [
+        {
+            'type': 'my_error',
+            'code': 'E1',
+            'loc': (),
+            'msg': 'this is a custom error bar',
+            'input': 1,
+            'ctx': {'foo': 'bar'},
+        }
+    ]
+
+
+def test_validation_error_pickle_unpicklable_input() -> None:
+    class Unpicklable:
+        def __reduce__(self):
+            raise TypeError('cannot pickle')
+
+        def __repr__(self):
+            return 'Unpicklable()'
+
+    def f(input_value):
+        raise ValueError(Unpicklable())
+
+    s = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.no_info_plain_validator_function(f)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python({'a': Unpicklable(), 'b': 1})
+
+    roundtripped = pickle.loads(pickle.dumps(exc_info.value))
+    assert roundtripped.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('a',), 'msg': 'Input should be a valid integer', 'input': 'Unpicklable()'},
+        {
+            'type': 'value_error',
+            'loc': ('b',),
+            'msg': 'Value error, ValueError(Unpicklable())',
+            'input': 1,
+            'ctx': {'error': 'ValueError(Unpicklable())'},
+        },
+    ]
+
+
+def test_validation_error_pickle_source() -> None:
+    inner = SchemaValidator(core_schema.int_schema(), {'title': 'Inner'})
+
+    def f(input_value):
+        return inner.validate_python(input_value)
+
+    s = SchemaValidator(core_schema.list_schema(core_schema.no_info_plain_validator_function(f)))
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python(['x'])
+
+    roundtripped = pickle.loads(pickle.dumps(exc_info.value))
+    assert roundtripped.errors(include_source=True) == exc_info.value.errors(include_source=True)
+    assert roundtripped.errors(include_source=True)[0]['source'] == {'title': 'Inner', 'loc_prefix': (0,)}
+
+
+def test_validation_error_getstate_setstate() -> None:
+    s = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python('x', strict=True)
+    with pytest.raises(ValidationError) as other_exc_info:
+        s.validate_python([])
+
+    error = other_exc_info.value
+    error.__setstate__(exc_info.value.__getstate__())
+    assert error.errors() == exc_info.value.errors()
+
+
 @pytest.mark.skipif('PYDANTIC_ERRORS_INCLUDE_URL' in os.environ, reason="can't test when envvar is set")
 def test_errors_include_url() -> None:
     s = SchemaValidator({'type': 'int'})
@@ -1127,6 +1270,22 @@ def test_errors_include_url() -> None:
     assert 'https://errors.pydantic.dev' in repr(exc_info.value)
 
 
+@pytest.mark.skipif('PYDANTIC_ERRORS_INCLUDE_URL' in os.environ, reason="can't test when envvar is set")
+def test_set_include_url() -> None:
+    s = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        s.validate_python('definitely not an int')
+    error = exc_info.value
+    try:
+        set_include_url(False)
+        assert 'https://errors.pydantic.dev' not in repr(error)
+        assert 'https://errors.pydantic.dev' not in str(error)
+        set_include_url(True)
+        assert 'https://errors.pydantic.dev' in repr(error)
+    finally:
+        set_include_url(True)
+
+
 @pytest.mark.skipif(sys.platform == 'emscripten', reason='no subprocesses on emscripten')
 @pytest.mark.parametrize(
     ('env_var', 'env_var_value', 'expected_to_have_url'),
@@ -1165,3 +1324,484 @@ def test_errors_include_url_envvar(env_var, env_var_value, expected_to_have_url)
     if 'PYDANTIC_ERRORS_OMIT_URL' in env:
         assert 'PYDANTIC_ERRORS_OMIT_URL is deprecated' in result.stdout
     assert ('https://errors.pydantic.dev' in result.stdout) == expected_to_have_url
+
+
+def test_error_messages_locale():
+    register_error_messages('test-de', {'less_than': 'Eingabe sollte kleiner als {lt} sein'})
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int', 'lt': 10}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([11, 'x'])
+
+    # insert_assert(exc_info.value.errors(include_url=False, locale='test-de'))
+    assert exc_info.value.errors(include_url=False, locale='test-de') == [
+        {'type': 'less_than', 'loc': (0,), 'msg': 'Eingabe sollte kleiner als 10 sein', 'input': 11, 'ctx': {'lt': 10}},
+        # no template registered for this locale, so the default is used
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        },
+    ]
+    assert exc_info.value.errors(include_url=False)[0]['msg'] == 'Input should be less than 10'
+    assert exc_info.value.errors(include_url=False, locale='test-unknown')[0]['msg'] == 'Input should be less than 10'
+    assert json.loads(exc_info.value.json(locale='test-de'))[0]['msg'] == 'Eingabe sollte kleiner als 10 sein'
+
+
+def test_error_messages_locale_everywhere():
+    register_error_messages('test-de', {'less_than': 'Eingabe sollte kleiner als {lt} sein'})
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int', 'lt': 10}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([11])
+    msg = 'Eingabe sollte kleiner als 10 sein'
+    assert msg in exc_info.value.display(locale='test-de')
+    assert exc_info.value.first_error(locale='test-de')['msg'] == msg
+    assert exc_info.value.categorize(locale='test-de')['value'][0]['msg'] == msg
+    assert exc_info.value.to_dict(locale='test-de')['errors'][0]['msg'] == msg
+    assert msg not in str(exc_info.value)
+    assert exc_info.value.errors()[0]['msg'] == 'Input should be less than 10'
+
+
+def test_register_error_messages_invalid():
+    with pytest.raises(KeyError, match="Invalid error type: 'not_an_error'"):
+        register_error_messages('test-de', {'not_an_error': 'foo'})
+
+
+def test_error_code():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error {foo}', {'foo': 'FOOBAR'}, 'E123')
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(f)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 1})
+
+    # insert_assert(exc_info.value.errors(include_url=False, include_code=True))
+    assert exc_info.value.errors(include_url=False, include_code=True) == [
+        {
+            'type': 'int_parsing',
+            'code': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        },
+        {
+            'type': 'my_error',
+            'code': 'E123',
+            'loc': ('b',),
+            'msg': 'this is a custom error FOOBAR',
+            'input': 1,
+            'ctx': {'foo': 'FOOBAR'},
+        },
+    ]
+    assert all('code' not in e for e in exc_info.value.errors())
+    assert [e['code'] for e in json.loads(exc_info.value.json(include_code=True))] == ['int_parsing', 'E123']
+    assert all('code' not in e for e in json.loads(exc_info.value.json()))
+
+
+def test_custom_error_code():
+    e = PydanticCustomError('my_error', 'this is a custom error', None, 'E123')
+    assert e.code == 'E123'
+    assert PydanticCustomError('my_error', 'this is a custom error').code is None
+
+    loaded = pickle.loads(pickle.dumps(e))
+    assert (loaded.type, loaded.message_template, loaded.context, loaded.code) == (
+        'my_error',
+        'this is a custom error',
+        None,
+        'E123',
+    )
+
+
+def test_json_max_input_repr_len():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x' * 100, 'b': list(range(100)), 'c': 'short'})
+
+    errors = json.loads(exc_info.value.json(max_input_repr_len=20))
+    assert [e['input'] for e in errors] == ['xxxxxxxxxx...xxxxxxxxx', '[0,1,2,3,4...97,98,99]', 'short']
+
+    errors = json.loads(exc_info.value.json())
+    assert [e['input'] for e in errors] == ['x' * 100, list(range(100)), 'short']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [1, 2], 'b': {'x': 1}, 'c': None})
+    errors = json.loads(exc_info.value.json(max_input_repr_len=20, indent=2))
+    assert [e['input'] for e in errors] == [[1, 2], {'x': 1}, None]
+
+    with pytest.raises(ValueError, match='max_input_repr_len must be greater than 0'):
+        exc_info.value.json(max_input_repr_len=0)
+
+
+def test_validation_error_to_dict():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'title': 'MyList'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x', 'y'])
+
+    # insert_assert(exc_info.value.to_dict(include_url=False))
+    assert exc_info.value.to_dict(include_url=False) == {
+        'title': 'MyList',
+        'error_count': 2,
+        'errors': [
+            {
+                'type': 'int_parsing',
+                'loc': (1,),
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'x',
+                'ctx': {'reason': 'invalid digit at position 0'},
+            },
+            {
+                'type': 'int_parsing',
+                'loc': (2,),
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'y',
+                'ctx': {'reason': 'invalid digit at position 0'},
+            },
+        ],
+    }
+    assert exc_info.value.to_dict()['errors'] == exc_info.value.errors()
+    assert [e['loc'] for e in exc_info.value.to_dict(loc_prefix=['body', 0])['errors']] == [
+        ('body', 0, 1),
+        ('body', 0, 2),
+    ]
+
+    with pytest.raises(TypeError, match='Location must be a list or tuple of strings and ints'):
+        exc_info.value.to_dict(loc_prefix='body')
+
+
+@pytest.mark.skipif(sys.version_info < (3, 11), reason='ExceptionGroup requires Python 3.11+')
+def test_as_exception_group():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        {'title': 'Foo'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [1, 'x', 'y']})
+
+    group = exc_info.value.as_exception_group()
+    assert isinstance(group, ExceptionGroup)  # noqa: F821
+    assert group.message == '3 validation errors for Foo'
+    nested, missing = group.exceptions
+    assert nested.message == 'a'
+    assert [(e.loc, e.type, str(e)) for e in nested.exceptions] == [
+        (('a', 1), 'int_parsing', 'Input should be a valid integer, unable to parse string as an integer'),
+        (('a', 2), 'int_parsing', 'Input should be a valid integer, unable to parse string as an integer'),
+    ]
+    assert isinstance(missing, ValueError)
+    assert (missing.loc, missing.type, str(missing)) == (('b',), 'missing', 'Field required')
+
+    match, rest = group.split(lambda e: getattr(e, 'type', None) == 'missing')
+    assert match.exceptions == (missing,)
+    assert [e.loc for e in rest.exceptions[0].exceptions] == [('a', 1), ('a', 2)]
+
+
+def test_categorize():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error')
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(gt=0)),
+                'c': core_schema.typed_dict_field(core_schema.str_schema()),
+                'd': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(f)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': -1, 'd': 1})
+
+    categories = exc_info.value.categorize(include_url=False)
+    assert list(categories) == ['missing', 'type', 'value', 'custom']
+    assert {category: [(e['type'], e['loc']) for e in errors] for category, errors in categories.items()} == {
+        'missing': [('missing', ('c',))],
+        'type': [('int_parsing', ('a',))],
+        'value': [('greater_than', ('b',))],
+        'custom': [('my_error', ('d',))],
+    }
+    assert categories['value'][0] == exc_info.value.errors(include_url=False)[1]
+
+
+def test_errors_as_objects():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error {foo}', {'foo': 'FOOBAR'}, 'E123')
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(gt=0)),
+                'b': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(f)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': -1, 'b': 1})
+
+    detail, custom_detail = exc_info.value.errors(as_objects=True)
+    assert isinstance(detail, ErrorDetail)
+    assert detail.type == detail.code == 'greater_than'
+    assert detail.loc == ('a',)
+    assert detail.msg == 'Input should be greater than 0'
+    assert detail.input == -1
+    assert detail.ctx == {'gt': 0}
+    assert detail.url == 'https://errors.pydantic.dev/latest/v/greater_than'
+    assert repr(detail) == "ErrorDetail(type='greater_than', loc=('a',), msg='Input should be greater than 0', input=-1)"
+
+    assert (custom_detail.type, custom_detail.code, custom_detail.msg) == (
+        'my_error',
+        'E123',
+        'this is a custom error FOOBAR',
+    )
+    assert custom_detail.url is None
+
+    detail = exc_info.value.errors(as_objects=True, include_url=False, include_context=False, include_input=False)[0]
+    assert (detail.url, detail.ctx, detail.input) == (None, None, None)
+
+    for kwarg in 'include_code', 'loc_as_pointer', 'include_source':
+        with pytest.raises(TypeError, match="can't be used with `as_objects=True`"):
+            exc_info.value.errors(as_objects=True, **{kwarg: True})
+
+
+def test_error_detail_pickle():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error {foo}', {'foo': 'FOOBAR'})
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(gt=0)),
+                'b': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(f)),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': -1, 'b': 1})
+
+    for detail in exc_info.value.errors(as_objects=True, include_url=False):
+        loaded = pickle.loads(pickle.dumps(detail))
+        assert isinstance(loaded, ErrorDetail)
+        assert repr(loaded) == repr(detail)
+        assert (loaded.ctx, loaded.url) == (detail.ctx, None)
+
+
+@pytest.mark.parametrize(
+    'loc,expected',
+    [
+        ((), ''),
+        (('a',), 'a'),
+        (('items', 0, 'name'), 'items[0].name'),
+        ((0, 'a', -1), '[0].a[-1]'),
+        (('a', 'b.c', 'd'), 'a["b.c"].d'),
+        (('a', ('b', 1)), 'a[(b, 1)]'),
+        (['a', 1], 'a[1]'),
+    ],
+)
+def test_format_loc(loc, expected):
+    assert format_loc(loc) == expected
+
+
+def test_error_detail_field_path():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'items': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema()))}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'items': [1, 'x']})
+    (detail,) = exc_info.value.errors(as_objects=True)
+    assert detail.field_path() == 'items[1]'
+    assert detail.field_path() == format_loc(detail.loc)
+
+
+@pytest.mark.parametrize(
+    'loc_format,expected',
+    [
+        ('dotted', 'items.1.`a.b`'),
+        ('json_pointer', '/items/1/a.b'),
+        ('bracketed', 'items[1][a.b]'),
+    ],
+)
+def test_display_loc_format(loc_format, expected):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(
+                    core_schema.list_schema(
+                        core_schema.typed_dict_schema({'a.b': core_schema.typed_dict_field(core_schema.int_schema())})
+                    )
+                )
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'items': [{'a.b': 1}, {'a.b': 'x'}]})
+    display = exc_info.value.display(loc_format=loc_format)
+    assert display.splitlines()[1] == expected
+    assert display.replace(expected, 'items.1.`a.b`') == str(exc_info.value)
+
+
+def test_display_json_pointer_escaping():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a/b~c': 'x'})
+    assert exc_info.value.display(loc_format='json_pointer').splitlines()[1] == '/a~1b~0c'
+
+
+def test_display_root_error():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.display(loc_format='json_pointer') == str(exc_info.value)
+
+
+def test_display_invalid_loc_format():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    with pytest.raises(ValueError, match="Invalid loc_format: slashed, should be 'dotted', 'json_pointer' or"):
+        exc_info.value.display(loc_format='slashed')
+
+
+def test_errors_loc_as_pointer():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+                )
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'items': [{'a/b~c': 1, 'name': 'x'}, {'a/b~c': 'y'}]})
+    error = exc_info.value
+    assert [e['loc'] for e in error.errors()] == [('items', 0, 'name'), ('items', 1, 'a/b~c')]
+    assert [e['loc'] for e in error.errors(loc_as_pointer=True)] == ['/items/0/name', '/items/1/a~1b~0c']
+    assert [e['loc'] for e in json.loads(error.json(loc_as_pointer=True))] == ['/items/0/name', '/items/1/a~1b~0c']
+    assert [e['loc'] for e in json.loads(error.json())] == [['items', 0, 'name'], ['items', 1, 'a/b~c']]
+
+
+def test_errors_loc_as_pointer_root():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.errors(loc_as_pointer=True)[0]['loc'] == ''
+    assert json.loads(exc_info.value.json(loc_as_pointer=True))[0]['loc'] == ''
+
+
+def test_first_error():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'b': core_schema.typed_dict_field(core_schema.int_schema())})
+                ),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+                'd': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': {'b': 'x'}, 'c': 'y', 'd': 'z'})
+    error = exc_info.value
+
+    assert error.first_error() == error.errors()[0]
+    assert error.first_error()['loc'] == ('a', 'b')
+    assert error.first_error(prioritize_shallow=True) == error.errors()[1]
+    assert error.first_error(include_url=False, include_context=False, include_input=False) == {
+        'type': 'int_parsing',
+        'loc': ('a', 'b'),
+        'msg': 'Input should be a valid integer, unable to parse string as an integer',
+    }
+
+
+def test_first_error_no_errors():
+    assert ValidationError.from_exception_data('Foobar', []).first_error() is None
+
+
+def test_errors_url_types():
+    error = ValidationError.from_exception_data(
+        'Foobar',
+        [
+            {'type': 'int_type', 'loc': ('a',), 'input': 'x'},
+            {'type': 'string_type', 'loc': ('b',), 'input': 1},
+            {'type': PydanticCustomError('my_error', 'my message'), 'loc': ('c',), 'input': 2},
+        ],
+    )
+    assert ['url' in e for e in error.errors()] == [True, True, False]
+    assert ['url' in e for e in error.errors(url_types={'int_type', 'my_error'})] == [True, False, False]
+    assert ['url' in e for e in error.errors(exclude_url_types={'int_type'})] == [False, True, False]
+    assert ['url' in e for e in error.errors(url_types=frozenset(), include_url=True)] == [False, False, False]
+    assert ['url' in e for e in error.errors(include_url=False, url_types={'int_type'})] == [False, False, False]
+    assert [e.url is not None for e in error.errors(as_objects=True, url_types={'string_type'})] == [
+        False,
+        True,
+        False,
+    ]
+
+    assert ['url' in e for e in json.loads(error.json())] == [True, True, False]
+    assert ['url' in e for e in json.loads(error.json(url_types={'string_type'}))] == [False, True, False]
+    assert ['url' in e for e in json.loads(error.json(exclude_url_types={'string_type'}))] == [True, False, False]
+
+
+def test_errors_include_source():
+    inner = SchemaValidator(
+        core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}), {'title': 'Inner'}
+    )
+
+    def f(input_value):
+        return inner.validate_python(input_value)
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.no_info_plain_validator_function(f))
+                ),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        {'title': 'Outer'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [{'x': 1}, {'x': 'y'}], 'b': 'z'})
+    error = exc_info.value
+
+    assert [e['loc'] for e in error.errors()] == [('a', 1, 'x'), ('b',)]
+    assert all('source' not in e for e in error.errors())
+
+    errors = error.errors(include_source=True, include_url=False)
+    assert errors[0]['source'] == {'title': 'Inner', 'loc_prefix': ('a', 1)}
+    assert 'source' not in errors[1]
+
+    assert error.errors(include_source=True, loc_as_pointer=True)[0]['source'] == {
+        'title': 'Inner',
+        'loc_prefix': '/a/1',
+    }
+
+    json_errors = json.loads(error.json(include_source=True))
+    assert json_errors[0]['source'] == {'title': 'Inner', 'loc_prefix': ['a', 1]}
+    assert 'source' not in json_errors[1]
+    assert 'source' not in json.loads(error.json())[0]
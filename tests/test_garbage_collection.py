@@ -5,7 +5,7 @@ from weakref import WeakValueDictionary
 
 import pytest
 
-from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+from pydantic_core import RawJson, SchemaSerializer, SchemaValidator, Secret, core_schema
 
 GC_TEST_SCHEMA_INNER = core_schema.definitions_schema(
     core_schema.definition_reference_schema(schema_ref='model'),
@@ -118,3 +118,71 @@ def test_gc_validator_iterator() -> None:
     gc.collect(2)
 
     assert len(cache) == 0
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() == 'PyPy', reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899'
+)
+def test_gc_secret() -> None:
+    class Holder:
+        secret: Secret
+
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+
+    for _ in range(10_000):
+        holder = Holder()
+        holder.secret = Secret(holder)
+        cache[id(holder)] = holder
+        del holder
+
+    gc.collect(0)
+    gc.collect(1)
+    gc.collect(2)
+
+    assert len(cache) == 0
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() == 'PyPy', reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899'
+)
+def test_gc_raw_json() -> None:
+    class Holder:
+        raw_json: RawJson
+
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+
+    for _ in range(10_000):
+        holder = Holder()
+        holder.raw_json = RawJson(holder, '{}')
+        cache[id(holder)] = holder
+        del holder
+
+    gc.collect(0)
+    gc.collect(1)
+    gc.collect(2)
+
+    assert len(cache) == 0
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() == 'PyPy', reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899'
+)
+def test_gc_invalid_value() -> None:
+    class Holder(list):
+        pass
+
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+
+    for _ in range(10_000):
+        holder = Holder()
+        result = v.validate_python({'a': holder}, mark_invalid=True)
+        holder.append(result['a'])
+        cache[id(holder)] = holder
+        del holder, result
+
+    gc.collect(0)
+    gc.collect(1)
+    gc.collect(2)
+
+    assert len(cache) == 0
@@ -5,7 +5,7 @@ from datetime import datetime, timezone
 from typing import Optional
 
 import pytest
-from dirty_equals import AnyThing, IsBytes, IsStr, IsTuple
+from dirty_equals import AnyThing, IsBytes, IsInt, IsStr, IsTuple
 from hypothesis import given, strategies
 from typing_extensions import TypedDict
 
@@ -118,6 +118,7 @@ def test_definition_cycles(definition_schema, data):
                 'loc': IsTuple(length=(1, None)),
                 'msg': 'Recursion error - cyclic reference detected',
                 'input': AnyThing(),
+                'ctx': {'object_id': IsInt(), 'cycle_loc': IsTuple(length=(1, None))},
             }
         ]
 
@@ -0,0 +1,155 @@
+import json
+
+import pytest
+
+from pydantic_core import InvalidValue, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+class MyModel:
+    # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture(scope='module')
+def model_schema():
+    return core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(core_schema.str_schema(min_length=2)),
+                'age': core_schema.model_field(core_schema.int_schema(ge=0)),
+                'tags': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default=[])
+                ),
+            }
+        ),
+    )
+
+
+def test_mark_invalid_model(model_schema):
+    v = SchemaValidator(model_schema)
+    m = v.validate_python({'name': 'x', 'age': -1, 'tags': [1, 'a']}, mark_invalid=True)
+    assert isinstance(m.name, InvalidValue)
+    assert m.name.input == 'x'
+    assert m.name.errors() == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': 'String should have at least 2 characters',
+            'ctx': {'min_length': 2},
+        }
+    ]
+    assert m.age.errors(include_context=False) == [
+        {'type': 'greater_than_equal', 'loc': (), 'msg': 'Input should be greater than or equal to 0'}
+    ]
+    assert m.tags.errors()[0]['loc'] == (1,)
+    assert repr(m.age) == 'InvalidValue(input=-1, error_count=1)'
+    assert m.__pydantic_fields_set__ == {'name', 'age', 'tags'}
+
+    m = v.validate_python({'name': 'xy', 'age': 1}, mark_invalid=True)
+    assert m.__dict__ == {'name': 'xy', 'age': 1, 'tags': []}
+
+
+def test_mark_invalid_off_by_default(model_schema):
+    v = SchemaValidator(model_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'x', 'age': 1})
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('name',)
+
+
+def test_mark_invalid_missing_still_raises(model_schema):
+    v = SchemaValidator(model_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'x'}, mark_invalid=True)
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing']
+
+
+def test_mark_invalid_json(model_schema):
+    v = SchemaValidator(model_schema)
+    m = v.validate_json('{"name": "xy", "age": "old", "tags": [1]}', mark_invalid=True)
+    assert m.name == 'xy'
+    assert m.age.input == 'old'
+    assert m.age.errors()[0]['type'] == 'int_parsing'
+
+
+def test_mark_invalid_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(), required=False),
+            }
+        )
+    )
+    output = v.validate_python({'a': 'x', 'b': 1}, mark_invalid=True)
+    assert output.keys() == {'a', 'b'}
+    assert output['a'].input == 'x'
+    assert output['b'].errors()[0]['type'] == 'string_type'
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right'])
+def test_mark_invalid_union(mode):
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.str_schema())}),
+            ],
+            mode=mode,
+        )
+    )
+    assert v.validate_python({'a': 'x'}, mark_invalid=True) == {'a': 'x'}
+    assert v.validate_python({'a': 1}, mark_invalid=True) == {'a': 1}
+
+    # with no valid choice, the first with invalid fields is used
+    output = v.validate_python({'a': []}, mark_invalid=True)
+    assert output['a'].errors()[0]['type'] == 'int_type'
+
+
+def test_inline_errors(model_schema):
+    v = SchemaValidator(model_schema)
+    s = SchemaSerializer(model_schema)
+    m = v.validate_python({'name': 'x', 'age': 3}, mark_invalid=True)
+    error = {
+        '__error__': {
+            'input': 'x',
+            'errors': [
+                {
+                    'type': 'string_too_short',
+                    'loc': [],
+                    'msg': 'String should have at least 2 characters',
+                    'ctx': {'min_length': 2},
+                }
+            ],
+        }
+    }
+    assert json.loads(s.to_json(m, inline_errors=True)) == {'name': error, 'age': 3, 'tags': []}
+    assert s.to_python(m, mode='json', inline_errors=True) == {'name': error, 'age': 3, 'tags': []}
+    python_error = s.to_python(m, inline_errors=True)['name']
+    assert python_error['__error__']['errors'][0]['loc'] == ()
+
+    ctx = s.serializer_context(inline_errors=True)
+    assert json.loads(ctx.to_json(m)) == {'name': error, 'age': 3, 'tags': []}
+
+
+def test_inline_errors_exception_context():
+    def f(value):
+        raise ValueError('bad value')
+
+    schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.no_info_plain_validator_function(f))}
+    )
+    output = SchemaValidator(schema).validate_python({'a': object()}, mark_invalid=True)
+    error = json.loads(SchemaSerializer(schema).to_json(output, inline_errors=True))['a']['__error__']
+    assert error['input'].startswith('<object object at')
+    assert error['errors'] == [
+        {'type': 'value_error', 'loc': [], 'msg': 'Value error, bad value', 'ctx': {'error': 'bad value'}}
+    ]
+
+
+def test_invalid_value_without_inline_errors(model_schema):
+    v = SchemaValidator(model_schema)
+    s = SchemaSerializer(model_schema)
+    m = v.validate_python({'name': 'xy', 'age': 'x'}, mark_invalid=True)
+    with pytest.warns(UserWarning, match='Expected `int` but got `InvalidValue`'):
+        assert s.to_python(m)['age'] is m.age
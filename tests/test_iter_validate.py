@@ -0,0 +1,83 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_iter_validate():
+    v = SchemaValidator(core_schema.int_schema())
+    results = list(v.iter_validate([1, '2', 'x', 4]))
+    assert [index for index, _ in results] == [0, 1, 2, 3]
+    assert results[0] == (0, 1)
+    assert results[1] == (1, 2)
+    assert results[3] == (3, 4)
+
+    index, error = results[2]
+    assert index == 2
+    assert isinstance(error, ValidationError)
+    assert error.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+
+def test_iter_validate_lazy():
+    consumed = []
+
+    def gen():
+        for i in range(3):
+            consumed.append(i)
+            yield i
+
+    v = SchemaValidator(core_schema.int_schema())
+    it = v.iter_validate(gen())
+    assert consumed == []
+    assert next(it) == (0, 0)
+    assert consumed == [0]
+    assert it.index == 1
+    assert list(it) == [(1, 1), (2, 2)]
+    assert consumed == [0, 1, 2]
+    with pytest.raises(StopIteration):
+        next(it)
+
+
+def test_iter_validate_strict_context():
+    def f(input_value, info):
+        return input_value * info.context['multiplier']
+
+    v = SchemaValidator(core_schema.with_info_after_validator_function(f, core_schema.int_schema()))
+    results = list(v.iter_validate([1, '2'], strict=True, context={'multiplier': 10}))
+    assert results[0] == (0, 10)
+    assert isinstance(results[1][1], ValidationError)
+    assert results[1][1].errors(include_url=False)[0]['type'] == 'int_type'
+
+
+def test_iter_validate_iteration_error():
+    def gen():
+        yield 1
+        raise RuntimeError('broken source')
+
+    v = SchemaValidator(core_schema.int_schema())
+    it = v.iter_validate(gen())
+    assert next(it) == (0, 1)
+    with pytest.raises(RuntimeError, match='broken source'):
+        next(it)
+
+
+def test_iter_validate_not_iterable():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='is not iterable'):
+        v.iter_validate(1)
+
+
+def test_iter_validate_internal_error():
+    def f(input_value):
+        raise TypeError('internal')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(TypeError, match='internal'):
+        list(v.iter_validate([1]))
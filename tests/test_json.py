@@ -1,6 +1,8 @@
+import io
 import json
 import platform
 import re
+from decimal import Decimal
 from typing import List
 
 import pytest
@@ -111,6 +113,24 @@ def test_float(input_value, expected):
         assert v.validate_json(input_value) == expected
 
 
+def _validate_or_error_types(validate, input_value, strict):
+    try:
+        return validate(input_value, strict=strict)
+    except ValidationError as e:
+        return [error['type'] for error in e.errors()]
+
+
+@pytest.mark.parametrize('strict', [True, False])
+@pytest.mark.parametrize('schema_type', ['int', 'float', 'str', 'bool', 'none'])
+@pytest.mark.parametrize('input_value', [5, 5.0, 5.5, '5', 'x', True, None, [5]])
+def test_scalar_root_parity(schema_type, input_value, strict):
+    """A scalar at the root of a JSON document validates the same as the equivalent python value."""
+    v = SchemaValidator({'type': schema_type})
+    from_python = _validate_or_error_types(v.validate_python, input_value, strict)
+    from_json = _validate_or_error_types(v.validate_json, json.dumps(input_value), strict)
+    assert from_json == from_python
+
+
 def test_typed_dict():
     v = SchemaValidator(
         {
@@ -158,6 +178,7 @@ def test_error_loc():
             'loc': ('field_a', 2),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -203,6 +224,130 @@ def test_json_invalid():
     ]
 
 
+def test_json_max_input_size():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+    assert v.validate_json('[1, 2]', max_input_size=6) == [1, 2]
+    assert v.validate_json(b'[1, 2]', max_input_size=6) == [1, 2]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 2, 3]', max_input_size=6)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_too_large',
+            'loc': (),
+            'msg': 'JSON input should be at most 6 bytes',
+            'input': '[1, 2, 3]',
+            'ctx': {'max_input_size': 6},
+        }
+    ]
+    # the size is checked before parsing, so invalid JSON gets the same error
+    with pytest.raises(ValidationError, match=r'JSON input should be at most 6 bytes \[type=json_too_large'):
+        v.validate_json('[1, 2, 3', max_input_size=6)
+    # size is measured in bytes, not characters
+    with pytest.raises(ValidationError, match=r'\[type=json_too_large'):
+        SchemaValidator({'type': 'str'}).validate_json('"££"', max_input_size=5)
+
+
+def test_json_on_progress():
+    v = SchemaValidator(core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema())))
+    items = [{'id': i, 'name': f'item {i}', 'tags': ['a', 'b'], 'x': [1.5, None, True]} for i in range(20_000)]
+    data = json.dumps(items).encode()
+    offsets = []
+    assert v.validate_json(data, on_progress=offsets.append) == items
+    assert len(offsets) > 2
+    assert offsets == sorted(offsets)
+    assert offsets[-1] == len(data)
+    assert all(0 < offset <= len(data) for offset in offsets)
+
+
+def test_json_on_progress_small():
+    v = SchemaValidator(core_schema.any_schema())
+    data = '{"a": [1, "\\u00a3", {}], "b": "x"}'
+    offsets = []
+    assert v.validate_json(data, on_progress=offsets.append) == {'a': [1, '£', {}], 'b': 'x'}
+    assert offsets == [len(data)]
+    offsets = []
+    assert v.validate_json('12', on_progress=offsets.append) == 12
+    assert offsets == [2]
+
+
+def test_json_on_progress_invalid():
+    v = SchemaValidator(core_schema.any_schema())
+    for data in ['[1, 2', '{"a": 1,}', '[1] x', '[' * 300 + ']' * 300, '{"a":' * 300 + '1' + '}' * 300, '']:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_json(data, on_progress=lambda _: None)
+        with pytest.raises(ValidationError) as exc_info_no_progress:
+            v.validate_json(data)
+        assert exc_info.value.errors(include_url=False) == exc_info_no_progress.value.errors(include_url=False)
+
+
+def test_json_on_progress_error():
+    def on_progress(offset):
+        raise RuntimeError('cancelled')
+
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(RuntimeError, match='cancelled'):
+        v.validate_json('[1, 2]', on_progress=on_progress)
+
+
+def test_json_on_progress_allow_partial():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValueError, match='`on_progress` cannot be used with `allow_partial`'):
+        v.validate_json('[1, 2', allow_partial=True, on_progress=lambda _: None)
+
+
+def test_json_concatenated():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert v.validate_json('{"a": 1}\n{"a": "2"}\n', json_mode='concatenated') == [{'a': 1}, {'a': 2}]
+    output = v.validate_json(b' {"a": 1}{"a": 2}\t\r\n {"a": 3}', json_mode='concatenated')
+    assert output == [{'a': 1}, {'a': 2}, {'a': 3}]
+    assert v.validate_json('{"a": 1}', json_mode='concatenated') == [{'a': 1}]
+    assert v.validate_json(' \n', json_mode='concatenated') == []
+
+    assert SchemaValidator(core_schema.int_schema()).validate_json('1 2\n3', json_mode='concatenated') == [1, 2, 3]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1}\n{"a": "x"}\n{}', json_mode='concatenated')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1, 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        },
+        {'type': 'missing', 'loc': (2, 'a'), 'msg': 'Field required', 'input': {}},
+    ]
+
+
+def test_json_concatenated_invalid():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1}\n{"a": 2', json_mode='concatenated')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': 'Invalid JSON: EOF while parsing an object at line 2 column 7',
+            'input': '{"a": 1}\n{"a": 2',
+            'ctx': {'error': 'EOF while parsing an object at line 2 column 7'},
+        }
+    ]
+
+    with pytest.raises(ValueError, match="Invalid json_mode, should be `'document'` or `'concatenated'`"):
+        v.validate_json('1', json_mode='lines')
+    with pytest.raises(ValueError, match="`json_mode='concatenated'` cannot be used with `allow_partial`"):
+        v.validate_json('1', json_mode='concatenated', allow_partial=True)
+
+
+def test_json_concatenated_decimal():
+    v = SchemaValidator(core_schema.decimal_schema())
+    assert v.validate_json('1.10 0.1234567890123456789', json_mode='concatenated', json_float_mode='decimal') == [
+        Decimal('1.10'),
+        Decimal('0.1234567890123456789'),
+    ]
+
+
 class Foobar:
     def __str__(self):
         return 'Foobar.__str__'
@@ -222,6 +367,85 @@ def test_to_json():
         to_json([1, 2], 2)
 
 
+def test_to_json_file():
+    f = io.BytesIO()
+    assert to_json([1, 2], file=f) is None
+    assert f.getvalue() == b'[1,2]'
+
+    f = io.BytesIO()
+    assert to_json({'a': [1, 2]}, indent=2, file=f) is None
+    assert f.getvalue() == b'{\n  "a": [\n    1,\n    2\n  ]\n}'
+
+
+def test_to_json_file_large():
+    value = [{'id': i, 'name': f'item {i}'} for i in range(10_000)]
+    writes = []
+
+    class Writer:
+        def write(self, data):
+            writes.append(data)
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.dict_schema()))
+    assert s.to_json(value, file=Writer()) is None
+    # output is written in chunks rather than in one go
+    assert len(writes) > 1
+    assert all(isinstance(chunk, bytes) for chunk in writes)
+    assert b''.join(writes) == s.to_json(value)
+
+
+def test_to_json_file_partial_write():
+    class PartialWriter:
+        def __init__(self):
+            self.data = b''
+
+        def write(self, data):
+            # only ever accept a few bytes at a time, like a raw stream might
+            chunk = bytes(data[:3])
+            self.data += chunk
+            return len(chunk)
+
+    f = PartialWriter()
+    assert to_json({'a': 'hello world'}, file=f) is None
+    assert f.data == b'{"a":"hello world"}'
+
+
+def test_to_json_file_write_zero():
+    class ZeroWriter:
+        def write(self, data):
+            return 0
+
+    with pytest.raises(OSError, match=r'write\(\) returned 0'):
+        to_json([1, 2], file=ZeroWriter())
+
+
+def test_to_json_file_write_error():
+    class BrokenWriter:
+        def write(self, data):
+            raise RuntimeError('disk full')
+
+    with pytest.raises(RuntimeError, match='disk full'):
+        to_json([1, 2], file=BrokenWriter())
+
+    value = ['x' * 1000 for _ in range(100)]
+    with pytest.raises(RuntimeError, match='disk full'):
+        SchemaSerializer(core_schema.list_schema()).to_json(value, file=BrokenWriter())
+
+
+def test_to_json_file_serialization_error():
+    f = io.BytesIO()
+    with pytest.raises(PydanticSerializationError, match=r'Unable to serialize unknown type: <.+\.Foobar'):
+        to_json([1, Foobar()], file=f)
+
+
+def test_to_json_file_collect_warnings():
+    s = SchemaSerializer(core_schema.int_schema())
+    f = io.BytesIO()
+    output, warnings = s.to_json('x', file=f, collect_warnings=True)
+    assert output is None
+    assert f.getvalue() == b'"x"'
+    assert len(warnings) == 1
+
+
 def test_to_json_fallback():
     with pytest.raises(PydanticSerializationError, match=r'Unable to serialize unknown type: <.+\.Foobar'):
         to_json(Foobar())
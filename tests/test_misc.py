@@ -139,12 +139,14 @@ def test_validation_error_multiple(pydantic_version):
             'loc': ('x',),
             'msg': 'Input should be a valid number, unable to parse string as a number',
             'input': 'x' * 60,
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {
             'type': 'int_parsing',
             'loc': ('y',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'y',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
     assert repr(exc_info.value) == (
@@ -244,6 +244,17 @@ all_schema_functions = [
             'custom_error_message': 'Hello',
         },
     ),
+    (
+        core_schema.constraints_schema,
+        args(core_schema.int_schema(), [{'kind': 'gt', 'value': 0}]),
+        {'type': 'constraints', 'schema': {'type': 'int'}, 'constraints': [{'kind': 'gt', 'value': 0}]},
+    ),
+    (
+        core_schema.self_validating_schema,
+        args(core_schema.int_schema()),
+        {'type': 'self-validating', 'schema': {'type': 'int'}},
+    ),
+    (core_schema.secret_schema, args(core_schema.str_schema()), {'type': 'secret', 'schema': {'type': 'str'}}),
     (core_schema.json_schema, args({'type': 'int'}), {'type': 'json', 'schema': {'type': 'int'}}),
     (core_schema.url_schema, args(), {'type': 'url'}),
     (core_schema.multi_host_url_schema, args(), {'type': 'multi-host-url'}),
@@ -291,6 +302,10 @@ all_schema_functions = [
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
     (core_schema.complex_schema, args(), {'type': 'complex'}),
+    (core_schema.fraction_schema, args(), {'type': 'fraction'}),
+    (core_schema.fraction_schema, args(allow_float=True, le=1), {'type': 'fraction', 'allow_float': True, 'le': 1}),
+    (core_schema.base64_schema, args(), {'type': 'base64'}),
+    (core_schema.base64_schema, args(url_safe=True), {'type': 'base64', 'url_safe': True}),
     (core_schema.invalid_schema, args(), {'type': 'invalid'}),
 ]
 
@@ -0,0 +1,82 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_validate_many():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many([1, '2', 3.0]) == [1, 2, 3]
+    assert v.validate_many((i for i in range(3))) == [0, 1, 2]
+    assert v.validate_many([]) == []
+
+
+def test_validate_many_raises_first_error():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_many([1, 'x', 'y'])
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+
+def test_validate_many_nested_location():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_many([{'a': 1}, {'a': 2}, {'a': 'x'}])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(2, 'a')]
+
+
+def test_validate_many_return_errors():
+    v = SchemaValidator(core_schema.int_schema())
+    results = v.validate_many([1, 'x', '3'], return_errors=True)
+    assert results[0] == 1
+    assert results[2] == 3
+
+    error = results[1]
+    assert isinstance(error, ValidationError)
+    assert error.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+
+def test_validate_many_strict():
+    v = SchemaValidator(core_schema.int_schema())
+    results = v.validate_many([1, '2'], strict=True, return_errors=True)
+    assert results[0] == 1
+    assert isinstance(results[1], ValidationError)
+
+
+def test_validate_many_context():
+    def f(input_value, info):
+        return input_value * info.context['multiplier']
+
+    v = SchemaValidator(core_schema.with_info_after_validator_function(f, core_schema.int_schema()))
+    assert v.validate_many([1, 2], context={'multiplier': 10}) == [10, 20]
+
+
+def test_validate_many_not_iterable():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match="'int' object is not iterable"):
+        v.validate_many(1)
+
+
+def test_validate_many_internal_error():
+    def f(input_value):
+        raise RuntimeError('boom')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='boom'):
+        v.validate_many([1], return_errors=True)
@@ -0,0 +1,214 @@
+import copy
+import dataclasses
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    def __copy__(self):
+        # matches `BaseModel.__copy__`, the fields set must not be shared with the copy
+        m = self.__class__.__new__(self.__class__)
+        m.__dict__ = copy.copy(self.__dict__)
+        m.__pydantic_fields_set__ = copy.copy(self.__pydantic_fields_set__)
+        m.__pydantic_extra__ = copy.copy(self.__pydantic_extra__)
+        return m
+
+
+@pytest.fixture
+def model_validator():
+    return SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.str_schema()),
+                    'c': core_schema.model_field(core_schema.bool_schema()),
+                }
+            ),
+        )
+    )
+
+
+def test_model(model_validator: SchemaValidator):
+    base = model_validator.validate_python({'a': 1, 'b': 'x', 'c': True})
+    base.__pydantic_fields_set__ = {'a'}
+
+    m = model_validator.validate_update(base, {'a': '2', 'b': 'y'})
+    assert isinstance(m, MyModel)
+    assert m is not base
+    assert m.__dict__ == {'a': 2, 'b': 'y', 'c': True}
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+    assert base.__dict__ == {'a': 1, 'b': 'x', 'c': True}
+    assert base.__pydantic_fields_set__ == {'a'}
+
+
+class PlainCopyModel:
+    # without `__copy__`, `copy.copy` shares the fields set between the copy and the original
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def test_model_fields_set_not_shared():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            PlainCopyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+                }
+            ),
+        )
+    )
+    base = v.validate_python({'a': 1})
+    assert base.__pydantic_fields_set__ == {'a'}
+
+    m = v.validate_update(base, {'b': 2})
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+    assert base.__pydantic_fields_set__ == {'a'}
+
+    with pytest.raises(ValidationError):
+        v.validate_update(base, {'b': 3, 'a': 'x'})
+    assert base.__pydantic_fields_set__ == {'a'}
+    assert base.__dict__ == {'a': 1, 'b': 0}
+
+
+def test_model_errors(model_validator: SchemaValidator):
+    base = model_validator.validate_python({'a': 1, 'b': 'x', 'c': True})
+
+    with pytest.raises(ValidationError) as exc_info:
+        model_validator.validate_update(base, {'a': 'x', 'b': 'y', 'c': 'z'})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        },
+        {
+            'type': 'bool_parsing',
+            'loc': ('c',),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'z',
+        },
+    ]
+    assert base.__dict__ == {'a': 1, 'b': 'x', 'c': True}
+
+
+def test_model_unknown_field(model_validator: SchemaValidator):
+    base = model_validator.validate_python({'a': 1, 'b': 'x', 'c': True})
+
+    with pytest.raises(ValidationError) as exc_info:
+        model_validator.validate_update(base, {'d': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'no_such_attribute',
+            'loc': ('d',),
+            'msg': "Object has no attribute 'd'",
+            'input': 1,
+            'ctx': {'attribute': 'd'},
+        }
+    ]
+
+
+def test_empty_patch(model_validator: SchemaValidator):
+    base = model_validator.validate_python({'a': 1, 'b': 'x', 'c': True})
+    m = model_validator.validate_update(base, {})
+    assert m is not base
+    assert m.__dict__ == base.__dict__
+
+
+def test_info_data():
+    calls = []
+
+    def f(v, info):
+        calls.append(info.data)
+        return v
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_info_after_validator_function(f, core_schema.int_schema())
+                ),
+            }
+        )
+    )
+    assert v.validate_update({'a': 1, 'b': 2}, {'a': '3', 'b': '4'}) == {'a': 3, 'b': 4}
+    # fields earlier in the patch are already applied when later fields are validated
+    assert calls == [{'a': 3}]
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    base = {'a': 1, 'b': [1, 2]}
+    assert v.validate_update(base, {'b': ['3']}) == {'a': 1, 'b': [3]}
+    assert base == {'a': 1, 'b': [1, 2]}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_update(base, {'b': [1, 'x']})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+
+def test_typed_dict_extra():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_update({'a': 1}, {'x': '2'}) == {'a': 1, 'x': 2}
+
+
+def test_dataclass():
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: int
+        b: str
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            MyDataclass,
+            core_schema.dataclass_args_schema(
+                'MyDataclass',
+                [
+                    core_schema.dataclass_field('a', core_schema.int_schema()),
+                    core_schema.dataclass_field('b', core_schema.str_schema()),
+                ],
+            ),
+            ['a', 'b'],
+        )
+    )
+    base = MyDataclass(a=1, b='x')
+    assert v.validate_update(base, {'a': '2'}) == MyDataclass(a=2, b='x')
+    assert base == MyDataclass(a=1, b='x')
+
+
+def test_not_supported():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='validate_assignment is not supported for int'):
+        v.validate_update(1, {'a': 1})
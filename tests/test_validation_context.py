@@ -117,3 +117,47 @@ def test_validate_assignment_with_context():
     assert m2 == {'f1': "3| context: {'x': 'y', 'f1': '3'}", 'f2': "2| context: {'x': 'y', 'f1': '1', 'f2': '2'}"}
     assert model_extra is None
     assert fields_set == {'f1'}
+
+
+def test_default_context():
+    def f(input_value, info):
+        return input_value + f'| context: {info.context}'
+
+    v = SchemaValidator(
+        core_schema.with_info_plain_validator_function(f), default_context={'locale': 'en', 'currency': 'EUR'}
+    )
+
+    assert v.validate_python('a') == "a| context: {'locale': 'en', 'currency': 'EUR'}"
+    assert v.validate_python('a', context={'locale': 'fr', 'x': 1}) == (
+        "a| context: {'locale': 'fr', 'currency': 'EUR', 'x': 1}"
+    )
+    assert v.validate_json('"a"', context={'x': 1}) == "a| context: {'locale': 'en', 'currency': 'EUR', 'x': 1}"
+    assert v.validate_strings('a', context={}) == "a| context: {'locale': 'en', 'currency': 'EUR'}"
+    # a context which isn't a dict can't be merged, so it replaces the defaults
+    assert v.validate_python('a', context='frogspawn') == 'a| context: frogspawn'
+
+
+def test_default_context_not_mutated():
+    def f(input_value, info):
+        info.context['seen'] = input_value
+        return input_value
+
+    default_context = {'x': 'y'}
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f), default_context=default_context)
+    per_call_context = {'a': 'b'}
+
+    assert v.validate_python('1') == '1'
+    assert v.validate_python('2', context=per_call_context) == '2'
+    assert default_context == {'x': 'y'}
+    assert per_call_context == {'a': 'b'}
+
+
+def test_default_context_validate_assignment():
+    def f(input_value, info):
+        return input_value + f'| context: {info.context}'
+
+    field = core_schema.model_field(core_schema.with_info_plain_validator_function(f))
+    v = SchemaValidator(core_schema.model_fields_schema({'f': field}), default_context={'x': 'y'})
+    m, _, _ = v.validate_python({'f': '1'})
+    m, _, _ = v.validate_assignment(m, 'f', '2', context={'z': 1})
+    assert m == {'f': "2| context: {'x': 'y', 'z': 1}"}
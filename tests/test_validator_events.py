@@ -0,0 +1,138 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_validator_events():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    events = []
+    assert v.validate_python(
+        {'a': '1', 'b': ['x', 'y']}, on_validator_event=lambda *args: events.append(args)
+    ) == {'a': 1, 'b': ['x', 'y']}
+    assert events == [
+        ('enter', 'typed-dict', ()),
+        ('enter', 'int', ('a',)),
+        ('exit', 'int', ('a',)),
+        ('enter', 'list[str]', ('b',)),
+        ('enter', 'str', ('b', 0)),
+        ('exit', 'str', ('b', 0)),
+        ('enter', 'str', ('b', 1)),
+        ('exit', 'str', ('b', 1)),
+        ('exit', 'list[str]', ('b',)),
+        ('exit', 'typed-dict', ()),
+    ]
+
+
+def test_validator_events_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    events = []
+    with pytest.raises(ValidationError):
+        v.validate_python(['x'], on_validator_event=lambda *args: events.append(args))
+    # validators are exited when they fail, too
+    assert events == [
+        ('enter', 'list[int]', ()),
+        ('enter', 'int', (0,)),
+        ('exit', 'int', (0,)),
+        ('exit', 'list[int]', ()),
+    ]
+
+
+def test_validator_events_callback_error():
+    v = SchemaValidator(core_schema.int_schema())
+
+    def on_validator_event(event, name, location):
+        raise RuntimeError(f'{event} {name}')
+
+    with pytest.raises(RuntimeError, match='enter int'):
+        v.validate_python(1, on_validator_event=on_validator_event)
+
+
+def test_validator_events_not_set():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python('1') == 1
+    assert v.validate_python('1', on_validator_event=None) == 1
+
+
+def test_validator_stats():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    output, stats = v.validate_python({'a': '1', 'b': ['x', 'y', 'z']}, return_validator_stats=True)
+    assert output == {'a': 1, 'b': ['x', 'y', 'z']}
+    assert stats == {'typed-dict': 1, 'int': 1, 'list[str]': 1, 'str': 3}
+    # ordered as the validators first ran
+    assert list(stats) == ['typed-dict', 'int', 'list[str]', 'str']
+
+    assert v.validate_python({'a': 1, 'b': []}) == {'a': 1, 'b': []}
+
+
+def test_validator_stats_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    # choices that were tried count, even if they failed
+    assert v.validate_python('1', return_validator_stats=True) == ('1', {'union[int,str]': 1, 'int': 1, 'str': 1})
+
+
+def test_validator_stats_with_events():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    events = []
+    output, stats = v.validate_python(
+        [1, 2], on_validator_event=lambda event, name, location: events.append(event), return_validator_stats=True
+    )
+    assert output == [1, 2]
+    assert stats == {'list[int]': 1, 'int': 2}
+    assert len(events) == 6
+
+
+def test_validator_stats_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError):
+        v.validate_python(['x'], return_validator_stats=True)
+
+
+def test_validator_events_exact_list_items():
+    # exact items of strict lists are usually copied without running the item validator
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(strict=True)))
+    events = []
+    assert v.validate_python([1, 2], on_validator_event=lambda *args: events.append(args[:2])) == [1, 2]
+    assert events == [
+        ('enter', 'list[int]'),
+        ('enter', 'int'),
+        ('exit', 'int'),
+        ('enter', 'int'),
+        ('exit', 'int'),
+        ('exit', 'list[int]'),
+    ]
+    assert v.validate_python([1, 2, 3], return_validator_stats=True) == ([1, 2, 3], {'list[int]': 1, 'int': 3})
+
+
+def test_validator_events_location():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.str_schema(),
+            core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0),
+        )
+    )
+    locations = []
+    v.validate_python(
+        {'x': (1, 2)},
+        on_validator_event=lambda event, name, location: event == 'enter' and locations.append((name, location)),
+    )
+    assert locations == [
+        ('dict[str,tuple[int, ...]]', ()),
+        ('str', ('x',)),
+        ('tuple[int, ...]', ('x',)),
+        ('int', ('x', 0)),
+        ('int', ('x', 1)),
+    ]
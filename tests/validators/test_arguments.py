@@ -120,6 +120,7 @@ def test_args_kwargs():
                         'loc': (0,),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'x',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     },
                     {
                         'type': 'bool_parsing',
@@ -226,6 +227,7 @@ def test_positional_args(py_and_json: PyAndJson, input_value, expected):
                         'loc': ('a',),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'x',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     },
                     {
                         'type': 'bool_parsing',
@@ -545,6 +547,7 @@ def test_var_args_only(py_and_json: PyAndJson, input_value, expected):
                         'loc': (0,),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'x',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     }
                 ],
             ),
@@ -559,12 +562,14 @@ def test_var_args_only(py_and_json: PyAndJson, input_value, expected):
                         'loc': (1,),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'x',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     },
                     {
                         'type': 'int_parsing',
                         'loc': (2,),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'y',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     },
                 ],
             ),
@@ -993,6 +998,7 @@ def test_function_types():
             'loc': (1,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'b',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {
             'type': 'missing_keyword_only_argument',
@@ -1011,12 +1017,14 @@ def test_function_types():
             'loc': (1,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'b',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {
             'type': 'int_parsing',
             'loc': ('c',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'c',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
@@ -0,0 +1,78 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('aGVsbG8/', b'hello?'),
+        ('aGk=', b'hi'),
+        ('', b''),
+        ('aGk', Err('Input should be valid base64, Invalid padding [type=base64_decode')),
+        ('aGVsbG8_', Err("Input should be valid base64, Invalid symbol 95, offset 7. [type=base64_decode")),
+        ('?', Err('Input should be valid base64, ')),
+        (123, Err('Input should be a valid string [type=string_type')),
+    ],
+)
+def test_base64(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.base64_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('aGVsbG8_', b'hello?'),
+        ('aGk=', b'hi'),
+        ('aGVsbG8/', Err('Input should be valid base64, Invalid symbol 47, offset 7. [type=base64_decode')),
+    ],
+)
+def test_base64_url_safe(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.base64_schema(url_safe=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_allow_missing_padding():
+    v = SchemaValidator(core_schema.base64_schema(allow_missing_padding=True))
+    assert v.validate_python('aGk') == b'hi'
+    assert v.validate_python('aGk=') == b'hi'
+
+
+def test_bytes_input():
+    v = SchemaValidator(core_schema.base64_schema())
+    assert v.validate_python(b'aGk=') == b'hi'
+
+    v = SchemaValidator(core_schema.base64_schema(strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'aGk=')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': b'aGk='}
+    ]
+
+
+def test_base64_error_details():
+    v = SchemaValidator(core_schema.base64_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('aGk')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'base64_decode',
+            'loc': (),
+            'msg': 'Input should be valid base64, Invalid padding',
+            'input': 'aGk',
+            'ctx': {'error': 'Invalid padding'},
+        }
+    ]
@@ -79,12 +79,14 @@ def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false,allow_bool_from_int:true,numeric_strict:false}),'
+        'definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'bool', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true,allow_bool_from_int:true,numeric_strict:false}),'
+        'definitions=[],cache_strings=True)'
     )
 
 
@@ -107,3 +109,51 @@ def test_validate_assignment_not_supported() -> None:
     v = SchemaValidator(core_schema.bool_schema())
     with pytest.raises(TypeError, match='validate_assignment is not supported for bool'):
         v.validate_assignment(False, 'foo', True)
+
+
+def test_allow_bool_from_int(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.bool_schema(allow_bool_from_int=False))
+    assert v.validate_test(True) is True
+    # other lax coercions are unaffected
+    assert v.validate_test('true') is True
+    for input_value in (1, 0):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        # insert_assert(exc_info.value.errors(include_url=False))
+        assert exc_info.value.errors(include_url=False) == [
+            {'type': 'bool_type', 'loc': (), 'msg': 'Input should be a valid boolean', 'input': input_value}
+        ]
+
+    assert SchemaValidator(core_schema.bool_schema()).validate_python(1) is True
+
+
+def test_numeric_strict(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.bool_schema(numeric_strict=True))
+    assert v.validate_test(True) is True
+    assert v.validate_test(1) is True
+    assert v.validate_test(0) is False
+    assert v.validate_test('1') is True
+    assert v.validate_test('0') is False
+    # non-numeric strings are unaffected
+    assert v.validate_test('yes') is True
+    assert v.validate_test('off') is False
+
+    for input_value in (2, -1, '2', '3.14', '1.0', 3.14, 1.0):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        # insert_assert(exc_info.value.errors(include_url=False))
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'bool_parsing',
+                'loc': (),
+                'msg': 'Input should be a valid boolean, unable to interpret input',
+                'input': input_value,
+                'ctx': {'value': input_value},
+            }
+        ]
+
+    with pytest.raises(ValidationError, match=r'Input should be a valid boolean \[type=bool_type'):
+        v.validate_test(1, strict=True)
+
+    # without `numeric_strict` floats which are whole numbers are still accepted
+    assert SchemaValidator(core_schema.bool_schema()).validate_python(1.0) is True
@@ -121,3 +121,41 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+def test_length_target(py_and_json: PyAndJson):
+    # 'aGVsbG8=' is 8 characters of base64 which decodes to the 5 bytes of b'hello'
+    v = py_and_json({'type': 'bytes', 'max_length': 6}, {'val_json_bytes': 'base64'})
+    assert v.validate_test('aGVsbG8=') == b'hello'
+
+    v = py_and_json({'type': 'bytes', 'max_length': 6, 'length_target': 'source'}, {'val_json_bytes': 'base64'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('aGVsbG8=')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_too_long',
+            'loc': (),
+            'msg': 'Data should have at most 6 characters',
+            'input': 'aGVsbG8=',
+            'ctx': {'max_length': 6, 'unit': 'characters'},
+        }
+    ]
+
+
+def test_length_target_without_encoding():
+    v = SchemaValidator({'type': 'bytes', 'min_length': 3, 'length_target': 'source'})
+    # without an encoding the decoded bytes are used, b'\xc3\xa9\xc3\xa9' is 4 bytes but 2 characters
+    assert v.validate_python('éé') == 'éé'.encode()
+    with pytest.raises(ValidationError, match='Data should have at least 3 bytes'):
+        v.validate_python(b'ab')
+
+
+def test_length_target_python_bytes():
+    v = SchemaValidator({'type': 'bytes', 'min_length': 3, 'length_target': 'source'}, {'val_json_bytes': 'hex'})
+    # bytes input isn't decoded, so its own length is used
+    assert v.validate_python(b'abc') == b'abc'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('61')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'min_length': 3, 'unit': 'characters'}
+    assert exc_info.value.errors(include_url=False)[0]['msg'] == 'Data should have at least 3 characters'
+    assert v.validate_python('616263') == b'abc'
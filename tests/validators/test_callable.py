@@ -1,3 +1,5 @@
+from functools import partial
+
 import pytest
 
 from pydantic_core import SchemaValidator, ValidationError
@@ -44,6 +46,9 @@ def test_callable():
         ((1,), False),
         (CallableClass, True),
         (CallableClass(), True),
+        (partial(lambda x, y: x + y, 1), True),
+        (CallableClass().__call__, True),
+        ('ddd'.upper, True),
     ],
 )
 def test_callable_cases(input_value, expected):
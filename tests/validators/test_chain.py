@@ -50,6 +50,7 @@ def test_chain_error():
             'loc': (),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'abc',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -5,7 +5,7 @@ import sys
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err
 
@@ -153,3 +153,30 @@ def test_string_complex():
     assert v.validate_strings('+1.23e-4-5.67e+8J') == complex(1.23e-4, -5.67e8)
     with pytest.raises(ValidationError, match=re.escape(EXPECTED_PARSE_ERROR_MESSAGE)):
         v.validate_strings("{'real': 1, 'imag': 0}")
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (complex(3, 4), complex(3, 4)),
+        ('3+4j', complex(3, 4)),
+        (-5, complex(-5, 0)),
+        (
+            complex(3, 4.1),
+            Err('Complex input should have an absolute value less than or equal to 5 [type=complex_max_abs'),
+        ),
+        (
+            complex(float('nan'), 0),
+            Err('Complex input should have an absolute value less than or equal to 5 [type=complex_max_abs'),
+        ),
+    ],
+    ids=repr,
+)
+def test_complex_max_abs(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema(max_abs=5))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'max_abs': 5.0}
+    else:
+        assert v.validate_python(input_value) == expected
@@ -0,0 +1,155 @@
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import (
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
+
+from ..conftest import PyAndJson
+
+
+def test_constraints(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.constraints_schema(
+            core_schema.int_schema(), [{'kind': 'gt', 'value': 0}, {'kind': 'multiple_of', 'value': 2}]
+        )
+    )
+    assert v.validate_test(4) == 4
+    assert v.validate_test('4') == 4
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(3)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'multiple_of',
+            'loc': (),
+            'msg': 'Input should be a multiple of 2',
+            'input': 3,
+            'ctx': {'multiple_of': 2},
+        }
+    ]
+
+
+def test_constraints_order():
+    v = SchemaValidator(
+        core_schema.constraints_schema(
+            core_schema.int_schema(), [{'kind': 'multiple_of', 'value': 2}, {'kind': 'gt', 'value': 0}]
+        )
+    )
+    # only the first failing constraint is reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(-3)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'multiple_of',
+            'loc': (),
+            'msg': 'Input should be a multiple of 2',
+            'input': -3,
+            'ctx': {'multiple_of': 2},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(-4)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'greater_than', 'loc': (), 'msg': 'Input should be greater than 0', 'input': -4, 'ctx': {'gt': 0}}
+    ]
+
+
+@pytest.mark.parametrize(
+    'kind,value,input_value,expected',
+    [
+        ('gt', 1, 2, 2),
+        ('gt', 1, 1, ('greater_than', 'Input should be greater than 1')),
+        ('ge', 1, 1, 1),
+        ('ge', 1, 0, ('greater_than_equal', 'Input should be greater than or equal to 1')),
+        ('lt', 1, 0, 0),
+        ('lt', 1, 1, ('less_than', 'Input should be less than 1')),
+        ('le', 1, 1, 1),
+        ('le', 1, 2, ('less_than_equal', 'Input should be less than or equal to 1')),
+    ],
+)
+def test_constraint_kinds(kind, value, input_value, expected):
+    v = SchemaValidator(core_schema.constraints_schema(core_schema.int_schema(), [{'kind': kind, 'value': value}]))
+    if isinstance(expected, tuple):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        error_type, msg = expected
+        assert exc_info.value.errors(include_url=False) == [
+            {'type': error_type, 'loc': (), 'msg': msg, 'input': input_value, 'ctx': {kind: value}}
+        ]
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_constraints_float():
+    v = SchemaValidator(
+        core_schema.constraints_schema(
+            core_schema.float_schema(), [{'kind': 'ge', 'value': 0.5}, {'kind': 'lt', 'value': 10}]
+        )
+    )
+    assert v.validate_python(0.5) == 0.5
+    with pytest.raises(ValidationError, match=r'greater than or equal to 0.5 \[type=greater_than_equal'):
+        v.validate_python(0.1)
+    with pytest.raises(ValidationError, match=r'Input should be less than 10 \[type=less_than'):
+        v.validate_python(10.0)
+
+
+def test_constraints_float_multiple_of():
+    v = SchemaValidator(
+        core_schema.constraints_schema(core_schema.float_schema(), [{'kind': 'multiple_of', 'value': 0.1}])
+    )
+    assert v.validate_python(0.3) == 0.3
+    assert v.validate_python(0.7) == 0.7
+    with pytest.raises(ValidationError, match=r'Input should be a multiple of 0.1 \[type=multiple_of'):
+        v.validate_python(0.35)
+
+
+def test_constraints_decimal():
+    v = SchemaValidator(
+        core_schema.constraints_schema(core_schema.decimal_schema(), [{'kind': 'multiple_of', 'value': Decimal('0.5')}])
+    )
+    assert v.validate_python('1.5') == Decimal('1.5')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1.2')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'multiple_of',
+            'loc': (),
+            'msg': 'Input should be a multiple of 0.5',
+            'input': '1.2',
+            'ctx': {'multiple_of': Decimal('0.5')},
+        }
+    ]
+
+
+def test_constraints_inner_error():
+    v = SchemaValidator(core_schema.constraints_schema(core_schema.int_schema(), [{'kind': 'gt', 'value': 0}]))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer, unable to parse string as an integer'):
+        v.validate_python('foo')
+
+
+def test_constraints_repr():
+    v = SchemaValidator(core_schema.constraints_schema(core_schema.int_schema(), [{'kind': 'gt', 'value': 0}]))
+    assert repr(v).startswith('SchemaValidator(title="constraints[int]"')
+
+
+def test_constraints_serializer():
+    s = SchemaSerializer(core_schema.constraints_schema(core_schema.int_schema(), [{'kind': 'gt', 'value': 0}]))
+    assert s.to_python(1) == 1
+    assert s.to_json(1) == b'1'
+
+
+def test_unknown_constraint():
+    msg = r"constraints\.0\.kind\s+Input should be 'gt', 'ge', 'lt', 'le' or 'multiple_of'"
+    with pytest.raises(SchemaError, match=msg):
+        validate_core_schema(
+            {'type': 'constraints', 'schema': {'type': 'int'}, 'constraints': [{'kind': 'foo', 'value': 1}]}
+        )
@@ -931,6 +931,148 @@ def test_frozen():
     ]
 
 
+def frozen_dataclass_validator(cls, fields, **kwargs):
+    return SchemaValidator(
+        core_schema.dataclass_schema(
+            cls,
+            core_schema.dataclass_args_schema(
+                cls.__name__, [core_schema.dataclass_field(name, schema) for name, schema in fields.items()]
+            ),
+            list(fields),
+            **kwargs,
+        )
+    )
+
+
+def test_frozen_from_dataclass_params():
+    @dataclasses.dataclass(frozen=True)
+    class MyModel:
+        f: str
+
+    v = frozen_dataclass_validator(MyModel, {'f': core_schema.str_schema()})
+    m = v.validate_python({'f': 'x'})
+    assert m.f == 'x'
+    with pytest.raises(dataclasses.FrozenInstanceError):
+        m.f = 'y'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(m, 'f', 'y')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'frozen_instance', 'loc': (), 'msg': 'Instance is frozen', 'input': 'y'}
+    ]
+    assert m.f == 'x'
+
+    # an explicit `frozen` in the schema takes precedence
+    v = frozen_dataclass_validator(MyModel, {'f': core_schema.str_schema()}, frozen=False)
+    m = v.validate_python({'f': 'x'})
+    v.validate_assignment(m, 'f', 'y')
+    assert m.f == 'y'
+
+
+def test_frozen_nested():
+    @dataclasses.dataclass(frozen=True)
+    class Inner:
+        a: int
+
+    @dataclasses.dataclass(frozen=True)
+    class Outer:
+        inner: Inner
+        b: str
+
+    inner_schema = core_schema.dataclass_schema(
+        Inner,
+        core_schema.dataclass_args_schema('Inner', [core_schema.dataclass_field('a', core_schema.int_schema())]),
+        ['a'],
+    )
+    v = frozen_dataclass_validator(Outer, {'inner': inner_schema, 'b': core_schema.str_schema()})
+    m = v.validate_python({'inner': {'a': '1'}, 'b': 'x'})
+    assert m == Outer(inner=Inner(a=1), b='x')
+    assert hash(m) == hash(Outer(inner=Inner(a=1), b='x'))
+
+
+@pytest.mark.parametrize('revalidate_instances', ['always', 'never'])
+def test_frozen_revalidate(revalidate_instances):
+    @dataclasses.dataclass(frozen=True)
+    class MyModel:
+        a: int
+
+    v = frozen_dataclass_validator(MyModel, {'a': core_schema.int_schema()}, revalidate_instances=revalidate_instances)
+    m = MyModel(a=1)
+    m2 = v.validate_python(m)
+    assert m2 == m
+    if revalidate_instances == 'always':
+        assert m2 is not m
+    else:
+        assert m2 is m
+
+    # invalid data on an existing instance is only caught when revalidating
+    bad = MyModel(a='x')
+    if revalidate_instances == 'always':
+        with pytest.raises(ValidationError, match='Input should be a valid integer'):
+            v.validate_python(bad)
+    else:
+        assert v.validate_python(bad) is bad
+
+
+@pytest.mark.parametrize(
+    'default_schema',
+    [
+        core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default_factory=list),
+        core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default=[]),
+    ],
+    ids=['default_factory', 'default'],
+)
+def test_mutable_default_not_shared(default_schema):
+    @dataclasses.dataclass
+    class MyModel:
+        items: List[int] = dataclasses.field(default_factory=list)
+
+    v = frozen_dataclass_validator(MyModel, {'items': default_schema})
+    m1 = v.validate_python({})
+    m2 = v.validate_python({})
+    assert m1.items == m2.items == []
+    assert m1.items is not m2.items
+    m1.items.append(1)
+    assert v.validate_python({}).items == []
+
+
+def test_default_factory_init_false_and_data():
+    @dataclasses.dataclass
+    class MyModel:
+        a: int
+        b: List[int] = dataclasses.field(default_factory=list)
+        c: Dict[str, int] = dataclasses.field(init=False)
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            MyModel,
+            core_schema.dataclass_args_schema(
+                'MyModel',
+                [
+                    core_schema.dataclass_field('a', core_schema.int_schema(), kw_only=False),
+                    core_schema.dataclass_field(
+                        'b',
+                        core_schema.with_default_schema(
+                            core_schema.list_schema(),
+                            default_factory=lambda data: [data['a']] * 2,
+                            default_factory_takes_data=True,
+                        ),
+                    ),
+                    core_schema.dataclass_field(
+                        'c', core_schema.with_default_schema(core_schema.dict_schema(), default_factory=dict), init=False
+                    ),
+                ],
+            ),
+            ['a', 'b', 'c'],
+        )
+    )
+    m1 = v.validate_python({'a': 3})
+    m2 = v.validate_python(ArgsKwargs((3,)))
+    assert (m1.a, m1.b, m1.c) == (3, [3, 3], {})
+    assert m1.b is not m2.b
+    assert m1.c is not m2.c
+
+
 def test_frozen_field():
     @dataclasses.dataclass
     class MyModel:
@@ -1713,3 +1855,17 @@ def test_dataclass_args_init_with_default(input_value, extra_behavior, expected)
             assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert dataclasses.asdict(v.validate_python(input_value)) == expected
+
+
+def test_dataclass_field_examples():
+    schema = core_schema.dataclass_args_schema(
+        'MyDataclass',
+        [
+            core_schema.dataclass_field(name='a', schema=core_schema.int_schema(), kw_only=False, examples=[1]),
+            core_schema.dataclass_field(name='b', schema=core_schema.int_schema()),
+        ],
+    )
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x',), {'b': 'y'}))
+    assert [(e['loc'], e['ctx'].get('examples')) for e in exc_info.value.errors()] == [((0,), [1]), (('b',), None)]
@@ -203,6 +203,38 @@ def test_decimal_multiple_of(py_and_json: PyAndJson, multiple_of: float, input_v
         assert isinstance(output, Decimal)
 
 
+@pytest.mark.parametrize(
+    'multiple_of,input_value,remainder',
+    [
+        ('0.01', '1.23', None),
+        ('0.01', '1.230000', None),
+        ('0.01', '1.235', Decimal('0.005')),
+        ('0.01', '-1.235', Decimal('-0.005')),
+        ('1E+2', '1.5E+3', None),
+        ('0.03', '0.1', Decimal('0.01')),
+        # the quotient has more digits than the default decimal context precision
+        ('0.01', '1E+40', None),
+        ('0.03', '1E+40', Decimal('0.01')),
+    ],
+)
+def test_decimal_multiple_of_scale(multiple_of, input_value, remainder):
+    v = SchemaValidator(core_schema.decimal_schema(multiple_of=Decimal(multiple_of)))
+    if remainder is None:
+        assert v.validate_python(input_value) == Decimal(input_value)
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'multiple_of',
+                'loc': (),
+                'msg': f'Input should be a multiple of {multiple_of}',
+                'input': input_value,
+                'ctx': {'multiple_of': Decimal(multiple_of), 'remainder': remainder},
+            }
+        ]
+
+
 def test_union_decimal_py():
     v = SchemaValidator(
         {'type': 'union', 'choices': [{'type': 'decimal', 'strict': True}, {'type': 'decimal', 'multiple_of': 7}]}
@@ -224,7 +256,7 @@ def test_union_decimal_py():
             'loc': ('decimal',),
             'msg': 'Input should be a multiple of 7',
             'input': '5',
-            'ctx': {'multiple_of': 7},
+            'ctx': {'multiple_of': 7, 'remainder': Decimal(5)},
         },
     ]
 
@@ -487,3 +519,72 @@ def test_union_with_str_prefers_str() -> None:
 
     assert s.validate_python('1.23') == '1.23'
     assert s.validate_python(1.23) == Decimal('1.23')
+
+
+def test_locale_format() -> None:
+    v = SchemaValidator(core_schema.decimal_schema(locale_format={'group': '.', 'decimal': ','}, decimal_places=2))
+    assert v.validate_python('1.234,56') == Decimal('1234.56')
+    assert v.validate_json('"-1.000,5"') == Decimal('-1000.5')
+    assert v.validate_python(Decimal('1.5')) == Decimal('1.5')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1,234.56')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'decimal_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid decimal',
+            'input': '1,234.56',
+            'ctx': {'expected_format': '1.234,56'},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Decimal input should have no more than 2 decimal places'):
+        v.validate_python('1,234')
+
+
+def test_json_float_mode_decimal() -> None:
+    v = SchemaValidator(core_schema.decimal_schema())
+    # the value is read as a float by default, so precision is lost
+    assert v.validate_json('0.1234567890123456789') == Decimal('0.12345678901234568')
+    assert v.validate_json('0.1234567890123456789', json_float_mode='decimal') == Decimal('0.1234567890123456789')
+    assert v.validate_json('1.10', json_float_mode='decimal').as_tuple().exponent == -2
+    assert v.validate_json('123456789012345678901234.5', json_float_mode='decimal') == Decimal(
+        '123456789012345678901234.5'
+    )
+    assert v.validate_json('1e-30', json_float_mode='decimal') == Decimal('1e-30')
+
+
+def test_json_float_mode_decimal_nested() -> None:
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'price': core_schema.typed_dict_field(core_schema.decimal_schema()),
+                'rates': core_schema.typed_dict_field(core_schema.list_schema(core_schema.decimal_schema())),
+                'ratio': core_schema.typed_dict_field(core_schema.float_schema()),
+            }
+        )
+    )
+    output = v.validate_json(
+        '{"price": 1.5, "price": 19.99000000000000000001, "rates": [1, 0.30000000000000000004], "ratio": 0.5}',
+        json_float_mode='decimal',
+    )
+    assert output == {
+        'price': Decimal('19.99000000000000000001'),
+        'rates': [Decimal(1), Decimal('0.30000000000000000004')],
+        'ratio': 0.5,
+    }
+
+
+def test_json_float_mode_decimal_constraints() -> None:
+    v = SchemaValidator(core_schema.decimal_schema(max_digits=20))
+    assert v.validate_json('0.1234567890123456789', json_float_mode='decimal') == Decimal('0.1234567890123456789')
+    with pytest.raises(ValidationError, match='Decimal input should have no more than 20 digits in total'):
+        v.validate_json('0.123456789012345678901', json_float_mode='decimal')
+
+
+def test_json_float_mode_invalid() -> None:
+    v = SchemaValidator(core_schema.decimal_schema())
+    with pytest.raises(ValueError, match="Invalid json_float_mode, should be `'float'` or `'decimal'`"):
+        v.validate_json('1.5', json_float_mode='lossless')
+    with pytest.raises(ValueError, match="`json_float_mode='decimal'` cannot be used with `allow_partial`"):
+        v.validate_json('1.5', json_float_mode='decimal', allow_partial=True)
@@ -104,6 +104,7 @@ def test_nullable_error():
             'loc': ('sub_branch', 'typed-dict', 'width'),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
@@ -332,10 +333,39 @@ def test_recursion_branch():
             'loc': ('branch',),
             'msg': 'Recursion error - cyclic reference detected',
             'input': {'name': 'recursive', 'branch': IsPartialDict(name='recursive')},
+            'ctx': {'object_id': id(b), 'cycle_loc': ('branch',)},
         }
     ]
 
 
+def test_recursion_cycle_loc():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('Branch')),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'branch': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Branch')),
+                            required=False,
+                        ),
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    b = {'name': 'recursive'}
+    b['branch'] = {'name': 'child', 'branch': b}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'name': 'ok'}, b])
+    # `loc` is the path from the root, `cycle_loc` is the path from the object back to itself
+    [error] = exc_info.value.errors(include_url=False)
+    assert error['loc'] == (1, 'branch', 'branch')
+    assert error['ctx'] == {'object_id': id(b), 'cycle_loc': ('branch', 'branch')}
+
+
 def test_recursion_branch_from_attributes():
     v = SchemaValidator(
         core_schema.definitions_schema(
@@ -381,6 +411,7 @@ def test_recursion_branch_from_attributes():
             'loc': ('branch',),
             'msg': 'Recursion error - cyclic reference detected',
             'input': HasAttributes(name='root', branch=AnyThing()),
+            'ctx': {'object_id': id(data), 'cycle_loc': ('branch',)},
         }
     ]
 
@@ -407,6 +438,7 @@ def test_definition_list():
             'loc': (0,),
             'msg': 'Recursion error - cyclic reference detected',
             'input': [IsList(length=1)],
+            'ctx': {'object_id': id(data), 'cycle_loc': (0,)},
         }
     ]
 
@@ -480,12 +512,14 @@ def test_multiple_tuple_recursion(multiple_tuple_schema: SchemaValidator):
             'loc': ('f1', 1),
             'msg': 'Recursion error - cyclic reference detected',
             'input': [1, IsList(length=2)],
+            'ctx': {'object_id': id(data), 'cycle_loc': (1,)},
         },
         {
             'type': 'recursion_loop',
             'loc': ('f2', 1),
             'msg': 'Recursion error - cyclic reference detected',
             'input': [1, IsList(length=2)],
+            'ctx': {'object_id': id(data), 'cycle_loc': (1,)},
         },
     ]
 
@@ -502,12 +536,14 @@ def test_multiple_tuple_recursion_once(multiple_tuple_schema: SchemaValidator):
             'loc': ('f1', 1),
             'msg': 'Recursion error - cyclic reference detected',
             'input': [1, IsList(length=2)],
+            'ctx': {'object_id': id(data), 'cycle_loc': (1,)},
         },
         {
             'type': 'recursion_loop',
             'loc': ('f2', 1),
             'msg': 'Recursion error - cyclic reference detected',
             'input': [1, IsList(length=2)],
+            'ctx': {'object_id': id(data), 'cycle_loc': (1,)},
         },
     ]
 
@@ -545,6 +581,7 @@ def test_definition_wrap():
             'loc': (1,),
             'msg': 'Recursion error - cyclic reference detected',
             'input': IsList(positions={0: 1}, length=2),
+            'ctx': {'object_id': id(t), 'cycle_loc': (1,)},
         }
     ]
 
@@ -640,6 +677,7 @@ def test_union_cycle(strict: bool):
             'loc': ('typed-dict', 'foobar', 0),
             'msg': 'Recursion error - cyclic reference detected',
             'input': {'foobar': [{'foobar': IsList(length=1)}]},
+            'ctx': {'object_id': id(data), 'cycle_loc': ('typed-dict', 'foobar', 0)},
         }
     ]
 
@@ -667,8 +705,9 @@ def test_function_name():
 
     assert v.validate_python(123) == 123
 
+    input_value = 'input value'
     with pytest.raises(ValidationError) as exc_info:
-        v.validate_python('input value')
+        v.validate_python(input_value)
 
     assert exc_info.value.errors(include_url=False) == [
         {
@@ -676,12 +715,14 @@ def test_function_name():
             'loc': ('function-after[f(), ...]',),
             'msg': 'Recursion error - cyclic reference detected',
             'input': 'input value',
+            'ctx': {'object_id': id(input_value), 'cycle_loc': ('function-after[f(), ...]',)},
         },
         {
             'type': 'int_parsing',
             'loc': ('int',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'input value',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
@@ -936,6 +977,7 @@ def test_cyclic_data() -> None:
             'loc': ('b', 'a'),
             'msg': 'Recursion error - cyclic reference detected',
             'input': cyclic_data,
+            'ctx': {'object_id': id(cyclic_data), 'cycle_loc': ('b', 'a')},
         }
     ]
 
@@ -986,6 +1028,7 @@ def test_cyclic_data_threeway() -> None:
             'loc': ('b', 'c', 'a'),
             'msg': 'Recursion error - cyclic reference detected',
             'input': cyclic_data,
+            'ctx': {'object_id': id(cyclic_data), 'cycle_loc': ('b', 'c', 'a')},
         }
     ]
 
@@ -1,12 +1,14 @@
+import json
 import re
-from collections import OrderedDict
+from collections import Counter, OrderedDict
 from collections.abc import Mapping
+from types import MappingProxyType
 from typing import Any, Dict
 
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -57,6 +59,7 @@ def test_dict_value_error(py_and_json: PyAndJson):
             'loc': ('b',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -72,12 +75,14 @@ def test_dict_error_key_int():
             'loc': (3,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {
             'type': 'int_parsing',
             'loc': (-4,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong2',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
@@ -89,13 +94,35 @@ def test_dict_error_key_other():
     assert exc_info.value.errors(include_url=False) == [
         {
             'type': 'int_parsing',
-            'loc': ('(1, 2)',),
+            'loc': ((1, 2),),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
 
+def test_dict_error_key_compound():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            values_schema=core_schema.typed_dict_schema({'field': core_schema.typed_dict_field(core_schema.int_schema())})
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({('a', (1, 'b.c')): {'field': 'wrong'}})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (('a', (1, 'b.c')), 'field'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+    assert json.loads(exc_info.value.json(include_url=False))[0]['loc'] == [['a', [1, 'b.c']], 'field']
+    assert '(a, (1, `b.c`)).field\n  Input should be a valid integer' in str(exc_info.value)
+
+
 def test_dict_any_value():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'str'}})
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'str'}})
@@ -125,6 +152,77 @@ def test_mapping():
         v.validate_python(MyMapping({'1': 2, 3: '4'}))
 
 
+def test_mappingproxy():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    assert v.validate_python(MappingProxyType({'1': 2, 3: '4'})) == {1: 2, 3: 4}
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema(), strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(MappingProxyType({'1': 2}))
+
+
+class DuckMapping:
+    """Implements the mapping protocol without being a `collections.abc.Mapping`"""
+
+    def __init__(self, d):
+        self._d = d
+
+    def keys(self):
+        return self._d.keys()
+
+    def __getitem__(self, key):
+        return self._d[key]
+
+
+def test_duck_mapping():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    assert v.validate_python(DuckMapping({'1': 2, 3: '4'})) == {1: 2, 3: 4}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(DuckMapping({'1': 'x'}))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('1',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema(), strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(DuckMapping({'1': 2}))
+
+
+def test_duck_mapping_getitem_error():
+    class BadDuckMapping(DuckMapping):
+        def __getitem__(self, key):
+            raise RuntimeError('intentional error')
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(BadDuckMapping({'a': 1}))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'mapping_type',
+            'loc': (),
+            'msg': 'Input should be a valid mapping, error: RuntimeError: intentional error',
+            'input': HasRepr(IsStr(regex='.+BadDuckMapping object at.+')),
+            'ctx': {'error': 'RuntimeError: intentional error'},
+        }
+    ]
+
+
+def test_not_a_mapping():
+    class OnlyGetItem:
+        def __getitem__(self, key):
+            return key
+
+    v = SchemaValidator(core_schema.dict_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(OnlyGetItem())
+
+
 def test_key_error():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_python({'1': True}) == {1: 1}
@@ -136,6 +234,7 @@ def test_key_error():
             'loc': ('x', '[key]'),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -258,3 +357,120 @@ def test_json_dict_complex_key():
     assert v.validate_json('{"1+2j": 2, "infj": 4}') == {complex(1, 2): 2, complex(0, float('inf')): 4}
     with pytest.raises(ValidationError, match='Input should be a valid complex string'):
         v.validate_json('{"1+2j": 2, "": 4}') == {complex(1, 2): 2, complex(0, float('inf')): 4}
+
+
+def test_dict_required_keys(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'int'}, 'required_keys': ['a', 'b']}
+    )
+    assert v.validate_test({'a': 1, 'b': '2', 'c': 3}) == {'a': 1, 'b': 2, 'c': 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'c': 3})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {'c': 3}},
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'c': 3}},
+    ]
+
+
+def test_dict_required_keys_value_error():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'int'}, 'required_keys': ['a', 'b']})
+    # a key with an invalid value is present, so only reports the value error
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        },
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 'x'}},
+    ]
+
+
+def test_dict_required_keys_key_error():
+    v = SchemaValidator(
+        {
+            'type': 'dict',
+            'keys_schema': {'type': 'str', 'min_length': 2},
+            'values_schema': {'type': 'int'},
+            'required_keys': ['a'],
+        }
+    )
+    # a key which fails key validation is still present, so isn't also reported as missing
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': ('a', '[key]'),
+            'msg': 'String should have at least 2 characters',
+            'input': 'a',
+            'ctx': {'min_length': 2},
+        }
+    ]
+
+
+def test_ordered_dict_output(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), output_type='ordered_dict')
+    )
+    output = v.validate_test({'b': '1', 'a': 2})
+    assert type(output) is OrderedDict
+    assert list(output.items()) == [('b', 1), ('a', 2)]
+
+
+def test_counter_output():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), output_type='counter')
+    )
+    output = v.validate_python({'a': '3', 'b': 1})
+    assert type(output) is Counter
+    assert output.most_common(1) == [('a', 3)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+
+def test_key_collision_error(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'1': 'a', '01': 'b', '2': 'c'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dict_key_collision',
+            'loc': ('01', '[key]'),
+            'msg': "Dictionary keys should be unique after validation, got colliding keys '1', '01'",
+            'input': '01',
+            'ctx': {'keys': "'1', '01'"},
+        }
+    ]
+
+
+def test_key_collision_python_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema()))
+    with pytest.raises(ValidationError, match=r"got colliding keys 1, '1' \[type=dict_key_collision,"):
+        v.validate_python({1: 'a', '1': 'b'})
+
+
+@pytest.mark.parametrize('on_key_collision,expected', [('last_wins', {1: 'b'}), ('first_wins', {1: 'a'})])
+def test_key_collision_wins(py_and_json: PyAndJson, on_key_collision, expected):
+    v = py_and_json(
+        core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema(), on_key_collision=on_key_collision)
+    )
+    assert v.validate_test({'1': 'a', '01': 'b'}) == expected
+
+
+def test_key_collision_json_duplicate_keys():
+    # duplicate keys in the input itself aren't collisions, the last value wins as before
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema()))
+    assert v.validate_json('{"1": "a", "1": "b"}') == {1: 'b'}
+
+
+def test_key_collision_invalid():
+    with pytest.raises(SchemaError, match="Invalid dict on_key_collision: 'other'"):
+        SchemaValidator({'type': 'dict', 'on_key_collision': 'other'})
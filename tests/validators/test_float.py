@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -169,6 +169,7 @@ def test_union_float_simple(py_and_json: PyAndJson):
             'loc': ('float',),
             'msg': 'Input should be a valid number, unable to parse string as a number',
             'input': 'xxx',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {
             'type': 'list_type',
@@ -183,12 +184,12 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,locale_format:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,locale_format:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -333,6 +334,17 @@ def test_non_finite_constrained_float_values(input_value, allow_inf_nan, expecte
         assert v.validate_python(input_value) == expected
 
 
+@pytest.mark.parametrize('input_value', [float('nan'), float('inf'), float('-inf')])
+def test_non_finite_multiple_of(input_value):
+    v = SchemaValidator(core_schema.float_schema(allow_inf_nan=True, multiple_of=0.5))
+    output = v.validate_python(input_value)
+    assert output == input_value or (math.isnan(output) and math.isnan(input_value))
+
+    v = SchemaValidator(core_schema.float_schema(allow_inf_nan=False, multiple_of=0.5))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python(input_value)
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -412,3 +424,140 @@ def test_allow_inf_nan_false_json() -> None:
     assert exc_info3.value.errors(include_url=False) == [
         {'type': 'finite_number', 'loc': (), 'msg': 'Input should be a finite number', 'input': float('-inf')}
     ]
+
+
+@pytest.mark.parametrize('allow_inf_nan', [None, True, False])
+@pytest.mark.parametrize('kwargs', [{}, {'gt': -100}, {'strict': True}])
+@pytest.mark.parametrize('input_value', [float('nan'), float('inf'), float('-inf'), 'nan', '-inf'])
+def test_finite(input_value, allow_inf_nan, kwargs: Dict[str, Any]):
+    v = SchemaValidator(core_schema.float_schema(finite=True, allow_inf_nan=allow_inf_nan, **kwargs))
+    assert v.validate_python(1.5) == 1.5
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [e['type'] for e in exc_info.value.errors()] == [
+        'float_type' if kwargs.get('strict') and isinstance(input_value, str) else 'finite_number'
+    ]
+
+
+def test_finite_overrides_config():
+    v = SchemaValidator(core_schema.float_schema(finite=True), core_schema.CoreConfig(allow_inf_nan=True))
+    with pytest.raises(ValidationError, match=r'Input should be a finite number \[type=finite_number,'):
+        v.validate_json('Infinity')
+
+    v = SchemaValidator(core_schema.float_schema(finite=False), core_schema.CoreConfig(allow_inf_nan=False))
+    with pytest.raises(ValidationError, match=r'Input should be a finite number \[type=finite_number,'):
+        v.validate_json('NaN')
+    assert SchemaValidator(core_schema.float_schema(finite=False)).validate_json('NaN') == IsFloatNan()
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1.234,56', 1234.56),
+        ('1.234.567,5', 1234567.5),
+        (' -1,5 ', -1.5),
+        ('1234', 1234),
+        (',5', 0.5),
+        (1.5, 1.5),
+        ('1,2,3', Err('unable to parse string as a number [type=float_parsing')),
+        ('1.234.', Err('unable to parse string as a number [type=float_parsing')),
+        ('.234', Err('unable to parse string as a number [type=float_parsing')),
+        ('1..234', Err('unable to parse string as a number [type=float_parsing')),
+        ('1,234.5', Err('unable to parse string as a number [type=float_parsing')),
+        ('1,2x', Err('unable to parse string as a number [type=float_parsing')),
+    ],
+)
+def test_locale_format(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.float_schema(locale_format={'group': '.', 'decimal': ','}))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_locale_format_error_context():
+    v = SchemaValidator(core_schema.float_schema(locale_format={'group': ' ', 'decimal': ','}, ge=0))
+    assert v.validate_python('1 234,5') == 1234.5
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1.234,5')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'float_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid number, unable to parse string as a number',
+            'input': '1.234,5',
+            'ctx': {'expected_format': '1 234,56'},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 0'):
+        v.validate_python('-1,5')
+
+
+def test_locale_format_strict():
+    v = SchemaValidator(core_schema.float_schema(locale_format={'group': '.', 'decimal': ','}, strict=True))
+    assert v.validate_python(1.5) == 1.5
+    with pytest.raises(ValidationError, match='Input should be a valid number \\[type=float_type'):
+        v.validate_python('1,5')
+
+
+@pytest.mark.parametrize(
+    'locale_format,message',
+    [
+        ({'group': '.', 'decimal': '.'}, '`locale_format` group and decimal must be different characters'),
+        ({'group': '', 'decimal': ','}, '`locale_format` group must be a single non-digit character'),
+        ({'group': '.', 'decimal': ',,'}, '`locale_format` decimal must be a single non-digit character'),
+        ({'group': '1', 'decimal': ','}, '`locale_format` group must be a single non-digit character'),
+    ],
+)
+def test_locale_format_invalid(locale_format, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(core_schema.float_schema(locale_format=locale_format))
+
+
+@pytest.mark.parametrize(
+    'input_value,reason',
+    [
+        ('', 'empty string'),
+        ('1.5x', 'invalid digit at position 3'),
+        ('1__5', 'invalid underscore placement'),
+        ('1.2.3', 'invalid digit at position 3'),
+        (' +1_000.5x', 'invalid digit at position 9'),
+    ],
+)
+@pytest.mark.parametrize('kwargs', [{}, {'gt': 0}])
+def test_float_parsing_reason(py_and_json: PyAndJson, input_value, reason, kwargs):
+    v = py_and_json(core_schema.float_schema(**kwargs))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'float_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid number, unable to parse string as a number',
+            'input': input_value,
+            'ctx': {'reason': reason},
+        }
+    ]
+
+
+class NumpyFloatLike:
+    """stands in for `numpy.float32`, which implements the number protocol but isn't a `float` subclass"""
+
+    def __float__(self) -> float:
+        return 1.5
+
+
+class NumpyIntLike:
+    def __index__(self) -> int:
+        return 3
+
+
+@pytest.mark.parametrize('strict', [False, True])
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}])
+def test_number_protocol(strict, kwargs):
+    v = SchemaValidator(core_schema.float_schema(strict=strict, **kwargs))
+    output = v.validate_python(NumpyFloatLike())
+    assert output == 1.5
+    assert type(output) is float
+    assert v.validate_python(NumpyIntLike()) == 3.0
@@ -0,0 +1,151 @@
+from __future__ import annotations
+
+import json
+import re
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class FractionSubclass(Fraction):
+    pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0, Fraction(0)),
+        (42, Fraction(42)),
+        ('3/4', Fraction(3, 4)),
+        (' -3/4 ', Fraction(-3, 4)),
+        ('0.75', Fraction(3, 4)),
+        ('42', Fraction(42)),
+        ('wrong', Err("Input should be a valid fraction [type=fraction_parsing, input_value='wrong', input_type=str]")),
+        ('1/0', Err('Input should be a valid fraction [type=fraction_parsing')),
+        ('1/2/3', Err('Input should be a valid fraction [type=fraction_parsing')),
+        (0.5, Err('Fraction input should be an integer, string or Fraction object [type=fraction_type')),
+        (True, Err('Fraction input should be an integer, string or Fraction object [type=fraction_type')),
+        ([1, 2], Err('Fraction input should be an integer, string or Fraction object [type=fraction_type')),
+    ],
+    ids=repr,
+)
+def test_fraction(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'fraction'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Fraction)
+
+
+def test_fraction_instance():
+    v = SchemaValidator(core_schema.fraction_schema())
+    f = Fraction(1, 3)
+    assert v.validate_python(f) is f
+    sub = FractionSubclass(1, 3)
+    assert v.validate_python(sub) is sub
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Fraction(3, 4), Fraction(3, 4)),
+        (FractionSubclass(3, 4), Fraction(3, 4)),
+        ('3/4', Err('Fraction input should be an integer, string or Fraction object [type=fraction_type')),
+        (3, Err('Fraction input should be an integer, string or Fraction object [type=fraction_type')),
+    ],
+    ids=repr,
+)
+def test_fraction_strict_py(input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_fraction_strict_json():
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    assert v.validate_json('"3/4"') == Fraction(3, 4)
+    assert v.validate_json('3') == Fraction(3)
+    with pytest.raises(ValidationError, match=re.escape('[type=fraction_type')):
+        v.validate_json('0.5')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0.5, Fraction(1, 2)),
+        (0.1, Fraction(3602879701896397, 36028797018963968)),
+        (2.0, Fraction(2)),
+        (float('inf'), Err('Input should be a finite number [type=finite_number')),
+        (float('nan'), Err('Input should be a finite number [type=finite_number')),
+    ],
+    ids=repr,
+)
+def test_fraction_allow_float(input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema(allow_float=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_fraction_allow_float_json():
+    v = SchemaValidator(core_schema.fraction_schema(allow_float=True))
+    assert v.validate_json('0.25') == Fraction(1, 4)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'ge': Fraction(1, 2)}, '1/2', Fraction(1, 2)),
+        ({'ge': Fraction(1, 2)}, '1/3', Err('Input should be greater than or equal to 1/2 [type=greater_than_equal')),
+        ({'le': '1/2'}, '1/3', Fraction(1, 3)),
+        ({'le': '1/2'}, '2/3', Err('Input should be less than or equal to 1/2 [type=less_than_equal')),
+        ({'gt': 0}, '1/3', Fraction(1, 3)),
+        ({'gt': 0}, 0, Err('Input should be greater than 0 [type=greater_than')),
+        ({'lt': 1}, '2/3', Fraction(2, 3)),
+        ({'lt': 1}, 1, Err('Input should be less than 1 [type=less_than')),
+    ],
+    ids=repr,
+)
+def test_fraction_constraints(kwargs, input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_fraction_constraints_ctx():
+    v = SchemaValidator(core_schema.fraction_schema(le=Fraction(1, 2)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('3/4')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': (),
+            'msg': 'Input should be less than or equal to 1/2',
+            'input': '3/4',
+            'ctx': {'le': Fraction(1, 2)},
+        }
+    ]
+
+
+def test_fraction_serialization():
+    v = SchemaValidator(core_schema.fraction_schema())
+    ser = SchemaSerializer(core_schema.fraction_schema())
+    assert ser.to_python(Fraction(3, 4)) == Fraction(3, 4)
+    assert ser.to_python(Fraction(3, 4), mode='json') == '3/4'
+    assert json.loads(ser.to_json(Fraction(3, 4))) == '3/4'
+    assert v.validate_json(ser.to_json(Fraction(-1, 3))) == Fraction(-1, 3)
@@ -114,6 +114,7 @@ def test_frozenset_multiple_errors():
             'loc': (0,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'a',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {'type': 'int_type', 'loc': (1,), 'msg': 'Input should be a valid integer', 'input': (1, 2)},
         {'type': 'int_type', 'loc': (2,), 'msg': 'Input should be a valid integer', 'input': []},
@@ -249,7 +250,9 @@ def test_repr():
         'validator=FrozenSet(FrozenSetValidator{'
         'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
         'name:"frozenset[any]",'
-        'fail_fast:false'
+        'fail_fast:false,'
+        'skip_items:SkipItems{empty:false,none:false},'
+        'coerce_scalar:CoerceScalar{enabled:false,str_like:false}'
         '}),'
         'definitions=[],'
         'cache_strings=True)'
@@ -310,6 +313,7 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
             ],
             id='fail_fast',
@@ -322,12 +326,14 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
                 {
                     'type': 'int_parsing',
                     'loc': (2,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'again',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
             ],
             id='not_fail_fast',
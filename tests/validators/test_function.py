@@ -189,7 +189,7 @@ def test_function_wrap_repr():
 
     assert (
         v.validate_python('input value')
-        == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:false}))'
+        == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:false,coerce_enum:false}))'
     )
 
 
@@ -201,7 +201,7 @@ def test_function_wrap_str():
 
     assert (
         v.validate_python('input value')
-        == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:false}))'
+        == 'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:false,coerce_enum:false}))'
     )
 
 
@@ -233,10 +233,34 @@ def test_wrap_error():
             'loc': (),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
 
+def test_wrap_error_reraised():
+    caught = []
+
+    def f(input_value, validator):
+        try:
+            return validator(input_value)
+        except ValidationError as e:
+            caught.append(e)
+            raise e
+
+    v = SchemaValidator(core_schema.no_info_wrap_validator_function(f, core_schema.list_schema(core_schema.int_schema())))
+
+    bad = object()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x', 1, bad])
+    errors = exc_info.value.errors(include_url=False)
+    assert [(e['type'], e['loc']) for e in errors] == [('int_parsing', (0,)), ('int_type', (2,))]
+    # inputs are passed through untouched
+    assert errors[1]['input'] is bad
+    # the re-raised error is left as it was
+    assert caught[0].errors(include_url=False) == errors
+
+
 @pytest.mark.parametrize(
     'config,input_str',
     (
@@ -279,6 +303,7 @@ def test_function_wrap_location():
             'loc': ('foo',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -297,9 +322,10 @@ def test_function_wrap_invalid_location():
     assert exc_info.value.errors(include_url=False) == [
         {
             'type': 'int_parsing',
-            'loc': ("('4',)",),
+            'loc': (('4',),),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -82,6 +82,7 @@ def test_error_index(py_and_json: PyAndJson):
             'loc': (0,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     gen = v.validate_test([1, 2, 3, 'wrong', 4])
@@ -101,6 +102,7 @@ def test_error_index(py_and_json: PyAndJson):
             'loc': (3,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     assert next(gen) == 4
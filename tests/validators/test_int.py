@@ -375,6 +375,7 @@ def test_union_int_simple(py_and_json: PyAndJson):
             'loc': ('int',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'xxx',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {
             'type': 'list_type',
@@ -389,12 +390,14 @@ def test_int_repr():
     v = SchemaValidator({'type': 'int'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,json_number_strict:false,'
+        'allow_int_from_bool:true,strict_number_protocol:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,json_number_strict:false,'
+        'allow_int_from_bool:true,strict_number_protocol:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
@@ -563,3 +566,162 @@ def test_json_big_int_key():
     assert v.validate_python({big_integer: 'x'}) == {big_integer: 'x'}
     assert v.validate_json('{"' + str(big_integer) + '": "x"}') == {big_integer: 'x'}
     assert v.validate_strings({str(big_integer): 'x'}) == {big_integer: 'x'}
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'gt': 0}])
+def test_json_number_strict(kwargs):
+    v = SchemaValidator(core_schema.int_schema(json_number_strict=True, **kwargs))
+    assert v.validate_json('1') == 1
+    # lax coercion of other inputs is unaffected
+    assert v.validate_json('"1"') == 1
+    assert v.validate_python(1.0) == 1
+    for input_value in ('1.0', '1e3'):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_json(input_value)
+        # insert_assert(exc_info.value.errors(include_url=False))
+        assert exc_info.value.errors(include_url=False) == [
+            {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': float(input_value)}
+        ]
+
+
+def test_json_number_strict_disabled():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_json('1.0') == 1
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'gt': -1}])
+def test_allow_int_from_bool(py_and_json: PyAndJson, kwargs):
+    v = py_and_json(core_schema.int_schema(allow_int_from_bool=False, **kwargs))
+    assert v.validate_test(1) == 1
+    # other lax coercions are unaffected
+    assert v.validate_test('1') == 1
+    for input_value in (True, False):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        # insert_assert(exc_info.value.errors(include_url=False))
+        assert exc_info.value.errors(include_url=False) == [
+            {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': input_value}
+        ]
+
+    assert SchemaValidator(core_schema.int_schema(**kwargs)).validate_python(True) == 1
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1' + '0' * 99, int('1' + '0' * 99)),
+        (int('1' + '0' * 99), int('1' + '0' * 99)),
+        ('-' + '1' + '0' * 99, -int('1' + '0' * 99)),
+        ('1' + '0' * 99 + '1', Err('Input should be less than or equal to 1' + '0' * 99 + ' [type=less_than_equal')),
+        ('-1' + '0' * 99 + '1', Err('Input should be greater than or equal to -1' + '0' * 99)),
+        (2**63, 2**63),
+    ],
+)
+def test_big_int_bounds(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.int_schema(le=10**99, ge=-(10**99)))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, int)
+
+
+@pytest.mark.parametrize('kwargs', [{'gt': 2**63}, {'ge': 2**63 + 1}, {'lt': -(2**63)}, {'le': -(2**63) - 1}])
+def test_bounds_beyond_i64(kwargs):
+    v = SchemaValidator(core_schema.int_schema(**kwargs))
+    # small values are compared against the big bound, not a truncated one
+    with pytest.raises(ValidationError):
+        v.validate_python(0)
+    big = 2**64 if 'gt' in kwargs or 'ge' in kwargs else -(2**64)
+    assert v.validate_python(str(big)) == big
+    assert v.validate_json(str(big)) == big
+
+
+def test_big_int_multiple_of():
+    v = SchemaValidator(core_schema.int_schema(multiple_of=10**50))
+    assert v.validate_python('1' + '0' * 100) == 10**100
+    with pytest.raises(ValidationError, match='Input should be a multiple of 1' + '0' * 50):
+        v.validate_python(10**100 + 1)
+
+
+@pytest.mark.parametrize(
+    'input_value,reason',
+    [
+        ('', 'empty string'),
+        ('  ', 'empty string'),
+        ('12x4', 'invalid digit at position 2'),
+        (' -1.5', 'invalid digit at position 3'),
+        ('1__0', 'invalid underscore placement'),
+        ('1_000x', 'invalid digit at position 5'),
+        ('+', 'invalid number format'),
+    ],
+)
+@pytest.mark.parametrize('kwargs', [{}, {'gt': 0}])
+def test_int_parsing_reason(py_and_json: PyAndJson, input_value, reason, kwargs):
+    v = py_and_json(core_schema.int_schema(**kwargs))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': input_value,
+            'ctx': {'reason': reason},
+        }
+    ]
+
+
+class NumpyIntLike:
+    """stands in for `numpy.int64`, which implements the number protocol but isn't an `int` subclass"""
+
+    def __init__(self, value: int):
+        self.value = value
+
+    def __index__(self) -> int:
+        return self.value
+
+    def __int__(self) -> int:
+        return self.value
+
+    def __float__(self) -> float:
+        return float(self.value)
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}])
+def test_number_protocol(kwargs):
+    v = SchemaValidator(core_schema.int_schema(**kwargs))
+    # read via `__index__` rather than `__float__`, so large values aren't rounded
+    output = v.validate_python(NumpyIntLike(2**62 + 1))
+    assert output == 2**62 + 1
+    assert type(output) is int
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(NumpyIntLike(1), strict=True)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_type'
+
+
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}])
+def test_strict_number_protocol(kwargs):
+    v = SchemaValidator(core_schema.int_schema(strict=True, strict_number_protocol=True, **kwargs))
+    assert v.validate_python(NumpyIntLike(2**62 + 1)) == 2**62 + 1
+    assert v.validate_python(3) == 3
+
+    class FloatLike:
+        def __float__(self) -> float:
+            return 1.0
+
+    # only the integer protocol is accepted, and other strict checks still apply
+    for input_value in (FloatLike(), '1', 1.0, True):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_type'
+
+
+def test_strict_number_protocol_constraints():
+    v = SchemaValidator(core_schema.int_schema(strict=True, strict_number_protocol=True, lt=10))
+    assert v.validate_python(NumpyIntLike(9)) == 9
+    with pytest.raises(ValidationError, match='Input should be less than 10'):
+        v.validate_python(NumpyIntLike(10))
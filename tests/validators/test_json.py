@@ -3,7 +3,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import RawJson, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -112,6 +112,7 @@ def test_any_python(input_value, expected):
                         'loc': (3,),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'err',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     }
                 ],
             ),
@@ -177,3 +178,31 @@ def test_any_schema_no_schema():
     assert 'validator:None' in plain_repr(v)
     v = SchemaValidator(core_schema.json_schema(core_schema.int_schema()))
     assert 'validator:Some(' in plain_repr(v)
+
+
+def test_keep_raw():
+    v = SchemaValidator(
+        core_schema.json_schema(core_schema.dict_schema(values_schema=core_schema.int_schema()), keep_raw=True)
+    )
+    raw = '{"b": 2,  "a": "1"}'
+    output = v.validate_python(raw)
+    assert isinstance(output, RawJson)
+    assert output.value == {'b': 2, 'a': 1}
+    assert output.raw == raw
+    assert output == RawJson({'b': 2, 'a': 1}, raw)
+    assert output != RawJson({'b': 2, 'a': 1}, '{"b":2,"a":1}')
+    assert repr(output) == 'RawJson({\'b\': 2, \'a\': 1}, \'{"b": 2,  "a": "1"}\')'
+    assert v.validate_python(raw.encode()).raw == raw
+
+    output = SchemaValidator(core_schema.json_schema(keep_raw=True)).validate_python('[1, 2.50]')
+    assert output.value == [1, 2.5]
+    assert output.raw == '[1, 2.50]'
+
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer'):
+        v.validate_python('{"a": "x"}')
+
+
+def test_keep_raw_allow_partial():
+    v = SchemaValidator(core_schema.json_schema(keep_raw=True))
+    with pytest.raises(ValueError, match='`keep_raw` json schemas cannot be used with `allow_partial`'):
+        v.validate_python('[1, 2, 3', allow_partial=True)
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 
 def test_lax_or_strict():
@@ -36,3 +36,36 @@ def test_lax_or_strict_default_strict():
     # but it is if we set `strict` to True
     with pytest.raises(ValidationError, match='Input should be a valid integer'):
         v.validate_python('123', strict=True)
+
+
+def test_lax_or_strict_from_context():
+    def field(name):
+        return core_schema.typed_dict_field(
+            core_schema.lax_or_strict_schema(
+                core_schema.int_schema(),
+                core_schema.int_schema(strict=True),
+                strict_context_key='strict_fields',
+                strict_context_name=name,
+            )
+        )
+
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': field('a'), 'b': field('b')}))
+    assert v.validate_python({'a': '1', 'b': '2'}) == {'a': 1, 'b': 2}
+    assert v.validate_python({'a': '1', 'b': '2'}, context={'other': 1}) == {'a': 1, 'b': 2}
+    assert v.validate_python({'a': '1', 'b': 2}, context={'strict_fields': ['b']}) == {'a': 1, 'b': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': '1', 'b': '2'}, context={'strict_fields': {'b'}})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('b',))]
+
+    # a context which can't be queried doesn't make the field strict
+    assert v.validate_python({'a': '1', 'b': '2'}, context=[1]) == {'a': 1, 'b': 2}
+
+
+def test_lax_or_strict_from_context_requires_both():
+    with pytest.raises(SchemaError, match='`strict_context_key` and `strict_context_name` must be set together'):
+        SchemaValidator(
+            core_schema.lax_or_strict_schema(
+                core_schema.int_schema(), core_schema.int_schema(strict=True), strict_context_key='strict_fields'
+            )
+        )
@@ -7,7 +7,7 @@ from typing import Any, Dict, Iterator, List, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -134,6 +134,7 @@ def test_list_error(input_value, index):
             'loc': (index,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -408,6 +409,7 @@ def test_max_length_fail_fast(error_in_func: bool) -> None:
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 }
             ],
             id='fail_fast',
@@ -420,12 +422,14 @@ def test_max_length_fail_fast(error_in_func: bool) -> None:
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
                 {
                     'type': 'int_parsing',
                     'loc': (2,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'again',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
             ],
             id='not_fail_fast',
@@ -442,6 +446,83 @@ def test_list_fail_fast(fail_fast, expected):
     assert exc_info.value.errors(include_url=False) == expected
 
 
+@pytest.mark.parametrize(
+    'schema_kwargs,input_value,expected',
+    [
+        ({'skip_empty': True}, ['a', '', 'b'], ['a', 'b']),
+        ({'skip_empty': True}, ['a', None], Err('Input should be a valid string [type=string_type')),
+        ({'skip_none': True}, ['a', None, 'b'], ['a', 'b']),
+        ({'skip_none': True}, ['a', ''], Err('String should have at least 1 character [type=string_too_short')),
+        ({'skip_empty': True, 'skip_none': True}, ['', None, 'a', None, ''], ['a']),
+        ({'skip_empty': True, 'skip_none': True}, [], []),
+    ],
+    ids=repr,
+)
+def test_list_skip_items(py_and_json: PyAndJson, schema_kwargs, input_value, expected):
+    v = py_and_json(
+        core_schema.list_schema(core_schema.str_schema(min_length=1), **schema_kwargs)  # type: ignore[arg-type]
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_list_skip_items_lengths():
+    v = SchemaValidator(core_schema.list_schema(min_length=2, max_length=2, skip_empty=True, skip_none=True))
+    assert v.validate_python([1, None, '', [], {}, 2]) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, None, '', ()])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_short',
+            'loc': (),
+            'msg': 'List should have at least 2 items after validation, not 1',
+            'input': [1, None, '', ()],
+            'ctx': {'field_type': 'List', 'min_length': 2, 'actual_length': 1},
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, None, 2, 3])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_long',
+            'loc': (),
+            'msg': 'List should have at most 2 items after validation, not more',
+            'input': [1, None, 2, 3],
+            'ctx': {'field_type': 'List', 'max_length': 2, 'actual_length': None},
+        }
+    ]
+
+
+def test_list_skip_items_error_loc():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), skip_none=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([None, 1, None, 'x'])
+    # locations refer to the position in the input, including skipped items
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (3,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+
+def test_list_skip_empty_custom_len():
+    class Empty:
+        def __len__(self):
+            return 0
+
+    v = SchemaValidator(core_schema.list_schema(skip_empty=True))
+    item = Empty()
+    # only builtin strings and collections are considered empty
+    assert v.validate_python([item, b'', ()]) == [item]
+
+
 class MySequence(collections.abc.Sequence):
     def __init__(self, data: List[Any]):
         self._data = data
@@ -547,3 +628,143 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+@pytest.mark.parametrize(
+    'schema_kwargs,input_value,expected',
+    [
+        ({'coerce_scalar': True}, 1, [1]),
+        ({'coerce_scalar': True}, '1', Err('[type=list_type')),
+        ({'coerce_scalar': True}, [1, '2'], [1, 2]),
+        ({'coerce_scalar': True}, {'a': 1}, Err('[type=list_type')),
+        ({'coerce_scalar': True, 'coerce_scalar_str': True}, '1', [1]),
+        ({'coerce_scalar_str': True}, '1', Err('[type=list_type')),
+        ({}, 1, Err('[type=list_type')),
+    ],
+    ids=repr,
+)
+def test_list_coerce_scalar(py_and_json: PyAndJson, schema_kwargs, input_value, expected):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), **schema_kwargs))  # type: ignore[arg-type]
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_list_coerce_scalar_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), coerce_scalar=True, min_length=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_from_float',
+            'loc': (0,),
+            'msg': 'Input should be a valid integer, got a number with a fractional part',
+            'input': 1.5,
+        }
+    ]
+    with pytest.raises(ValidationError, match='List should have at least 2 items after validation, not 1'):
+        v.validate_python(1)
+
+
+def test_list_coerce_scalar_bytes():
+    v = SchemaValidator(core_schema.list_schema(coerce_scalar=True, coerce_scalar_str=True))
+    assert v.validate_python(b'abc') == [b'abc']
+    assert v.validate_python(bytearray(b'abc')) == [bytearray(b'abc')]
+    assert v.validate_python(None) == [None]
+    assert v.validate_python((1, 2)) == [1, 2]
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_value',
+    [
+        (core_schema.int_schema(strict=True), list(range(1000))),
+        (core_schema.str_schema(strict=True), ['a', 'b', 'c']),
+        (core_schema.bool_schema(strict=True), [True, False]),
+        (core_schema.int_schema(strict=True), []),
+    ],
+)
+def test_list_strict_exact_items(items_schema, input_value):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert output is not input_value
+
+
+def test_list_strict_exact_items_fallback():
+    class MyInt(int):
+        pass
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(strict=True), max_length=3))
+    output = v.validate_python([1, MyInt(2)])
+    assert output == [1, 2]
+    # the int subclass isn't passed through, it's validated like in the general path
+    assert type(output[1]) is int
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, True, '3'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_type', (1,)), ('int_type', (2,))]
+
+    with pytest.raises(ValidationError, match='List should have at most 3 items after validation, not 4'):
+        v.validate_python([1, 2, 3, 4])
+
+
+def test_list_exact_items_lax():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python([1, '2', 3.0]) == [1, 2, 3]
+    assert v.validate_python([1, 2], strict=True) == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python([1, '2'], strict=True)
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(strict=True)))
+    assert v.validate_python([1, '2'], strict=False) == [1, 2]
+
+
+def test_error_index_with_skipped_items(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), skip_none=True))
+    assert v.validate_test([1, None, 2]) == [1, 2]
+    # skipped `None` holes don't shift the index of later items
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([None, 1, None, 'x'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(3,)]
+
+
+def test_one_based_index(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.list_schema(core_schema.list_schema(core_schema.int_schema()), one_based_index=True, max_length=3)
+    )
+    assert v.validate_test([[1], [2]]) == [[1], [2]]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([['x'], [1, 'y']])
+    # only this list's indices are 1-based, the nested list counts from 0
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1, 0), (2, 1)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([[1], [2], [3], [4]])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('too_long', ())]
+
+
+def test_one_based_index_coerce_scalar():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), one_based_index=True, coerce_scalar=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
+
+
+def test_deque_output(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), output_type='deque'))
+    output = v.validate_test([1, '2', 3])
+    assert isinstance(output, deque)
+    assert output == deque([1, 2, 3])
+    assert output.maxlen is None
+
+
+def test_deque_output_maxlen():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), output_type='deque', maxlen=2))
+    output = v.validate_python([1, 2, 3])
+    assert output == deque([2, 3])
+    assert output.maxlen == 2
+
+    with pytest.raises(SchemaError, match="`maxlen` can only be used with `output_type='deque'`"):
+        SchemaValidator(core_schema.list_schema(core_schema.int_schema(), maxlen=2))
@@ -191,6 +191,7 @@ def test_union():
             'loc': ('int',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'c',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
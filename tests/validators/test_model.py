@@ -7,7 +7,7 @@ from typing import Any, Callable, Dict, List, Set, Tuple
 import pytest
 from dirty_equals import HasRepr, IsInstance
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 
 def test_model_class():
@@ -118,7 +118,13 @@ def test_model_class_extra_forbid():
         m = v.validate_python({'field_a': 'test', 'field_b': 12, 'field_c': 'extra'})
 
     assert exc_info.value.errors(include_url=False) == [
-        {'type': 'extra_forbidden', 'loc': ('field_c',), 'msg': 'Extra inputs are not permitted', 'input': 'extra'}
+        {
+            'type': 'extra_forbidden',
+            'loc': ('field_c',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 'extra',
+            'ctx': {'value': "'extra'"},
+        }
     ]
 
 
@@ -751,6 +757,7 @@ def test_revalidate_always():
             'loc': ('field_b',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -1383,3 +1390,343 @@ def test_model_with_enum_int_field_validation_should_succeed_for_any_type_equali
     v.validate_assignment(m, 'enum_field', Decimal(1))
     v.validate_assignment(m, 'enum_field_2', Decimal(2))
     v.validate_assignment(m, 'enum_field_3', IntWrappable(3))
+
+
+def _revalidated_model_schema(cls, root_validator, **kwargs):
+    return core_schema.model_schema(
+        cls,
+        core_schema.with_info_after_validator_function(
+            root_validator,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema(ge=0)),
+                    'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=1)),
+                }
+            ),
+        ),
+        **kwargs,
+    )
+
+
+def test_revalidate_after_root():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    calls = []
+
+    def f(input_value, info):
+        calls.append(input_value[0].copy())
+        input_value[0]['a'] -= 10
+        input_value[0]['b'] = str(input_value[0]['b'])
+        return input_value
+
+    v = SchemaValidator(_revalidated_model_schema(MyModel, f))
+    m = v.validate_python({'a': 5})
+    assert m.__dict__ == {'a': -5, 'b': '1'}
+
+    v = SchemaValidator(_revalidated_model_schema(MyModel, f, revalidate_after_root=True))
+    calls.clear()
+    m = v.validate_python({'a': 15})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': 5, 'b': 1}
+    # fields set come from the input, not from the revalidated data
+    assert m.__pydantic_fields_set__ == {'a'}
+    # the root validator runs once, only the fields are revalidated
+    assert calls == [{'a': 15, 'b': 1}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 5})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'greater_than_equal',
+            'loc': ('a',),
+            'msg': 'Input should be greater than or equal to 0',
+            'input': -5,
+            'ctx': {'ge': 0},
+        }
+    ]
+
+
+def test_revalidate_after_root_wrap_init():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    def f(input_value, handler, info):
+        model_dict, model_extra, fields_set = handler(input_value)
+        return {**model_dict, 'b': '2'}, model_extra, fields_set
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.with_info_wrap_validator_function(
+                f,
+                core_schema.model_fields_schema({'b': core_schema.model_field(core_schema.int_schema())}),
+            ),
+            revalidate_after_root=True,
+        )
+    )
+    m = MyModel()
+    v.validate_python({'b': 1}, self_instance=m)
+    assert m.__dict__ == {'b': 2}
+
+
+def test_revalidate_after_root_without_root_validators():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            revalidate_after_root=True,
+        )
+    )
+    m = v.validate_python({'a': '1'})
+    assert m.__dict__ == {'a': 1}
+
+
+def test_private_fields():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+        private_fields={
+            '_b': core_schema.with_default_schema(core_schema.int_schema(), default='2', validate_default=True),
+            '_c': core_schema.with_default_schema(
+                core_schema.list_schema(), default_factory=lambda data: [data['a']], default_factory_takes_data=True
+            ),
+        },
+    )
+    v = SchemaValidator(schema)
+    m = v.validate_python({'a': '1', '_b': 'ignored'})
+    assert m.__dict__ == {'a': 1}
+    assert m.__pydantic_fields_set__ == {'a'}
+    assert m.__pydantic_private__ == {'_b': 2, '_c': [1]}
+
+    m = MyModel()
+    v.validate_python({'a': 3}, self_instance=m)
+    assert m.__dict__ == {'a': 3}
+    assert m.__pydantic_private__ == {'_b': 2, '_c': [3]}
+
+    # private fields aren't serialized
+    assert SchemaSerializer(schema).to_python(m) == {'a': 3}
+
+
+def test_private_fields_errors():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            private_fields={
+                '_b': core_schema.with_default_schema(core_schema.int_schema(), default='x', validate_default=True)
+            },
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('_b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+    with pytest.raises(SchemaError, match='Private field "_b" must have a default'):
+        SchemaValidator(
+            core_schema.model_schema(
+                MyModel, core_schema.model_fields_schema({}), private_fields={'_b': core_schema.int_schema()}
+            )
+        )
+
+
+@pytest.mark.parametrize(
+    'revalidate_instances,exact_revalidated,subclass_revalidated',
+    [(None, False, False), ('never', False, False), ('always', True, True), ('subclass-instances', False, True)],
+)
+def test_revalidate_instances_nested_field(revalidate_instances, exact_revalidated, subclass_revalidated):
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class InnerSub(Inner):
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())}),
+        revalidate_instances=revalidate_instances,
+    )
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'inner': core_schema.typed_dict_field(inner_schema)}),
+    )
+    inner = SchemaValidator(inner_schema).validate_python({'x': 1})
+    sub = InnerSub()
+    sub.__dict__ = {'x': '2'}
+    sub.__pydantic_fields_set__ = {'x'}
+    sub.__pydantic_extra__ = None
+
+    output = v.validate_python({'inner': inner})['inner']
+    assert (output is not inner) == exact_revalidated
+    assert type(output) is Inner
+
+    output = v.validate_python({'inner': sub})['inner']
+    assert (output is not sub) == subclass_revalidated
+    if subclass_revalidated:
+        # revalidation builds an instance of the schema class
+        assert type(output) is Inner
+        assert output.__dict__ == {'x': 2}
+        assert output.__pydantic_fields_set__ == {'x'}
+
+
+def test_revalidate_instances_invalid():
+    class MyModel:
+        pass
+
+    with pytest.raises(SchemaError, match='Invalid revalidate_instances value: sometimes'):
+        SchemaValidator(
+            {
+                'type': 'model',
+                'cls': MyModel,
+                'revalidate_instances': 'sometimes',
+                'schema': {'type': 'model-fields', 'fields': {}},
+            }
+        )
+
+
+def slots_model_schema(cls, **kwargs):
+    return core_schema.model_schema(
+        cls,
+        core_schema.model_fields_schema(
+            {
+                'field_a': core_schema.model_field(core_schema.str_schema()),
+                'field_b': core_schema.model_field(core_schema.int_schema()),
+            }
+        ),
+        **kwargs,
+    )
+
+
+def test_model_class_slots():
+    class MyModel:
+        __slots__ = 'field_a', 'field_b', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(slots_model_schema(MyModel, revalidate_instances='always'))
+    m = v.validate_python({'field_a': 'test', 'field_b': '12'})
+    assert isinstance(m, MyModel)
+    assert not hasattr(m, '__dict__')
+    assert m.field_a == 'test'
+    assert m.field_b == 12
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+    assert m.__pydantic_extra__ is None
+
+    m2 = v.validate_python(m)
+    assert m2 is not m
+    assert (m2.field_a, m2.field_b) == ('test', 12)
+    assert m2.__pydantic_fields_set__ == {'field_a', 'field_b'}
+
+    v.validate_assignment(m, 'field_b', '13')
+    assert (m.field_a, m.field_b) == ('test', 13)
+
+    m3 = MyModel.__new__(MyModel)
+    v.validate_python({'field_a': 'x', 'field_b': 1}, self_instance=m3)
+    assert (m3.field_a, m3.field_b) == ('x', 1)
+    assert m3.__pydantic_fields_set__ == {'field_a', 'field_b'}
+
+
+def test_model_class_slots_mixed():
+    class Base:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class MyModel(Base):
+        __slots__ = ('field_a',)
+
+    v = SchemaValidator(slots_model_schema(MyModel, revalidate_instances='always'))
+    m = v.validate_python({'field_a': 'test', 'field_b': 12})
+    assert m.field_a == 'test'
+    assert m.field_b == 12
+    # only fields without a slot are stored in `__dict__`
+    assert m.__dict__ == {'field_b': 12}
+    assert m.__pydantic_fields_set__ == {'field_a', 'field_b'}
+
+    m2 = v.validate_python(m)
+    assert (m2.field_a, m2.field_b) == ('test', 12)
+    assert m2.__dict__ == {'field_b': 12}
+
+    v.validate_assignment(m, 'field_a', 'changed')
+    assert m.field_a == 'changed'
+    assert m.__dict__ == {'field_b': 12}
+
+
+def test_model_class_slots_non_field_slot():
+    class MyModel:
+        __slots__ = (
+            'field_a',
+            'field_b',
+            '_cache',
+            '__pydantic_fields_set__',
+            '__pydantic_extra__',
+            '__pydantic_private__',
+        )
+
+    v = SchemaValidator(
+        slots_model_schema(MyModel, revalidate_instances='always', config={'extra_fields_behavior': 'forbid'})
+    )
+    m = v.validate_python({'field_a': 'test', 'field_b': 12})
+    m._cache = 'cached'
+
+    m2 = v.validate_python(m)
+    assert (m2.field_a, m2.field_b) == ('test', 12)
+    assert m2.__pydantic_fields_set__ == {'field_a', 'field_b'}
+    assert not hasattr(m2, '_cache')
+
+
+def test_model_class_slots_missing_pydantic_slots():
+    class MyModel:
+        __slots__ = 'field_a', 'field_b', '__pydantic_fields_set__'
+
+    with pytest.raises(SchemaError, match="`MyModel` has no `__dict__`, so `__slots__` must include"):
+        SchemaValidator(slots_model_schema(MyModel))
+
+
+def test_model_class_slots_field_without_slot():
+    class MyModel:
+        __slots__ = 'field_a', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(slots_model_schema(MyModel))
+    with pytest.raises(AttributeError, match="'MyModel' object has no attribute 'field_b'"):
+        v.validate_python({'field_a': 'test', 'field_b': 12})
+
+
+def test_model_return_fields_set():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'field_a': core_schema.model_field(core_schema.str_schema()),
+                    'field_b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default=1)
+                    ),
+                }
+            ),
+        )
+    )
+    m, fields_set = v.validate_python({'field_a': 'test'}, return_fields_set=True)
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 1}
+    assert fields_set == {'field_a'}
+    # the returned set is a copy
+    fields_set.add('field_b')
+    assert m.__pydantic_fields_set__ == {'field_a'}
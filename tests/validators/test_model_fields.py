@@ -3,12 +3,21 @@ import re
 import sys
 from dataclasses import dataclass
 from datetime import datetime
+from types import MappingProxyType
 from typing import Any, Dict, List, Mapping, Union
 
 import pytest
 from dirty_equals import FunctionCheck, HasRepr, IsStr
 
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from pydantic_core import (
+    CoreConfig,
+    PydanticCustomError,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import Err, PyAndJson
 
@@ -192,6 +201,79 @@ def test_ignore_extra():
     )
 
 
+def test_ignore_extra_audit():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())}, model_name='MyModel', extra_audit=True
+        )
+    )
+
+    context = {}
+    assert v.validate_python({'field_a': 'x', 'field_b': 1, 'field_c': 2}, context=context) == (
+        {'field_a': 'x'},
+        None,
+        {'field_a'},
+    )
+    assert context == {'ignored_extras': [('MyModel', 'field_b'), ('MyModel', 'field_c')]}
+    v.validate_json('{"field_a": "x", "field_d": null}', context=context)
+    assert context['ignored_extras'] == [('MyModel', 'field_b'), ('MyModel', 'field_c'), ('MyModel', 'field_d')]
+
+    context = {}
+    v.validate_python({'field_a': 'x'}, context=context)
+    assert context == {}
+    # no errors without a context to record to
+    assert v.validate_python({'field_a': 'x', 'field_b': 1}) == ({'field_a': 'x'}, None, {'field_a'})
+
+    context = {}
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python({'field_a': 1, 'field_b': 1}, context=context)
+    assert context == {}
+
+
+def test_ignore_extra_audit_union():
+    def fields_schema(model_name, *fields):
+        return core_schema.model_fields_schema(
+            {f: core_schema.model_field(core_schema.int_schema()) for f in fields},
+            model_name=model_name,
+            extra_audit=True,
+        )
+
+    v = SchemaValidator(core_schema.union_schema([fields_schema('A', 'a'), fields_schema('B', 'a', 'b')]))
+    context = {}
+    assert v.validate_python({'a': 1, 'b': 2, 'z': 3}, context=context)[0] == {'a': 1, 'b': 2}
+    assert context == {'ignored_extras': [('B', 'z')]}
+
+    # keys ignored by a valid nested model aren't recorded when the outer model is invalid
+    outer = core_schema.model_fields_schema(
+        {
+            'inner': core_schema.model_field(fields_schema('Inner', 'x')),
+            'y': core_schema.model_field(core_schema.int_schema()),
+        },
+        model_name='Outer',
+    )
+    v = SchemaValidator(core_schema.union_schema([outer, fields_schema('Other', 'w')], mode='left_to_right'))
+    context = {}
+    assert v.validate_python({'inner': {'x': 1, 'z': 2}, 'y': 'x', 'w': 1}, context=context)[0] == {'w': 1}
+    assert context == {'ignored_extras': [('Other', 'inner'), ('Other', 'y')]}
+
+
+def test_ignore_extra_audit_default_context():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())}, model_name='MyModel', extra_audit=True
+        ),
+        default_context={'default': True},
+    )
+    context = {}
+    v.validate_python({'field_a': 'x', 'field_b': 1}, context=context)
+    assert context == {'ignored_extras': [('MyModel', 'field_b')]}
+
+
+def test_extra_audit_invalid():
+    with pytest.raises(SchemaError, match='extra_audit can only be used if extra_behavior=ignore'):
+        SchemaValidator(core_schema.model_fields_schema({}, extra_audit=True, extra_behavior='forbid'))
+
+
 def test_forbid_extra():
     v = SchemaValidator(
         {
@@ -205,10 +287,36 @@ def test_forbid_extra():
         v.validate_python({'field_a': 'abc', 'field_b': 1})
 
     assert exc_info.value.errors(include_url=False) == [
-        {'type': 'extra_forbidden', 'loc': ('field_b',), 'msg': 'Extra inputs are not permitted', 'input': 1}
+        {
+            'type': 'extra_forbidden',
+            'loc': ('field_b',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 1,
+            'ctx': {'value': '1'},
+        }
     ]
 
 
+def test_forbid_extra_value_context():
+    v = SchemaValidator(
+        {
+            'type': 'model-fields',
+            'fields': {'field_a': {'type': 'model-field', 'schema': {'type': 'str'}}},
+            'extra_behavior': 'forbid',
+        }
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'abc', 'field_b': 'x' * 200})
+    # long values are truncated in the context
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'value': "'" + 'x' * 49 + '...' + 'x' * 48 + "'"}
+    assert 'ctx' not in exc_info.value.errors(include_url=False, include_context=False)[0]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"field_a": "abc", "field_b": [1, null]}')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'value': '[1, None]'}
+
+
 def test_allow_extra_invalid():
     with pytest.raises(SchemaError, match='extras_schema can only be used if extra_behavior=allow'):
         SchemaValidator(
@@ -377,6 +485,7 @@ def test_validate_assignment_allow_extra_validate():
             'loc': ('other_field',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'xyz',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -424,6 +533,7 @@ def test_json_error():
             'loc': ('field_a', 1),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -769,6 +879,7 @@ def test_alias_error_loc_alias(py_and_json: PyAndJson):
             'loc': ('foo', 'x'),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -780,6 +891,7 @@ def test_alias_error_loc_alias(py_and_json: PyAndJson):
             'loc': ('bar', 1, -1),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -815,6 +927,7 @@ def test_alias_error_loc_field_names(py_and_json: PyAndJson):
             'loc': ('field_a',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -826,6 +939,7 @@ def test_alias_error_loc_field_names(py_and_json: PyAndJson):
             'loc': ('field_a',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -895,6 +1009,7 @@ def test_model_fields_deep():
             'loc': ('field_b', 'field_d', 'field_f'),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'xx',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -1398,6 +1513,7 @@ def test_alias_extra(py_and_json: PyAndJson):
             'loc': ('field_a',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': '...',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -1717,7 +1833,13 @@ def test_extra_behavior_forbid(config: Union[core_schema.CoreConfig, None], sche
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'f': 'x', 'extra_field': 123})
     assert exc_info.value.errors(include_url=False) == [
-        {'type': 'extra_forbidden', 'loc': ('extra_field',), 'msg': 'Extra inputs are not permitted', 'input': 123}
+        {
+            'type': 'extra_forbidden',
+            'loc': ('extra_field',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 123,
+            'ctx': {'value': '123'},
+        }
     ]
 
     v.validate_assignment(m, 'f', 'y')
@@ -1781,3 +1903,92 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+class DuckMap:
+    def __init__(self, **kwargs):
+        self._d = kwargs
+
+    def keys(self):
+        return self._d.keys()
+
+    def __getitem__(self, k):
+        return self._d[k]
+
+
+@pytest.mark.parametrize('input_value', [MappingProxyType({'a': '1', 'b': 2}), DuckMap(a='1', b=2)], ids=repr)
+def test_read_only_mappings(input_value):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'a': core_schema.model_field(schema=core_schema.int_schema()),
+                'b': core_schema.model_field(schema=core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.validate_python(input_value) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+    assert v.validate_python(input_value, from_attributes=True) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.validate_python(input_value, strict=True)
+
+
+def test_field_examples():
+    examples = [1, 2]
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'a': core_schema.model_field(schema=core_schema.int_schema(), examples=examples),
+                'b': core_schema.model_field(
+                    schema=core_schema.list_schema(core_schema.int_schema(gt=0)), examples=[[1, 2]]
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1, 'b': [1]}) == ({'a': 1, 'b': [1]}, None, {'a', 'b'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': [0]})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0', 'examples': [1, 2]},
+        },
+        # errors for items of the field's value don't get the field's examples
+        {'type': 'greater_than', 'loc': ('b', 0), 'msg': 'Input should be greater than 0', 'input': 0, 'ctx': {'gt': 0}},
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {'examples': [[1, 2]]}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment({'a': 1, 'b': [1]}, 'a', 'x')
+    assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+        'reason': 'invalid digit at position 0',
+        'examples': [1, 2],
+    }
+
+
+def test_field_examples_custom_error_context():
+    ctx = {'foo': 'bar'}
+
+    def f(input_value):
+        raise PydanticCustomError('my_error', 'my message {foo}', ctx)
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'a': core_schema.model_field(schema=core_schema.no_info_plain_validator_function(f), examples=['x'])
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'my_error', 'loc': ('a',), 'msg': 'my message bar', 'input': 1, 'ctx': {'foo': 'bar', 'examples': ['x']}}
+    ]
+    # the context the error was raised with isn't changed
+    assert ctx == {'foo': 'bar'}
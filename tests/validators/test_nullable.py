@@ -20,6 +20,7 @@ def test_nullable():
             'loc': (),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'hello',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -42,6 +42,16 @@ def test_schema_validator_containing_config():
     assert m == {'f': 'y'}
 
 
+def show_context(input_value, info):
+    return input_value + f'| context: {info.context}'
+
+
+def test_schema_validator_containing_default_context():
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(show_context), default_context={'x': 'y'})
+    v = pickle.loads(pickle.dumps(v))
+    assert v.validate_python('a') == "a| context: {'x': 'y'}"
+
+
 def test_schema_validator_tz_pickle() -> None:
     """
     https://github.com/pydantic/pydantic-core/issues/589
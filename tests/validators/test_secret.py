@@ -0,0 +1,93 @@
+import pickle
+
+import pytest
+
+from pydantic_core import SchemaValidator, Secret, ValidationError, core_schema
+
+from ..conftest import PyAndJson
+
+
+def test_secret(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.secret_schema(core_schema.int_schema()))
+    secret = v.validate_test('123')
+    assert isinstance(secret, Secret)
+    assert secret.get_secret_value() == 123
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('x')
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_parsing'
+
+
+def test_repr_hides_value():
+    v = SchemaValidator(core_schema.secret_schema(core_schema.str_schema()))
+    secret = v.validate_python('hunter2')
+    assert repr(secret) == "Secret('**********')"
+    assert str(secret) == '**********'
+    assert 'hunter2' not in repr({'password': secret})
+
+
+def test_secret_input_revalidated():
+    v = SchemaValidator(core_schema.secret_schema(core_schema.int_schema()))
+    secret = v.validate_python(Secret('1'))
+    assert secret.get_secret_value() == 1
+
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(Secret('x'))
+
+
+def test_nested_in_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'password': core_schema.typed_dict_field(core_schema.secret_schema(core_schema.str_schema()))}
+        )
+    )
+    output = v.validate_json('{"password": "hunter2"}')
+    assert output == {'password': Secret('hunter2')}
+    assert repr(output) == "{'password': Secret('**********')}"
+
+
+def test_eq_hash():
+    assert Secret('a') == Secret('a')
+    assert Secret('a') != Secret('b')
+    assert Secret('a') != 'a'
+    assert hash(Secret('a')) == hash('a')
+    assert Secret[str] is Secret
+
+
+def test_pickle():
+    secret = pickle.loads(pickle.dumps(Secret(['a', 1])))
+    assert secret.get_secret_value() == ['a', 1]
+
+
+def test_secret_str_length():
+    v = SchemaValidator(core_schema.secret_schema(core_schema.str_schema(min_length=8)))
+    secret = v.validate_python('correct horse')
+    assert len(secret) == 13
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('hunter2')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': 'String should have at least 8 characters',
+            'input': Secret('hunter2'),
+            'ctx': {'min_length': 8},
+        }
+    ]
+    assert 'hunter2' not in str(exc_info.value)
+    assert "input_value=Secret('**********')" in str(exc_info.value)
+
+
+def test_secret_bytes():
+    v = SchemaValidator(core_schema.secret_schema(core_schema.bytes_schema(max_length=4)))
+    secret = v.validate_python(b'abc')
+    assert secret.get_secret_value() == b'abc'
+    assert len(secret) == 3
+    assert repr(secret) == "Secret('**********')"
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"abcdef"')
+    assert exc_info.value.errors(include_url=False)[0]['input'] == Secret('abcdef')
+    assert 'abcdef' not in str(exc_info.value)
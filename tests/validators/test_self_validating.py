@@ -0,0 +1,121 @@
+import pytest
+from dirty_equals import HasRepr, IsInstance
+
+from pydantic_core import PydanticCustomError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import PyAndJson
+
+
+class Celsius:
+    def __init__(self, degrees):
+        self.degrees = degrees
+
+    def __pydantic_validate__(self, info):
+        if self.degrees < -273.15:
+            raise ValueError('below absolute zero')
+        return float(self.degrees)
+
+
+def test_self_validating(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.self_validating_schema(core_schema.float_schema()))
+    assert v.validate_test(21.5) == 21.5
+    assert v.validate_test('21.5') == 21.5
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('x')
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'float_parsing'
+
+
+def test_self_validating_method():
+    v = SchemaValidator(core_schema.self_validating_schema(core_schema.float_schema()))
+    assert v.validate_python(Celsius(21)) == 21.0
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Celsius(-300))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, below absolute zero',
+            'input': IsInstance(Celsius),
+            'ctx': {'error': HasRepr(repr(ValueError('below absolute zero')))},
+        }
+    ]
+
+
+def test_return_value_not_revalidated():
+    class Raw:
+        def __pydantic_validate__(self, info):
+            return 'not a float'
+
+    v = SchemaValidator(core_schema.self_validating_schema(core_schema.float_schema()))
+    assert v.validate_python(Raw()) == 'not a float'
+
+
+def test_validation_info():
+    infos = []
+
+    class Recorder:
+        def __pydantic_validate__(self, info):
+            infos.append(info)
+            return info.context
+
+    v = SchemaValidator(core_schema.self_validating_schema(core_schema.int_schema()), {'title': 'Foo'})
+    assert v.validate_python(Recorder(), context={'a': 1}) == {'a': 1}
+    (info,) = infos
+    assert info.mode == 'python'
+    assert info.config == {'title': 'Foo'}
+    assert info.field_name is None
+
+
+def test_custom_error():
+    class Strict:
+        def __pydantic_validate__(self, info):
+            raise PydanticCustomError('my_error', 'My {thing} error', {'thing': 'custom'})
+
+    v = SchemaValidator(core_schema.self_validating_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Strict())
+    errors = exc_info.value.errors(include_url=False)
+    assert [(e['type'], e['msg'], e['ctx']) for e in errors] == [('my_error', 'My custom error', {'thing': 'custom'})]
+
+
+def test_other_errors_raised():
+    class Broken:
+        def __pydantic_validate__(self, info):
+            raise RuntimeError('broken')
+
+    v = SchemaValidator(core_schema.self_validating_schema(core_schema.int_schema()))
+    with pytest.raises(RuntimeError, match='broken'):
+        v.validate_python(Broken())
+
+
+def test_instance_attribute_ignored():
+    class NotSelfValidating:
+        pass
+
+    obj = NotSelfValidating()
+    obj.__pydantic_validate__ = lambda info: 1
+
+    v = SchemaValidator(core_schema.self_validating_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(obj)
+
+
+def test_in_model_field():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'temp': core_schema.typed_dict_field(core_schema.self_validating_schema(core_schema.float_schema()))}
+        )
+    )
+    assert v.validate_python({'temp': Celsius(10)}) == {'temp': 10.0}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'temp': Celsius(-300)})
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('temp',)
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.self_validating_schema(core_schema.float_schema()))
+    assert s.to_python(1.5) == 1.5
+    assert s.to_json(1.5) == b'1.5'
@@ -98,6 +98,7 @@ def test_set_multiple_errors():
             'loc': (0,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'a',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
         {'type': 'int_type', 'loc': (1,), 'msg': 'Input should be a valid integer', 'input': (1, 2)},
         {'type': 'int_type', 'loc': (2,), 'msg': 'Input should be a valid integer', 'input': []},
@@ -290,6 +291,7 @@ def test_set_any(input_value, expected):
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
             ],
             id='fail_fast',
@@ -302,12 +304,14 @@ def test_set_any(input_value, expected):
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
                 {
                     'type': 'int_parsing',
                     'loc': (2,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'again',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
             ],
             id='not_fail_fast',
@@ -321,3 +325,75 @@ def test_set_fail_fast(fail_fast, expected):
         v.validate_python([1, 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_set_skip_items(schema_type):
+    v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'str'}, 'skip_empty': True, 'skip_none': True})
+    assert v.validate_python(['a', '', None, 'b', 'a']) == {'a', 'b'}
+    assert v.validate_json('["a", "", null, "b"]') == {'a', 'b'}
+
+
+def test_set_skip_items_lengths():
+    v = SchemaValidator({'type': 'set', 'min_length': 2, 'max_length': 2, 'skip_none': True})
+    assert v.validate_python([1, None, 2, None]) == {1, 2}
+    with pytest.raises(ValidationError, match='Set should have at least 2 items after validation, not 1'):
+        v.validate_python([1, None, None])
+    with pytest.raises(ValidationError, match='Set should have at most 2 items after validation, not more'):
+        v.validate_python([1, None, 2, 3])
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_set_coerce_scalar(schema_type):
+    v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'int'}, 'coerce_scalar': True})
+    assert v.validate_python(1) == {1}
+    assert v.validate_json('1') == {1}
+    assert v.validate_python([1, 2, 1]) == {1, 2}
+    with pytest.raises(ValidationError, match='Input should be a valid array'):
+        v.validate_json('"1"')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (0,)
+
+
+def test_set_coerce_scalar_str():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'str'}, 'coerce_scalar': True, 'coerce_scalar_str': True})
+    assert v.validate_python('abc') == {'abc'}
+    assert v.validate_python(['a', 'b']) == {'a', 'b'}
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_set_item_not_hashable(schema_type):
+    v = SchemaValidator({'type': schema_type})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, {'a': 2}, [3]])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'set_item_not_hashable',
+            'loc': (1,),
+            'msg': 'Set items should be hashable, got an unhashable `dict`',
+            'input': {'a': 2},
+            'ctx': {'item_type': 'dict'},
+        },
+        {
+            'type': 'set_item_not_hashable',
+            'loc': (2,),
+            'msg': 'Set items should be hashable, got an unhashable `list`',
+            'input': [3],
+            'ctx': {'item_type': 'list'},
+        },
+    ]
+
+    with pytest.raises(ValidationError, match=r'got an unhashable `dict` \[type=set_item_not_hashable,'):
+        v.validate_json('[1, {"a": 2}]')
+
+
+def test_set_item_hash_error():
+    class BadHash:
+        def __hash__(self):
+            raise RuntimeError('no hash')
+
+    v = SchemaValidator({'type': 'set'})
+    with pytest.raises(RuntimeError, match='no hash'):
+        v.validate_python([BadHash()])
@@ -212,11 +212,57 @@ def test_regex_error(engine):
     ]
 
 
+def test_shared_pattern():
+    pattern = r'^[a-z]+-\d+$'
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                name: core_schema.typed_dict_field(core_schema.str_schema(pattern=pattern))
+                for name in ('a', 'b', 'c')
+            }
+        )
+    )
+    assert v.validate_python({'a': 'x-1', 'b': 'y-2', 'c': 'z-3'}) == {'a': 'x-1', 'b': 'y-2', 'c': 'z-3'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x-1', 'b': 'y', 'c': '3'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('b',), ('c',)]
+
+    # a validator built later with the same pattern behaves the same
+    v2 = SchemaValidator(core_schema.str_schema(pattern=pattern))
+    assert v2.validate_python('abc-123') == 'abc-123'
+
+    # invalid patterns aren't cached, they fail every time
+    for _ in range(2):
+        with pytest.raises(SchemaError, match='unclosed group'):
+            SchemaValidator(core_schema.str_schema(pattern='(abc'))
+
+
+@pytest.mark.parametrize(
+    'input_value,valid',
+    [
+        ('a' * 10, True),
+        ('a' * 11, False),
+        ('é' * 10, True),
+        ('é' * 11, False),
+        ('🐈' * 5 + 'a' * 5, True),
+        ('  ' + 'a' * 10 + '  ', True),
+        ('  ' + 'é' * 10 + '  ', True),
+    ],
+)
+def test_length_ascii_and_non_ascii(py_and_json: PyAndJson, input_value, valid):
+    v = py_and_json(core_schema.str_schema(min_length=10, max_length=10, strip_whitespace=True))
+    if valid:
+        assert v.validate_test(input_value) == input_value.strip()
+    else:
+        with pytest.raises(ValidationError, match='String should have at most 10 characters'):
+            v.validate_test(input_value)
+
+
 def test_default_validator():
     v = SchemaValidator(core_schema.str_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:true,coerce_numbers_to_str:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:true,coerce_numbers_to_str:false,coerce_enum:false}),definitions=[],cache_strings=True)'
     )
 
 
@@ -268,6 +314,56 @@ def test_lax_subclass_plain_enum(kwargs):
     assert repr(p) == "'one'"
 
 
+@pytest.mark.parametrize('kwargs', [{}, {'max_length': 5}], ids=repr)
+def test_coerce_enum_strict(FruitEnum, kwargs):
+    from enum import Enum
+
+    class PlainEnum(Enum):
+        ONE = 'one'
+
+    v = SchemaValidator(core_schema.str_schema(strict=True, coerce_enum=True, **kwargs))
+    p = v.validate_python(PlainEnum.ONE)
+    assert p == 'one'
+    assert type(p) is str
+    p = v.validate_python(FruitEnum.pear)
+    assert p == 'pear'
+    assert type(p) is str
+    assert v.validate_python('abc') == 'abc'
+
+    v = SchemaValidator(core_schema.str_schema(strict=True, **kwargs))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(PlainEnum.ONE)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'string_type'
+
+
+def test_coerce_enum_non_str_value():
+    from enum import Enum
+
+    class IntEnum(Enum):
+        ONE = 1
+
+    v = SchemaValidator(core_schema.str_schema(coerce_enum=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(IntEnum.ONE)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': IntEnum.ONE}
+    ]
+
+
+def test_coerce_enum_constraints():
+    from enum import Enum
+
+    class LongEnum(Enum):
+        LONG = 'abcdefgh'
+
+    v = SchemaValidator(core_schema.str_schema(coerce_enum=True, max_length=5, to_upper=True))
+    assert v.validate_python(LongEnum.LONG.value[:3]) == 'ABC'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(LongEnum.LONG)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'string_too_long'
+    assert v.validate_json('"abc"') == 'ABC'
+
+
 def test_subclass_preserved() -> None:
     class StrSubclass(str):
         pass
@@ -417,3 +513,127 @@ def test_compiled_regex(engine) -> None:
     v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), regex_engine=engine))
     assert v.validate_python('abc') == 'abc'
     assert v.validate_python('ABC') == 'ABC'
+
+
+@pytest.mark.parametrize(
+    'input_value,code_point,position',
+    [
+        ('ab\x00c', 'U+0000', 2),
+        ('\x1b[31m', 'U+001B', 0),
+        ('abc\x7f', 'U+007F', 3),
+        ('é\x85', 'U+0085', 1),
+        ('a\tb', 'U+0009', 1),
+    ],
+)
+def test_forbid_control_chars(input_value: str, code_point: str, position: int):
+    v = SchemaValidator(core_schema.str_schema(forbid_control_chars=True))
+    assert v.validate_python('hello world') == 'hello world'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_control_char',
+            'loc': (),
+            'msg': f'String should not contain control characters, found {code_point} at position {position}',
+            'input': input_value,
+            'ctx': {'code_point': code_point, 'position': position},
+        }
+    ]
+
+
+def test_forbid_control_chars_allow_whitespace():
+    v = SchemaValidator(core_schema.str_schema(forbid_control_chars=True, allow_control_whitespace=True))
+    assert v.validate_python('a\tb\r\nc') == 'a\tb\r\nc'
+    with pytest.raises(ValidationError, match=r'found U\+0000 at position 2 \[type=string_control_char'):
+        v.validate_python('a\n\x00')
+
+
+def test_forbid_control_chars_after_strip():
+    v = SchemaValidator(core_schema.str_schema(forbid_control_chars=True, strip_whitespace=True))
+    assert v.validate_python('\n hello\t') == 'hello'
+    with pytest.raises(ValidationError, match=r'found U\+0001 at position 1 \[type=string_control_char'):
+        v.validate_json('" a\\u0001"')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foo    bar', 'foo bar'),
+        ('foo bar', 'foo bar'),
+        ('a\t\n b\r\nc', 'a b c'),
+        ('  foo  ', ' foo '),
+        ('foo\u00a0\u2003bar', 'foo bar'),
+        ('', ''),
+    ],
+)
+def test_collapse_whitespace(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.str_schema(collapse_whitespace=True))
+    assert v.validate_test(input_value) == expected
+
+
+def test_collapse_ascii_whitespace_only():
+    v = SchemaValidator(core_schema.str_schema(collapse_whitespace=True, collapse_unicode_whitespace=False))
+    assert v.validate_python('foo  \u00a0\u00a0bar\t\tbaz') == 'foo \u00a0\u00a0bar baz'
+
+
+def test_collapse_whitespace_after_strip():
+    v = SchemaValidator(
+        core_schema.str_schema(strip_whitespace=True, collapse_whitespace=True, max_length=7, pattern=r'^\w+ \w+$')
+    )
+    assert v.validate_python('  foo   bar  ') == 'foo bar'
+    # the collapsed value is what's length checked
+    with pytest.raises(ValidationError, match='String should have at most 7 characters'):
+        v.validate_python('foo   barr')
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('a   b  c')
+
+
+def test_control_chars_allowed_by_default():
+    v = SchemaValidator(core_schema.str_schema())
+    assert v.validate_python('a\x00b') == 'a\x00b'
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'ge': 'A', 'lt': 'Z'}, 'A', 'A'),
+        ({'ge': 'A', 'lt': 'Z'}, 'Mango', 'Mango'),
+        ({'ge': 'A', 'lt': 'Z'}, 'Z', Err('Input should be less than Z [type=less_than')),
+        ({'ge': 'B'}, 'A', Err('Input should be greater than or equal to B [type=greater_than_equal')),
+        ({'gt': 'v1.2'}, 'v1.2', Err('Input should be greater than v1.2 [type=greater_than')),
+        ({'gt': 'v1.2'}, 'v1.10', Err('Input should be greater than v1.2 [type=greater_than')),
+        ({'gt': 'v1.2'}, 'v1.3', 'v1.3'),
+        ({'le': 'b'}, 'b', 'b'),
+        ({'le': 'b'}, 'ba', Err('Input should be less than or equal to b [type=less_than_equal')),
+        # code point ordering, upper case letters come before lower case ones
+        ({'lt': 'a'}, 'Z', 'Z'),
+        ({'lt': 'z'}, 'é', Err('Input should be less than z [type=less_than')),
+    ],
+)
+def test_str_bounds(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json(core_schema.str_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_str_bounds_after_transform():
+    v = SchemaValidator(core_schema.str_schema(ge='a', to_lower=True, strip_whitespace=True))
+    assert v.validate_python(' Mango ') == 'mango'
+
+    v = SchemaValidator(core_schema.str_schema(lt='a', to_upper=True))
+    assert v.validate_python('mango') == 'MANGO'
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(core_schema.str_schema(lt='a')).validate_python('mango')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than',
+            'loc': (),
+            'msg': 'Input should be less than a',
+            'input': 'mango',
+            'ctx': {'lt': 'a'},
+        }
+    ]
@@ -3,7 +3,7 @@ from enum import Enum
 import pytest
 from dirty_equals import IsAnyStr
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -24,6 +24,7 @@ from .test_typed_dict import Cls
                         'loc': ('apple', 'bar'),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'wrong',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     }
                 ],
             ),
@@ -137,6 +138,7 @@ def test_simple_tagged_union(py_and_json: PyAndJson, input_value, expected):
                         'loc': (123, 'bar'),
                         'msg': 'Input should be a valid integer, unable to parse string as an integer',
                         'input': 'wrong',
+                        'ctx': {'reason': 'invalid digit at position 0'},
                     }
                 ],
             ),
@@ -620,3 +622,51 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+def tagged_union_of(n: int, **kwargs) -> core_schema.TaggedUnionSchema:
+    choices = {
+        f'tag{i}': core_schema.typed_dict_schema(
+            {'kind': core_schema.typed_dict_field(core_schema.literal_schema([f'tag{i}']))}
+        )
+        for i in range(n)
+    }
+    return core_schema.tagged_union_schema(choices=choices, discriminator='kind', **kwargs)
+
+
+@pytest.mark.parametrize(
+    'max_tags_in_error,expected_tags',
+    [
+        (None, "'tag0', 'tag1', 'tag2', 'tag3', 'tag4'"),
+        (2, "'tag0', 'tag1' and 3 more"),
+        (4, "'tag0', 'tag1', 'tag2', 'tag3' and 1 more"),
+        (5, "'tag0', 'tag1', 'tag2', 'tag3', 'tag4'"),
+        (10, "'tag0', 'tag1', 'tag2', 'tag3', 'tag4'"),
+    ],
+)
+def test_max_tags_in_error(max_tags_in_error, expected_tags):
+    v = SchemaValidator(tagged_union_of(5, max_tags_in_error=max_tags_in_error))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 'other'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'union_tag_invalid',
+            'loc': (),
+            'msg': f"Input tag 'other' found using 'kind' does not match any of the expected tags: {expected_tags}",
+            'input': {'kind': 'other'},
+            'ctx': {'discriminator': "'kind'", 'tag': 'other', 'expected_tags': expected_tags},
+        }
+    ]
+
+
+def test_max_tags_in_error_all_choices_valid():
+    v = SchemaValidator(tagged_union_of(300, max_tags_in_error=3))
+    # tags omitted from the error are still matched
+    assert v.validate_python({'kind': 'tag299'}) == {'kind': 'tag299'}
+    with pytest.raises(ValidationError, match=r"expected tags: 'tag0', 'tag1', 'tag2' and 297 more"):
+        v.validate_python({'kind': 'other'})
+
+
+def test_max_tags_in_error_zero():
+    with pytest.raises(SchemaError, match='`max_tags_in_error` must be greater than 0'):
+        SchemaValidator(tagged_union_of(2, max_tags_in_error=0))
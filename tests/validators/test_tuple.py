@@ -1,11 +1,11 @@
 import re
-from collections import deque
-from typing import Any, Dict, Type
+from collections import deque, namedtuple
+from typing import Any, Dict, NamedTuple, Type
 
 import pytest
-from dirty_equals import IsNonNegative, IsTuple
+from dirty_equals import HasRepr, IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -189,6 +189,7 @@ def test_tuple_var_len_errors(input_value, index):
             'loc': (index,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -217,6 +218,7 @@ def test_tuple_fix_len_errors(input_value, items, index):
             'loc': (index,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -507,6 +509,7 @@ def test_length_constraints_omit(input_value, expected):
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 }
             ],
             id='fail_fast',
@@ -519,12 +522,14 @@ def test_length_constraints_omit(input_value, expected):
                     'loc': (1,),
                     'msg': 'Input should be a valid integer, unable to parse string as an integer',
                     'input': 'not-num',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
                 {
                     'type': 'float_parsing',
                     'loc': (2,),
                     'msg': 'Input should be a valid number, unable to parse string as a number',
                     'input': 'again',
+                    'ctx': {'reason': 'invalid digit at position 0'},
                 },
             ],
             id='not_fail_fast',
@@ -547,3 +552,161 @@ def test_tuple_fail_fast(fail_fast, expected):
         v.validate_python(['str', 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+@pytest.mark.parametrize(
+    'allow_from,input_value,expected',
+    [
+        (['list'], [1, 2], (1, 2)),
+        (['list'], (1, 2), Err('Input should be a valid tuple [type=tuple_type,')),
+        (['list'], {1, 2}, Err('Input should be a valid tuple [type=tuple_type,')),
+        (['tuple', 'set'], frozenset({1}), (1,)),
+        (['tuple', 'set'], (i for i in [1]), Err('Input should be a valid tuple [type=tuple_type,')),
+        (['generator'], (i for i in [1, 2]), (1, 2)),
+        (['generator'], iter([1, 2]), (1, 2)),
+        (['generator'], {1: 2}.keys(), Err('Input should be a valid tuple [type=tuple_type,')),
+        (['generator'], [1, 2], Err('Input should be a valid tuple [type=tuple_type,')),
+        ([], (1, 2), Err('Input should be a valid tuple [type=tuple_type,')),
+    ],
+)
+def test_tuple_allow_from(allow_from, input_value, expected):
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, allow_from=allow_from)
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_tuple_allow_from_json():
+    # JSON arrays are always accepted
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, allow_from=['set']))
+    assert v.validate_json('[1, 2]') == (1, 2)
+
+
+def test_tuple_allow_from_strict():
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, allow_from=['list'], strict=True)
+    )
+    assert v.validate_python((1, 2)) == (1, 2)
+    with pytest.raises(ValidationError, match=r'Input should be a valid tuple \[type=tuple_type,'):
+        v.validate_python([1, 2])
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.tuple_schema([core_schema.int_schema()], coerce_scalar=True), '1', Err('type=tuple_type')),
+        (core_schema.tuple_schema([core_schema.int_schema()], coerce_scalar=True), 1, (1,)),
+        (core_schema.tuple_schema([core_schema.int_schema()], coerce_scalar=True), [1], (1,)),
+        (
+            core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, coerce_scalar=True),
+            1,
+            (1,),
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()], coerce_scalar=True),
+            1,
+            Err('1\n  Field required [type=missing'),
+        ),
+        (
+            core_schema.tuple_schema(
+                [core_schema.str_schema()], variadic_item_index=0, coerce_scalar=True, coerce_scalar_str=True
+            ),
+            'abc',
+            ('abc',),
+        ),
+    ],
+)
+def test_tuple_coerce_scalar(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_tuple_coerce_scalar_allow_from():
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [core_schema.int_schema()], variadic_item_index=0, allow_from=['list'], coerce_scalar=True
+        )
+    )
+    assert v.validate_python(1) == (1,)
+    assert v.validate_python([1, 2]) == (1, 2)
+    with pytest.raises(ValidationError, match='Input should be a valid tuple'):
+        v.validate_python({1, 2})
+
+
+def test_one_based_index(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.tuple_schema(
+            [core_schema.str_schema(), core_schema.int_schema()], variadic_item_index=1, one_based_index=True
+        )
+    )
+    assert v.validate_test(['a', 1, 2]) == ('a', 1, 2)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 1, 'x', 'y'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (3,), (4,)]
+
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], one_based_index=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
+
+
+class Point(NamedTuple):
+    x: int
+    y: int
+
+
+def test_named_tuple_cls(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()], cls=Point))
+    output = v.validate_test(['1', 2])
+    assert output == Point(1, 2)
+    assert type(output) is Point
+    assert v.validate_test(Point(3, 4)) == Point(3, 4)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1])
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing']
+
+
+def test_named_tuple_cls_error():
+    class Positive(namedtuple('Positive', 'x')):
+        def __new__(cls, x):
+            if x <= 0:
+                raise ValueError('x must be positive')
+            return super().__new__(cls, x)
+
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], cls=Positive))
+    assert v.validate_python([1]) == Positive(1)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([0])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, x must be positive',
+            'input': [0],
+            'ctx': {'error': HasRepr(repr(ValueError('x must be positive')))},
+        }
+    ]
+
+
+def test_named_tuple_cls_schema_errors():
+    with pytest.raises(SchemaError, match='named tuple `Point` has 2 fields but `items_schema` has 1 item'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], cls=Point))
+
+    with pytest.raises(SchemaError, match="`variadic_item_index` can't be used with the named tuple `Point`"):
+        SchemaValidator(
+            core_schema.tuple_schema(
+                [core_schema.int_schema(), core_schema.int_schema()], variadic_item_index=1, cls=Point
+            )
+        )
+
+    with pytest.raises(SchemaError, match='`cls` must be a named tuple, not `list`'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], cls=list))
@@ -2,6 +2,7 @@ import gc
 import math
 import platform
 import re
+import sys
 import weakref
 from typing import Any, Dict, Mapping, Union
 
@@ -181,7 +182,13 @@ def test_forbid_extra():
         v.validate_python({'field_a': 'abc', 'field_b': 1})
 
     assert exc_info.value.errors(include_url=False) == [
-        {'type': 'extra_forbidden', 'loc': ('field_b',), 'msg': 'Extra inputs are not permitted', 'input': 1}
+        {
+            'type': 'extra_forbidden',
+            'loc': ('field_b',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 1,
+            'ctx': {'value': '1'},
+        }
     ]
 
 
@@ -229,6 +236,7 @@ def test_json_error():
             'loc': ('field_a', 1),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -664,6 +672,7 @@ def test_alias_error_loc_alias(py_and_json: PyAndJson):
             'loc': ('foo', 'x'),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -675,6 +684,7 @@ def test_alias_error_loc_alias(py_and_json: PyAndJson):
             'loc': ('bar', 1, -1),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -710,6 +720,7 @@ def test_alias_error_loc_field_names(py_and_json: PyAndJson):
             'loc': ('field_a',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -721,6 +732,7 @@ def test_alias_error_loc_field_names(py_and_json: PyAndJson):
             'loc': ('field_a',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'not_int',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -777,6 +789,7 @@ def test_model_deep():
             'loc': ('field_b', 'field_d', 'field_f'),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'xx',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -809,6 +822,7 @@ def test_alias_extra(py_and_json: PyAndJson):
             'loc': ('field_a',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': '...',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -1137,7 +1151,13 @@ def test_extra_behavior_forbid(config: Union[core_schema.CoreConfig, None], sche
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python({'f': 'x', 'extra_field': 123})
     assert exc_info.value.errors(include_url=False) == [
-        {'type': 'extra_forbidden', 'loc': ('extra_field',), 'msg': 'Extra inputs are not permitted', 'input': 123}
+        {
+            'type': 'extra_forbidden',
+            'loc': ('extra_field',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 123,
+            'ctx': {'value': '123'},
+        }
     ]
 
 
@@ -1196,3 +1216,158 @@ def test_leak_typed_dict():
     gc.collect()
 
     assert ref() is None
+
+
+def test_partial():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.with_default_schema(core_schema.str_schema(), default='x')),
+                'c': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'d': core_schema.typed_dict_field(core_schema.int_schema())})
+                ),
+            }
+        )
+    )
+    assert v.validate_python({}, partial=True) == {}
+    assert v.validate_python({'a': '1', 'c': {}}, partial=True) == {'a': 1, 'c': {}}
+    assert v.validate_json('{"b": "y"}', partial=True) == {'b': 'y'}
+    assert v.validate_strings({'a': '2'}, partial=True) == {'a': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'}, partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert [e['type'] for e in exc_info.value.errors()] == ['missing', 'missing']
+
+
+def test_partial_extra_forbid():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())}, extra_behavior='forbid'
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'b': 1}, partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'extra_forbidden',
+            'loc': ('b',),
+            'msg': 'Extra inputs are not permitted',
+            'input': 1,
+            'ctx': {'value': '1'},
+        }
+    ]
+
+
+def test_validate_assignment():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    d = {'a': 1, 'b': 'x'}
+    assert v.validate_assignment(d, 'a', '2') == {'a': 2, 'b': 'x'}
+    assert d == {'a': 2, 'b': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(d, 'a', 'x')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0'},
+        }
+    ]
+
+    with pytest.raises(ValidationError, match="Object has no attribute 'c'"):
+        v.validate_assignment(d, 'c', 1)
+
+
+@pytest.mark.parametrize('cache_strings', [True, 'keys'])
+def test_extra_keys_cached(cache_strings):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.int_schema())}, extra_behavior='allow'
+        ),
+        {'cache_strings': cache_strings},
+    )
+    (key1,) = v.validate_json('{"field_a": 1, "extra_key": 2}').keys() - {'field_a'}
+    (key2,) = v.validate_json('{"field_a": 1, "extra_key": 2}').keys() - {'field_a'}
+    assert key1 == 'extra_key'
+    assert key1 is key2
+
+
+def test_field_keys_interned():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({''.join(['field', '_a']): core_schema.typed_dict_field(core_schema.int_schema())})
+    )
+    (key,) = v.validate_json('{"field_a": 1}').keys()
+    assert key is sys.intern('field_a')
+
+
+def test_return_fields_set():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0), required=False
+                ),
+                'nested': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.typed_dict_schema({'c': core_schema.typed_dict_field(core_schema.int_schema())}),
+                        default={'c': 1},
+                    ),
+                    required=False,
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1}, return_fields_set=True) == ({'a': 1, 'b': 0, 'nested': {'c': 1}}, {'a'})
+    # fields of nested typed dicts aren't included
+    assert v.validate_python({'a': 1, 'b': 2, 'nested': {'c': 3}}, return_fields_set=True) == (
+        {'a': 1, 'b': 2, 'nested': {'c': 3}},
+        {'a', 'b', 'nested'},
+    )
+    assert v.validate_python({'a': 1}) == {'a': 1, 'b': 0, 'nested': {'c': 1}}
+
+
+def test_return_fields_set_not_model_or_typed_dict():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python([1, '2'], return_fields_set=True) == [1, 2]
+
+
+def test_field_examples():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema(), examples=[1, 2])},
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'ctx': {'reason': 'invalid digit at position 0', 'examples': [1, 2]},
+        }
+    ]
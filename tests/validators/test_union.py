@@ -206,6 +206,7 @@ def test_nullable_via_union():
             'loc': ('int',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'hello',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
@@ -237,6 +238,7 @@ def test_union_list_bool_int():
             'loc': ('list[int]', 1),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'true',
+            'ctx': {'reason': 'invalid digit at position 0'},
         },
     ]
 
@@ -267,7 +269,7 @@ def test_one_choice():
     v = SchemaValidator({'type': 'union', 'choices': [{'type': 'str'}]})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false,coerce_numbers_to_str:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="str",validator=Str(StrValidator{strict:false,coerce_numbers_to_str:false,coerce_enum:false}),definitions=[],cache_strings=True)'
     )
     assert v.validate_python('hello') == 'hello'
 
@@ -15,6 +15,7 @@ from pydantic_core import (
     Some,
     ValidationError,
     core_schema,
+    validate_core_schema,
 )
 
 from ..conftest import PyAndJson
@@ -403,6 +404,7 @@ def test_validate_default_error_tuple():
             'loc': (0,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'wrong',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -428,6 +430,7 @@ def test_validate_default_error_typed_dict():
             'loc': ('x',),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'xx',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 
@@ -492,6 +495,73 @@ def test_deepcopy_mutable_defaults():
     assert m2.str_dict_with_default is not m1.str_dict_with_default
 
 
+class MutableDefault:
+    def __init__(self):
+        self.items = []
+
+
+def test_copy_on_default_deep():
+    default = MutableDefault()
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.any_schema(), default=default, copy_on_default='deep')
+                )
+            }
+        )
+    )
+    first = v.validate_python({})['x']
+    first.items.append(1)
+    second = v.validate_python({})['x']
+    assert first is not default
+    assert second is not first
+    assert default.items == []
+    assert second.items == []
+
+
+def test_copy_on_default_shallow():
+    default = [[1]]
+    v = SchemaValidator(
+        core_schema.with_default_schema(core_schema.any_schema(), default=default, copy_on_default='shallow')
+    )
+    value = v.get_default_value().value
+    assert value == [[1]]
+    assert value is not default
+    assert value[0] is default[0]
+
+
+def test_copy_on_default_none():
+    default = []
+    v = SchemaValidator(
+        core_schema.with_default_schema(core_schema.any_schema(), default=default, copy_on_default='none')
+    )
+    assert v.get_default_value().value is default
+
+
+def test_copy_on_default_hashable_not_copied():
+    default = MutableDefault()
+    v = SchemaValidator(core_schema.with_default_schema(core_schema.any_schema(), default=default))
+    assert v.get_default_value().value is default
+
+
+def test_copy_on_default_factory():
+    shared = []
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.any_schema(), default_factory=lambda: shared, copy_on_default='deep'
+        )
+    )
+    assert v.get_default_value().value is not shared
+
+
+def test_copy_on_default_invalid():
+    with pytest.raises(SchemaError, match="Input should be 'none', 'shallow' or 'deep'"):
+        validate_core_schema(
+            core_schema.with_default_schema(core_schema.any_schema(), default=1, copy_on_default='yes')
+        )
+
+
 def test_default_value() -> None:
     s = core_schema.with_default_schema(core_schema.list_schema(core_schema.int_schema()), default=[1, 2, 3])
 
@@ -525,6 +595,7 @@ def test_default_value_validate_default_fail() -> None:
             'loc': (0,),
             'msg': 'Input should be a valid integer, unable to parse string as an integer',
             'input': 'a',
+            'ctx': {'reason': 'invalid digit at position 0'},
         }
     ]
 